@@ -63,7 +63,7 @@ fn main() {
     let st = Instant::now();
     for _ in 0..LOOPS_NUM {
         let value: Value = serde_json::from_str(JSON_TEXT).unwrap();
-        format!("{value}");
+        let _ = format!("{value}");
     }
     let ed = Instant::now();
     println!(
@@ -0,0 +1,408 @@
+/*
+ * Copyright (c) 2023 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * cJSON compatibility layer for the C interface of ylong_json.
+ *
+ * Components using cJSON can switch to ylong_json by including this header
+ * instead of "cJSON.h". The cJSON functions are mapped onto the `ylong_json_*`
+ * functions, which requires ylong_json to be built with the `c_adapter` feature.
+ *
+ * Differences from cJSON:
+ * 1. `cJSON` is an opaque type, so the fields of the struct, such as `child`,
+ *    `next`, `valuestring` and `valuedouble`, can not be accessed. Use
+ *    `cJSON_GetStringValue`, `cJSON_GetNumberValue`, `cJSON_GetArrayItem` and
+ *    `cJSON_ArrayForEach` instead.
+ * 2. `cJSON_GetObjectItem` is case-sensitive, the same as
+ *    `cJSON_GetObjectItemCaseSensitive`.
+ * 3. `cJSON_Print` outputs the same plain text as `cJSON_PrintUnformatted`.
+ * 4. `cJSON_ArrayForEach` only supports arrays.
+ * 5. The returned strings must be freed by `cJSON_free`.
+ * 6. `cJSON_CreateStringReference` refers to the string of the caller, which
+ *    must stay valid and unchanged until the item is deleted or changed. To
+ *    add such a string to an array or an object without copying it, use
+ *    `ylong_json_add_string_reference_to_array` and
+ *    `ylong_json_add_string_reference_to_object`.
+ *    `cJSON_CreateObjectReference`, `cJSON_CreateArrayReference` and
+ *    `cJSON_AddItemReferenceTo*` are not provided, because a child item is
+ *    freed together with its only parent, so an item can not be shared by
+ *    several parents. Use `cJSON_Duplicate` instead.
+ */
+
+#ifndef YLONG_JSON_CJSON_H
+#define YLONG_JSON_CJSON_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef void cJSON;
+typedef int cJSON_bool;
+
+/* Functions of the C interface of ylong_json. */
+cJSON *ylong_json_parse(char *value, char **err_msg);
+cJSON *ylong_json_parse_with_length(const char *value, size_t len, char **err_msg);
+cJSON *ylong_json_parse_with_opts(const char *value, const char **return_parse_end, int require_end,
+                                  char **err_msg);
+void ylong_json_free_string(char *string);
+char *ylong_json_print_unformatted(const cJSON *item);
+int ylong_json_print_preallocated(const cJSON *item, char *buf, int len, int fmt);
+void ylong_json_delete(cJSON *item);
+cJSON *ylong_json_duplicate(const cJSON *item, int recurse);
+int ylong_json_compare(const cJSON *a, const cJSON *b, int case_sensitive);
+
+cJSON *ylong_json_create_null(void);
+int ylong_json_is_null(cJSON *item);
+cJSON *ylong_json_create_bool(int boolean);
+int ylong_json_is_bool(const cJSON *item);
+int ylong_json_get_value_from_bool(const cJSON *boolean, int *value);
+cJSON *ylong_json_create_double_number(double number);
+int ylong_json_is_number(const cJSON *item);
+double ylong_json_get_double_value(const cJSON *item);
+int ylong_json_set_double_value_to_number(cJSON *number, double value);
+cJSON *ylong_json_create_string(const char *string);
+cJSON *ylong_json_create_string_reference(const char *string);
+int ylong_json_is_string(const cJSON *item);
+char *ylong_json_get_string_value(const cJSON *item);
+
+cJSON *ylong_json_create_array(void);
+cJSON *ylong_json_create_double_array(const double *numbers, int count);
+cJSON *ylong_json_create_string_array(const char *const *strings, int count);
+int ylong_json_is_array(const cJSON *item);
+int ylong_json_get_array_size(const cJSON *array, int *size);
+cJSON *ylong_json_get_array_item(const cJSON *array, int index);
+int ylong_json_add_item_to_array(cJSON *array, cJSON *item);
+int ylong_json_add_string_reference_to_array(cJSON *array, const char *string);
+int ylong_json_insert_item_in_array(cJSON *array, int which, cJSON *item);
+int ylong_json_replace_array_item_by_index(cJSON *array, int index, cJSON *new_item);
+cJSON *ylong_json_detach_item_from_array(cJSON *array, int which);
+void ylong_json_delete_array_item_by_index(cJSON *array, int index);
+
+cJSON *ylong_json_create_object(void);
+int ylong_json_is_object(const cJSON *item);
+int ylong_json_has_object_item(cJSON *object, const char *string);
+cJSON *ylong_json_get_object_item(const cJSON *object, const char *string);
+int ylong_json_add_item_to_object(cJSON *object, const char *string, cJSON *item);
+cJSON *ylong_json_add_null_to_object(cJSON *object, const char *string);
+cJSON *ylong_json_add_bool_to_object(cJSON *object, const char *string, int boolean);
+cJSON *ylong_json_add_double_to_object(cJSON *object, const char *string, double number);
+cJSON *ylong_json_add_string_to_object(cJSON *object, const char *string, const char *value);
+cJSON *ylong_json_add_string_reference_to_object(cJSON *object, const char *string, const char *value);
+cJSON *ylong_json_add_array_to_object(cJSON *object, const char *string);
+cJSON *ylong_json_add_object_to_object(cJSON *object, const char *string);
+int ylong_json_replace_object_item_by_index(cJSON *object, const char *index, cJSON *new_item);
+cJSON *ylong_json_detach_item_from_object(cJSON *object, const char *string);
+cJSON *ylong_json_detach_item_via_pointer(cJSON *parent, const cJSON *item);
+void ylong_json_delete_object_item_by_index(cJSON *object, const char *index);
+
+/* Parsing and printing. */
+static inline cJSON *cJSON_ParseWithLength(const char *value, size_t buffer_length)
+{
+    return ylong_json_parse_with_length(value, buffer_length, NULL);
+}
+
+static inline cJSON *cJSON_Parse(const char *value)
+{
+    return ylong_json_parse((char *)value, NULL);
+}
+
+static inline cJSON *cJSON_ParseWithOpts(const char *value, const char **return_parse_end,
+                                         cJSON_bool require_null_terminated)
+{
+    return ylong_json_parse_with_opts(value, return_parse_end, require_null_terminated, NULL);
+}
+
+static inline char *cJSON_Print(const cJSON *item)
+{
+    return ylong_json_print_unformatted(item);
+}
+
+static inline char *cJSON_PrintUnformatted(const cJSON *item)
+{
+    return ylong_json_print_unformatted(item);
+}
+
+static inline cJSON_bool cJSON_PrintPreallocated(cJSON *item, char *buffer, const int length,
+                                                 const cJSON_bool format)
+{
+    return ylong_json_print_preallocated(item, buffer, length, format);
+}
+
+static inline void cJSON_Delete(cJSON *item)
+{
+    ylong_json_delete(item);
+}
+
+static inline void cJSON_free(void *object)
+{
+    ylong_json_free_string((char *)object);
+}
+
+static inline cJSON *cJSON_Duplicate(const cJSON *item, cJSON_bool recurse)
+{
+    return ylong_json_duplicate(item, recurse);
+}
+
+static inline cJSON_bool cJSON_Compare(const cJSON *a, const cJSON *b, const cJSON_bool case_sensitive)
+{
+    return ylong_json_compare(a, b, case_sensitive);
+}
+
+/* Type checks and values. */
+static inline cJSON_bool cJSON_IsNull(const cJSON *item)
+{
+    return ylong_json_is_null((cJSON *)item);
+}
+
+static inline cJSON_bool cJSON_IsBool(const cJSON *item)
+{
+    return ylong_json_is_bool(item);
+}
+
+static inline cJSON_bool cJSON_IsTrue(const cJSON *item)
+{
+    int value = 0;
+    return ylong_json_get_value_from_bool(item, &value) && value;
+}
+
+static inline cJSON_bool cJSON_IsFalse(const cJSON *item)
+{
+    int value = 1;
+    return ylong_json_get_value_from_bool(item, &value) && !value;
+}
+
+static inline cJSON_bool cJSON_IsNumber(const cJSON *item)
+{
+    return ylong_json_is_number(item);
+}
+
+static inline cJSON_bool cJSON_IsString(const cJSON *item)
+{
+    return ylong_json_is_string(item);
+}
+
+static inline cJSON_bool cJSON_IsArray(const cJSON *item)
+{
+    return ylong_json_is_array(item);
+}
+
+static inline cJSON_bool cJSON_IsObject(const cJSON *item)
+{
+    return ylong_json_is_object(item);
+}
+
+static inline char *cJSON_GetStringValue(const cJSON *item)
+{
+    return ylong_json_get_string_value(item);
+}
+
+static inline double cJSON_GetNumberValue(const cJSON *item)
+{
+    return ylong_json_get_double_value(item);
+}
+
+static inline double cJSON_SetNumberHelper(cJSON *object, double number)
+{
+    ylong_json_set_double_value_to_number(object, number);
+    return number;
+}
+
+#define cJSON_SetNumberValue(object, number) cJSON_SetNumberHelper((object), (double)(number))
+
+/* Creation. */
+static inline cJSON *cJSON_CreateNull(void)
+{
+    return ylong_json_create_null();
+}
+
+static inline cJSON *cJSON_CreateTrue(void)
+{
+    return ylong_json_create_bool(1);
+}
+
+static inline cJSON *cJSON_CreateFalse(void)
+{
+    return ylong_json_create_bool(0);
+}
+
+static inline cJSON *cJSON_CreateBool(cJSON_bool boolean)
+{
+    return ylong_json_create_bool(boolean);
+}
+
+static inline cJSON *cJSON_CreateNumber(double num)
+{
+    return ylong_json_create_double_number(num);
+}
+
+static inline cJSON *cJSON_CreateString(const char *string)
+{
+    return ylong_json_create_string(string);
+}
+
+static inline cJSON *cJSON_CreateStringReference(const char *string)
+{
+    return ylong_json_create_string_reference(string);
+}
+
+static inline cJSON *cJSON_CreateArray(void)
+{
+    return ylong_json_create_array();
+}
+
+static inline cJSON *cJSON_CreateObject(void)
+{
+    return ylong_json_create_object();
+}
+
+static inline cJSON *cJSON_CreateDoubleArray(const double *numbers, int count)
+{
+    return ylong_json_create_double_array(numbers, count);
+}
+
+static inline cJSON *cJSON_CreateStringArray(const char *const *strings, int count)
+{
+    return ylong_json_create_string_array(strings, count);
+}
+
+/* Arrays. */
+static inline int cJSON_GetArraySize(const cJSON *array)
+{
+    int size = 0;
+    ylong_json_get_array_size(array, &size);
+    return size;
+}
+
+static inline cJSON *cJSON_GetArrayItem(const cJSON *array, int index)
+{
+    return ylong_json_get_array_item(array, index);
+}
+
+static inline cJSON_bool cJSON_AddItemToArray(cJSON *array, cJSON *item)
+{
+    return ylong_json_add_item_to_array(array, item);
+}
+
+static inline cJSON_bool cJSON_InsertItemInArray(cJSON *array, int which, cJSON *newitem)
+{
+    return ylong_json_insert_item_in_array(array, which, newitem);
+}
+
+static inline cJSON_bool cJSON_ReplaceItemInArray(cJSON *array, int which, cJSON *newitem)
+{
+    return ylong_json_replace_array_item_by_index(array, which, newitem);
+}
+
+static inline cJSON *cJSON_DetachItemFromArray(cJSON *array, int which)
+{
+    return ylong_json_detach_item_from_array(array, which);
+}
+
+static inline void cJSON_DeleteItemFromArray(cJSON *array, int which)
+{
+    ylong_json_delete_array_item_by_index(array, which);
+}
+
+#define cJSON_ArrayForEach(element, array)                                                                \
+    for (int cjson_index_ = 0; ((element) = cJSON_GetArrayItem((array), cjson_index_)) != NULL; cjson_index_++)
+
+/* Objects. */
+static inline cJSON *cJSON_GetObjectItem(const cJSON *object, const char *string)
+{
+    return ylong_json_get_object_item(object, string);
+}
+
+static inline cJSON *cJSON_GetObjectItemCaseSensitive(const cJSON *object, const char *string)
+{
+    return ylong_json_get_object_item(object, string);
+}
+
+static inline cJSON_bool cJSON_HasObjectItem(const cJSON *object, const char *string)
+{
+    return ylong_json_has_object_item((cJSON *)object, string);
+}
+
+static inline cJSON_bool cJSON_AddItemToObject(cJSON *object, const char *string, cJSON *item)
+{
+    return ylong_json_add_item_to_object(object, string, item);
+}
+
+static inline cJSON_bool cJSON_ReplaceItemInObject(cJSON *object, const char *string, cJSON *newitem)
+{
+    return ylong_json_replace_object_item_by_index(object, string, newitem);
+}
+
+static inline cJSON *cJSON_DetachItemFromObject(cJSON *object, const char *string)
+{
+    return ylong_json_detach_item_from_object(object, string);
+}
+
+static inline cJSON *cJSON_DetachItemViaPointer(cJSON *parent, cJSON *item)
+{
+    return ylong_json_detach_item_via_pointer(parent, item);
+}
+
+static inline void cJSON_DeleteItemFromObject(cJSON *object, const char *string)
+{
+    ylong_json_delete_object_item_by_index(object, string);
+}
+
+static inline cJSON *cJSON_AddNullToObject(cJSON *object, const char *name)
+{
+    return ylong_json_add_null_to_object(object, name);
+}
+
+static inline cJSON *cJSON_AddTrueToObject(cJSON *object, const char *name)
+{
+    return ylong_json_add_bool_to_object(object, name, 1);
+}
+
+static inline cJSON *cJSON_AddFalseToObject(cJSON *object, const char *name)
+{
+    return ylong_json_add_bool_to_object(object, name, 0);
+}
+
+static inline cJSON *cJSON_AddBoolToObject(cJSON *object, const char *name, cJSON_bool boolean)
+{
+    return ylong_json_add_bool_to_object(object, name, boolean);
+}
+
+static inline cJSON *cJSON_AddNumberToObject(cJSON *object, const char *name, double number)
+{
+    return ylong_json_add_double_to_object(object, name, number);
+}
+
+static inline cJSON *cJSON_AddStringToObject(cJSON *object, const char *name, const char *string)
+{
+    return ylong_json_add_string_to_object(object, name, string);
+}
+
+static inline cJSON *cJSON_AddArrayToObject(cJSON *object, const char *name)
+{
+    return ylong_json_add_array_to_object(object, name);
+}
+
+static inline cJSON *cJSON_AddObjectToObject(cJSON *object, const char *name)
+{
+    return ylong_json_add_object_to_object(object, name);
+}
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* YLONG_JSON_CJSON_H */
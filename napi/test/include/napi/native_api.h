@@ -0,0 +1,32 @@
+/*
+ * Copyright (c) 2023 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Replaces the header of `napi:ace_napi` with the Node-API of Node.js, so the
+ * module can be tested on a host without an ArkTS runtime.
+ */
+
+#ifndef YLONG_JSON_TEST_NATIVE_API_H
+#define YLONG_JSON_TEST_NATIVE_API_H
+
+#include <node_api.h>
+
+#define DECLARE_NAPI_FUNCTION(name, func) \
+    { (name), nullptr, (func), nullptr, nullptr, nullptr, napi_default, nullptr }
+
+#define DECLARE_NAPI_PROPERTY(name, val) \
+    { (name), nullptr, nullptr, nullptr, nullptr, (val), napi_default, nullptr }
+
+#endif // YLONG_JSON_TEST_NATIVE_API_H
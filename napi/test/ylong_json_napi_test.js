@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) 2023 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Tests the NAPI module with Node.js, run by `tests/sdv_napi_test.rs`.
+ *
+ * Usage: node --expose-gc ylong_json_napi_test.js <path of the module>
+ */
+
+'use strict';
+
+const assert = require('assert');
+const ylongJson = require(process.argv[2]);
+
+const INVALID_PARAM = String(ylongJson.ErrorCode.INVALID_PARAM);
+
+function assertInvalid(func) {
+  assert.throws(func, (err) => err.code === INVALID_PARAM);
+}
+
+function testParseAndStringify() {
+  const root = ylongJson.parse('{"name": "ylong", "list": [1, 2.5, true, null]}');
+  assert.strictEqual(ylongJson.getType(root), ylongJson.ValueType.OBJECT);
+  assert.strictEqual(ylongJson.getString(ylongJson.getObjectItem(root, 'name')), 'ylong');
+  const list = ylongJson.getObjectItem(root, 'list');
+  assert.strictEqual(ylongJson.getArraySize(list), 4);
+  assert.strictEqual(ylongJson.getNumber(ylongJson.getArrayItem(list, 1)), 2.5);
+  assert.strictEqual(ylongJson.getBoolean(ylongJson.getArrayItem(list, 2)), true);
+  assert.strictEqual(ylongJson.getArrayItem(list, 4), undefined);
+  assert.deepStrictEqual(ylongJson.getObjectKeys(root).sort(), ['list', 'name']);
+  assert.deepStrictEqual(JSON.parse(ylongJson.stringify(root)), { name: 'ylong', list: [1, 2.5, true, null] });
+  ylongJson.destroy(root);
+
+  assert.throws(() => ylongJson.parse('[1, 2'), (err) => err.code === String(ylongJson.ErrorCode.EOF));
+}
+
+function testBuild() {
+  const root = ylongJson.createObject();
+  const array = ylongJson.setObjectItem(root, 'array', ylongJson.createArray());
+  ylongJson.addItemToArray(array, ylongJson.createNumber(1));
+  ylongJson.addItemToArray(array, ylongJson.createString('a'));
+  const copy = ylongJson.duplicate(root);
+  ylongJson.removeArrayItem(array, 0);
+  assert.strictEqual(ylongJson.stringify(root), '{"array":["a"]}');
+  assert.strictEqual(ylongJson.stringify(copy), '{"array":[1,"a"]}');
+  ylongJson.removeObjectItem(root, 'array');
+  assert.strictEqual(ylongJson.stringify(root), '{}');
+  ylongJson.destroy(root);
+  ylongJson.destroy(copy);
+}
+
+function testDestroyed() {
+  const root = ylongJson.parse('[[1]]');
+  const child = ylongJson.getArrayItem(root, 0);
+  const grandchild = ylongJson.getArrayItem(child, 0);
+  ylongJson.destroy(root);
+  // Destroying twice or using any handle of the value afterwards throws.
+  assertInvalid(() => ylongJson.destroy(root));
+  assertInvalid(() => ylongJson.stringify(root));
+  assertInvalid(() => ylongJson.getArraySize(child));
+  assertInvalid(() => ylongJson.getNumber(grandchild));
+}
+
+function testBorrowed() {
+  const root = ylongJson.parse('{"a": [1], "b": 2}');
+  const a = ylongJson.getObjectItem(root, 'a');
+  // A borrowed handle can be neither destroyed nor moved into another value.
+  assertInvalid(() => ylongJson.destroy(a));
+  const other = ylongJson.createArray();
+  assertInvalid(() => ylongJson.addItemToArray(other, a));
+  assertInvalid(() => ylongJson.addItemToArray(a, root));
+  assert.strictEqual(ylongJson.stringify(a), '[1]');
+
+  // Changing the parent invalidates the handles borrowed from it.
+  const item = ylongJson.getArrayItem(a, 0);
+  ylongJson.addItemToArray(a, ylongJson.createNull());
+  assertInvalid(() => ylongJson.getNumber(item));
+  assert.strictEqual(ylongJson.getNumber(ylongJson.getArrayItem(a, 0)), 1);
+  ylongJson.removeObjectItem(root, 'b');
+  assertInvalid(() => ylongJson.stringify(a));
+  assert.strictEqual(ylongJson.stringify(root), '{"a":[1,null]}');
+
+  ylongJson.destroy(other);
+  ylongJson.destroy(root);
+}
+
+function testMoved() {
+  const array = ylongJson.createArray();
+  const item = ylongJson.parse('{"x": 1}');
+  const x = ylongJson.getObjectItem(item, 'x');
+  const added = ylongJson.addItemToArray(array, item);
+  // The array owns the item, so its old handles are invalid and it is not destroyed twice.
+  assertInvalid(() => ylongJson.destroy(item));
+  assertInvalid(() => ylongJson.getNumber(x));
+  assertInvalid(() => ylongJson.addItemToArray(array, item));
+  assertInvalid(() => ylongJson.destroy(added));
+  assert.strictEqual(ylongJson.stringify(added), '{"x":1}');
+  assert.strictEqual(ylongJson.stringify(array), '[{"x":1}]');
+
+  // A value can not be added to itself.
+  assertInvalid(() => ylongJson.addItemToArray(array, array));
+  ylongJson.destroy(array);
+}
+
+function testInvalidParameters() {
+  assertInvalid(() => ylongJson.stringify({}));
+  assertInvalid(() => ylongJson.stringify(1));
+  assertInvalid(() => ylongJson.getType());
+  const number = ylongJson.createNumber(1);
+  assertInvalid(() => ylongJson.getString(number));
+  assertInvalid(() => ylongJson.addItemToArray(number, ylongJson.createNull()));
+  ylongJson.destroy(number);
+}
+
+function testCollected() {
+  // The values which are not destroyed are released with their handles.
+  for (let i = 0; i < 1000; i++) {
+    const root = ylongJson.parse('{"a": [1, 2, 3]}');
+    ylongJson.getArrayItem(ylongJson.getObjectItem(root, 'a'), 0);
+  }
+  global.gc();
+}
+
+testParseAndStringify();
+testBuild();
+testDestroyed();
+testBorrowed();
+testMoved();
+testInvalidParameters();
+testCollected();
+console.log('ylong_json_napi_test passed');
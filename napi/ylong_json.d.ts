@@ -0,0 +1,156 @@
+/*
+ * Copyright (c) 2023 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/**
+ * Parses, serializes and edits JSON texts with ylong_json.
+ *
+ * A JSON value is represented by an opaque handle. A handle returned by `parse`,
+ * `duplicate` and `create*` owns its value and should be released by `destroy`,
+ * otherwise the value is released after the handle and all the handles borrowed
+ * from it are garbage collected. A handle returned by `getArrayItem`,
+ * `getObjectItem`, `addItemToArray` and `setObjectItem` is borrowed from its
+ * parent, and is invalid after the parent is changed or destroyed.
+ *
+ * Using an invalid handle throws an error with `ErrorCode.INVALID_PARAM`, and so
+ * does destroying a borrowed handle or a handle which has been destroyed.
+ *
+ * Failed calls throw an error whose `code` is one of `ErrorCode`.
+ */
+declare namespace ylongJson {
+  /**
+   * An opaque handle of a JSON value.
+   */
+  interface JsonHandle {}
+
+  /**
+   * The types of JSON values.
+   */
+  enum ValueType {
+    NULL = 0,
+    BOOLEAN = 1,
+    NUMBER = 2,
+    STRING = 3,
+    ARRAY = 4,
+    OBJECT = 5
+  }
+
+  /**
+   * The error codes thrown by this module.
+   */
+  enum ErrorCode {
+    /** The JSON text has a syntax error. */
+    SYNTAX = 1,
+    /** The JSON text ends unexpectedly. */
+    EOF = 2,
+    /** The JSON text is nested too deeply. */
+    DEPTH = 3,
+    /** The JSON text contains an invalid UTF-8 sequence. */
+    UTF8 = 4,
+    /** A number can not be represented. */
+    NUMBER = 5,
+    /** Other errors. */
+    OTHER = 7,
+    /** The parameters are invalid, such as a value of a wrong type or an invalid handle. */
+    INVALID_PARAM = 401
+  }
+
+  /**
+   * Parses a JSON text. Throws an error with the code of the parsing error if it fails.
+   */
+  function parse(text: string): JsonHandle;
+
+  /**
+   * Gets the line number, column number and byte offset of the error of the last parsing
+   * on the current thread, or an empty array if the position is unknown. The offset counts
+   * the UTF-8 bytes from the beginning of the text.
+   */
+  function getLastErrorPosition(): number[];
+
+  /**
+   * Serializes a JSON value into a text without whitespace.
+   */
+  function stringify(handle: JsonHandle): string;
+
+  /**
+   * Releases a JSON value which is owned by the handle. All the handles of the value are
+   * invalid afterwards. Throws an error if the handle is borrowed or invalid.
+   */
+  function destroy(handle: JsonHandle): void;
+
+  /**
+   * Deep copies a JSON value. The copy must be released by `destroy`.
+   */
+  function duplicate(handle: JsonHandle): JsonHandle;
+
+  /**
+   * Gets the type of a JSON value.
+   */
+  function getType(handle: JsonHandle): ValueType;
+
+  function createNull(): JsonHandle;
+
+  function createBoolean(value: boolean): JsonHandle;
+
+  /**
+   * Creates a JSON number. Integers within the safe range are stored as integers.
+   */
+  function createNumber(value: number): JsonHandle;
+
+  function createString(value: string): JsonHandle;
+
+  function createArray(): JsonHandle;
+
+  function createObject(): JsonHandle;
+
+  function getBoolean(handle: JsonHandle): boolean;
+
+  function getNumber(handle: JsonHandle): number;
+
+  function getString(handle: JsonHandle): string;
+
+  function getArraySize(array: JsonHandle): number;
+
+  /**
+   * Gets the item at the index of an array, or undefined if the index is out of range.
+   */
+  function getArrayItem(array: JsonHandle, index: number): JsonHandle | undefined;
+
+  /**
+   * Adds an item to the end of an array, which takes the ownership of the item.
+   * The item must be owned by its handle and must not contain the array. The handles
+   * of the item are invalid afterwards, use the returned one instead.
+   */
+  function addItemToArray(array: JsonHandle, item: JsonHandle): JsonHandle;
+
+  function removeArrayItem(array: JsonHandle, index: number): void;
+
+  /**
+   * Gets the item of the key in an object, or undefined if the key does not exist.
+   */
+  function getObjectItem(object: JsonHandle, key: string): JsonHandle | undefined;
+
+  /**
+   * Sets the item of the key in an object, which takes the ownership of the item.
+   * The item must be owned by its handle and must not contain the object. The handles
+   * of the item are invalid afterwards, use the returned one instead.
+   */
+  function setObjectItem(object: JsonHandle, key: string, item: JsonHandle): JsonHandle;
+
+  function removeObjectItem(object: JsonHandle, key: string): void;
+
+  function getObjectKeys(object: JsonHandle): string[];
+}
+
+export default ylongJson;
@@ -0,0 +1,778 @@
+/*
+ * Copyright (c) 2023 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * NAPI module exposing the C interface of ylong_json to ArkTS.
+ *
+ * A JSON value is represented by an opaque handle, which is a JS object
+ * wrapping the pointer returned by the C interface. The lifetime rules are the
+ * same as the C interface, and they are checked by the handles:
+ * 1. A handle returned by `parse`, `duplicate` and `create*` owns its value
+ *    and should be released by `destroy`. If it is not, the value is released
+ *    after the handle and all the handles borrowed from it are collected.
+ * 2. A handle returned by `getArrayItem`, `getObjectItem`, `addItemToArray`
+ *    and `setObjectItem` is borrowed from its parent and is invalid after the
+ *    parent is changed or destroyed. It can not be destroyed.
+ * 3. `addItemToArray` and `setObjectItem` take the ownership of the item. The
+ *    handle passed in is invalid afterwards, use the returned one instead.
+ * Using an invalid handle, destroying it or a borrowed handle throws an error
+ * with `ERR_INVALID_PARAM` instead of touching the released memory.
+ */
+
+#include <cmath>
+#include <cstdint>
+#include <memory>
+#include <mutex>
+#include <string>
+#include <unordered_map>
+#include <unordered_set>
+#include <vector>
+
+#include "napi/native_api.h"
+#include "napi/native_node_api.h"
+
+extern "C" {
+void *ylong_json_parse_with_length(const char *value, size_t len, char **err_msg);
+int ylong_json_get_last_error(void);
+int ylong_json_get_last_error_position(size_t *line, size_t *column);
+int ylong_json_get_last_error_offset(size_t *offset);
+void ylong_json_free_string(char *string);
+char *ylong_json_print_unformatted(const void *item);
+void ylong_json_delete(void *item);
+void *ylong_json_duplicate(const void *item, int recurse);
+
+void *ylong_json_create_null(void);
+int ylong_json_is_null(void *item);
+void *ylong_json_create_bool(int boolean);
+int ylong_json_is_bool(const void *item);
+int ylong_json_get_value_from_bool(const void *boolean, int *value);
+void *ylong_json_create_double_number(double number);
+void *ylong_json_create_int_number(long long number);
+int ylong_json_is_number(const void *item);
+int ylong_json_get_double_value_from_number(const void *number, double *value);
+void *ylong_json_create_string_with_length(const char *string, size_t len);
+int ylong_json_is_string(const void *item);
+int ylong_json_get_value_from_string_with_length(const void *string, char **value, size_t *len);
+
+void *ylong_json_create_array(void);
+int ylong_json_is_array(const void *item);
+int ylong_json_get_array_size(const void *array, int *size);
+void *ylong_json_get_array_item(const void *array, int index);
+int ylong_json_add_item_to_array(void *array, void *item);
+void ylong_json_delete_array_item_by_index(void *array, int index);
+
+void *ylong_json_create_object(void);
+int ylong_json_is_object(const void *item);
+void *ylong_json_get_object_item(const void *object, const char *string);
+int ylong_json_add_item_to_object(void *object, const char *string, void *item);
+void ylong_json_delete_object_item_by_index(void *object, const char *index);
+void *ylong_json_object_iter_create(void *object);
+int ylong_json_object_iter_next(void *iter, const char **key, void **value);
+void ylong_json_object_iter_free(void *iter);
+}
+
+namespace {
+// The same as `SUCCESS` of the C interface.
+constexpr int YLONG_JSON_SUCCESS = 1;
+
+// Error codes of the C interface, see `YLONG_JSON_ERR_*` in `src/adapter.rs`.
+constexpr int32_t ERR_SYNTAX = 1;
+constexpr int32_t ERR_EOF = 2;
+constexpr int32_t ERR_DEPTH = 3;
+constexpr int32_t ERR_UTF8 = 4;
+constexpr int32_t ERR_NUMBER = 5;
+constexpr int32_t ERR_OTHER = 7;
+// The parameters are invalid, such as a wrong type of value or an invalid handle.
+constexpr int32_t ERR_INVALID_PARAM = 401;
+
+// The types of JSON values returned by `getType`.
+enum ValueType : int32_t {
+    TYPE_NULL = 0,
+    TYPE_BOOLEAN = 1,
+    TYPE_NUMBER = 2,
+    TYPE_STRING = 3,
+    TYPE_ARRAY = 4,
+    TYPE_OBJECT = 5,
+};
+
+// Integers in this range are represented exactly by a double.
+constexpr double MAX_SAFE_INTEGER = 9007199254740991.0;
+
+constexpr size_t MAX_ARGC = 3;
+
+void ThrowError(napi_env env, int32_t code, const std::string &message)
+{
+    std::string codeStr = std::to_string(code);
+    napi_throw_error(env, codeStr.c_str(), message.c_str());
+}
+
+bool GetArgs(napi_env env, napi_callback_info info, size_t expected, napi_value *argv)
+{
+    size_t argc = MAX_ARGC;
+    napi_value args[MAX_ARGC] = { nullptr };
+    if (napi_get_cb_info(env, info, &argc, args, nullptr, nullptr) != napi_ok || argc < expected) {
+        ThrowError(env, ERR_INVALID_PARAM, "Wrong number of arguments.");
+        return false;
+    }
+    for (size_t i = 0; i < expected; i++) {
+        argv[i] = args[i];
+    }
+    return true;
+}
+
+napi_value Undefined(napi_env env)
+{
+    napi_value result = nullptr;
+    napi_get_undefined(env, &result);
+    return result;
+}
+
+// The values which can be reached from an owned root. The tree is released
+// with the last handle of it, and releases the root if it is still alive.
+struct Tree {
+    void *root = nullptr;
+    // False after the root is destroyed or moved into another value.
+    bool alive = true;
+    // The times each array or object is changed, which invalidate the handles
+    // borrowed from it.
+    std::unordered_map<void *, uint64_t> versions;
+
+    explicit Tree(void *item) : root(item) {}
+    Tree(const Tree &) = delete;
+    Tree &operator=(const Tree &) = delete;
+    ~Tree()
+    {
+        if (alive) {
+            ylong_json_delete(root);
+        }
+    }
+
+    uint64_t VersionOf(void *container) const
+    {
+        auto iter = versions.find(container);
+        return iter != versions.end() ? iter->second : 0;
+    }
+};
+
+struct Handle {
+    void *item = nullptr;
+    std::shared_ptr<Tree> tree;
+    // The handle which the item is borrowed from, or null if the handle owns the root.
+    std::shared_ptr<Handle> parent;
+    // The version of the parent when the item is got.
+    uint64_t parentVersion = 0;
+
+    bool IsOwned() const
+    {
+        return parent == nullptr;
+    }
+
+    bool IsValid() const
+    {
+        if (!tree->alive) {
+            return false;
+        }
+        return parent == nullptr || (parent->IsValid() && tree->VersionOf(parent->item) == parentVersion);
+    }
+
+    // Invalidates the handles borrowed from the item, called after it is changed.
+    void Touch() const
+    {
+        tree->versions[item]++;
+    }
+};
+
+using HandleRef = std::shared_ptr<Handle>;
+
+// The data wrapped by the JS objects of all the alive handles, so the data of
+// other objects is never used as a handle.
+std::mutex g_handlesMutex;
+std::unordered_set<void *> g_handles;
+
+void FinalizeHandle(napi_env env, void *data, void *hint)
+{
+    {
+        std::lock_guard<std::mutex> lock(g_handlesMutex);
+        g_handles.erase(data);
+    }
+    delete static_cast<HandleRef *>(data);
+}
+
+napi_value WrapHandle(napi_env env, HandleRef handle)
+{
+    napi_value result = nullptr;
+    if (napi_create_object(env, &result) != napi_ok) {
+        return nullptr;
+    }
+    auto data = new HandleRef(std::move(handle));
+    {
+        std::lock_guard<std::mutex> lock(g_handlesMutex);
+        g_handles.insert(data);
+    }
+    if (napi_wrap(env, result, data, FinalizeHandle, nullptr, nullptr) != napi_ok) {
+        FinalizeHandle(env, data, nullptr);
+        ThrowError(env, ERR_OTHER, "Failed to create a JSON handle.");
+        return nullptr;
+    }
+    return result;
+}
+
+// Creates a handle which owns the item.
+napi_value CreateHandle(napi_env env, void *item)
+{
+    if (item == nullptr) {
+        return Undefined(env);
+    }
+    auto handle = std::make_shared<Handle>();
+    handle->item = item;
+    handle->tree = std::make_shared<Tree>(item);
+    return WrapHandle(env, std::move(handle));
+}
+
+// Creates a handle of an item which is borrowed from the parent.
+napi_value CreateBorrowedHandle(napi_env env, const HandleRef &parent, void *item)
+{
+    if (item == nullptr) {
+        return Undefined(env);
+    }
+    auto handle = std::make_shared<Handle>();
+    handle->item = item;
+    handle->tree = parent->tree;
+    handle->parent = parent;
+    handle->parentVersion = parent->tree->VersionOf(parent->item);
+    return WrapHandle(env, std::move(handle));
+}
+
+bool GetHandle(napi_env env, napi_value value, HandleRef &handle)
+{
+    void *data = nullptr;
+    napi_valuetype type = napi_undefined;
+    napi_typeof(env, value, &type);
+    if (type == napi_object && napi_unwrap(env, value, &data) == napi_ok) {
+        std::lock_guard<std::mutex> lock(g_handlesMutex);
+        if (g_handles.count(data) == 0) {
+            data = nullptr;
+        }
+    } else {
+        data = nullptr;
+    }
+    if (data == nullptr) {
+        ThrowError(env, ERR_INVALID_PARAM, "The parameter is not a JSON handle.");
+        return false;
+    }
+    handle = *static_cast<HandleRef *>(data);
+    if (!handle->IsValid()) {
+        ThrowError(env, ERR_INVALID_PARAM, "The JSON handle has been destroyed, moved or invalidated by its parent.");
+        return false;
+    }
+    return true;
+}
+
+// Gets a handle which owns its root, so the item can be moved into the container.
+bool GetOwnedItem(napi_env env, napi_value value, const HandleRef &container, HandleRef &item)
+{
+    if (!GetHandle(env, value, item)) {
+        return false;
+    }
+    if (!item->IsOwned()) {
+        ThrowError(env, ERR_INVALID_PARAM, "A borrowed JSON handle can not be moved, duplicate it instead.");
+        return false;
+    }
+    if (item->tree == container->tree) {
+        ThrowError(env, ERR_INVALID_PARAM, "A JSON value can not be added to itself.");
+        return false;
+    }
+    return true;
+}
+
+bool GetStringArg(napi_env env, napi_value value, std::string &str)
+{
+    size_t len = 0;
+    if (napi_get_value_string_utf8(env, value, nullptr, 0, &len) != napi_ok) {
+        ThrowError(env, ERR_INVALID_PARAM, "The parameter is not a string.");
+        return false;
+    }
+    std::vector<char> buf(len + 1);
+    napi_get_value_string_utf8(env, value, buf.data(), buf.size(), &len);
+    str.assign(buf.data(), len);
+    return true;
+}
+
+bool GetIndex(napi_env env, napi_value value, int32_t &index)
+{
+    if (napi_get_value_int32(env, value, &index) != napi_ok || index < 0) {
+        ThrowError(env, ERR_INVALID_PARAM, "The parameter is not a valid index.");
+        return false;
+    }
+    return true;
+}
+
+// parse(text: string): JsonHandle
+napi_value Parse(napi_env env, napi_callback_info info)
+{
+    napi_value argv[1] = { nullptr };
+    std::string text;
+    if (!GetArgs(env, info, 1, argv) || !GetStringArg(env, argv[0], text)) {
+        return nullptr;
+    }
+
+    char *errMsg = nullptr;
+    void *item = ylong_json_parse_with_length(text.c_str(), text.size(), &errMsg);
+    if (item == nullptr) {
+        std::string message = errMsg != nullptr ? errMsg : "Failed to parse the JSON text.";
+        ylong_json_free_string(errMsg);
+        int32_t code = ylong_json_get_last_error();
+        ThrowError(env, code != 0 ? code : ERR_OTHER, message);
+        return nullptr;
+    }
+    return CreateHandle(env, item);
+}
+
+// getLastErrorPosition(): number[]
+napi_value GetLastErrorPosition(napi_env env, napi_callback_info info)
+{
+    size_t line = 0;
+    size_t column = 0;
+    size_t offset = 0;
+    napi_value result = nullptr;
+    napi_create_array(env, &result);
+    if (ylong_json_get_last_error_position(&line, &column) == YLONG_JSON_SUCCESS &&
+        ylong_json_get_last_error_offset(&offset) == YLONG_JSON_SUCCESS) {
+        napi_value value = nullptr;
+        napi_create_int64(env, static_cast<int64_t>(line), &value);
+        napi_set_element(env, result, 0, value);
+        napi_create_int64(env, static_cast<int64_t>(column), &value);
+        napi_set_element(env, result, 1, value);
+        napi_create_int64(env, static_cast<int64_t>(offset), &value);
+        napi_set_element(env, result, 2, value);
+    }
+    return result;
+}
+
+// stringify(handle: JsonHandle): string
+napi_value Stringify(napi_env env, napi_callback_info info)
+{
+    napi_value argv[1] = { nullptr };
+    HandleRef item;
+    if (!GetArgs(env, info, 1, argv) || !GetHandle(env, argv[0], item)) {
+        return nullptr;
+    }
+
+    char *text = ylong_json_print_unformatted(item->item);
+    if (text == nullptr) {
+        ThrowError(env, ERR_OTHER, "Failed to serialize the JSON value.");
+        return nullptr;
+    }
+    napi_value result = nullptr;
+    napi_create_string_utf8(env, text, NAPI_AUTO_LENGTH, &result);
+    ylong_json_free_string(text);
+    return result;
+}
+
+// destroy(handle: JsonHandle): void
+napi_value Destroy(napi_env env, napi_callback_info info)
+{
+    napi_value argv[1] = { nullptr };
+    HandleRef item;
+    if (!GetArgs(env, info, 1, argv) || !GetHandle(env, argv[0], item)) {
+        return nullptr;
+    }
+    if (!item->IsOwned()) {
+        ThrowError(env, ERR_INVALID_PARAM, "A borrowed JSON handle can not be destroyed, destroy its root instead.");
+        return nullptr;
+    }
+    // The handles of the tree are invalid afterwards, and the tree will not release the root again.
+    item->tree->alive = false;
+    ylong_json_delete(item->item);
+    return Undefined(env);
+}
+
+// duplicate(handle: JsonHandle): JsonHandle
+napi_value Duplicate(napi_env env, napi_callback_info info)
+{
+    napi_value argv[1] = { nullptr };
+    HandleRef item;
+    if (!GetArgs(env, info, 1, argv) || !GetHandle(env, argv[0], item)) {
+        return nullptr;
+    }
+    return CreateHandle(env, ylong_json_duplicate(item->item, 1));
+}
+
+// getType(handle: JsonHandle): ValueType
+napi_value GetType(napi_env env, napi_callback_info info)
+{
+    napi_value argv[1] = { nullptr };
+    HandleRef item;
+    if (!GetArgs(env, info, 1, argv) || !GetHandle(env, argv[0], item)) {
+        return nullptr;
+    }
+
+    int32_t type = TYPE_NULL;
+    if (ylong_json_is_bool(item->item)) {
+        type = TYPE_BOOLEAN;
+    } else if (ylong_json_is_number(item->item)) {
+        type = TYPE_NUMBER;
+    } else if (ylong_json_is_string(item->item)) {
+        type = TYPE_STRING;
+    } else if (ylong_json_is_array(item->item)) {
+        type = TYPE_ARRAY;
+    } else if (ylong_json_is_object(item->item)) {
+        type = TYPE_OBJECT;
+    }
+    napi_value result = nullptr;
+    napi_create_int32(env, type, &result);
+    return result;
+}
+
+// createNull(): JsonHandle
+napi_value CreateNull(napi_env env, napi_callback_info info)
+{
+    return CreateHandle(env, ylong_json_create_null());
+}
+
+// createBoolean(value: boolean): JsonHandle
+napi_value CreateBoolean(napi_env env, napi_callback_info info)
+{
+    napi_value argv[1] = { nullptr };
+    bool value = false;
+    if (!GetArgs(env, info, 1, argv)) {
+        return nullptr;
+    }
+    if (napi_get_value_bool(env, argv[0], &value) != napi_ok) {
+        ThrowError(env, ERR_INVALID_PARAM, "The parameter is not a boolean.");
+        return nullptr;
+    }
+    return CreateHandle(env, ylong_json_create_bool(value ? 1 : 0));
+}
+
+// createNumber(value: number): JsonHandle
+napi_value CreateNumber(napi_env env, napi_callback_info info)
+{
+    napi_value argv[1] = { nullptr };
+    double value = 0.0;
+    if (!GetArgs(env, info, 1, argv)) {
+        return nullptr;
+    }
+    if (napi_get_value_double(env, argv[0], &value) != napi_ok || !std::isfinite(value)) {
+        ThrowError(env, ERR_NUMBER, "The parameter is not a finite number.");
+        return nullptr;
+    }
+    // Integers are stored as integers, so they are printed without a fraction.
+    if (std::trunc(value) == value && std::fabs(value) <= MAX_SAFE_INTEGER) {
+        return CreateHandle(env, ylong_json_create_int_number(static_cast<long long>(value)));
+    }
+    return CreateHandle(env, ylong_json_create_double_number(value));
+}
+
+// createString(value: string): JsonHandle
+napi_value CreateString(napi_env env, napi_callback_info info)
+{
+    napi_value argv[1] = { nullptr };
+    std::string value;
+    if (!GetArgs(env, info, 1, argv) || !GetStringArg(env, argv[0], value)) {
+        return nullptr;
+    }
+    return CreateHandle(env, ylong_json_create_string_with_length(value.data(), value.size()));
+}
+
+// createArray(): JsonHandle
+napi_value CreateArray(napi_env env, napi_callback_info info)
+{
+    return CreateHandle(env, ylong_json_create_array());
+}
+
+// createObject(): JsonHandle
+napi_value CreateObject(napi_env env, napi_callback_info info)
+{
+    return CreateHandle(env, ylong_json_create_object());
+}
+
+// getBoolean(handle: JsonHandle): boolean
+napi_value GetBoolean(napi_env env, napi_callback_info info)
+{
+    napi_value argv[1] = { nullptr };
+    HandleRef item;
+    if (!GetArgs(env, info, 1, argv) || !GetHandle(env, argv[0], item)) {
+        return nullptr;
+    }
+
+    int value = 0;
+    if (ylong_json_get_value_from_bool(item->item, &value) != YLONG_JSON_SUCCESS) {
+        ThrowError(env, ERR_INVALID_PARAM, "The JSON value is not a boolean.");
+        return nullptr;
+    }
+    napi_value result = nullptr;
+    napi_get_boolean(env, value != 0, &result);
+    return result;
+}
+
+// getNumber(handle: JsonHandle): number
+napi_value GetNumber(napi_env env, napi_callback_info info)
+{
+    napi_value argv[1] = { nullptr };
+    HandleRef item;
+    if (!GetArgs(env, info, 1, argv) || !GetHandle(env, argv[0], item)) {
+        return nullptr;
+    }
+
+    double value = 0.0;
+    if (ylong_json_get_double_value_from_number(item->item, &value) != YLONG_JSON_SUCCESS) {
+        ThrowError(env, ERR_INVALID_PARAM, "The JSON value is not a number.");
+        return nullptr;
+    }
+    napi_value result = nullptr;
+    napi_create_double(env, value, &result);
+    return result;
+}
+
+// getString(handle: JsonHandle): string
+napi_value GetString(napi_env env, napi_callback_info info)
+{
+    napi_value argv[1] = { nullptr };
+    HandleRef item;
+    if (!GetArgs(env, info, 1, argv) || !GetHandle(env, argv[0], item)) {
+        return nullptr;
+    }
+
+    char *value = nullptr;
+    size_t len = 0;
+    if (ylong_json_get_value_from_string_with_length(item->item, &value, &len) != YLONG_JSON_SUCCESS) {
+        ThrowError(env, ERR_INVALID_PARAM, "The JSON value is not a string.");
+        return nullptr;
+    }
+    napi_value result = nullptr;
+    napi_create_string_utf8(env, value, len, &result);
+    return result;
+}
+
+// getArraySize(array: JsonHandle): number
+napi_value GetArraySize(napi_env env, napi_callback_info info)
+{
+    napi_value argv[1] = { nullptr };
+    HandleRef array;
+    if (!GetArgs(env, info, 1, argv) || !GetHandle(env, argv[0], array)) {
+        return nullptr;
+    }
+
+    int size = 0;
+    if (ylong_json_get_array_size(array->item, &size) != YLONG_JSON_SUCCESS) {
+        ThrowError(env, ERR_INVALID_PARAM, "The JSON value is not an array.");
+        return nullptr;
+    }
+    napi_value result = nullptr;
+    napi_create_int32(env, size, &result);
+    return result;
+}
+
+// getArrayItem(array: JsonHandle, index: number): JsonHandle | undefined
+napi_value GetArrayItem(napi_env env, napi_callback_info info)
+{
+    napi_value argv[2] = { nullptr };
+    HandleRef array;
+    int32_t index = 0;
+    if (!GetArgs(env, info, 2, argv) || !GetHandle(env, argv[0], array) || !GetIndex(env, argv[1], index)) {
+        return nullptr;
+    }
+    return CreateBorrowedHandle(env, array, ylong_json_get_array_item(array->item, index));
+}
+
+// addItemToArray(array: JsonHandle, item: JsonHandle): JsonHandle
+napi_value AddItemToArray(napi_env env, napi_callback_info info)
+{
+    napi_value argv[2] = { nullptr };
+    HandleRef array;
+    HandleRef item;
+    if (!GetArgs(env, info, 2, argv) || !GetHandle(env, argv[0], array) ||
+        !GetOwnedItem(env, argv[1], array, item)) {
+        return nullptr;
+    }
+
+    if (ylong_json_add_item_to_array(array->item, item->item) != YLONG_JSON_SUCCESS) {
+        ThrowError(env, ERR_INVALID_PARAM, "The JSON value is not an array.");
+        return nullptr;
+    }
+    // The array owns the item now, and may have moved the other items.
+    item->tree->alive = false;
+    array->Touch();
+    int size = 0;
+    ylong_json_get_array_size(array->item, &size);
+    return CreateBorrowedHandle(env, array, ylong_json_get_array_item(array->item, size - 1));
+}
+
+// removeArrayItem(array: JsonHandle, index: number): void
+napi_value RemoveArrayItem(napi_env env, napi_callback_info info)
+{
+    napi_value argv[2] = { nullptr };
+    HandleRef array;
+    int32_t index = 0;
+    if (!GetArgs(env, info, 2, argv) || !GetHandle(env, argv[0], array) || !GetIndex(env, argv[1], index)) {
+        return nullptr;
+    }
+    ylong_json_delete_array_item_by_index(array->item, index);
+    array->Touch();
+    return Undefined(env);
+}
+
+// getObjectItem(object: JsonHandle, key: string): JsonHandle | undefined
+napi_value GetObjectItem(napi_env env, napi_callback_info info)
+{
+    napi_value argv[2] = { nullptr };
+    HandleRef object;
+    std::string key;
+    if (!GetArgs(env, info, 2, argv) || !GetHandle(env, argv[0], object) || !GetStringArg(env, argv[1], key)) {
+        return nullptr;
+    }
+    return CreateBorrowedHandle(env, object, ylong_json_get_object_item(object->item, key.c_str()));
+}
+
+// setObjectItem(object: JsonHandle, key: string, item: JsonHandle): JsonHandle
+napi_value SetObjectItem(napi_env env, napi_callback_info info)
+{
+    napi_value argv[3] = { nullptr };
+    HandleRef object;
+    std::string key;
+    HandleRef item;
+    if (!GetArgs(env, info, 3, argv) || !GetHandle(env, argv[0], object) || !GetStringArg(env, argv[1], key) ||
+        !GetOwnedItem(env, argv[2], object, item)) {
+        return nullptr;
+    }
+
+    if (ylong_json_add_item_to_object(object->item, key.c_str(), item->item) != YLONG_JSON_SUCCESS) {
+        ThrowError(env, ERR_INVALID_PARAM, "The JSON value is not an object.");
+        return nullptr;
+    }
+    // The object owns the item now, and may have released the old item or moved the others.
+    item->tree->alive = false;
+    object->Touch();
+    return CreateBorrowedHandle(env, object, ylong_json_get_object_item(object->item, key.c_str()));
+}
+
+// removeObjectItem(object: JsonHandle, key: string): void
+napi_value RemoveObjectItem(napi_env env, napi_callback_info info)
+{
+    napi_value argv[2] = { nullptr };
+    HandleRef object;
+    std::string key;
+    if (!GetArgs(env, info, 2, argv) || !GetHandle(env, argv[0], object) || !GetStringArg(env, argv[1], key)) {
+        return nullptr;
+    }
+    ylong_json_delete_object_item_by_index(object->item, key.c_str());
+    object->Touch();
+    return Undefined(env);
+}
+
+// getObjectKeys(object: JsonHandle): string[]
+napi_value GetObjectKeys(napi_env env, napi_callback_info info)
+{
+    napi_value argv[1] = { nullptr };
+    HandleRef object;
+    if (!GetArgs(env, info, 1, argv) || !GetHandle(env, argv[0], object)) {
+        return nullptr;
+    }
+
+    void *iter = ylong_json_object_iter_create(object->item);
+    if (iter == nullptr) {
+        ThrowError(env, ERR_INVALID_PARAM, "The JSON value is not an object.");
+        return nullptr;
+    }
+    napi_value result = nullptr;
+    napi_create_array(env, &result);
+    const char *key = nullptr;
+    void *value = nullptr;
+    uint32_t index = 0;
+    while (ylong_json_object_iter_next(iter, &key, &value) == YLONG_JSON_SUCCESS) {
+        napi_value element = nullptr;
+        napi_create_string_utf8(env, key, NAPI_AUTO_LENGTH, &element);
+        napi_set_element(env, result, index++, element);
+    }
+    ylong_json_object_iter_free(iter);
+    return result;
+}
+
+napi_value CreateEnum(napi_env env, const std::vector<std::pair<const char *, int32_t>> &items)
+{
+    napi_value result = nullptr;
+    napi_create_object(env, &result);
+    for (const auto &item : items) {
+        napi_value value = nullptr;
+        napi_create_int32(env, item.second, &value);
+        napi_set_named_property(env, result, item.first, value);
+    }
+    return result;
+}
+
+napi_value Init(napi_env env, napi_value exports)
+{
+    napi_property_descriptor desc[] = {
+        DECLARE_NAPI_FUNCTION("parse", Parse),
+        DECLARE_NAPI_FUNCTION("getLastErrorPosition", GetLastErrorPosition),
+        DECLARE_NAPI_FUNCTION("stringify", Stringify),
+        DECLARE_NAPI_FUNCTION("destroy", Destroy),
+        DECLARE_NAPI_FUNCTION("duplicate", Duplicate),
+        DECLARE_NAPI_FUNCTION("getType", GetType),
+        DECLARE_NAPI_FUNCTION("createNull", CreateNull),
+        DECLARE_NAPI_FUNCTION("createBoolean", CreateBoolean),
+        DECLARE_NAPI_FUNCTION("createNumber", CreateNumber),
+        DECLARE_NAPI_FUNCTION("createString", CreateString),
+        DECLARE_NAPI_FUNCTION("createArray", CreateArray),
+        DECLARE_NAPI_FUNCTION("createObject", CreateObject),
+        DECLARE_NAPI_FUNCTION("getBoolean", GetBoolean),
+        DECLARE_NAPI_FUNCTION("getNumber", GetNumber),
+        DECLARE_NAPI_FUNCTION("getString", GetString),
+        DECLARE_NAPI_FUNCTION("getArraySize", GetArraySize),
+        DECLARE_NAPI_FUNCTION("getArrayItem", GetArrayItem),
+        DECLARE_NAPI_FUNCTION("addItemToArray", AddItemToArray),
+        DECLARE_NAPI_FUNCTION("removeArrayItem", RemoveArrayItem),
+        DECLARE_NAPI_FUNCTION("getObjectItem", GetObjectItem),
+        DECLARE_NAPI_FUNCTION("setObjectItem", SetObjectItem),
+        DECLARE_NAPI_FUNCTION("removeObjectItem", RemoveObjectItem),
+        DECLARE_NAPI_FUNCTION("getObjectKeys", GetObjectKeys),
+        DECLARE_NAPI_PROPERTY("ValueType", CreateEnum(env, {
+            { "NULL", TYPE_NULL },
+            { "BOOLEAN", TYPE_BOOLEAN },
+            { "NUMBER", TYPE_NUMBER },
+            { "STRING", TYPE_STRING },
+            { "ARRAY", TYPE_ARRAY },
+            { "OBJECT", TYPE_OBJECT },
+        })),
+        DECLARE_NAPI_PROPERTY("ErrorCode", CreateEnum(env, {
+            { "SYNTAX", ERR_SYNTAX },
+            { "EOF", ERR_EOF },
+            { "DEPTH", ERR_DEPTH },
+            { "UTF8", ERR_UTF8 },
+            { "NUMBER", ERR_NUMBER },
+            { "OTHER", ERR_OTHER },
+            { "INVALID_PARAM", ERR_INVALID_PARAM },
+        })),
+    };
+    napi_define_properties(env, exports, sizeof(desc) / sizeof(desc[0]), desc);
+    return exports;
+}
+
+napi_module g_ylongJsonModule = {
+    .nm_version = 1,
+    .nm_flags = 0,
+    .nm_filename = nullptr,
+    .nm_register_func = Init,
+    .nm_modname = "ylongJson",
+    .nm_priv = nullptr,
+    .reserved = { nullptr },
+};
+} // namespace
+
+extern "C" __attribute__((constructor)) void RegisterYlongJsonModule(void)
+{
+    napi_module_register(&g_ylongJsonModule);
+}
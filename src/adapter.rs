@@ -13,11 +13,16 @@
 
 #![allow(clippy::missing_safety_doc)]
 
+use crate::deserializer::Deserializer;
+use crate::reader::BytesReader;
+use crate::states::parse_value;
 use crate::*;
-use core::ptr::{null_mut, slice_from_raw_parts};
-use core::str::from_utf8_unchecked;
-use libc::{c_char, c_double, c_int, c_longlong, c_void, strlen};
-use std::ffi::CString;
+use core::cell::Cell;
+use core::ptr::{self, null_mut, slice_from_raw_parts, slice_from_raw_parts_mut};
+use libc::{c_char, c_double, c_int, c_longlong, c_void, size_t, strlen};
+use std::ffi::{CStr, CString};
+use std::fs::File;
+use std::io::{BufWriter, Write};
 
 /// Boolean value mapping.
 const FALSE: c_int = 0;
@@ -34,6 +39,33 @@ const NULL_MUT_CSTR: *mut c_char = null_mut::<c_char>();
 /// A void* pointer is passed to C for use.
 pub type YlongJson = c_void;
 
+/// A void* pointer to an iterator over an array or object, which is passed to C for use.
+pub type YlongJsonIter = c_void;
+
+// Copies `len` bytes from C into a JsonString. JSON strings are stored as UTF-8,
+// so invalid UTF-8 sequences are replaced with U+FFFD.
+unsafe fn string_from_raw(string: *const c_char, len: usize) -> JsonString {
+    let slice = &*slice_from_raw_parts(string as *const u8, len);
+    JsonString::from(String::from_utf8_lossy(slice))
+}
+
+// Refers to a C string without copying it if it is valid UTF-8, and copies it otherwise.
+unsafe fn string_reference_from_raw(string: *const c_char) -> JsonString {
+    let len = strlen(string);
+    let slice = &*slice_from_raw_parts(string as *const u8, len);
+    match core::str::from_utf8(slice) {
+        Ok(_) => JsonString::from_c_str_unchecked(string as *const u8, len),
+        Err(_) => string_from_raw(string, len),
+    }
+}
+
+// Borrows a C string as a key. Keys are stored as `String`, so a key which is
+// not valid UTF-8 can neither be found nor added, and `None` is returned.
+unsafe fn key_from_raw<'a>(string: *const c_char) -> Option<&'a str> {
+    let slice = &*slice_from_raw_parts(string as *const u8, strlen(string));
+    core::str::from_utf8(slice).ok()
+}
+
 /// Parses a JSON text string.
 /// Returns a JSON object on success and null on failure.
 #[no_mangle]
@@ -45,17 +77,202 @@ pub unsafe extern "C" fn ylong_json_parse(
     // reduces the cost of converting between char* and &[u8].
     let len = strlen(value);
     let slice = &*slice_from_raw_parts(value as *mut u8, len);
+    parse_slice(slice, err_msg)
+}
 
-    let value = match JsonValue::from_text(slice) {
-        Ok(v) => v,
+/// Parses a JSON text of `len` bytes, which does not need to be terminated with '\0'.
+/// Returns a JSON object on success and null on failure.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_parse_with_length(
+    value: *const c_char,
+    len: size_t,
+    err_msg: *mut *mut c_char,
+) -> *mut YlongJson {
+    if value.is_null() {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let slice = &*slice_from_raw_parts(value as *const u8, len);
+    parse_slice(slice, err_msg)
+}
+
+/// Parses a JSON text string like `ylong_json_parse`, but allows contents behind the value.
+/// If `require_end` is not 0, only whitespace is allowed behind the value.
+/// If `return_parse_end` is not null, it is set to the end of the value on success, and to the
+/// position where the error is found on failure. If `err_msg` is not null, error messages are
+/// written into it on failure.
+/// Returns a JSON object on success and null on failure.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_parse_with_opts(
+    value: *const c_char,
+    return_parse_end: *mut *const c_char,
+    require_end: c_int,
+    err_msg: *mut *mut c_char,
+) -> *mut YlongJson {
+    if value.is_null() {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let len = strlen(value);
+    let slice = &*slice_from_raw_parts(value as *const u8, len);
+
+    let mut deserializer = Deserializer::new_from_slice(slice);
+    let result = parse_value(&mut deserializer).and_then(|value| {
+        if require_end == FALSE {
+            return Ok(value);
+        }
+        match deserializer.reader.discard_whitespace() {
+            Ok(None) => Ok(value),
+            _ => Err(ParseError::TrailingBytes(deserializer.reader.position()).into()),
+        }
+    });
+
+    if !return_parse_end.is_null() {
+        let mut end = deserializer.reader.index();
+        // The unexpected character has been read for the error message.
+        if let Err(Error::Parsing(ParseError::UnexpectedCharacter(_, ch))) = result {
+            end -= ch.len_utf8();
+        }
+        *return_parse_end = value.add(end);
+    }
+    finish_parsing(result, err_msg)
+}
+
+/// Reads and parses a JSON text from the file at `path`.
+/// Returns a JSON object on success and null on failure.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_parse_file(
+    path: *const c_char,
+    err_msg: *mut *mut c_char,
+) -> *mut YlongJson {
+    if path.is_null() {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let result = match CStr::from_ptr(path).to_str() {
+        Ok(path) => JsonValue::from_file(path),
+        Err(_) => Err(Error::Utf8Transform),
+    };
+    finish_parsing(result, err_msg)
+}
+
+unsafe fn parse_slice(slice: &[u8], err_msg: *mut *mut c_char) -> *mut YlongJson {
+    finish_parsing(JsonValue::from_text(slice), err_msg)
+}
+
+// Records the result as the last error, and converts it to YlongJson*.
+unsafe fn finish_parsing(
+    result: Result<JsonValue, Error>,
+    err_msg: *mut *mut c_char,
+) -> *mut YlongJson {
+    match result {
+        Ok(value) => {
+            set_last_error(None);
+            Box::into_raw(Box::from(value)) as *mut YlongJson
+        }
         Err(e) => {
+            set_last_error(Some(&e));
             // If an error occurs, writes error messages into err_msg.
-            *err_msg = CString::from_vec_unchecked(e.to_string().into_bytes()).into_raw();
-            return NULL_MUT_YLONG_JSON;
+            if !err_msg.is_null() {
+                *err_msg = CString::from_vec_unchecked(e.to_string().into_bytes()).into_raw();
+            }
+            NULL_MUT_YLONG_JSON
         }
+    }
+}
+
+// Error codes of the last parsing on the current thread.
+
+/// The parsing succeeded.
+pub const YLONG_JSON_ERR_NONE: c_int = 0;
+/// The text has an unexpected character, or has contents behind the value.
+pub const YLONG_JSON_ERR_SYNTAX: c_int = 1;
+/// The text ends before the value is finished.
+pub const YLONG_JSON_ERR_EOF: c_int = 2;
+/// Arrays and objects are nested too deeply.
+pub const YLONG_JSON_ERR_DEPTH: c_int = 3;
+/// The text has invalid UTF-8 bytes or invalid unicode escapes.
+pub const YLONG_JSON_ERR_UTF8: c_int = 4;
+/// A number can not be represented.
+pub const YLONG_JSON_ERR_NUMBER: c_int = 5;
+/// The input can not be read.
+pub const YLONG_JSON_ERR_IO: c_int = 6;
+/// Other errors.
+pub const YLONG_JSON_ERR_OTHER: c_int = 7;
+
+std::thread_local! {
+    // Error code, line number, column number and byte offset of the last parsing.
+    static LAST_ERROR: Cell<(c_int, usize, usize, usize)> = const { Cell::new((YLONG_JSON_ERR_NONE, 0, 0, 0)) };
+}
+
+fn set_last_error(error: Option<&Error>) {
+    let (code, position, column) = match error {
+        None => (YLONG_JSON_ERR_NONE, None, None),
+        Some(Error::Parsing(e)) => match e {
+            ParseError::UnexpectedCharacter(p, _)
+            | ParseError::TrailingComma(p)
+            | ParseError::MissingColon(p)
+            | ParseError::MissingComma(p)
+            | ParseError::DuplicateKey(p, _) => (YLONG_JSON_ERR_SYNTAX, Some(p), Some(p.column())),
+            ParseError::TrailingBytes(p) => (YLONG_JSON_ERR_SYNTAX, Some(p), None),
+            ParseError::ParsingUnfinished => (YLONG_JSON_ERR_SYNTAX, None, None),
+            ParseError::InvalidUtf8Bytes(p) => (YLONG_JSON_ERR_UTF8, Some(p), None),
+            ParseError::UnexpectedEndOfJson(p) => (YLONG_JSON_ERR_EOF, Some(p), None),
+        },
+        Some(Error::ExceedRecursionLimit) => (YLONG_JSON_ERR_DEPTH, None, None),
+        Some(Error::Utf8Transform) => (YLONG_JSON_ERR_UTF8, None, None),
+        Some(Error::ParseNumber) => (YLONG_JSON_ERR_NUMBER, None, None),
+        Some(Error::Io(_) | Error::Reader(_)) => (YLONG_JSON_ERR_IO, None, None),
+        Some(_) => (YLONG_JSON_ERR_OTHER, None, None),
+    };
+    let last = match position {
+        Some(p) => (code, p.line(), column.unwrap_or(0), p.offset()),
+        None => (code, 0, 0, 0),
     };
+    LAST_ERROR.with(|cell| cell.set(last));
+}
+
+/// Gets the error code of the last parsing on the current thread,
+/// which is one of the `YLONG_JSON_ERR_*` constants.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_get_last_error() -> c_int {
+    LAST_ERROR.with(|cell| cell.get().0)
+}
+
+/// Gets the position of the error of the last parsing on the current thread.
+/// Both numbers start from 1, and the column number is 0 if it is unknown.
+/// Returns failure if the last parsing succeeded or the position is unknown.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_get_last_error_position(
+    line: *mut size_t,
+    column: *mut size_t,
+) -> c_int {
+    let (code, l, c, _) = LAST_ERROR.with(|cell| cell.get());
+    if code == YLONG_JSON_ERR_NONE || l == 0 {
+        return FAILURE;
+    }
+    if !line.is_null() {
+        *line = l;
+    }
+    if !column.is_null() {
+        *column = c;
+    }
+    SUCCESS
+}
 
-    Box::into_raw(Box::from(value)) as *mut YlongJson
+/// Gets the byte offset of the error of the last parsing on the current thread,
+/// which starts from 0 and counts from the beginning of the input.
+/// Returns failure if the last parsing succeeded or the position is unknown.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_get_last_error_offset(offset: *mut size_t) -> c_int {
+    let (code, l, _, o) = LAST_ERROR.with(|cell| cell.get());
+    if code == YLONG_JSON_ERR_NONE || l == 0 {
+        return FAILURE;
+    }
+    if !offset.is_null() {
+        *offset = o;
+    }
+    SUCCESS
 }
 
 /// Frees a C string.
@@ -90,6 +307,76 @@ pub unsafe extern "C" fn ylong_json_print_unformatted(item: *const YlongJson) ->
     CString::from_vec_unchecked(vec).into_raw()
 }
 
+/// Outputs a JSON object into a buffer provided by the caller, in plain format if `fmt`
+/// is 0 and in formatted format otherwise. The output is terminated with '\0'.
+/// Returns success if the output and the terminator fit in `len` bytes and failure otherwise.
+/// No memory is allocated for the output.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_print_preallocated(
+    item: *const YlongJson,
+    buf: *mut c_char,
+    len: c_int,
+    fmt: c_int,
+) -> c_int {
+    if item.is_null() || buf.is_null() || len <= 0 {
+        return FAILURE;
+    }
+
+    let value = &*(item as *const JsonValue);
+    let buf = &mut *slice_from_raw_parts_mut(buf as *mut u8, len as usize);
+
+    // The last byte is reserved for the terminator. Writing into a slice fails
+    // when the slice is full.
+    let mut output = &mut buf[..len as usize - 1];
+    let result = if fmt == FALSE {
+        value.compact_encode(&mut output)
+    } else {
+        value.formatted_encode(&mut output)
+    };
+    if result.is_err() {
+        return FAILURE;
+    }
+
+    let written = len as usize - 1 - output.len();
+    buf[written] = 0;
+    SUCCESS
+}
+
+/// Outputs a JSON object to the file at `path`, in plain format if `fmt` is 0 and in
+/// formatted format otherwise. The file is created if it does not exist, and truncated if
+/// it does. The output is written in pieces, without holding the whole string in memory.
+/// Returns success or failure.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_print_to_file(
+    item: *const YlongJson,
+    path: *const c_char,
+    fmt: c_int,
+) -> c_int {
+    if item.is_null() || path.is_null() {
+        return FAILURE;
+    }
+
+    let value = &*(item as *const JsonValue);
+    let path = match CStr::from_ptr(path).to_str() {
+        Ok(path) => path,
+        Err(_) => return FAILURE,
+    };
+    let mut output = match File::create(path) {
+        Ok(file) => BufWriter::new(file),
+        Err(_) => return FAILURE,
+    };
+
+    let result = if fmt == FALSE {
+        value.compact_encode(&mut output)
+    } else {
+        value.formatted_encode(&mut output)
+    };
+    if result.is_err() || output.flush().is_err() {
+        return FAILURE;
+    }
+    SUCCESS
+}
+
 /// Deletes a JSON object.
 #[no_mangle]
 pub unsafe extern "C" fn ylong_json_delete(item: *mut YlongJson) {
@@ -125,6 +412,80 @@ pub unsafe extern "C" fn ylong_json_duplicate(
     Box::into_raw(Box::from(value.clone())) as *mut YlongJson
 }
 
+/// Compares two JSON items recursively. If `case_sensitive` is FALSE, the keys of objects
+/// are compared ignoring ASCII case.
+/// Returns TRUE if the two items are equal, and FALSE otherwise or if either item is null.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_compare(
+    a: *const YlongJson,
+    b: *const YlongJson,
+    case_sensitive: c_int,
+) -> c_int {
+    if a.is_null() || b.is_null() {
+        return FALSE;
+    }
+
+    let a = &*(a as *mut JsonValue);
+    let b = &*(b as *mut JsonValue);
+    compare_values(a, b, case_sensitive != FALSE) as c_int
+}
+
+fn compare_values(a: &JsonValue, b: &JsonValue, case_sensitive: bool) -> bool {
+    if case_sensitive {
+        return a == b;
+    }
+
+    match (a, b) {
+        (JsonValue::Array(a), JsonValue::Array(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .zip(b.iter())
+                    .all(|(a, b)| compare_values(a, b, false))
+        }
+        (JsonValue::Object(a), JsonValue::Object(b)) => {
+            a.len() == b.len()
+                && a.iter().all(|(k, v)| {
+                    b.iter()
+                        .any(|(bk, bv)| k.eq_ignore_ascii_case(bk) && compare_values(v, bv, false))
+                })
+        }
+        _ => a == b,
+    }
+}
+
+/// Applies a JSON Merge Patch (RFC 7396) to a JSON item. The patch is not changed or consumed.
+/// Returns `FAILURE` if the target or the patch is null, and `SUCCESS` otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_merge_patch(
+    target: *mut YlongJson,
+    patch: *const YlongJson,
+) -> c_int {
+    if target.is_null() || patch.is_null() {
+        return FAILURE;
+    }
+
+    let target = &mut *(target as *mut JsonValue);
+    let patch = &*(patch as *mut JsonValue);
+    target.merge_patch(patch);
+    SUCCESS
+}
+
+/// Merges a JSON item into another one recursively. Members of objects are merged one by one,
+/// while other items in `dst` are replaced by copies of those in `src`. The `src` is not
+/// changed or consumed.
+/// Returns `FAILURE` if `dst` or `src` is null, and `SUCCESS` otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_merge(dst: *mut YlongJson, src: *const YlongJson) -> c_int {
+    if dst.is_null() || src.is_null() {
+        return FAILURE;
+    }
+
+    let dst = &mut *(dst as *mut JsonValue);
+    let src = &*(src as *mut JsonValue);
+    dst.merge(src);
+    SUCCESS
+}
+
 /// Creates a JSON null object and returns a new JSON null object.
 #[no_mangle]
 pub unsafe extern "C" fn ylong_json_create_null() -> *mut YlongJson {
@@ -311,6 +672,25 @@ pub unsafe extern "C" fn ylong_json_get_int_value_from_number(
     SUCCESS
 }
 
+/// Gets the double value of a JSON number object.
+/// Returns NaN if the item is null or not a number.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_get_double_value(item: *const YlongJson) -> c_double {
+    let mut value = c_double::NAN;
+    ylong_json_get_double_value_from_number(item, &mut value as *mut c_double);
+    value
+}
+
+/// Gets the integer value of a JSON number object.
+/// Returns 0 if the item is null or not a number, so use `ylong_json_is_number` first
+/// if 0 is a valid value.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_get_int_value(item: *const YlongJson) -> c_longlong {
+    let mut value: c_longlong = 0;
+    ylong_json_get_int_value_from_number(item, &mut value as *mut c_longlong);
+    value
+}
+
 /// Sets the double value of a JSON number object.
 /// Returns a `c_int` indicating whether the operation was successful (SUCCESS) or failed (FAILURE).
 #[no_mangle]
@@ -359,9 +739,39 @@ pub unsafe extern "C" fn ylong_json_create_string(string: *const c_char) -> *mut
         return NULL_MUT_YLONG_JSON;
     }
 
-    let len = strlen(string);
-    let slice = &*slice_from_raw_parts(string as *mut u8, len);
-    let string = CString::from_vec_unchecked(slice.to_vec());
+    let string = string_from_raw(string, strlen(string));
+    Box::into_raw(Box::from(JsonValue::String(string))) as *mut YlongJson
+}
+
+/// Creates a `YlongJson` string from `len` bytes, which may contain NUL.
+/// If the input string is null, it returns a null `YlongJson`.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_create_string_with_length(
+    string: *const c_char,
+    len: size_t,
+) -> *mut YlongJson {
+    if string.is_null() {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let string = string_from_raw(string, len);
+    Box::into_raw(Box::from(JsonValue::String(string))) as *mut YlongJson
+}
+
+/// Creates a `YlongJson` string which refers to a given C-style string without copying it.
+/// The C-style string is not freed when the item is deleted, so it must stay valid and unchanged
+/// until the item is deleted or changed. Duplicating the item copies the string.
+/// If the string is not valid UTF-8, it is copied like `ylong_json_create_string`.
+/// If the input string is null, it returns a null `YlongJson`.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_create_string_reference(
+    string: *const c_char,
+) -> *mut YlongJson {
+    if string.is_null() {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let string = string_reference_from_raw(string);
     Box::into_raw(Box::from(JsonValue::String(string))) as *mut YlongJson
 }
 
@@ -377,8 +787,9 @@ pub unsafe extern "C" fn ylong_json_is_string(item: *const YlongJson) -> c_int {
     item.is_string() as c_int
 }
 
-/// The char* returned by this function differs from the original data,
-/// meaning that any changes to this char* will not be reflected in the original data.
+/// Gets the value of a `YlongJson` string. The char* is terminated with NUL and is owned by the
+/// item, so it must not be freed or changed, and it is invalid after the item is changed or deleted.
+/// Returns `FAILURE` if the item is null or not a string, and `SUCCESS` otherwise.
 #[no_mangle]
 pub unsafe extern "C" fn ylong_json_get_value_from_string(
     string: *const YlongJson,
@@ -388,14 +799,81 @@ pub unsafe extern "C" fn ylong_json_get_value_from_string(
         return FAILURE;
     }
 
-    let string = &*(string as *mut JsonValue);
+    let string = &*(string as *const JsonValue);
+    let string = match string.try_as_string() {
+        Ok(s) => s,
+        Err(_) => return FAILURE,
+    };
+    *value = string.as_c_ptr() as *mut c_char;
+    SUCCESS
+}
+
+/// Gets the value of a `YlongJson` string and its length in bytes, which is needed if the string
+/// contains NUL. The char* is terminated with NUL and is owned by the item, so it must not be freed
+/// or changed, and it is invalid after the item is changed or deleted.
+/// Returns `FAILURE` if any parameter is null or the item is not a string, and `SUCCESS` otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_get_value_from_string_with_length(
+    string: *const YlongJson,
+    value: *mut *mut c_char,
+    len: *mut size_t,
+) -> c_int {
+    if string.is_null() || value.is_null() || len.is_null() {
+        return FAILURE;
+    }
+
+    let string = &*(string as *const JsonValue);
     let string = match string.try_as_string() {
         Ok(s) => s,
         Err(_) => return FAILURE,
     };
-    // If `c_adapter` feature is on, the pointer of the inner char arrays can be obtained directly,
-    // because the string pointer actually points to a `CString`
-    *value = string.as_ptr() as *mut c_char;
+    *len = string.len();
+    *value = string.as_c_ptr() as *mut c_char;
+    SUCCESS
+}
+
+/// Gets the value of a `YlongJson` string.
+/// Returns null if the item is null or not a string. The returned char* is owned by the item,
+/// so it must not be freed, and it is invalid after the item is changed or deleted.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_get_string_value(item: *const YlongJson) -> *mut c_char {
+    let mut value = NULL_MUT_CSTR;
+    ylong_json_get_value_from_string(item, &mut value as *mut *mut c_char);
+    value
+}
+
+/// Copies the value of a `YlongJson` string into a buffer of `cap` bytes, and terminates it with NUL.
+/// If `written` is not null, it is set to the length of the string without the NUL, even if the
+/// buffer is too small, so the caller can retry with a buffer of `*written + 1` bytes.
+/// Returns `FAILURE` if the item or the buffer is null, the item is not a string, or the buffer
+/// is too small, and `SUCCESS` otherwise. The buffer is not changed on failure.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_get_string_into(
+    item: *const YlongJson,
+    buf: *mut c_char,
+    cap: size_t,
+    written: *mut size_t,
+) -> c_int {
+    if item.is_null() || buf.is_null() {
+        return FAILURE;
+    }
+
+    let item = &*(item as *mut JsonValue);
+    let string = match item.try_as_string() {
+        Ok(s) => s.as_bytes(),
+        Err(_) => return FAILURE,
+    };
+
+    if !written.is_null() {
+        *written = string.len();
+    }
+    if string.len() >= cap {
+        return FAILURE;
+    }
+
+    let buf = &mut *slice_from_raw_parts_mut(buf as *mut u8, string.len() + 1);
+    buf[..string.len()].copy_from_slice(string);
+    buf[string.len()] = 0;
     SUCCESS
 }
 
@@ -415,9 +893,28 @@ pub unsafe extern "C" fn ylong_json_set_value_to_string(
         Ok(s) => s,
         Err(_) => return FAILURE,
     };
-    let len = strlen(value);
-    let slice = &*slice_from_raw_parts(value as *mut u8, len);
-    *string = CString::from_vec_unchecked(slice.to_vec());
+    *string = string_from_raw(value, strlen(value));
+    SUCCESS
+}
+
+/// Sets a `YlongJson` string to `len` bytes, which may contain NUL.
+/// If the `YlongJson` string or the input string is null, it returns `FAILURE`.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_set_value_to_string_with_length(
+    string: *mut YlongJson,
+    value: *const c_char,
+    len: size_t,
+) -> c_int {
+    if string.is_null() || value.is_null() {
+        return FAILURE;
+    }
+
+    let string = &mut *(string as *mut JsonValue);
+    let string = match string.try_as_mut_string() {
+        Ok(s) => s,
+        Err(_) => return FAILURE,
+    };
+    *string = string_from_raw(value, len);
     SUCCESS
 }
 
@@ -427,6 +924,67 @@ pub unsafe extern "C" fn ylong_json_create_array() -> *mut YlongJson {
     Box::into_raw(Box::from(JsonValue::Array(Array::new()))) as *mut YlongJson
 }
 
+/// Creates a `YlongJson` array of integer numbers from a C array of `count` numbers.
+/// Returns null if the numbers are null or the count is negative.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_create_int_array(
+    numbers: *const c_longlong,
+    count: c_int,
+) -> *mut YlongJson {
+    if numbers.is_null() || count < 0 {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let numbers = &*slice_from_raw_parts(numbers, count as usize);
+    let mut array = Array::new();
+    for number in numbers {
+        array.push(JsonValue::Number(Number::Signed(*number)));
+    }
+    Box::into_raw(Box::from(JsonValue::Array(array))) as *mut YlongJson
+}
+
+/// Creates a `YlongJson` array of double numbers from a C array of `count` numbers.
+/// Returns null if the numbers are null or the count is negative.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_create_double_array(
+    numbers: *const c_double,
+    count: c_int,
+) -> *mut YlongJson {
+    if numbers.is_null() || count < 0 {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let numbers = &*slice_from_raw_parts(numbers, count as usize);
+    let mut array = Array::new();
+    for number in numbers {
+        array.push(JsonValue::Number(Number::Float(*number)));
+    }
+    Box::into_raw(Box::from(JsonValue::Array(array))) as *mut YlongJson
+}
+
+/// Creates a `YlongJson` array of strings from a C array of `count` C-style strings.
+/// The strings are copied. Returns null if the strings or any of them are null,
+/// or the count is negative.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_create_string_array(
+    strings: *const *const c_char,
+    count: c_int,
+) -> *mut YlongJson {
+    if strings.is_null() || count < 0 {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let strings = &*slice_from_raw_parts(strings, count as usize);
+    let mut array = Array::new();
+    for string in strings {
+        if string.is_null() {
+            return NULL_MUT_YLONG_JSON;
+        }
+        array.push(JsonValue::String(string_from_raw(*string, strlen(*string))));
+    }
+    Box::into_raw(Box::from(JsonValue::Array(array))) as *mut YlongJson
+}
+
 /// Checks if the `YlongJson` item is an array.
 /// Returns `FALSE` if the item is null or not an array, and `TRUE` otherwise.
 #[no_mangle]
@@ -507,13 +1065,62 @@ pub unsafe extern "C" fn ylong_json_add_item_to_array(
     SUCCESS
 }
 
-/// Replaces a `YlongJson` item in an array by index with a new item.
-/// Returns `FAILURE` if the array or the new item is null, the index is out of bounds, or any error occurs, and `SUCCESS` otherwise.
+/// Creates a JSON string which refers to a given C-style string, the same as
+/// `ylong_json_create_string_reference`, and adds it to the end of a JSON array.
+/// Returns `FAILURE` if the array or the string is null, or the first parameter is not an array,
+/// and `SUCCESS` otherwise.
 #[no_mangle]
-pub unsafe extern "C" fn ylong_json_replace_array_item_by_index(
+pub unsafe extern "C" fn ylong_json_add_string_reference_to_array(
     array: *mut YlongJson,
-    index: c_int,
-    new_item: *mut YlongJson,
+    string: *const c_char,
+) -> c_int {
+    if array.is_null() || string.is_null() {
+        return FAILURE;
+    }
+
+    let array_ref = &mut *(array as *mut JsonValue);
+    let array_ref = match array_ref.try_as_mut_array() {
+        Ok(a) => a,
+        Err(_) => return FAILURE,
+    };
+
+    array_ref.push(JsonValue::String(string_reference_from_raw(string)));
+    SUCCESS
+}
+
+/// Inserts a `YlongJson` item into an array at the specified index, shifting the items after it.
+/// If the index is not less than the size of the array, the item is added to the end.
+/// Returns `FAILURE` if the array or the item is null, the index is negative, or the first
+/// parameter is not an array, and `SUCCESS` otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_insert_item_in_array(
+    array: *mut YlongJson,
+    which: c_int,
+    item: *mut YlongJson,
+) -> c_int {
+    if array.is_null() || item.is_null() || which < 0 {
+        return FAILURE;
+    }
+
+    let array_ref = &mut *(array as *mut JsonValue);
+    let array_ref = match array_ref.try_as_mut_array() {
+        Ok(a) => a,
+        Err(_) => return FAILURE,
+    };
+
+    let value = Box::from_raw(item as *mut JsonValue);
+    array_ref.insert(which as usize, *value);
+
+    SUCCESS
+}
+
+/// Replaces a `YlongJson` item in an array by index with a new item.
+/// Returns `FAILURE` if the array or the new item is null, the index is out of bounds, or any error occurs, and `SUCCESS` otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_replace_array_item_by_index(
+    array: *mut YlongJson,
+    index: c_int,
+    new_item: *mut YlongJson,
 ) -> c_int {
     if array.is_null() || new_item.is_null() {
         return FAILURE;
@@ -536,6 +1143,7 @@ pub unsafe extern "C" fn ylong_json_replace_array_item_by_index(
 }
 
 /// Removes a `YlongJson` item from an array by index.
+/// The caller takes the ownership of the returned item and should delete it by `ylong_json_delete`.
 /// Returns null `YlongJson` if the array is null, the item doesn't exist, or any error occurs.
 #[no_mangle]
 pub unsafe extern "C" fn ylong_json_remove_array_item_by_index(
@@ -559,6 +1167,73 @@ pub unsafe extern "C" fn ylong_json_remove_array_item_by_index(
     NULL_MUT_YLONG_JSON
 }
 
+/// Detaches a `YlongJson` item from an array by index, without deleting it.
+/// The caller takes the ownership of the returned item and should delete it by `ylong_json_delete`.
+/// Returns null `YlongJson` if the array is null, the item doesn't exist, or any error occurs.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_detach_item_from_array(
+    array: *mut YlongJson,
+    which: c_int,
+) -> *mut YlongJson {
+    if which < 0 {
+        return NULL_MUT_YLONG_JSON;
+    }
+    ylong_json_remove_array_item_by_index(array, which)
+}
+
+/// Detaches an item from a JSON object by key, without deleting it.
+/// The caller takes the ownership of the returned item and should delete it by `ylong_json_delete`.
+/// Returns null `YlongJson` if the object or the key is null, the item doesn't exist, or any error occurs.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_detach_item_from_object(
+    object: *mut YlongJson,
+    string: *const c_char,
+) -> *mut YlongJson {
+    ylong_json_remove_object_item_by_index(object, string)
+}
+
+/// Detaches an item from its parent array or object, without deleting it. The item must be
+/// a pointer obtained from the parent, such as by `ylong_json_get_array_item` or
+/// `ylong_json_get_object_item`.
+/// The caller takes the ownership of the returned item and should delete it by `ylong_json_delete`.
+/// Returns null `YlongJson` if the parent or the item is null, or the item is not a child of the parent.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_detach_item_via_pointer(
+    parent: *mut YlongJson,
+    item: *const YlongJson,
+) -> *mut YlongJson {
+    if parent.is_null() || item.is_null() {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let item = item as *const JsonValue;
+    let value = match &mut *(parent as *mut JsonValue) {
+        JsonValue::Array(array) => match array.iter().position(|v| ptr::eq(v, item)) {
+            Some(index) => array.remove(index),
+            None => None,
+        },
+        JsonValue::Object(object) => {
+            let mut key = None;
+            for (k, v) in object.iter() {
+                if ptr::eq(v, item) {
+                    key = Some(k.clone());
+                    break;
+                }
+            }
+            match key {
+                Some(key) => object.remove(&key),
+                None => None,
+            }
+        }
+        _ => None,
+    };
+
+    match value {
+        Some(v) => Box::into_raw(Box::new(v)) as *mut YlongJson,
+        None => NULL_MUT_YLONG_JSON,
+    }
+}
+
 /// Deletes a `YlongJson` item from an array by index.
 #[no_mangle]
 pub unsafe extern "C" fn ylong_json_delete_array_item_by_index(
@@ -577,6 +1252,55 @@ pub unsafe extern "C" fn ylong_json_delete_array_item_by_index(
     array.remove(index as usize);
 }
 
+/// Iterator over the items of a JSON array, created by `ylong_json_array_iter_create`.
+struct ArrayIter {
+    inner: Box<dyn Iterator<Item = *mut JsonValue>>,
+}
+
+/// Creates an iterator over the items of a JSON array.
+/// The array must not be changed or deleted until the iterator is freed by
+/// `ylong_json_array_iter_free`, but the items can be changed in place.
+/// Returns null if the array is null or not an array.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_array_iter_create(array: *mut YlongJson) -> *mut YlongJsonIter {
+    if array.is_null() {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let array = &mut *(array as *mut JsonValue);
+    let array = match array.try_as_mut_array() {
+        Ok(a) => a,
+        Err(_) => return NULL_MUT_YLONG_JSON,
+    };
+
+    let inner = Box::new(array.iter_mut().map(|v| v as *mut JsonValue));
+    Box::into_raw(Box::new(ArrayIter { inner })) as *mut YlongJsonIter
+}
+
+/// Gets the next item of an array iterator. The item is still owned by the array.
+/// Returns null if the iterator is null or all items have been visited.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_array_iter_next(iter: *mut YlongJsonIter) -> *mut YlongJson {
+    if iter.is_null() {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let iter = &mut *(iter as *mut ArrayIter);
+    match iter.inner.next() {
+        Some(v) => v as *mut YlongJson,
+        None => NULL_MUT_YLONG_JSON,
+    }
+}
+
+/// Frees an array iterator. The array is not changed.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_array_iter_free(iter: *mut YlongJsonIter) {
+    if iter.is_null() {
+        return;
+    }
+    let _ = Box::from_raw(iter as *mut ArrayIter);
+}
+
 /// In list_array mode, it is more efficient to get a node through this method and then delete it.
 #[cfg(feature = "list_array")]
 #[no_mangle]
@@ -599,7 +1323,7 @@ pub unsafe extern "C" fn ylong_json_get_array_node(
     }
 
     let node = array_ref.get_node_mut(index as usize).unwrap();
-    node as *mut Node<JsonValue> as *mut YlongJson
+    node.export() as *mut YlongJson
 }
 
 /// Retrieves a `YlongJson` item from an array node.
@@ -611,7 +1335,11 @@ pub unsafe extern "C" fn ylong_json_get_item_from_array_node(
     if array_node.is_null() {
         return NULL_MUT_YLONG_JSON;
     }
-    let node = &mut *(array_node as *mut Node<JsonValue>);
+    // A removed node, or a node of a deleted array, is rejected.
+    let node = match Node::<JsonValue>::from_exported(array_node as usize) {
+        Some(node) => node,
+        None => return NULL_MUT_YLONG_JSON,
+    };
     node.get_element_mut() as *mut JsonValue as *mut YlongJson
 }
 
@@ -636,7 +1364,7 @@ pub unsafe extern "C" fn ylong_json_add_item_to_array_then_get_node(
     let value = Box::from_raw(item as *mut JsonValue);
     array_ref.push(*value);
 
-    array_ref.last_node_mut().unwrap() as *mut Node<JsonValue> as *mut YlongJson
+    array_ref.last_node_mut().unwrap().export() as *mut YlongJson
 }
 
 /// Replaces an item of an array node with a new item.
@@ -651,7 +1379,11 @@ pub unsafe extern "C" fn ylong_json_replace_item_of_array_node(
         return FAILURE;
     }
 
-    let node = &mut *(array_node as *mut Node<JsonValue>);
+    // A removed node, or a node of a deleted array, is rejected.
+    let node = match Node::<JsonValue>::from_exported(array_node as usize) {
+        Some(node) => node,
+        None => return FAILURE,
+    };
     let value = node.get_element_mut();
 
     let new_value = Box::from_raw(new_item as *mut JsonValue);
@@ -660,6 +1392,7 @@ pub unsafe extern "C" fn ylong_json_replace_item_of_array_node(
 }
 
 /// Removes an array node.
+/// Returns null if the node has been removed or its array has been deleted.
 #[cfg(feature = "list_array")]
 #[no_mangle]
 pub unsafe extern "C" fn ylong_json_remove_array_node(
@@ -669,11 +1402,16 @@ pub unsafe extern "C" fn ylong_json_remove_array_node(
         return NULL_MUT_YLONG_JSON;
     }
 
-    let node = &mut *(array_node as *mut Node<JsonValue>);
+    // A removed node, or a node of a deleted array, is rejected.
+    let node = match Node::<JsonValue>::from_exported(array_node as usize) {
+        Some(node) => node,
+        None => return NULL_MUT_YLONG_JSON,
+    };
     Box::into_raw(Box::new(node.remove_self().unwrap())) as *mut YlongJson
 }
 
 /// Deletes an array node.
+/// Does nothing if the node has been removed or its array has been deleted.
 #[cfg(feature = "list_array")]
 #[no_mangle]
 pub unsafe extern "C" fn ylong_json_delete_array_node(array_node: *mut YlongJson) {
@@ -681,7 +1419,11 @@ pub unsafe extern "C" fn ylong_json_delete_array_node(array_node: *mut YlongJson
         return;
     }
 
-    let node = &mut *(array_node as *mut Node<JsonValue>);
+    // A removed node, or a node of a deleted array, is rejected.
+    let node = match Node::<JsonValue>::from_exported(array_node as usize) {
+        Some(node) => node,
+        None => return,
+    };
     let _ = node.remove_self();
 }
 
@@ -741,12 +1483,10 @@ pub unsafe extern "C" fn ylong_json_has_object_item(
         Err(_) => return FALSE,
     };
 
-    // Using `ptr::slice_from_raw_parts` here dramatically
-    // reduces the cost of converting between char* and &[u8].
-    // Then use `from_utf8_unchecked` to further reduce cost.
-    let len = strlen(string);
-    let slice = &*slice_from_raw_parts(string as *mut u8, len);
-    let str = from_utf8_unchecked(slice);
+    let str = match key_from_raw(string) {
+        Some(k) => k,
+        None => return FALSE,
+    };
 
     object.contains_key(str) as c_int
 }
@@ -771,12 +1511,10 @@ pub unsafe extern "C" fn ylong_json_get_object_item(
         Err(_) => return NULL_MUT_YLONG_JSON,
     };
 
-    // Using `ptr::slice_from_raw_parts` here dramatically
-    // reduces the cost of converting between char* and &[u8].
-    // Then use `from_utf8_unchecked` to further reduce cost.
-    let len = strlen(string);
-    let slice = &*slice_from_raw_parts(string as *mut u8, len);
-    let index = from_utf8_unchecked(slice);
+    let index = match key_from_raw(string) {
+        Some(k) => k,
+        None => return NULL_MUT_YLONG_JSON,
+    };
 
     let target = match object_ref.get_mut(index) {
         Some(v) => v,
@@ -804,12 +1542,10 @@ pub unsafe extern "C" fn ylong_json_add_item_to_object(
         Err(_) => return FAILURE,
     };
 
-    // Using `ptr::slice_from_raw_parts` here dramatically
-    // reduces the cost of converting between char* and &[u8].
-    // Then use `from_utf8_unchecked` to further reduce cost.
-    let len = strlen(string);
-    let slice = &*slice_from_raw_parts(string as *mut u8, len);
-    let index = from_utf8_unchecked(slice);
+    let index = match key_from_raw(string) {
+        Some(k) => k,
+        None => return FAILURE,
+    };
 
     let value = Box::from_raw(item as *mut JsonValue);
 
@@ -818,6 +1554,138 @@ pub unsafe extern "C" fn ylong_json_add_item_to_object(
     SUCCESS
 }
 
+/// Creates a JSON null and adds it to a JSON object.
+/// Returns a pointer to the added item, or null if the object or the key is null,
+/// or the first parameter is not an object.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_add_null_to_object(
+    object: *mut YlongJson,
+    string: *const c_char,
+) -> *mut YlongJson {
+    add_value_to_object(object, string, JsonValue::Null)
+}
+
+/// Creates a JSON boolean and adds it to a JSON object.
+/// Returns a pointer to the added item, or null if the object or the key is null,
+/// or the first parameter is not an object.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_add_bool_to_object(
+    object: *mut YlongJson,
+    string: *const c_char,
+    boolean: c_int,
+) -> *mut YlongJson {
+    add_value_to_object(object, string, JsonValue::Boolean(boolean != FALSE))
+}
+
+/// Creates a JSON integer number and adds it to a JSON object.
+/// Returns a pointer to the added item, or null if the object or the key is null,
+/// or the first parameter is not an object.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_add_int_to_object(
+    object: *mut YlongJson,
+    string: *const c_char,
+    number: c_longlong,
+) -> *mut YlongJson {
+    add_value_to_object(object, string, JsonValue::Number(Number::Signed(number)))
+}
+
+/// Creates a JSON double number and adds it to a JSON object.
+/// Returns a pointer to the added item, or null if the object or the key is null,
+/// or the first parameter is not an object.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_add_double_to_object(
+    object: *mut YlongJson,
+    string: *const c_char,
+    number: c_double,
+) -> *mut YlongJson {
+    add_value_to_object(object, string, JsonValue::Number(Number::Float(number)))
+}
+
+/// Creates a JSON string and adds it to a JSON object.
+/// Returns a pointer to the added item, or null if the object, the key or the value is null,
+/// or the first parameter is not an object.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_add_string_to_object(
+    object: *mut YlongJson,
+    string: *const c_char,
+    value: *const c_char,
+) -> *mut YlongJson {
+    if value.is_null() {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let value = string_from_raw(value, strlen(value));
+    add_value_to_object(object, string, JsonValue::String(value))
+}
+
+/// Creates a JSON string which refers to a given C-style string, the same as
+/// `ylong_json_create_string_reference`, and adds it to a JSON object.
+/// Returns a pointer to the added item, or null if the object, the key or the value is null,
+/// or the first parameter is not an object.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_add_string_reference_to_object(
+    object: *mut YlongJson,
+    string: *const c_char,
+    value: *const c_char,
+) -> *mut YlongJson {
+    if value.is_null() {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let value = string_reference_from_raw(value);
+    add_value_to_object(object, string, JsonValue::String(value))
+}
+
+/// Creates an empty JSON array and adds it to a JSON object.
+/// Returns a pointer to the added array, or null if the object or the key is null,
+/// or the first parameter is not an object.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_add_array_to_object(
+    object: *mut YlongJson,
+    string: *const c_char,
+) -> *mut YlongJson {
+    add_value_to_object(object, string, JsonValue::Array(Array::new()))
+}
+
+/// Creates an empty JSON object and adds it to a JSON object.
+/// Returns a pointer to the added object, or null if the object or the key is null,
+/// or the first parameter is not an object.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_add_object_to_object(
+    object: *mut YlongJson,
+    string: *const c_char,
+) -> *mut YlongJson {
+    add_value_to_object(object, string, JsonValue::Object(Object::new()))
+}
+
+// Adds a value to a JSON object, then returns a pointer to the value in the object.
+unsafe fn add_value_to_object(
+    object: *mut YlongJson,
+    string: *const c_char,
+    value: JsonValue,
+) -> *mut YlongJson {
+    if object.is_null() || string.is_null() {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let object_ref = &mut *(object as *mut JsonValue);
+    let object_ref = match object_ref.try_as_mut_object() {
+        Ok(o) => o,
+        Err(_) => return NULL_MUT_YLONG_JSON,
+    };
+
+    let key = match key_from_raw(string) {
+        Some(k) => k,
+        None => return NULL_MUT_YLONG_JSON,
+    };
+
+    object_ref.insert(String::from(key), value);
+    match object_ref.get_mut(key) {
+        Some(v) => v as *mut JsonValue as *mut YlongJson,
+        None => NULL_MUT_YLONG_JSON,
+    }
+}
+
 /// Replaces an item in a JSON object by key.
 /// Returns a `c_int` indicating whether the operation was successful (SUCCESS) or failed (FAILURE).
 #[no_mangle]
@@ -836,12 +1704,10 @@ pub unsafe extern "C" fn ylong_json_replace_object_item_by_index(
         Err(_) => return FAILURE,
     };
 
-    // Using `ptr::slice_from_raw_parts` here dramatically
-    // reduces the cost of converting between char* and &[u8].
-    // Then use `from_utf8_unchecked` to further reduce cost.
-    let len = strlen(index);
-    let slice = &*slice_from_raw_parts(index as *mut u8, len);
-    let index = from_utf8_unchecked(slice);
+    let index = match key_from_raw(index) {
+        Some(k) => k,
+        None => return FAILURE,
+    };
 
     if let Some(value) = object_ref.get_mut(index) {
         let new_value = Box::from_raw(new_item as *mut JsonValue);
@@ -855,7 +1721,8 @@ pub unsafe extern "C" fn ylong_json_replace_object_item_by_index(
 }
 
 /// Removes an item in a JSON object by index.
-/// Returns a new JSON object without the item if successful, NULL_MUT_YLONG_JSON otherwise.
+/// The caller takes the ownership of the returned item and should delete it by `ylong_json_delete`.
+/// Returns the removed item if successful, NULL_MUT_YLONG_JSON otherwise.
 #[no_mangle]
 pub unsafe extern "C" fn ylong_json_remove_object_item_by_index(
     object: *mut YlongJson,
@@ -867,12 +1734,10 @@ pub unsafe extern "C" fn ylong_json_remove_object_item_by_index(
 
     let object = &mut *(object as *mut JsonValue);
 
-    // Using `ptr::slice_from_raw_parts` here dramatically
-    // reduces the cost of converting between char* and &[u8].
-    // Then use `from_utf8_unchecked` to further reduce cost.
-    let len = strlen(index);
-    let slice = &*slice_from_raw_parts(index as *mut u8, len);
-    let index = from_utf8_unchecked(slice);
+    let index = match key_from_raw(index) {
+        Some(k) => k,
+        None => return NULL_MUT_YLONG_JSON,
+    };
 
     if let Some(v) = object.remove(index) {
         return Box::into_raw(Box::new(v)) as *mut YlongJson;
@@ -893,12 +1758,10 @@ pub unsafe extern "C" fn ylong_json_delete_object_item_by_index(
 
     let object = &mut *(object as *mut JsonValue);
 
-    // Using ptr::slice_from_raw_parts here dramatically
-    // reduces the cost of converting between char* and &[u8].
-    // Then use from_utf8_unchecked to further reduce cost.
-    let len = strlen(index);
-    let slice = &*slice_from_raw_parts(index as *mut u8, len);
-    let index = from_utf8_unchecked(slice);
+    let index = match key_from_raw(index) {
+        Some(k) => k,
+        None => return,
+    };
 
     object.remove(index);
 }
@@ -923,8 +1786,7 @@ pub unsafe extern "C" fn ylong_json_get_all_object_items(
     };
 
     for (n, (k, v)) in object.iter_mut().enumerate() {
-        // k.clone ().into_bytes() is more efficient than k.as_bytes ().to_vec().
-        let k = CString::from_vec_unchecked(k.clone().into_bytes()).into_raw();
+        let k = CString::from_vec_unchecked(k.as_bytes().to_vec()).into_raw();
         let v = v as *mut JsonValue as *mut YlongJson;
         *(key.add(n)) = k;
         *(value.add(n)) = v;
@@ -957,6 +1819,108 @@ pub unsafe extern "C" fn ylong_json_for_each_object_item(
     SUCCESS
 }
 
+/// Applies a function to each key-value pair in a JSON object, passing `user_data` through.
+/// The key is only valid during the call of `func`. Iteration stops as soon as `func`
+/// returns FALSE.
+/// Returns SUCCESS if all pairs are visited, FAILURE if the object is null or not an object,
+/// or the iteration is stopped by `func`.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_for_each_object_item_with_context(
+    object: *mut YlongJson,
+    func: unsafe extern "C" fn(*const c_char, *mut YlongJson, *mut c_void) -> c_int,
+    user_data: *mut c_void,
+) -> c_int {
+    if object.is_null() {
+        return FAILURE;
+    }
+
+    let object = &mut *(object as *mut JsonValue);
+    let object = match object.try_as_mut_object() {
+        Ok(o) => o,
+        Err(_) => return FAILURE,
+    };
+
+    for (k, v) in object.iter_mut() {
+        let key = CString::from_vec_unchecked(k.as_bytes().to_vec());
+        let value = v as *mut JsonValue as *mut YlongJson;
+        if func(key.as_ptr(), value, user_data) == FALSE {
+            return FAILURE;
+        }
+    }
+    SUCCESS
+}
+
+/// Iterator over the items of a JSON object, created by `ylong_json_object_iter_create`.
+struct ObjectIter {
+    inner: Box<dyn Iterator<Item = (*const Key, *mut JsonValue)>>,
+    // The key of the current item, which is returned to C.
+    key: CString,
+}
+
+/// Creates an iterator over the key-value pairs of a JSON object.
+/// The object must not be changed or deleted until the iterator is freed by
+/// `ylong_json_object_iter_free`, but the values can be changed in place.
+/// Returns null if the object is null or not an object.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_object_iter_create(
+    object: *mut YlongJson,
+) -> *mut YlongJsonIter {
+    if object.is_null() {
+        return NULL_MUT_YLONG_JSON;
+    }
+
+    let object = &mut *(object as *mut JsonValue);
+    let object = match object.try_as_mut_object() {
+        Ok(o) => o,
+        Err(_) => return NULL_MUT_YLONG_JSON,
+    };
+
+    let inner = Box::new(
+        object
+            .iter_mut()
+            .map(|(k, v)| (k as *const Key, v as *mut JsonValue)),
+    );
+    let iter = ObjectIter {
+        inner,
+        key: CString::default(),
+    };
+    Box::into_raw(Box::new(iter)) as *mut YlongJsonIter
+}
+
+/// Gets the next key-value pair of an object iterator.
+/// The key is owned by the iterator and is valid until the next call of this function or
+/// `ylong_json_object_iter_free`, so it must not be freed. The value is still owned by the object.
+/// Returns `FAILURE` if any parameter is null or all items have been visited, and `SUCCESS` otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_object_iter_next(
+    iter: *mut YlongJsonIter,
+    key: *mut *const c_char,
+    value: *mut *mut YlongJson,
+) -> c_int {
+    if iter.is_null() || key.is_null() || value.is_null() {
+        return FAILURE;
+    }
+
+    let iter = &mut *(iter as *mut ObjectIter);
+    let (k, v) = match iter.inner.next() {
+        Some(item) => item,
+        None => return FAILURE,
+    };
+    iter.key = CString::from_vec_unchecked((*k).as_bytes().to_vec());
+    *key = iter.key.as_ptr();
+    *value = v as *mut YlongJson;
+    SUCCESS
+}
+
+/// Frees an object iterator, together with the key returned last. The object is not changed.
+#[no_mangle]
+pub unsafe extern "C" fn ylong_json_object_iter_free(iter: *mut YlongJsonIter) {
+    if iter.is_null() {
+        return;
+    }
+    let _ = Box::from_raw(iter as *mut ObjectIter);
+}
+
 /// Gets an object node from a JSON object by key.
 /// Returns a pointer to the object node if successful, NULL_MUT_YLONG_JSON otherwise.
 #[cfg(feature = "list_object")]
@@ -978,19 +1942,17 @@ pub unsafe extern "C" fn ylong_json_get_object_node(
         Err(_) => return NULL_MUT_YLONG_JSON,
     };
 
-    // Using `ptr::slice_from_raw_parts` here dramatically
-    // reduces the cost of converting between char* and &[u8].
-    // Then use `from_utf8_unchecked` to further reduce cost.
-    let len = strlen(string);
-    let slice = &*slice_from_raw_parts(string as *mut u8, len);
-    let index = from_utf8_unchecked(slice);
+    let index = match key_from_raw(string) {
+        Some(k) => k,
+        None => return NULL_MUT_YLONG_JSON,
+    };
 
-    // When using list to get a node, the return value points to the memory is CursorMut<JsonValue>.
+    // The node is handed out as a handle, which the node interfaces check before use.
     let target = match object_ref.get_node_mut(index) {
         Some(v) => v,
         None => return NULL_MUT_YLONG_JSON,
     };
-    target as *mut Node<(String, JsonValue)> as *mut YlongJson
+    target.export() as *mut YlongJson
 }
 
 /// Gets an item from an object node.
@@ -1004,7 +1966,11 @@ pub unsafe extern "C" fn ylong_json_get_item_from_object_node(
         return NULL_MUT_YLONG_JSON;
     }
 
-    let node = &mut *(object_node as *mut Node<(String, JsonValue)>);
+    // A removed node, or a node of a deleted object, is rejected.
+    let node = match Node::<(Key, JsonValue)>::from_exported(object_node as usize) {
+        Some(node) => node,
+        None => return NULL_MUT_YLONG_JSON,
+    };
     (&mut node.get_element_mut().1) as *mut JsonValue as *mut YlongJson
 }
 
@@ -1028,18 +1994,16 @@ pub unsafe extern "C" fn ylong_json_add_item_to_object_then_get_node(
         Err(_) => return NULL_MUT_YLONG_JSON,
     };
 
-    // Using `ptr::slice_from_raw_parts` here dramatically
-    // reduces the cost of converting between char* and &[u8].
-    // Then use `from_utf8_unchecked` to further reduce cost.
-    let len = strlen(string);
-    let slice = &*slice_from_raw_parts(string as *mut u8, len);
-    let string = from_utf8_unchecked(slice);
+    let string = match key_from_raw(string) {
+        Some(k) => k,
+        None => return NULL_MUT_YLONG_JSON,
+    };
 
     let value = Box::from_raw(item as *mut JsonValue);
     object_ref.insert(String::from(string), *value);
 
     let target = object_ref.last_node_mut().unwrap();
-    target as *mut Node<(String, JsonValue)> as *mut YlongJson
+    target.export() as *mut YlongJson
 }
 
 /// Replaces an item in an object node.
@@ -1054,7 +2018,11 @@ pub unsafe extern "C" fn ylong_json_replace_item_of_object_node(
         return FAILURE;
     }
 
-    let node = &mut *(object_node as *mut Node<(String, JsonValue)>);
+    // A removed node, or a node of a deleted object, is rejected.
+    let node = match Node::<(Key, JsonValue)>::from_exported(object_node as usize) {
+        Some(node) => node,
+        None => return FAILURE,
+    };
     let (_, value) = node.get_element_mut();
     let new_value = Box::from_raw(new_item as *mut JsonValue);
     *value = *new_value;
@@ -1063,6 +2031,7 @@ pub unsafe extern "C" fn ylong_json_replace_item_of_object_node(
 }
 
 /// Removes an object node.
+/// Returns null if the node has been removed or its object has been deleted.
 /// Returns a pointer to the removed item if successful, NULL_MUT_YLONG_JSON otherwise.
 #[cfg(feature = "list_object")]
 #[no_mangle]
@@ -1073,11 +2042,16 @@ pub unsafe extern "C" fn ylong_json_remove_object_node(
         return NULL_MUT_YLONG_JSON;
     }
 
-    let node = &mut *(object_node as *mut Node<(String, JsonValue)>);
+    // A removed node, or a node of a deleted object, is rejected.
+    let node = match Node::<(Key, JsonValue)>::from_exported(object_node as usize) {
+        Some(node) => node,
+        None => return NULL_MUT_YLONG_JSON,
+    };
     Box::into_raw(Box::new(node.remove_self().unwrap().1)) as *mut YlongJson
 }
 
 /// Deletes a node from a JSON object.
+/// Does nothing if the node has been removed or its object has been deleted.
 #[cfg(feature = "list_object")]
 #[no_mangle]
 pub unsafe extern "C" fn ylong_json_delete_object_node(object_node: *mut YlongJson) {
@@ -1085,7 +2059,11 @@ pub unsafe extern "C" fn ylong_json_delete_object_node(object_node: *mut YlongJs
         return;
     }
 
-    let node = &mut *(object_node as *mut Node<(String, JsonValue)>);
+    // A removed node, or a node of a deleted object, is rejected.
+    let node = match Node::<(Key, JsonValue)>::from_exported(object_node as usize) {
+        Some(node) => node,
+        None => return,
+    };
     let _ = node.remove_self();
 }
 
@@ -1095,7 +2073,7 @@ mod ut_adapter {
     use libc::*;
     use std::ffi::{CStr, CString};
     use std::mem::size_of;
-    use std::ptr::{null, null_mut};
+    use std::ptr::{null, null_mut, slice_from_raw_parts};
 
     const JSON_TEXT: &str = r#"
 {
@@ -1157,6 +2135,241 @@ mod ut_adapter {
         }
     }
 
+    /// UT test for `ylong_json_parse_with_length`.
+    ///
+    /// # Title
+    /// ut_ylong_json_parse_with_length
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_parse_with_length` to parse the beginning of some buffers.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_parse_with_length() {
+        unsafe {
+            // Null ptr
+            let err = null_mut::<c_char>();
+            let json = ylong_json_parse_with_length(
+                null(),
+                0,
+                &err as *const *mut c_char as *mut *mut c_char,
+            );
+            assert!(json.is_null());
+
+            // The buffer is not terminated with '\0', and has other contents behind.
+            let buf = b"[1, 2]garbage";
+            let err = null_mut::<c_char>();
+            let json = ylong_json_parse_with_length(
+                buf.as_ptr() as *const c_char,
+                6,
+                &err as *const *mut c_char as *mut *mut c_char,
+            );
+            assert!(err.is_null());
+            assert!(!json.is_null());
+            let result = ylong_json_print_unformatted(json);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "[1,2]");
+            ylong_json_delete(json);
+
+            // The contents behind the length are not parsed.
+            let err = null_mut::<c_char>();
+            let json = ylong_json_parse_with_length(
+                buf.as_ptr() as *const c_char,
+                5,
+                &err as *const *mut c_char as *mut *mut c_char,
+            );
+            assert!(!err.is_null());
+            assert!(json.is_null());
+            ylong_json_free_string(err);
+
+            // Embedded '\0' is an error instead of the end of the text.
+            let buf = b"[1]\0";
+            let err = null_mut::<c_char>();
+            let json = ylong_json_parse_with_length(
+                buf.as_ptr() as *const c_char,
+                4,
+                &err as *const *mut c_char as *mut *mut c_char,
+            );
+            assert!(!err.is_null());
+            assert!(json.is_null());
+            ylong_json_free_string(err);
+        }
+    }
+
+    /// UT test for `ylong_json_parse_with_opts`.
+    ///
+    /// # Title
+    /// ut_ylong_json_parse_with_opts
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_parse_with_opts` to parse some texts with different options.
+    /// 2. Checks if the returned values and end positions are correct.
+    #[test]
+    fn ut_ylong_json_parse_with_opts() {
+        unsafe {
+            // Null ptr
+            let json = ylong_json_parse_with_opts(null(), null_mut(), 0, null_mut());
+            assert!(json.is_null());
+
+            // Contents behind the value are allowed.
+            let str = str_to_c_char("[1, 2] [3]");
+            let mut end = null::<c_char>();
+            let json = ylong_json_parse_with_opts(str, &mut end, 0, null_mut());
+            assert!(!json.is_null());
+            assert_eq!(end.offset_from(str), 6);
+            ylong_json_delete(json);
+
+            // Only whitespace is allowed behind the value.
+            let err = null_mut::<c_char>();
+            let json = ylong_json_parse_with_opts(
+                str,
+                &mut end,
+                1,
+                &err as *const *mut c_char as *mut *mut c_char,
+            );
+            assert!(json.is_null());
+            assert!(!err.is_null());
+            assert_eq!(end.offset_from(str), 7);
+            ylong_json_free_string(err);
+            let _ = Box::from_raw(str);
+
+            let str = str_to_c_char("[1, 2] \n");
+            let json = ylong_json_parse_with_opts(str, &mut end, 1, null_mut());
+            assert!(!json.is_null());
+            assert_eq!(end.offset_from(str), 8);
+            ylong_json_delete(json);
+            let _ = Box::from_raw(str);
+
+            // The end points to the error.
+            let str = str_to_c_char("[1, 中]");
+            let json = ylong_json_parse_with_opts(str, &mut end, 0, null_mut());
+            assert!(json.is_null());
+            assert_eq!(end.offset_from(str), 4);
+            let _ = Box::from_raw(str);
+
+            let str = str_to_c_char("[1, ");
+            let json = ylong_json_parse_with_opts(str, &mut end, 0, null_mut());
+            assert!(json.is_null());
+            assert_eq!(end.offset_from(str), 4);
+            let _ = Box::from_raw(str);
+        }
+    }
+
+    /// UT test for `ylong_json_get_last_error`, `ylong_json_get_last_error_position` and
+    /// `ylong_json_get_last_error_offset`.
+    ///
+    /// # Title
+    /// ut_ylong_json_get_last_error
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_parse` to parse some texts.
+    /// 2. Calls `ylong_json_get_last_error`, `ylong_json_get_last_error_position` and
+    ///    `ylong_json_get_last_error_offset`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_get_last_error() {
+        unsafe fn offset(text: &str) -> Option<size_t> {
+            let str = str_to_c_char(text);
+            let json = ylong_json_parse(str, null_mut());
+            ylong_json_delete(json);
+            let _ = Box::from_raw(str);
+
+            let mut offset = 0;
+            (ylong_json_get_last_error_offset(&mut offset) == 1).then_some(offset)
+        }
+
+        unsafe fn parse(text: &str) -> (c_int, c_int, size_t, size_t) {
+            let str = str_to_c_char(text);
+            let err = null_mut::<c_char>();
+            let json = ylong_json_parse(str, &err as *const *mut c_char as *mut *mut c_char);
+            ylong_json_delete(json);
+            ylong_json_free_string(err);
+            let _ = Box::from_raw(str);
+
+            let (mut line, mut column) = (0, 0);
+            let found = ylong_json_get_last_error_position(&mut line, &mut column);
+            (ylong_json_get_last_error(), found, line, column)
+        }
+
+        unsafe {
+            assert_eq!(parse("[1, 2]"), (YLONG_JSON_ERR_NONE, 0, 0, 0));
+            assert_eq!(parse("[1,\n 2 x]"), (YLONG_JSON_ERR_SYNTAX, 1, 2, 4));
+            assert_eq!(parse("[1] 2"), (YLONG_JSON_ERR_SYNTAX, 1, 1, 0));
+            assert_eq!(parse("\n[1,"), (YLONG_JSON_ERR_EOF, 1, 2, 0));
+            assert_eq!(parse(&"[".repeat(1000)), (YLONG_JSON_ERR_DEPTH, 0, 0, 0));
+            assert_eq!(
+                parse("18446744073709551616"),
+                (YLONG_JSON_ERR_NUMBER, 0, 0, 0)
+            );
+            assert_eq!(parse(r#""\ud800\u0041""#), (YLONG_JSON_ERR_UTF8, 0, 0, 0));
+            assert_eq!(parse("null"), (YLONG_JSON_ERR_NONE, 0, 0, 0));
+
+            // Only the last parsing on the current thread is recorded.
+            parse("[");
+            std::thread::spawn(|| assert_eq!(ylong_json_get_last_error(), YLONG_JSON_ERR_NONE))
+                .join()
+                .unwrap();
+            assert_eq!(ylong_json_get_last_error(), YLONG_JSON_ERR_EOF);
+
+            assert_eq!(offset("[1,\n 2 x]"), Some(7));
+            assert_eq!(offset("[1] 2"), Some(4));
+            assert_eq!(offset("[1, 2]"), None);
+            assert_eq!(offset(&"[".repeat(1000)), None);
+        }
+    }
+
+    /// UT test for `ylong_json_parse_file`.
+    ///
+    /// # Title
+    /// ut_ylong_json_parse_file
+    ///
+    /// # Brief
+    /// 1. Writes some files.
+    /// 2. Calls `ylong_json_parse_file` to parse them.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_parse_file() {
+        unsafe {
+            // Null ptr
+            assert!(ylong_json_parse_file(null(), null_mut()).is_null());
+
+            let dir = std::env::temp_dir();
+            let path = dir.join("ut_ylong_json_parse_file.json");
+            std::fs::write(&path, "{\"array\":[1,2,3]}").unwrap();
+            let c_path = str_to_c_char(path.to_str().unwrap());
+            let err = null_mut::<c_char>();
+            let json =
+                ylong_json_parse_file(c_path, &err as *const *mut c_char as *mut *mut c_char);
+            assert!(err.is_null());
+            assert!(!json.is_null());
+            let result = ylong_json_print_unformatted(json);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "{\"array\":[1,2,3]}");
+            ylong_json_delete(json);
+
+            // Syntax error.
+            std::fs::write(&path, "[1,").unwrap();
+            let json =
+                ylong_json_parse_file(c_path, &err as *const *mut c_char as *mut *mut c_char);
+            assert!(json.is_null());
+            assert!(!err.is_null());
+            assert_eq!(ylong_json_get_last_error(), YLONG_JSON_ERR_EOF);
+            ylong_json_free_string(err);
+            std::fs::remove_file(&path).unwrap();
+            let _ = Box::from_raw(c_path);
+
+            // The file does not exist.
+            let c_path = str_to_c_char(dir.join("ut_ylong_json_no_such_file").to_str().unwrap());
+            let err = null_mut::<c_char>();
+            let json =
+                ylong_json_parse_file(c_path, &err as *const *mut c_char as *mut *mut c_char);
+            assert!(json.is_null());
+            assert!(!err.is_null());
+            assert_eq!(ylong_json_get_last_error(), YLONG_JSON_ERR_IO);
+            ylong_json_free_string(err);
+            let _ = Box::from_raw(c_path);
+        }
+    }
+
     //noinspection SpellCheckingInspection
     //noinspection ALL
     /// UT test for `ylong_json_free_string`.
@@ -1174,16 +2387,115 @@ mod ut_adapter {
             let string = null_mut();
             ylong_json_free_string(string);
 
-            let str = str_to_c_char(JSON_TEXT);
+            let str = str_to_c_char(JSON_TEXT);
+            let err = null_mut::<c_char>();
+            let json = ylong_json_parse(str, &err as *const *mut c_char as *mut *mut c_char);
+            assert!(err.is_null());
+            assert!(!json.is_null());
+
+            // The char* generated by `ylong_json_print_unformatted` needs
+            // to be destructed by calling `ylong_json_free_string`.
+            let result = ylong_json_print_unformatted(json);
+            ylong_json_free_string(result);
+
+            // Destruction
+            let _ = Box::from_raw(str);
+            ylong_json_delete(json);
+        }
+    }
+
+    /// UT test for `ylong_json_print_unformatted`.
+    ///
+    /// # Title
+    /// ut_ylong_json_print_unformatted
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_print_unformatted` to print the value as `C` string.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_print_unformatted() {
+        unsafe {
+            // Null ptr
+            let json = null_mut();
+            assert!(ylong_json_print_unformatted(json).is_null());
+
+            // Correct scene
+            let str = str_to_c_char("{\"array\":[1,2,3]}");
+            let err = null_mut::<c_char>();
+            let json = ylong_json_parse(str, &err as *const *mut c_char as *mut *mut c_char);
+            assert!(err.is_null());
+            assert!(!json.is_null());
+
+            let result = ylong_json_print_unformatted(json);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "{\"array\":[1,2,3]}");
+
+            // Destruction
+            let _ = Box::from_raw(str);
+            ylong_json_delete(json);
+        }
+    }
+
+    /// UT test for `ylong_json_print_preallocated`.
+    ///
+    /// # Title
+    /// ut_ylong_json_print_preallocated
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_print_preallocated` to print the value into buffers of different lengths.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_print_preallocated() {
+        unsafe {
+            let mut buf = [1 as c_char; 32];
+
+            // Null ptr
+            let json = null_mut();
+            assert_eq!(
+                ylong_json_print_preallocated(json, buf.as_mut_ptr(), 32, 0),
+                0
+            );
+
+            let str = str_to_c_char("{\"array\":[1,2]}");
             let err = null_mut::<c_char>();
             let json = ylong_json_parse(str, &err as *const *mut c_char as *mut *mut c_char);
             assert!(err.is_null());
             assert!(!json.is_null());
 
-            // The char* generated by `ylong_json_print_unformatted` needs
-            // to be destructed by calling `ylong_json_free_string`.
-            let result = ylong_json_print_unformatted(json);
-            ylong_json_free_string(result);
+            // Plain format, the buffer is large enough.
+            assert_eq!(
+                ylong_json_print_preallocated(json, buf.as_mut_ptr(), 32, 0),
+                1
+            );
+            let result = CStr::from_ptr(buf.as_ptr()).to_str().unwrap();
+            assert_eq!(result, "{\"array\":[1,2]}");
+
+            // Exactly enough for the output and the terminator.
+            assert_eq!(
+                ylong_json_print_preallocated(json, buf.as_mut_ptr(), 16, 0),
+                1
+            );
+            // The terminator does not fit.
+            assert_eq!(
+                ylong_json_print_preallocated(json, buf.as_mut_ptr(), 15, 0),
+                0
+            );
+            assert_eq!(
+                ylong_json_print_preallocated(json, buf.as_mut_ptr(), 0, 0),
+                0
+            );
+
+            // Formatted format.
+            assert_eq!(
+                ylong_json_print_preallocated(json, buf.as_mut_ptr(), 24, 1),
+                0
+            );
+            assert_eq!(
+                ylong_json_print_preallocated(json, buf.as_mut_ptr(), 32, 1),
+                1
+            );
+            let result = CStr::from_ptr(buf.as_ptr()).to_str().unwrap();
+            assert_eq!(result, "{\n    \"array\": [1, 2]\n}\n");
 
             // Destruction
             let _ = Box::from_raw(str);
@@ -1191,33 +2503,46 @@ mod ut_adapter {
         }
     }
 
-    /// UT test for `ylong_json_print_unformatted`.
+    /// UT test for `ylong_json_print_to_file`.
     ///
     /// # Title
-    /// ut_ylong_json_print_unformatted
+    /// ut_ylong_json_print_to_file
     ///
     /// # Brief
-    /// 1. Calls `ylong_json_print_unformatted` to print the value as `C` string.
+    /// 1. Calls `ylong_json_print_to_file` to print the value into a file.
     /// 2. Checks if the test results are correct.
     #[test]
-    fn ut_ylong_json_print_unformatted() {
+    fn ut_ylong_json_print_to_file() {
         unsafe {
+            let path = std::env::temp_dir().join("ut_ylong_json_print_to_file.json");
+            let c_path = str_to_c_char(path.to_str().unwrap());
+
             // Null ptr
-            let json = null_mut();
-            assert!(ylong_json_print_unformatted(json).is_null());
+            assert_eq!(ylong_json_print_to_file(null_mut(), c_path, 0), 0);
 
-            // Correct scene
-            let str = str_to_c_char("{\"array\":[1,2,3]}");
+            let str = str_to_c_char("{\"array\":[1,2]}");
             let err = null_mut::<c_char>();
             let json = ylong_json_parse(str, &err as *const *mut c_char as *mut *mut c_char);
-            assert!(err.is_null());
             assert!(!json.is_null());
+            assert_eq!(ylong_json_print_to_file(json, null(), 0), 0);
 
-            let result = ylong_json_print_unformatted(json);
-            let result = CString::from_raw(result).into_string().unwrap();
-            assert_eq!(result, "{\"array\":[1,2,3]}");
+            assert_eq!(ylong_json_print_to_file(json, c_path, 0), 1);
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), "{\"array\":[1,2]}");
+            assert_eq!(ylong_json_print_to_file(json, c_path, 1), 1);
+            assert_eq!(
+                std::fs::read_to_string(&path).unwrap(),
+                "{\n    \"array\": [1, 2]\n}\n"
+            );
+            std::fs::remove_file(&path).unwrap();
+
+            // The directory does not exist.
+            let bad_path = std::env::temp_dir().join("ut_ylong_json_no_such_dir/file.json");
+            let c_bad_path = str_to_c_char(bad_path.to_str().unwrap());
+            assert_eq!(ylong_json_print_to_file(json, c_bad_path, 0), 0);
 
             // Destruction
+            let _ = Box::from_raw(c_bad_path);
+            let _ = Box::from_raw(c_path);
             let _ = Box::from_raw(str);
             ylong_json_delete(json);
         }
@@ -1307,6 +2632,89 @@ mod ut_adapter {
         }
     }
 
+    /// UT test for `ylong_json_compare`.
+    ///
+    /// # Title
+    /// ut_ylong_json_compare
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_compare` to compare some items.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_compare() {
+        unsafe {
+            let str = str_to_c_char(r#"{"Key": [1, "a", {"b": null}], "other": true}"#);
+            let a = ylong_json_parse(str, null_mut());
+            let _ = Box::from_raw(str);
+            let str = str_to_c_char(r#"{"other": true, "key": [1, "a", {"B": null}]}"#);
+            let b = ylong_json_parse(str, null_mut());
+            let _ = Box::from_raw(str);
+
+            // Null ptr
+            assert_eq!(ylong_json_compare(a, null(), 1), 0);
+            assert_eq!(ylong_json_compare(null(), null(), 1), 0);
+
+            assert_eq!(ylong_json_compare(a, a, 1), 1);
+            assert_eq!(ylong_json_compare(a, b, 1), 0);
+            assert_eq!(ylong_json_compare(a, b, 0), 1);
+
+            let c = ylong_json_duplicate(b, 1);
+            assert_eq!(ylong_json_compare(b, c, 1), 1);
+            let str = str_to_c_char("other");
+            ylong_json_delete_object_item_by_index(c, str);
+            let _ = Box::from_raw(str);
+            assert_eq!(ylong_json_compare(b, c, 0), 0);
+
+            let null = ylong_json_create_null();
+            assert_eq!(ylong_json_compare(a, null, 0), 0);
+            ylong_json_delete(null);
+            ylong_json_delete(a);
+            ylong_json_delete(b);
+            ylong_json_delete(c);
+        }
+    }
+
+    /// UT test for `ylong_json_merge_patch` and `ylong_json_merge`.
+    ///
+    /// # Title
+    /// ut_ylong_json_merge
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_merge_patch` and `ylong_json_merge` to merge some items.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_merge() {
+        unsafe {
+            let str = str_to_c_char(r#"{"a": {"b": 1, "c": 2}, "d": 3}"#);
+            let target = ylong_json_parse(str, null_mut());
+            let _ = Box::from_raw(str);
+            let str = str_to_c_char(r#"{"a": {"c": null}, "e": 4}"#);
+            let patch = ylong_json_parse(str, null_mut());
+            let _ = Box::from_raw(str);
+
+            // Null ptr
+            assert_eq!(ylong_json_merge_patch(null_mut(), patch), 0);
+            assert_eq!(ylong_json_merge_patch(target, null()), 0);
+            assert_eq!(ylong_json_merge(null_mut(), patch), 0);
+            assert_eq!(ylong_json_merge(target, null()), 0);
+
+            let dst = ylong_json_duplicate(target, 1);
+            assert_eq!(ylong_json_merge_patch(target, patch), 1);
+            let result = ylong_json_print_unformatted(target);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, r#"{"a":{"b":1},"d":3,"e":4}"#);
+
+            assert_eq!(ylong_json_merge(dst, patch), 1);
+            let result = ylong_json_print_unformatted(dst);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, r#"{"a":{"b":1,"c":null},"d":3,"e":4}"#);
+
+            ylong_json_delete(target);
+            ylong_json_delete(patch);
+            ylong_json_delete(dst);
+        }
+    }
+
     /// UT test for `ylong_json_create_null`.
     ///
     /// # Title
@@ -1704,6 +3112,39 @@ mod ut_adapter {
         }
     }
 
+    /// UT test for `ylong_json_get_double_value` and `ylong_json_get_int_value`.
+    ///
+    /// # Title
+    /// ut_ylong_json_get_number_value
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_get_double_value` and `ylong_json_get_int_value` to get
+    ///    the value of numbers and other items.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_get_number_value() {
+        unsafe {
+            // Null ptr
+            assert!(ylong_json_get_double_value(null_mut()).is_nan());
+            assert_eq!(ylong_json_get_int_value(null_mut()), 0);
+
+            let double = ylong_json_create_double_number(3.5);
+            assert_eq!(ylong_json_get_double_value(double), 3.5);
+            assert_eq!(ylong_json_get_int_value(double), 3);
+            ylong_json_delete(double);
+
+            let int = ylong_json_create_int_number(-7);
+            assert_eq!(ylong_json_get_double_value(int), -7.0);
+            assert_eq!(ylong_json_get_int_value(int), -7);
+            ylong_json_delete(int);
+
+            let null = ylong_json_create_null();
+            assert!(ylong_json_get_double_value(null).is_nan());
+            assert_eq!(ylong_json_get_int_value(null), 0);
+            ylong_json_delete(null);
+        }
+    }
+
     /// UT test for `ylong_json_set_double_value_to_number`.
     ///
     /// # Title
@@ -1809,6 +3250,95 @@ mod ut_adapter {
         }
     }
 
+    /// UT test for `ylong_json_create_string_reference` and the functions adding string references.
+    ///
+    /// # Title
+    /// ut_ylong_json_string_reference
+    ///
+    /// # Brief
+    /// 1. Creates string references, and adds them to an array and an object.
+    /// 2. Checks if the items refer to the C strings without copying them.
+    /// 3. Deletes, duplicates and changes the items, and checks if the C strings are not freed
+    ///    or changed.
+    #[test]
+    fn ut_ylong_json_string_reference() {
+        unsafe {
+            assert!(ylong_json_create_string_reference(null()).is_null());
+
+            let text = CString::new("Hello World").unwrap();
+            let string = ylong_json_create_string_reference(text.as_ptr());
+            assert_eq!(
+                ylong_json_get_string_value(string) as *const c_char,
+                text.as_ptr()
+            );
+
+            // A duplicate owns a copy.
+            let duplicate = ylong_json_duplicate(string, 1);
+            let content = ylong_json_get_string_value(duplicate);
+            assert_ne!(content as *const c_char, text.as_ptr());
+            assert_eq!(CStr::from_ptr(content).to_bytes(), b"Hello World");
+            ylong_json_delete(duplicate);
+
+            // Setting a new value does not change the C string.
+            let other = CString::new("Other").unwrap();
+            assert_eq!(ylong_json_set_value_to_string(string, other.as_ptr()), 1);
+            assert_eq!(
+                CStr::from_ptr(ylong_json_get_string_value(string)).to_bytes(),
+                b"Other"
+            );
+            ylong_json_delete(string);
+            assert_eq!(text.as_bytes(), b"Hello World");
+
+            // Invalid UTF-8 is copied.
+            let invalid = CString::new(vec![b'a', 0xff]).unwrap();
+            let string = ylong_json_create_string_reference(invalid.as_ptr());
+            let content = ylong_json_get_string_value(string);
+            assert_ne!(content as *const c_char, invalid.as_ptr());
+            assert_eq!(CStr::from_ptr(content).to_str().unwrap(), "a\u{fffd}");
+            ylong_json_delete(string);
+
+            let array = ylong_json_create_array();
+            assert_eq!(ylong_json_add_string_reference_to_array(array, null()), 0);
+            assert_eq!(
+                ylong_json_add_string_reference_to_array(null_mut(), text.as_ptr()),
+                0
+            );
+            assert_eq!(
+                ylong_json_add_string_reference_to_array(array, text.as_ptr()),
+                1
+            );
+            let item = ylong_json_get_array_item(array, 0);
+            assert_eq!(
+                ylong_json_get_string_value(item) as *const c_char,
+                text.as_ptr()
+            );
+
+            let object = ylong_json_create_object();
+            let key = CString::new("key").unwrap();
+            assert!(
+                ylong_json_add_string_reference_to_object(object, key.as_ptr(), null()).is_null()
+            );
+            assert!(
+                ylong_json_add_string_reference_to_object(array, key.as_ptr(), text.as_ptr())
+                    .is_null()
+            );
+            let item =
+                ylong_json_add_string_reference_to_object(object, key.as_ptr(), text.as_ptr());
+            assert_eq!(
+                ylong_json_get_string_value(item) as *const c_char,
+                text.as_ptr()
+            );
+
+            let printed = ylong_json_print_unformatted(array);
+            assert_eq!(CStr::from_ptr(printed).to_bytes(), br#"["Hello World"]"#);
+            ylong_json_free_string(printed);
+
+            ylong_json_delete(array);
+            ylong_json_delete(object);
+            assert_eq!(text.as_bytes(), b"Hello World");
+        }
+    }
+
     /// UT test for `ylong_json_is_string`.
     ///
     /// # Title
@@ -1875,6 +3405,182 @@ mod ut_adapter {
             );
             ylong_json_delete(null);
         }
+
+        // The item is not changed by the getter, so it can be read on several threads.
+        let item = JsonValue::new_string("Hello World");
+        let pointers: Vec<usize> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| {
+                    scope.spawn(|| unsafe {
+                        let mut content = null_mut();
+                        let item = &item as *const JsonValue as *const YlongJson;
+                        assert_eq!(ylong_json_get_value_from_string(item, &mut content), 1);
+                        assert_eq!(CStr::from_ptr(content).to_bytes(), b"Hello World");
+                        content as usize
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert!(pointers.iter().all(|p| *p == pointers[0]));
+    }
+
+    /// UT test for `ylong_json_get_string_value`.
+    ///
+    /// # Title
+    /// ut_ylong_json_get_string_value
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_get_string_value` to get the value of strings and other items.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_get_string_value() {
+        unsafe {
+            // Null ptr
+            assert!(ylong_json_get_string_value(null_mut()).is_null());
+
+            let str = str_to_c_char("Hello World");
+            let string = ylong_json_create_string(str);
+            let value = ylong_json_get_string_value(string);
+            assert_eq!(CStr::from_ptr(value).to_str().unwrap(), "Hello World");
+            ylong_json_delete(string);
+            let _ = Box::from_raw(str);
+
+            let null = ylong_json_create_null();
+            assert!(ylong_json_get_string_value(null).is_null());
+            ylong_json_delete(null);
+        }
+    }
+
+    /// UT test for `ylong_json_get_string_into`.
+    ///
+    /// # Title
+    /// ut_ylong_json_get_string_into
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_get_string_into` to copy strings into buffers of different sizes.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_get_string_into() {
+        unsafe {
+            let mut buf = [1 as c_char; 8];
+            let mut written = 0usize;
+
+            // Null ptr
+            assert_eq!(
+                ylong_json_get_string_into(null(), buf.as_mut_ptr(), 8, &mut written),
+                0
+            );
+            let str = str_to_c_char("Hello");
+            let string = ylong_json_create_string(str);
+            let _ = Box::from_raw(str);
+            assert_eq!(
+                ylong_json_get_string_into(string, null_mut(), 8, &mut written),
+                0
+            );
+
+            // The buffer is too small for the NUL.
+            assert_eq!(
+                ylong_json_get_string_into(string, buf.as_mut_ptr(), 5, &mut written),
+                0
+            );
+            assert_eq!(written, 5);
+            assert_eq!(buf, [1; 8]);
+
+            assert_eq!(
+                ylong_json_get_string_into(string, buf.as_mut_ptr(), 6, null_mut()),
+                1
+            );
+            assert_eq!(CStr::from_ptr(buf.as_ptr()).to_str().unwrap(), "Hello");
+            assert_eq!(buf[6], 1);
+            ylong_json_delete(string);
+
+            let null = ylong_json_create_null();
+            assert_eq!(
+                ylong_json_get_string_into(null, buf.as_mut_ptr(), 8, &mut written),
+                0
+            );
+            ylong_json_delete(null);
+        }
+    }
+
+    /// UT test for `ylong_json_create_string_with_length`,
+    /// `ylong_json_get_value_from_string_with_length` and
+    /// `ylong_json_set_value_to_string_with_length`.
+    ///
+    /// # Title
+    /// ut_ylong_json_string_with_length
+    ///
+    /// # Brief
+    /// 1. Creates, parses, gets and sets strings containing NUL.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_string_with_length() {
+        unsafe {
+            let mut value = null_mut();
+            let mut len = 0usize;
+
+            // Null ptr
+            assert!(ylong_json_create_string_with_length(null(), 0).is_null());
+            assert_eq!(
+                ylong_json_get_value_from_string_with_length(null(), &mut value, &mut len),
+                0
+            );
+            assert_eq!(
+                ylong_json_set_value_to_string_with_length(null_mut(), null(), 0),
+                0
+            );
+
+            let bytes = b"a\0b";
+            let string = ylong_json_create_string_with_length(bytes.as_ptr() as *const c_char, 3);
+            assert_eq!(
+                ylong_json_get_value_from_string_with_length(string, &mut value, &mut len),
+                1
+            );
+            assert_eq!(len, 3);
+            assert_eq!(&*slice_from_raw_parts(value as *const u8, 4), b"a\0b\0");
+            let result = ylong_json_print_unformatted(string);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "\"a\\u0000b\"");
+
+            let bytes = b"\0\0";
+            assert_eq!(
+                ylong_json_set_value_to_string_with_length(
+                    string,
+                    bytes.as_ptr() as *const c_char,
+                    2
+                ),
+                1
+            );
+            assert_eq!(
+                ylong_json_get_value_from_string_with_length(string, &mut value, &mut len),
+                1
+            );
+            assert_eq!(len, 2);
+            ylong_json_delete(string);
+
+            // "\u0000" round-trips.
+            let text = str_to_c_char(r#"["x\u0000y"]"#);
+            let array = ylong_json_parse(text, null_mut());
+            let _ = Box::from_raw(text);
+            let item = ylong_json_get_array_item(array, 0);
+            assert_eq!(
+                ylong_json_get_value_from_string_with_length(item, &mut value, &mut len),
+                1
+            );
+            assert_eq!(&*slice_from_raw_parts(value as *const u8, len), b"x\0y");
+            let result = ylong_json_print_unformatted(array);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, r#"["x\u0000y"]"#);
+            ylong_json_delete(array);
+
+            let null = ylong_json_create_null();
+            assert_eq!(
+                ylong_json_get_value_from_string_with_length(null, &mut value, &mut len),
+                0
+            );
+            ylong_json_delete(null);
+        }
     }
 
     /// UT test for `ylong_json_set_value_to_string`.
@@ -1947,6 +3653,60 @@ mod ut_adapter {
         }
     }
 
+    /// UT test for `ylong_json_create_int_array`, `ylong_json_create_double_array` and
+    /// `ylong_json_create_string_array`.
+    ///
+    /// # Title
+    /// ut_ylong_json_create_primitive_array
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_create_*_array` to create arrays from C arrays.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_create_primitive_array() {
+        unsafe {
+            // Null ptr
+            assert!(ylong_json_create_int_array(null(), 0).is_null());
+            assert!(ylong_json_create_double_array(null(), 0).is_null());
+            assert!(ylong_json_create_string_array(null(), 0).is_null());
+
+            let ints = [1i64, -2, 3];
+            assert!(ylong_json_create_int_array(ints.as_ptr(), -1).is_null());
+            let array = ylong_json_create_int_array(ints.as_ptr(), 3);
+            let result = ylong_json_print_unformatted(array);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "[1,-2,3]");
+            ylong_json_delete(array);
+
+            let array = ylong_json_create_int_array(ints.as_ptr(), 0);
+            let result = ylong_json_print_unformatted(array);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "[]");
+            ylong_json_delete(array);
+
+            let doubles = [1.5f64, -0.25];
+            let array = ylong_json_create_double_array(doubles.as_ptr(), 2);
+            let result = ylong_json_print_unformatted(array);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "[1.5,-0.25]");
+            ylong_json_delete(array);
+
+            let a = str_to_c_char("a");
+            let b = str_to_c_char("Hello World");
+            let strings = [a as *const c_char, b as *const c_char];
+            let array = ylong_json_create_string_array(strings.as_ptr(), 2);
+            let result = ylong_json_print_unformatted(array);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "[\"a\",\"Hello World\"]");
+            ylong_json_delete(array);
+
+            let strings = [a as *const c_char, null()];
+            assert!(ylong_json_create_string_array(strings.as_ptr(), 2).is_null());
+            let _ = Box::from_raw(a);
+            let _ = Box::from_raw(b);
+        }
+    }
+
     /// UT test for `ut_ylong_json_is_array`.
     ///
     /// # Title
@@ -2108,6 +3868,105 @@ mod ut_adapter {
         }
     }
 
+    /// UT test for `ylong_json_insert_item_in_array`.
+    ///
+    /// # Title
+    /// ut_ylong_json_insert_item_in_array
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_insert_item_in_array` to insert items into the array.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_insert_item_in_array() {
+        unsafe {
+            // Null ptr
+            let array = null_mut();
+            let item = ylong_json_create_null();
+            assert_eq!(ylong_json_insert_item_in_array(array, 0, item), 0);
+            ylong_json_delete(item);
+
+            let array = ylong_json_create_array();
+            assert_eq!(ylong_json_insert_item_in_array(array, 0, null_mut()), 0);
+
+            // Negative index
+            let item = ylong_json_create_null();
+            assert_eq!(ylong_json_insert_item_in_array(array, -1, item), 0);
+            ylong_json_delete(item);
+
+            assert_eq!(
+                ylong_json_insert_item_in_array(array, 0, ylong_json_create_double_number(1.0)),
+                1
+            );
+            assert_eq!(
+                ylong_json_insert_item_in_array(array, 0, ylong_json_create_double_number(0.0)),
+                1
+            );
+            assert_eq!(
+                ylong_json_insert_item_in_array(array, 1, ylong_json_create_null()),
+                1
+            );
+            assert_eq!(
+                ylong_json_insert_item_in_array(array, 10, ylong_json_create_bool(1)),
+                1
+            );
+            let result = ylong_json_print_unformatted(array);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "[0.0,null,1.0,true]");
+            ylong_json_delete(array);
+
+            let null = ylong_json_create_null();
+            let null2 = ylong_json_create_null();
+            assert_eq!(ylong_json_insert_item_in_array(null, 0, null2), 0);
+            ylong_json_delete(null);
+            ylong_json_delete(null2);
+        }
+    }
+
+    /// UT test for `ylong_json_array_iter_create`, `ylong_json_array_iter_next` and
+    /// `ylong_json_array_iter_free`.
+    ///
+    /// # Title
+    /// ut_ylong_json_array_iter
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_array_iter_*` to iterate over some items.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_array_iter() {
+        unsafe {
+            // Null ptr
+            assert!(ylong_json_array_iter_create(null_mut()).is_null());
+            assert!(ylong_json_array_iter_next(null_mut()).is_null());
+            ylong_json_array_iter_free(null_mut());
+
+            let object = ylong_json_create_object();
+            assert!(ylong_json_array_iter_create(object).is_null());
+            ylong_json_delete(object);
+
+            let str = str_to_c_char("[1, 2, 3]");
+            let array = ylong_json_parse(str, null_mut());
+            let _ = Box::from_raw(str);
+            let iter = ylong_json_array_iter_create(array);
+            let mut sum = 0;
+            loop {
+                let item = ylong_json_array_iter_next(iter);
+                if item.is_null() {
+                    break;
+                }
+                sum += ylong_json_get_int_value(item);
+                assert_eq!(ylong_json_set_int_value_to_number(item, 0), 1);
+            }
+            assert!(ylong_json_array_iter_next(iter).is_null());
+            ylong_json_array_iter_free(iter);
+            assert_eq!(sum, 6);
+
+            let result = ylong_json_print_unformatted(array);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "[0,0,0]");
+            ylong_json_delete(array);
+        }
+    }
+
     /// UT test for `ylong_json_replace_item_in_array`.
     ///
     /// # Title
@@ -2159,7 +4018,7 @@ mod ut_adapter {
     ///
     /// # Brief
     /// 1. Calls `ylong_json_remove_array_item_by_index` to remove an item in the array by index.
-    /// (Uses the method 'remove' of Array.)
+    ///    (Uses the method 'remove' of Array.)
     /// 2. Checks if the test results are correct.
     #[test]
     fn ut_ylong_json_remove_array_item_by_index() {
@@ -2209,6 +4068,67 @@ mod ut_adapter {
         }
     }
 
+    /// UT test for `ylong_json_detach_item_via_pointer`, `ylong_json_detach_item_from_array`
+    /// and `ylong_json_detach_item_from_object`.
+    ///
+    /// # Title
+    /// ut_ylong_json_detach_item
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_detach_item_*` to detach items from arrays and objects.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_detach_item() {
+        unsafe {
+            let str = str_to_c_char(r#"{"a": [1, 2, 3], "b": null, "c": true}"#);
+            let object = ylong_json_parse(str, null_mut());
+            let _ = Box::from_raw(str);
+            let key = str_to_c_char("a");
+            let array = ylong_json_get_object_item(object, key);
+
+            // Null ptr
+            assert!(ylong_json_detach_item_via_pointer(null_mut(), array).is_null());
+            assert!(ylong_json_detach_item_via_pointer(object, null()).is_null());
+            assert!(ylong_json_detach_item_from_array(null_mut(), 0).is_null());
+            assert!(ylong_json_detach_item_from_object(object, null()).is_null());
+
+            // Not a child of the parent.
+            let item = ylong_json_get_array_item(array, 1);
+            assert!(ylong_json_detach_item_via_pointer(object, item).is_null());
+
+            let item = ylong_json_detach_item_via_pointer(array, item);
+            let result = ylong_json_print_unformatted(item);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "2");
+            ylong_json_delete(item);
+
+            assert!(ylong_json_detach_item_from_array(array, -1).is_null());
+            assert!(ylong_json_detach_item_from_array(array, 2).is_null());
+            let item = ylong_json_detach_item_from_array(array, 1);
+            assert_eq!(ylong_json_get_int_value(item), 3);
+            ylong_json_delete(item);
+
+            let array = ylong_json_detach_item_via_pointer(object, array);
+            let result = ylong_json_print_unformatted(array);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "[1]");
+            ylong_json_delete(array);
+
+            let _ = Box::from_raw(key);
+            let key = str_to_c_char("c");
+            let item = ylong_json_detach_item_from_object(object, key);
+            assert_eq!(ylong_json_is_bool(item), 1);
+            ylong_json_delete(item);
+            assert!(ylong_json_detach_item_from_object(object, key).is_null());
+            let _ = Box::from_raw(key);
+
+            let result = ylong_json_print_unformatted(object);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "{\"b\":null}");
+            ylong_json_delete(object);
+        }
+    }
+
     /// UT test for `ylong_json_delete_array_item_by_index`.
     ///
     /// # Title
@@ -2216,7 +4136,7 @@ mod ut_adapter {
     ///
     /// # Brief
     /// 1. Calls `ylong_json_delete_array_item_by_index` to delete an item in the array by index.
-    /// (Uses the method 'remove' of underlying data structure.)
+    ///    (Uses the method 'remove' of underlying data structure.)
     /// 2. Checks if the test results are correct.
     #[test]
     fn ut_ylong_json_delete_array_item_by_index() {
@@ -2583,6 +4503,66 @@ mod ut_adapter {
         }
     }
 
+    /// UT test for using stale array nodes.
+    ///
+    /// # Title
+    /// ut_ylong_json_stale_array_node
+    ///
+    /// # Brief
+    /// 1. Gets array nodes, then removes them or deletes the array.
+    /// 2. Calls the array node interfaces with the stale nodes.
+    /// 3. Checks if the calls fail without touching the nodes.
+    #[cfg(feature = "list_array")]
+    #[test]
+    fn ut_ylong_json_stale_array_node() {
+        unsafe {
+            let str = str_to_c_char("[1, 2]");
+            let mut msg = null_mut();
+            let array = ylong_json_parse(str, &mut msg as *mut *mut c_char);
+            let _ = Box::from_raw(str);
+
+            let node0 = ylong_json_get_array_node(array, 0);
+            let node1 = ylong_json_get_array_node(array, 1);
+            let slot0 = Node::<JsonValue>::from_exported(node0 as usize).unwrap() as *mut Node<_>;
+            ylong_json_delete_array_node(node0);
+            assert!(ylong_json_remove_array_node(node0).is_null());
+            assert!(ylong_json_get_item_from_array_node(node0).is_null());
+            ylong_json_delete_array_node(node0);
+            let item = ylong_json_create_null();
+            assert_eq!(ylong_json_replace_item_of_array_node(node0, item), 0);
+            ylong_json_delete(item);
+
+            let mut len = 0i32;
+            assert_eq!(ylong_json_get_array_size(array, &mut len as *mut c_int), 1);
+            assert_eq!(len, 1);
+            assert!(!ylong_json_get_item_from_array_node(node1).is_null());
+
+            // The slot of the removed node is reused by a new node, which the stale
+            // handle does not reach.
+            let node2 = ylong_json_add_item_to_array_then_get_node(array, ylong_json_create_null());
+            let slot2 = Node::<JsonValue>::from_exported(node2 as usize).unwrap() as *mut Node<_>;
+            assert_eq!(slot2, slot0);
+            assert_ne!(node2, node0);
+            ylong_json_delete_array_node(node0);
+            assert!(ylong_json_remove_array_node(node0).is_null());
+            assert_eq!(ylong_json_get_array_size(array, &mut len as *mut c_int), 1);
+            assert_eq!(len, 2);
+            assert!(!ylong_json_get_item_from_array_node(node2).is_null());
+
+            // An array node is not an object node.
+            #[cfg(feature = "list_object")]
+            {
+                assert!(ylong_json_get_item_from_object_node(node1).is_null());
+                assert!(ylong_json_remove_object_node(node1).is_null());
+            }
+
+            ylong_json_delete(array);
+            assert!(ylong_json_get_item_from_array_node(node1).is_null());
+            assert!(ylong_json_remove_array_node(node1).is_null());
+            assert!(ylong_json_get_item_from_array_node(node2).is_null());
+        }
+    }
+
     /// UT test for `ylong_json_create_object`.
     ///
     /// # Title
@@ -2825,6 +4805,126 @@ mod ut_adapter {
             ylong_json_delete(null);
             let _ = Box::from_raw(str);
             ylong_json_delete(item);
+
+            // A key which is not valid UTF-8 can neither be added nor found.
+            let object = ylong_json_create_object();
+            let key = CString::new(vec![0xff, 0xfe]).unwrap();
+            let str = key.as_ptr();
+            let item = ylong_json_create_null();
+            assert_eq!(ylong_json_add_item_to_object(object, str, item), 0);
+            assert!(ylong_json_add_null_to_object(object, str).is_null());
+            assert_eq!(ylong_json_has_object_item(object, str), 0);
+            assert!(ylong_json_get_object_item(object, str).is_null());
+            assert!(ylong_json_remove_object_item_by_index(object, str).is_null());
+            let mut len = 0i32;
+            assert_eq!(
+                ylong_json_get_object_size(object, &mut len as *mut c_int),
+                1
+            );
+            assert_eq!(len, 0);
+            ylong_json_delete(object);
+            ylong_json_delete(item);
+        }
+    }
+
+    /// UT test for `ylong_json_add_null_to_object` and the other functions which create
+    /// an item and add it to an object.
+    ///
+    /// # Title
+    /// ut_ylong_json_add_value_to_object
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_add_*_to_object` to add items to the object.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_add_value_to_object() {
+        unsafe {
+            // Null ptr
+            let str = str_to_c_char("a");
+            assert!(ylong_json_add_null_to_object(null_mut(), str).is_null());
+            let object = ylong_json_create_object();
+            assert!(ylong_json_add_null_to_object(object, null()).is_null());
+            assert!(ylong_json_add_string_to_object(object, str, null()).is_null());
+            let _ = Box::from_raw(str);
+
+            let keys = ["a", "b", "c", "d", "e", "f", "g"].map(|key| str_to_c_char(key));
+            let value = str_to_c_char("Hello World");
+            assert!(ylong_json_is_null(ylong_json_add_null_to_object(object, keys[0])) == 1);
+            assert!(ylong_json_is_bool(ylong_json_add_bool_to_object(object, keys[1], 1)) == 1);
+            assert!(
+                ylong_json_is_int_number(ylong_json_add_int_to_object(object, keys[2], 1)) == 1
+            );
+            let double = ylong_json_add_double_to_object(object, keys[3], 1.5);
+            assert!(ylong_json_is_double_number(double) == 1);
+            let string = ylong_json_add_string_to_object(object, keys[4], value);
+            assert!(ylong_json_is_string(string) == 1);
+            let array = ylong_json_add_array_to_object(object, keys[5]);
+            assert_eq!(
+                ylong_json_add_item_to_array(array, ylong_json_create_null()),
+                1
+            );
+            let inner = ylong_json_add_object_to_object(object, keys[6]);
+            assert!(!ylong_json_add_null_to_object(inner, keys[0]).is_null());
+            let result = ylong_json_print_unformatted(object);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(
+                result,
+                "{\"a\":null,\"b\":true,\"c\":1,\"d\":1.5,\"e\":\"Hello World\",\"f\":[null],\"g\":{\"a\":null}}"
+            );
+            ylong_json_delete(object);
+
+            // Not an object
+            let null = ylong_json_create_null();
+            assert!(ylong_json_add_array_to_object(null, keys[0]).is_null());
+            ylong_json_delete(null);
+
+            for key in keys {
+                let _ = Box::from_raw(key);
+            }
+            let _ = Box::from_raw(value);
+        }
+    }
+
+    /// UT test for `ylong_json_object_iter_create`, `ylong_json_object_iter_next` and
+    /// `ylong_json_object_iter_free`.
+    ///
+    /// # Title
+    /// ut_ylong_json_object_iter
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_object_iter_*` to iterate over some items.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_object_iter() {
+        unsafe {
+            let mut key = null();
+            let mut value = null_mut();
+
+            // Null ptr
+            assert!(ylong_json_object_iter_create(null_mut()).is_null());
+            assert_eq!(
+                ylong_json_object_iter_next(null_mut(), &mut key, &mut value),
+                0
+            );
+            ylong_json_object_iter_free(null_mut());
+
+            let array = ylong_json_create_array();
+            assert!(ylong_json_object_iter_create(array).is_null());
+            ylong_json_delete(array);
+
+            let str = str_to_c_char(r#"{"a": 1, "b": 2}"#);
+            let object = ylong_json_parse(str, null_mut());
+            let _ = Box::from_raw(str);
+            let iter = ylong_json_object_iter_create(object);
+            assert_eq!(ylong_json_object_iter_next(iter, null_mut(), &mut value), 0);
+            let mut items = Vec::new();
+            while ylong_json_object_iter_next(iter, &mut key, &mut value) == 1 {
+                let k = CStr::from_ptr(key).to_str().unwrap().to_string();
+                items.push((k, ylong_json_get_int_value(value)));
+            }
+            ylong_json_object_iter_free(iter);
+            assert_eq!(items, vec![(String::from("a"), 1), (String::from("b"), 2)]);
+            ylong_json_delete(object);
         }
     }
 
@@ -3152,6 +5252,71 @@ mod ut_adapter {
         }
     }
 
+    /// UT test for `ylong_json_for_each_object_item_with_context`.
+    ///
+    /// # Title
+    /// ut_ylong_json_for_each_object_item_with_context
+    ///
+    /// # Brief
+    /// 1. Calls `ylong_json_for_each_object_item_with_context` to do `func` for each item
+    ///    in the object with a context.
+    /// 2. Checks if the test results are correct.
+    #[test]
+    fn ut_ylong_json_for_each_object_item_with_context() {
+        unsafe {
+            // Collects the keys and sums the values, and stops at the key "C".
+            unsafe extern "C" fn func(
+                key: *const c_char,
+                value: *mut YlongJson,
+                user_data: *mut c_void,
+            ) -> c_int {
+                let context = &mut *(user_data as *mut (String, i64));
+                let key = CStr::from_ptr(key).to_str().unwrap();
+                context.0.push_str(key);
+                context.1 += ylong_json_get_int_value(value);
+                (key != "C") as c_int
+            }
+
+            let mut context = (String::new(), 0i64);
+            let user_data = &mut context as *mut (String, i64) as *mut c_void;
+
+            // Null ptr
+            assert_eq!(
+                ylong_json_for_each_object_item_with_context(null_mut(), func, user_data),
+                0
+            );
+
+            let text = str_to_c_char(r#"{"A":1,"B":2}"#);
+            let object = ylong_json_parse(text, null_mut());
+            let _ = Box::from_raw(text);
+            assert_eq!(
+                ylong_json_for_each_object_item_with_context(object, func, user_data),
+                1
+            );
+            assert_eq!(context, (String::from("AB"), 3));
+            ylong_json_delete(object);
+
+            let mut context = (String::new(), 0i64);
+            let user_data = &mut context as *mut (String, i64) as *mut c_void;
+            let text = str_to_c_char(r#"{"A":1,"B":2,"C":3,"D":4}"#);
+            let object = ylong_json_parse(text, null_mut());
+            let _ = Box::from_raw(text);
+            assert_eq!(
+                ylong_json_for_each_object_item_with_context(object, func, user_data),
+                0
+            );
+            assert_eq!(context, (String::from("ABC"), 6));
+            ylong_json_delete(object);
+
+            let null = ylong_json_create_null();
+            assert_eq!(
+                ylong_json_for_each_object_item_with_context(null, func, user_data),
+                0
+            );
+            ylong_json_delete(null);
+        }
+    }
+
     /// UT test for `ylong_json_get_object_node`.
     ///
     /// # Title
@@ -3404,4 +5569,47 @@ mod ut_adapter {
             ylong_json_delete(object);
         }
     }
+
+    /// UT test for using stale object nodes.
+    ///
+    /// # Title
+    /// ut_ylong_json_stale_object_node
+    ///
+    /// # Brief
+    /// 1. Gets object nodes, then removes them or deletes the object.
+    /// 2. Calls the object node interfaces with the stale nodes.
+    /// 3. Checks if the calls fail without touching the nodes.
+    #[cfg(feature = "list_object")]
+    #[test]
+    fn ut_ylong_json_stale_object_node() {
+        unsafe {
+            let object = ylong_json_create_object();
+            let str = str_to_c_char("a");
+            let node_a =
+                ylong_json_add_item_to_object_then_get_node(object, str, ylong_json_create_null());
+            let _ = Box::from_raw(str);
+            let str = str_to_c_char("b");
+            let node_b =
+                ylong_json_add_item_to_object_then_get_node(object, str, ylong_json_create_null());
+            let _ = Box::from_raw(str);
+
+            let item = ylong_json_remove_object_node(node_a);
+            assert!(!item.is_null());
+            ylong_json_delete(item);
+            assert!(ylong_json_remove_object_node(node_a).is_null());
+            assert!(ylong_json_get_item_from_object_node(node_a).is_null());
+            ylong_json_delete_object_node(node_a);
+            let item = ylong_json_create_null();
+            assert_eq!(ylong_json_replace_item_of_object_node(node_a, item), 0);
+            ylong_json_delete(item);
+
+            let result = ylong_json_print_unformatted(object);
+            let result = CString::from_raw(result).into_string().unwrap();
+            assert_eq!(result, "{\"b\":null}");
+
+            ylong_json_delete(object);
+            assert!(ylong_json_get_item_from_object_node(node_b).is_null());
+            ylong_json_delete_object_node(node_b);
+        }
+    }
 }
@@ -0,0 +1,230 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::writer::Write;
+use crate::Error;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The way byte slices are represented in JSON text by the serde layer.
+///
+/// JSON has no native binary type, so `serialize_bytes` and `deserialize_bytes`
+/// need to agree on a representation.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{Base64Alphabet, BytesFormat};
+///
+/// let format = BytesFormat::Base64(Base64Alphabet::UrlSafe);
+/// assert_ne!(format, BytesFormat::Array);
+/// assert_eq!(BytesFormat::default(), BytesFormat::Array);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BytesFormat {
+    /// Byte slices are written as an array of numbers, such as `[1,2,3]`. (Default)
+    #[default]
+    Array,
+    /// Byte slices are written as a base64 string with the specified alphabet.
+    Base64(Base64Alphabet),
+}
+
+/// The alphabet used by `BytesFormat::Base64`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base64Alphabet {
+    /// The standard alphabet of RFC 4648, using `+` and `/`, with `=` padding.
+    Standard,
+    /// The URL and filename safe alphabet of RFC 4648, using `-` and `_`, with `=` padding.
+    UrlSafe,
+}
+
+const STANDARD_TABLE: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE_TABLE: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const PADDING: u8 = b'=';
+
+impl Base64Alphabet {
+    fn table(&self) -> &'static [u8; 64] {
+        match self {
+            Self::Standard => STANDARD_TABLE,
+            Self::UrlSafe => URL_SAFE_TABLE,
+        }
+    }
+
+    fn decode_byte(&self, byte: u8) -> Option<u8> {
+        match byte {
+            b'A'..=b'Z' => Some(byte - b'A'),
+            b'a'..=b'z' => Some(byte - b'a' + 26),
+            b'0'..=b'9' => Some(byte - b'0' + 52),
+            b'+' if *self == Self::Standard => Some(62),
+            b'/' if *self == Self::Standard => Some(63),
+            b'-' if *self == Self::UrlSafe => Some(62),
+            b'_' if *self == Self::UrlSafe => Some(63),
+            _ => None,
+        }
+    }
+}
+
+/// Encodes `input` into base64 and writes the result into `writer`.
+pub(crate) fn encode<W: Write>(
+    writer: &mut W,
+    input: &[u8],
+    alphabet: Base64Alphabet,
+) -> Result<(), Error> {
+    let table = alphabet.table();
+    // Encodes 3 bytes into 4 characters at one time.
+    let mut buf = [0u8; 4];
+    let mut chunks = input.chunks_exact(3);
+    for chunk in chunks.by_ref() {
+        let n = (chunk[0] as u32) << 16 | (chunk[1] as u32) << 8 | chunk[2] as u32;
+        buf[0] = table[(n >> 18) as usize & 0x3f];
+        buf[1] = table[(n >> 12) as usize & 0x3f];
+        buf[2] = table[(n >> 6) as usize & 0x3f];
+        buf[3] = table[n as usize & 0x3f];
+        writer.write_all(&buf)?;
+    }
+
+    match *chunks.remainder() {
+        [a] => {
+            let n = (a as u32) << 16;
+            buf[0] = table[(n >> 18) as usize & 0x3f];
+            buf[1] = table[(n >> 12) as usize & 0x3f];
+            buf[2] = PADDING;
+            buf[3] = PADDING;
+            writer.write_all(&buf)?;
+        }
+        [a, b] => {
+            let n = (a as u32) << 16 | (b as u32) << 8;
+            buf[0] = table[(n >> 18) as usize & 0x3f];
+            buf[1] = table[(n >> 12) as usize & 0x3f];
+            buf[2] = table[(n >> 6) as usize & 0x3f];
+            buf[3] = PADDING;
+            writer.write_all(&buf)?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Decodes base64 `input` into bytes. Padding is optional.
+pub(crate) fn decode(input: &[u8], alphabet: Base64Alphabet) -> Result<Vec<u8>, Error> {
+    // Removes the padding, at most two `=` are allowed.
+    let mut end = input.len();
+    while end > 0 && input[end - 1] == PADDING && input.len() - end < 2 {
+        end -= 1;
+    }
+    let input = &input[..end];
+    if input.len() % 4 == 1 {
+        return Err(Error::Custom(String::from("Invalid base64 length")));
+    }
+
+    let mut output = Vec::with_capacity(input.len() / 4 * 3 + 2);
+    let mut n = 0u32;
+    for (i, byte) in input.iter().enumerate() {
+        let bits = alphabet.decode_byte(*byte).ok_or_else(|| {
+            Error::Custom(format!("Invalid base64 character: {:?}", *byte as char))
+        })?;
+        n = n << 6 | bits as u32;
+        if i % 4 == 3 {
+            output.push((n >> 16) as u8);
+            output.push((n >> 8) as u8);
+            output.push(n as u8);
+            n = 0;
+        }
+    }
+
+    match input.len() % 4 {
+        2 => output.push((n >> 4) as u8),
+        3 => {
+            output.push((n >> 10) as u8);
+            output.push((n >> 2) as u8);
+        }
+        _ => {}
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod ut_base64 {
+    use crate::base64::{decode, encode};
+    use crate::Base64Alphabet;
+
+    /// UT test for `encode`.
+    ///
+    /// # Title
+    /// ut_base64_encode
+    ///
+    /// # Brief
+    /// 1. Creates some byte slices.
+    /// 2. Calls `encode`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_base64_encode() {
+        let cases: [(&[u8], &str); 7] = [
+            (b"", ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (b"fooba", "Zm9vYmE="),
+            (b"foobar", "Zm9vYmFy"),
+        ];
+        for (input, expected) in cases {
+            let mut output = Vec::new();
+            encode(&mut output, input, Base64Alphabet::Standard).unwrap();
+            assert_eq!(output, expected.as_bytes());
+        }
+
+        let mut output = Vec::new();
+        encode(&mut output, &[0xfb, 0xff], Base64Alphabet::Standard).unwrap();
+        assert_eq!(output, b"+/8=");
+
+        let mut output = Vec::new();
+        encode(&mut output, &[0xfb, 0xff], Base64Alphabet::UrlSafe).unwrap();
+        assert_eq!(output, b"-_8=");
+    }
+
+    /// UT test for `decode`.
+    ///
+    /// # Title
+    /// ut_base64_decode
+    ///
+    /// # Brief
+    /// 1. Creates some base64 texts.
+    /// 2. Calls `decode`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_base64_decode() {
+        assert_eq!(decode(b"", Base64Alphabet::Standard).unwrap(), b"");
+        assert_eq!(decode(b"Zg==", Base64Alphabet::Standard).unwrap(), b"f");
+        assert_eq!(decode(b"Zm8", Base64Alphabet::Standard).unwrap(), b"fo");
+        assert_eq!(
+            decode(b"Zm9vYmFy", Base64Alphabet::Standard).unwrap(),
+            b"foobar"
+        );
+        assert_eq!(
+            decode(b"+/8=", Base64Alphabet::Standard).unwrap(),
+            [0xfb, 0xff]
+        );
+        assert_eq!(
+            decode(b"-_8=", Base64Alphabet::UrlSafe).unwrap(),
+            [0xfb, 0xff]
+        );
+
+        assert!(decode(b"-_8=", Base64Alphabet::Standard).is_err());
+        assert!(decode(b"Z", Base64Alphabet::Standard).is_err());
+        assert!(decode(b"Zg===", Base64Alphabet::Standard).is_err());
+        assert!(decode(b"Z g=", Base64Alphabet::Standard).is_err());
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{from_reader, to_writer, Error, JsonValue};
+use flate2::read::{DeflateDecoder, MultiGzDecoder};
+use flate2::write::{DeflateEncoder, GzEncoder};
+use flate2::Compression;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::io::{Read, Write};
+
+/// Deserializes an instance of type `T` from gzip-compressed JSON text, which is
+/// decompressed while it is parsed. Concatenated gzip members are read as one text.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{from_gzip_reader, to_gzip_writer};
+///
+/// let mut compressed = Vec::new();
+/// to_gzip_writer(&vec![1, 2, 3], &mut compressed).unwrap();
+///
+/// let value: Vec<u32> = from_gzip_reader(compressed.as_slice()).unwrap();
+/// assert_eq!(value, [1, 2, 3]);
+/// ```
+pub fn from_gzip_reader<R, T>(reader: R) -> Result<T, Error>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    from_reader(MultiGzDecoder::new(reader))
+}
+
+/// Serializes an instance of type `T` into compact JSON text compressed by gzip, which
+/// is compressed while it is written. The gzip stream is finished before returning.
+///
+/// # Examples
+/// ```
+/// use ylong_json::to_gzip_writer;
+///
+/// let mut compressed = Vec::new();
+/// to_gzip_writer(&"text", &mut compressed).unwrap();
+/// // The magic number of gzip.
+/// assert_eq!(compressed[..2], [0x1f, 0x8b]);
+/// ```
+pub fn to_gzip_writer<T, W>(value: &T, writer: &mut W) -> Result<(), Error>
+where
+    T: Serialize,
+    W: Write,
+{
+    let mut encoder = GzEncoder::new(writer, Compression::default());
+    to_writer(value, &mut encoder)?;
+    encoder.finish()?;
+    Ok(())
+}
+
+/// Deserializes an instance of type `T` from JSON text compressed by raw deflate,
+/// without the headers of gzip or zlib.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{from_deflate_reader, to_deflate_writer};
+///
+/// let mut compressed = Vec::new();
+/// to_deflate_writer(&(1, "a"), &mut compressed).unwrap();
+///
+/// let value: (u32, String) = from_deflate_reader(compressed.as_slice()).unwrap();
+/// assert_eq!(value, (1, String::from("a")));
+/// ```
+pub fn from_deflate_reader<R, T>(reader: R) -> Result<T, Error>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    from_reader(DeflateDecoder::new(reader))
+}
+
+/// Serializes an instance of type `T` into compact JSON text compressed by raw deflate,
+/// without the headers of gzip or zlib.
+///
+/// # Examples
+/// ```
+/// use ylong_json::to_deflate_writer;
+///
+/// let mut compressed = Vec::new();
+/// to_deflate_writer(&vec![0; 1000], &mut compressed).unwrap();
+/// assert!(compressed.len() < 100);
+/// ```
+pub fn to_deflate_writer<T, W>(value: &T, writer: &mut W) -> Result<(), Error>
+where
+    T: Serialize,
+    W: Write,
+{
+    let mut encoder = DeflateEncoder::new(writer, Compression::default());
+    to_writer(value, &mut encoder)?;
+    encoder.finish()?;
+    Ok(())
+}
+
+impl JsonValue {
+    /// Parses a JsonValue from gzip-compressed JSON text, which is decompressed while it
+    /// is parsed. Concatenated gzip members are read as one text.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let value = JsonValue::from_text(r#"{"a": [1, 2]}"#).unwrap();
+    /// let mut compressed = Vec::new();
+    /// value.compact_encode_gzip(&mut compressed).unwrap();
+    ///
+    /// let parsed = JsonValue::from_gzip_reader(compressed.as_slice()).unwrap();
+    /// assert_eq!(parsed, value);
+    /// ```
+    pub fn from_gzip_reader<R: Read>(input: R) -> Result<Self, Error> {
+        Self::from_reader(MultiGzDecoder::new(input))
+    }
+
+    /// Encodes the JsonValue as compact JSON text compressed by gzip, the same as
+    /// `JsonValue::compact_encode` followed by compression. The gzip stream is finished
+    /// before returning.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let value = JsonValue::from_text("[null]").unwrap();
+    /// let mut compressed = Vec::new();
+    /// value.compact_encode_gzip(&mut compressed).unwrap();
+    /// assert_eq!(compressed[..2], [0x1f, 0x8b]);
+    /// ```
+    pub fn compact_encode_gzip<W: Write>(&self, output: &mut W) -> Result<(), Error> {
+        let mut encoder = GzEncoder::new(output, Compression::default());
+        self.compact_encode(&mut encoder)?;
+        encoder.finish()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod ut_compress {
+    use crate::{
+        from_deflate_reader, from_gzip_reader, to_deflate_writer, to_gzip_writer, Error, JsonValue,
+    };
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::collections::BTreeMap;
+    use std::io::Write;
+
+    /// UT test for the gzip and deflate helpers.
+    ///
+    /// # Title
+    /// ut_compress
+    ///
+    /// # Brief
+    /// 1. Compresses some values by the helpers and decompresses them back.
+    /// 2. Checks if the values are the same.
+    /// 3. Checks if concatenated gzip members are read as one text.
+    /// 4. Checks if corrupt or uncompressed input is rejected.
+    #[test]
+    fn ut_compress() {
+        let mut map = BTreeMap::new();
+        map.insert(String::from("name"), vec![String::from("é"); 100]);
+
+        let mut gzip = Vec::new();
+        to_gzip_writer(&map, &mut gzip).unwrap();
+        assert!(gzip.len() < 100);
+        let parsed: BTreeMap<String, Vec<String>> = from_gzip_reader(gzip.as_slice()).unwrap();
+        assert_eq!(parsed, map);
+        let value = JsonValue::from_gzip_reader(gzip.as_slice()).unwrap();
+        assert_eq!(value["name"][99], JsonValue::from("é"));
+
+        let mut deflate = Vec::new();
+        to_deflate_writer(&map, &mut deflate).unwrap();
+        let parsed: BTreeMap<String, Vec<String>> =
+            from_deflate_reader(deflate.as_slice()).unwrap();
+        assert_eq!(parsed, map);
+
+        let mut members = Vec::new();
+        for part in [r#"{"a": "#, "[1, 2]}"] {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+            encoder.write_all(part.as_bytes()).unwrap();
+            members.extend(encoder.finish().unwrap());
+        }
+        let value = JsonValue::from_gzip_reader(members.as_slice()).unwrap();
+        assert_eq!(value, JsonValue::from_text(r#"{"a": [1, 2]}"#).unwrap());
+
+        let mut output = Vec::new();
+        value.compact_encode_gzip(&mut output).unwrap();
+        assert_eq!(
+            JsonValue::from_gzip_reader(output.as_slice()).unwrap(),
+            value
+        );
+
+        assert!(JsonValue::from_gzip_reader(&b"[1, 2]"[..]).is_err());
+        gzip.truncate(gzip.len() / 2);
+        let result = from_gzip_reader::<_, BTreeMap<String, Vec<String>>>(gzip.as_slice());
+        assert!(matches!(result, Err(Error::Io(_) | Error::Reader(_))));
+    }
+}
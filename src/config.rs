@@ -0,0 +1,604 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::consts::{NODE_CHUNK_SIZE, RECURSION_LIMIT};
+
+/// Options applied while parsing a JSON text, which are the limits protecting
+/// the parser from untrusted input and the RFC 8259 conformance mode. The same
+/// options are enforced by the tree parser, such as
+/// `JsonValue::from_text_with_config`, and the serde deserializer, such as
+/// `from_slice_with_config`.
+///
+/// The default configuration only limits the nesting depth to 128, the same
+/// as the parsing interfaces without a configuration. `ParseConfig::strict`
+/// also limits the sizes for input from untrusted sources.
+///
+/// The document limit is checked between tokens, and the string limit is
+/// checked on the decoded bytes, including the keys of objects.
+///
+/// The grammar of RFC 8259 is enforced unless a leniency flag, such as
+/// `allow_leading_plus`, is set for legacy texts. Any value is accepted at the
+/// top level, and unpaired surrogates in unicode escapes are always rejected.
+/// Parsed strings are always checked to be valid UTF-8. `strict_rfc8259`
+/// additionally checks the strings which are skipped and that numbers are
+/// finite, which costs a pass over every skipped string.
+///
+/// More options may be added later, so a configuration is created by
+/// `ParseConfig::default` or `ParseConfig::strict` and changed by the methods
+/// named after the fields.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{Error, JsonValue, ParseConfig};
+///
+/// let config = ParseConfig::default().max_array_len(2);
+/// assert!(JsonValue::from_text_with_config("[1, 2]", config).is_ok());
+/// assert!(matches!(
+///     JsonValue::from_text_with_config("[1, 2, 3]", config),
+///     Err(Error::ExceedArrayLimit)
+/// ));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseConfig {
+    /// The maximum nesting depth of arrays and objects.
+    pub max_depth: u32,
+    /// The maximum length in bytes of the whole text.
+    pub max_document_bytes: usize,
+    /// The maximum length in bytes of a decoded string.
+    pub max_string_bytes: usize,
+    /// The maximum number of elements in an array.
+    pub max_array_len: usize,
+    /// The maximum number of members in an object, including duplicate keys.
+    pub max_object_members: usize,
+    /// The maximum number of bytes allocated for the parsed values in total.
+    /// The amount is estimated as the decoded length of every string, including
+    /// the keys, and the size of a `JsonValue` for every array element and
+    /// object member, which is reached by small texts with many nested values.
+    pub max_alloc_bytes: usize,
+    /// Rejects invalid UTF-8 in skipped strings, such as unknown fields, and
+    /// numbers out of the range of `f64`, which RFC 8259 does not allow.
+    pub strict_rfc8259: bool,
+    /// Rejects objects in which a key appears more than once. RFC 8259 only
+    /// says that keys should be unique, and the last value of a duplicate key
+    /// is kept if this is `false`. Ignored values of the serde deserializer
+    /// are not checked.
+    pub reject_duplicate_keys: bool,
+    /// Records the keys which appear more than once in an object and their positions
+    /// into `ParseStats::duplicate_keys`, if the statistics are collected such as by
+    /// `JsonValue::from_text_with_config_and_stats`. It costs a lookup for every key,
+    /// and is ignored if `reject_duplicate_keys` is set.
+    pub record_duplicate_keys: bool,
+    /// How integers out of the range of `u64` and `i64` are handled.
+    pub number_overflow: NumberOverflow,
+    /// Accepts a leading plus sign in numbers, such as `+1`.
+    pub allow_leading_plus: bool,
+    /// Accepts leading zeros in the integer part of numbers, such as `0123`,
+    /// which is parsed as `123` rather than an octal number.
+    pub allow_leading_zeros: bool,
+    /// The largest number of nodes allocated in one chunk by the parsed arrays and
+    /// objects, if they are implemented with linked lists by `list_array` or
+    /// `list_object`. The default value is 64, and 0 is treated as 1.
+    ///
+    /// The first chunk of a container holds one node, and each following chunk
+    /// doubles the previous one until it reaches this size, so a larger size
+    /// means fewer allocations for long containers. The memory of removed nodes
+    /// is reused by the same container, and is only released when the container
+    /// is dropped. Containers created otherwise use the default value.
+    pub node_chunk_size: usize,
+}
+
+/// The policy for integers which have no fraction or exponent, but are out of
+/// the range of `u64` for positive ones or `i64` for negative ones.
+///
+/// `Number` has no arbitrary precision representation, so an oversized
+/// integer can either fail the parsing or lose its precision.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{JsonValue, NumberOverflow, ParseConfig};
+///
+/// let text = "[18446744073709551616]";
+/// assert!(JsonValue::from_text(text).is_err());
+///
+/// let config = ParseConfig::default().number_overflow(NumberOverflow::Float);
+/// let value = JsonValue::from_text_with_config(text, config).unwrap();
+/// assert_eq!(value[0].try_as_number().unwrap().try_as_f64().unwrap(), 18446744073709551616.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NumberOverflow {
+    /// Fails the parsing with `Error::ParseNumber`. (Default)
+    #[default]
+    Error,
+    /// Converts the integer to the nearest `f64`.
+    Float,
+}
+
+impl ParseConfig {
+    /// Creates a configuration for input from untrusted sources, which limits
+    /// the nesting depth to 32, the text to 1 MiB, strings to 64 KiB, arrays
+    /// to 65536 elements, objects to 4096 members and the allocated memory to
+    /// 16 MiB.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Error, JsonValue, ParseConfig};
+    ///
+    /// let text = "[".repeat(33) + &"]".repeat(33);
+    /// assert!(JsonValue::from_text(&text).is_ok());
+    /// assert!(matches!(
+    ///     JsonValue::from_text_with_config(&text, ParseConfig::strict()),
+    ///     Err(Error::ExceedRecursionLimit)
+    /// ));
+    /// ```
+    pub const fn strict() -> Self {
+        Self {
+            max_depth: 32,
+            max_document_bytes: 1 << 20,
+            max_string_bytes: 1 << 16,
+            max_array_len: 1 << 16,
+            max_object_members: 1 << 12,
+            max_alloc_bytes: 1 << 24,
+            strict_rfc8259: false,
+            reject_duplicate_keys: false,
+            record_duplicate_keys: false,
+            number_overflow: NumberOverflow::Error,
+            allow_leading_plus: false,
+            allow_leading_zeros: false,
+            node_chunk_size: NODE_CHUNK_SIZE,
+        }
+    }
+
+    /// Sets the maximum nesting depth of arrays and objects. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, ParseConfig};
+    ///
+    /// let config = ParseConfig::default().max_depth(1);
+    /// assert!(JsonValue::from_text_with_config("[[1]]", config).is_err());
+    /// ```
+    pub const fn max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum length in bytes of the whole text. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, ParseConfig};
+    ///
+    /// let config = ParseConfig::default().max_document_bytes(4);
+    /// assert!(JsonValue::from_text_with_config("[1, 2]", config).is_err());
+    /// ```
+    pub const fn max_document_bytes(mut self, max_document_bytes: usize) -> Self {
+        self.max_document_bytes = max_document_bytes;
+        self
+    }
+
+    /// Sets the maximum length in bytes of a decoded string. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, ParseConfig};
+    ///
+    /// let config = ParseConfig::default().max_string_bytes(2);
+    /// assert!(JsonValue::from_text_with_config(r#""abc""#, config).is_err());
+    /// ```
+    pub const fn max_string_bytes(mut self, max_string_bytes: usize) -> Self {
+        self.max_string_bytes = max_string_bytes;
+        self
+    }
+
+    /// Sets the maximum number of elements in an array. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, ParseConfig};
+    ///
+    /// let config = ParseConfig::default().max_array_len(1);
+    /// assert!(JsonValue::from_text_with_config("[1, 2]", config).is_err());
+    /// ```
+    pub const fn max_array_len(mut self, max_array_len: usize) -> Self {
+        self.max_array_len = max_array_len;
+        self
+    }
+
+    /// Sets the maximum number of members in an object. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, ParseConfig};
+    ///
+    /// let config = ParseConfig::default().max_object_members(1);
+    /// assert!(JsonValue::from_text_with_config(r#"{"a": 1, "b": 2}"#, config).is_err());
+    /// ```
+    pub const fn max_object_members(mut self, max_object_members: usize) -> Self {
+        self.max_object_members = max_object_members;
+        self
+    }
+
+    /// Sets the maximum number of bytes allocated for the parsed values. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, ParseConfig};
+    ///
+    /// let config = ParseConfig::default().max_alloc_bytes(8);
+    /// assert!(JsonValue::from_text_with_config(r#"["abcdefghij"]"#, config).is_err());
+    /// ```
+    pub const fn max_alloc_bytes(mut self, max_alloc_bytes: usize) -> Self {
+        self.max_alloc_bytes = max_alloc_bytes;
+        self
+    }
+
+    /// Sets whether invalid UTF-8 in skipped strings and numbers out of the range of `f64` are rejected. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, ParseConfig};
+    ///
+    /// let config = ParseConfig::default().strict_rfc8259(true);
+    /// assert!(JsonValue::from_text_with_config("1e400", config).is_err());
+    /// ```
+    pub const fn strict_rfc8259(mut self, strict_rfc8259: bool) -> Self {
+        self.strict_rfc8259 = strict_rfc8259;
+        self
+    }
+
+    /// Sets whether objects with duplicate keys are rejected. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, ParseConfig};
+    ///
+    /// let config = ParseConfig::default().reject_duplicate_keys(true);
+    /// assert!(JsonValue::from_text_with_config(r#"{"a": 1, "a": 2}"#, config).is_err());
+    /// ```
+    pub const fn reject_duplicate_keys(mut self, reject_duplicate_keys: bool) -> Self {
+        self.reject_duplicate_keys = reject_duplicate_keys;
+        self
+    }
+
+    /// Sets whether duplicate keys are recorded into `ParseStats::duplicate_keys`. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::ParseConfig;
+    ///
+    /// let config = ParseConfig::default().record_duplicate_keys(true);
+    /// assert!(config.record_duplicate_keys);
+    /// ```
+    pub const fn record_duplicate_keys(mut self, record_duplicate_keys: bool) -> Self {
+        self.record_duplicate_keys = record_duplicate_keys;
+        self
+    }
+
+    /// Sets how integers out of the range of `u64` and `i64` are handled. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, NumberOverflow, ParseConfig};
+    ///
+    /// let config = ParseConfig::default().number_overflow(NumberOverflow::Float);
+    /// assert!(JsonValue::from_text_with_config("18446744073709551616", config).is_ok());
+    /// ```
+    pub const fn number_overflow(mut self, number_overflow: NumberOverflow) -> Self {
+        self.number_overflow = number_overflow;
+        self
+    }
+
+    /// Sets whether a leading plus sign is accepted in numbers. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, ParseConfig};
+    ///
+    /// let config = ParseConfig::default().allow_leading_plus(true);
+    /// assert!(JsonValue::from_text_with_config("+1", config).is_ok());
+    /// ```
+    pub const fn allow_leading_plus(mut self, allow_leading_plus: bool) -> Self {
+        self.allow_leading_plus = allow_leading_plus;
+        self
+    }
+
+    /// Sets whether leading zeros are accepted in the integer part of numbers. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, ParseConfig};
+    ///
+    /// let config = ParseConfig::default().allow_leading_zeros(true);
+    /// assert!(JsonValue::from_text_with_config("0123", config).is_ok());
+    /// ```
+    pub const fn allow_leading_zeros(mut self, allow_leading_zeros: bool) -> Self {
+        self.allow_leading_zeros = allow_leading_zeros;
+        self
+    }
+
+    /// Sets the largest number of nodes allocated in one chunk by the list-based containers. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, ParseConfig};
+    ///
+    /// let config = ParseConfig::default().node_chunk_size(16);
+    /// assert!(JsonValue::from_text_with_config("[1, 2]", config).is_ok());
+    /// ```
+    pub const fn node_chunk_size(mut self, node_chunk_size: usize) -> Self {
+        self.node_chunk_size = node_chunk_size;
+        self
+    }
+}
+
+impl Default for ParseConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: RECURSION_LIMIT,
+            max_document_bytes: usize::MAX,
+            max_string_bytes: usize::MAX,
+            max_array_len: usize::MAX,
+            max_object_members: usize::MAX,
+            max_alloc_bytes: usize::MAX,
+            strict_rfc8259: false,
+            reject_duplicate_keys: false,
+            record_duplicate_keys: false,
+            number_overflow: NumberOverflow::Error,
+            allow_leading_plus: false,
+            allow_leading_zeros: false,
+            node_chunk_size: NODE_CHUNK_SIZE,
+        }
+    }
+}
+
+/// Options applied while encoding a JsonValue into a JSON text, such as by
+/// `JsonValue::to_compact_string_with_config`.
+///
+/// The default configuration is used by the encoding interfaces without a
+/// configuration, such as `JsonValue::to_compact_string`, and the policy for
+/// non-finite floats is also used by the serde serializer, such as `to_string`.
+///
+/// The default nesting depth limit is 128, the same as `ParseConfig::default`,
+/// so that everything parsed by default can be encoded, while values built
+/// with pathological nesting fail rather than overflow the stack.
+///
+/// More options may be added later, so a configuration is created by
+/// `EncodeConfig::default` and changed by the methods named after the fields.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{EncodeConfig, JsonValue, NonFiniteFloat};
+///
+/// let value = JsonValue::from(f64::NAN);
+/// assert!(value.to_compact_string().is_err());
+///
+/// let config = EncodeConfig::default().non_finite_float(NonFiniteFloat::Null);
+/// assert_eq!(value.to_compact_string_with_config(config).unwrap(), "null");
+///
+/// let mut escape_table = [false; 256];
+/// escape_table[0x7F] = true;
+/// let config = EncodeConfig::default().escape_table(Some(escape_table));
+/// let value = JsonValue::new_string("a\x7F");
+/// assert_eq!(value.to_compact_string_with_config(config).unwrap(), r#""a\u007f""#);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EncodeConfig {
+    /// How NaN and infinite floats are encoded, which JSON does not allow.
+    pub non_finite_float: NonFiniteFloat,
+    /// The maximum nesting depth of arrays and objects. Deeper values fail the
+    /// encoding with `Error::ExceedRecursionLimit`.
+    pub max_depth: u32,
+    /// The characters escaped in addition to those which JSON requires, such as
+    /// DEL or separators of a downstream log system. The table is indexed by the
+    /// code points from U+0000 to U+00FF, and the marked characters are written
+    /// as `\uXXXX`. The characters which are always escaped, such as the
+    /// quotation mark, keep their usual escapes.
+    pub escape_table: Option<[bool; 256]>,
+}
+
+impl EncodeConfig {
+    /// Sets how NaN and infinite floats are encoded. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{EncodeConfig, JsonValue, NonFiniteFloat};
+    ///
+    /// let config = EncodeConfig::default().non_finite_float(NonFiniteFloat::Null);
+    /// let value = JsonValue::from(f64::NAN);
+    /// assert_eq!(value.to_compact_string_with_config(config).unwrap(), "null");
+    /// ```
+    pub const fn non_finite_float(mut self, non_finite_float: NonFiniteFloat) -> Self {
+        self.non_finite_float = non_finite_float;
+        self
+    }
+
+    /// Sets the maximum nesting depth of arrays and objects. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{EncodeConfig, JsonValue};
+    ///
+    /// let config = EncodeConfig::default().max_depth(1);
+    /// let value = JsonValue::from_text("[[1]]").unwrap();
+    /// assert!(value.to_compact_string_with_config(config).is_err());
+    /// ```
+    pub const fn max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the characters escaped in addition to those which JSON requires. See the field of the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{EncodeConfig, JsonValue};
+    ///
+    /// let mut escape_table = [false; 256];
+    /// escape_table[0x7F] = true;
+    /// let config = EncodeConfig::default().escape_table(Some(escape_table));
+    /// let value = JsonValue::new_string("a\x7F");
+    /// assert_eq!(value.to_compact_string_with_config(config).unwrap(), r#""a\u007f""#);
+    /// ```
+    pub const fn escape_table(mut self, escape_table: Option<[bool; 256]>) -> Self {
+        self.escape_table = escape_table;
+        self
+    }
+}
+
+impl Default for EncodeConfig {
+    fn default() -> Self {
+        Self {
+            non_finite_float: NonFiniteFloat::Error,
+            max_depth: RECURSION_LIMIT,
+            escape_table: None,
+        }
+    }
+}
+
+/// The policy for NaN and infinite floats, which can not be represented in
+/// JSON texts.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{array, Array, EncodeConfig, JsonValue, NonFiniteFloat};
+///
+/// let value = JsonValue::Array(array!(f64::NAN, f64::INFINITY, f64::NEG_INFINITY));
+/// let config = EncodeConfig::default().non_finite_float(NonFiniteFloat::Literal);
+/// let text = value.to_compact_string_with_config(config).unwrap();
+/// assert_eq!(text, "[NaN,Infinity,-Infinity]");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NonFiniteFloat {
+    /// Fails the encoding with `Error::NonFiniteFloat`. (Default)
+    #[default]
+    Error,
+    /// Encodes them as `null`, which is not read back as a number.
+    Null,
+    /// Encodes them as the JSON5 literals `NaN`, `Infinity` and `-Infinity`,
+    /// which are not valid JSON.
+    Literal,
+}
+
+#[cfg(test)]
+mod ut_config {
+    use crate::consts::{NODE_CHUNK_SIZE, RECURSION_LIMIT};
+    use crate::{EncodeConfig, NonFiniteFloat, NumberOverflow, ParseConfig};
+
+    /// UT test for `ParseConfig::default` and `ParseConfig::strict`.
+    ///
+    /// # Title
+    /// ut_parse_config_presets
+    ///
+    /// # Brief
+    /// 1. Creates the default and strict configurations.
+    /// 2. Checks if the default one only limits the depth, and the strict one is tighter.
+    #[test]
+    fn ut_parse_config_presets() {
+        let default = ParseConfig::default();
+        assert_eq!(default.max_depth, RECURSION_LIMIT);
+        assert_eq!(default.max_document_bytes, usize::MAX);
+        assert_eq!(default.max_string_bytes, usize::MAX);
+        assert_eq!(default.max_array_len, usize::MAX);
+        assert_eq!(default.max_object_members, usize::MAX);
+        assert_eq!(default.max_alloc_bytes, usize::MAX);
+        assert!(!default.strict_rfc8259);
+        assert!(!default.reject_duplicate_keys);
+        assert!(!default.record_duplicate_keys);
+        assert_eq!(default.number_overflow, NumberOverflow::Error);
+        assert!(!default.allow_leading_plus);
+        assert!(!default.allow_leading_zeros);
+        assert_eq!(default.node_chunk_size, NODE_CHUNK_SIZE);
+
+        let strict = ParseConfig::strict();
+        assert!(strict.max_depth < default.max_depth);
+        assert!(strict.max_string_bytes < strict.max_document_bytes);
+        assert!(strict.max_document_bytes < strict.max_alloc_bytes);
+        assert_ne!(strict, default);
+    }
+
+    /// UT test for the setters of `ParseConfig` and `EncodeConfig`.
+    ///
+    /// # Title
+    /// ut_config_setters
+    ///
+    /// # Brief
+    /// 1. Changes every option of the configurations by the setters.
+    /// 2. Checks if only the options set are changed.
+    #[test]
+    fn ut_config_setters() {
+        const CONFIG: ParseConfig = ParseConfig::strict().max_depth(4);
+        assert_eq!(CONFIG.max_depth, 4);
+        assert_eq!(
+            CONFIG.max_string_bytes,
+            ParseConfig::strict().max_string_bytes
+        );
+
+        let config = ParseConfig::default()
+            .max_depth(1)
+            .max_document_bytes(2)
+            .max_string_bytes(3)
+            .max_array_len(4)
+            .max_object_members(5)
+            .max_alloc_bytes(6)
+            .strict_rfc8259(true)
+            .reject_duplicate_keys(true)
+            .record_duplicate_keys(true)
+            .number_overflow(NumberOverflow::Float)
+            .allow_leading_plus(true)
+            .allow_leading_zeros(true)
+            .node_chunk_size(7);
+        let expected = ParseConfig {
+            max_depth: 1,
+            max_document_bytes: 2,
+            max_string_bytes: 3,
+            max_array_len: 4,
+            max_object_members: 5,
+            max_alloc_bytes: 6,
+            strict_rfc8259: true,
+            reject_duplicate_keys: true,
+            record_duplicate_keys: true,
+            number_overflow: NumberOverflow::Float,
+            allow_leading_plus: true,
+            allow_leading_zeros: true,
+            node_chunk_size: 7,
+        };
+        assert_eq!(config, expected);
+
+        let config = EncodeConfig::default()
+            .non_finite_float(NonFiniteFloat::Literal)
+            .max_depth(2)
+            .escape_table(Some([true; 256]));
+        assert_eq!(config.non_finite_float, NonFiniteFloat::Literal);
+        assert_eq!(config.max_depth, 2);
+        assert_eq!(config.escape_table, Some([true; 256]));
+    }
+
+    /// UT test for `EncodeConfig::default`.
+    ///
+    /// # Title
+    /// ut_encode_config_default
+    ///
+    /// # Brief
+    /// 1. Creates the default encoding configuration.
+    /// 2. Checks if non-finite floats are rejected by default, and the depth
+    ///    limit is the same as the parsing one.
+    #[test]
+    fn ut_encode_config_default() {
+        let default = EncodeConfig::default();
+        assert_eq!(default.non_finite_float, NonFiniteFloat::Error);
+        assert_eq!(default.max_depth, ParseConfig::default().max_depth);
+        assert!(default.escape_table.is_none());
+    }
+}
@@ -75,10 +75,14 @@ pub(crate) const TRUE_LEFT_STR: &[u8] = b"rue";
 pub(crate) const UNICODE_START_STR: &[u8] = b"\\u";
 pub(crate) const COLON_STR: &[u8] = b":";
 pub(crate) const COMMA_STR: &[u8] = b",";
+pub(crate) const ELLIPSIS_STR: &[u8] = "…".as_bytes();
 pub(crate) const FOUR_SPACES_STR: &[u8] = b"    ";
+pub(crate) const INFINITY_STR: &[u8] = b"Infinity";
 pub(crate) const LEFT_CURLY_BRACKET_STR: &[u8] = b"{";
 pub(crate) const LEFT_SQUARE_BRACKET_STR: &[u8] = b"[";
 pub(crate) const LINE_FEED_STR: &[u8] = b"\n";
+pub(crate) const NAN_STR: &[u8] = b"NaN";
+pub(crate) const NEG_INFINITY_STR: &[u8] = b"-Infinity";
 pub(crate) const QUOTATION_MARK_STR: &[u8] = b"\"";
 pub(crate) const RIGHT_CURLY_BRACKET_STR: &[u8] = b"}";
 pub(crate) const RIGHT_SQUARE_BRACKET_STR: &[u8] = b"]";
@@ -86,6 +90,8 @@ pub(crate) const SPACE_STR: &[u8] = b" ";
 
 pub(crate) const RECURSION_LIMIT: u32 = 128;
 
+pub(crate) const NODE_CHUNK_SIZE: usize = 64;
+
 // Improves the string read rate by looking up tables.
 pub(crate) static ESCAPE: [bool; 256] = {
     const CT: bool = true; // Control character \x00..=\x1F
@@ -0,0 +1,380 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Array, Error, JsonValue, Number, Object};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Converts a Rust value to JsonValue.
+///
+/// Unlike `serde::Serialize`, it builds the JsonValue directly, and user code which
+/// implements it does not need to depend on serde. With the `derive` feature, it can be
+/// derived for structs and unit-only enums by `#[derive(ToJson)]`.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{JsonValue, Object, ToJson};
+///
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl ToJson for Point {
+///     fn to_json(&self) -> JsonValue {
+///         let mut object = Object::new();
+///         object.insert(String::from("x"), self.x.to_json());
+///         object.insert(String::from("y"), self.y.to_json());
+///         JsonValue::Object(object)
+///     }
+/// }
+///
+/// let value = vec![Point { x: 1, y: 2 }].to_json();
+/// assert_eq!(value.to_compact_string().unwrap(), r#"[{"x":1,"y":2}]"#);
+/// ```
+pub trait ToJson {
+    /// Converts the value to JsonValue.
+    fn to_json(&self) -> JsonValue;
+}
+
+/// Converts JsonValue to a Rust value.
+///
+/// Unlike `serde::Deserialize`, it reads an already parsed JsonValue, and user code which
+/// implements it does not need to depend on serde. With the `derive` feature, it can be
+/// derived for structs and unit-only enums by `#[derive(FromJson)]`.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{Error, FromJson, JsonValue};
+///
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl FromJson for Point {
+///     fn from_json(value: &JsonValue) -> Result<Self, Error> {
+///         let object = value.try_as_object()?;
+///         Ok(Point {
+///             x: ylong_json::from_member(object, "x")?,
+///             y: ylong_json::from_member(object, "y")?,
+///         })
+///     }
+/// }
+///
+/// let value = JsonValue::from_text(r#"[{"x": 1, "y": 2}]"#).unwrap();
+/// let points = Vec::<Point>::from_json(&value).unwrap();
+/// assert_eq!((points[0].x, points[0].y), (1, 2));
+/// ```
+pub trait FromJson: Sized {
+    /// Converts JsonValue to the value, or returns an error if the JsonValue has a wrong
+    /// type or is out of the range of the value.
+    fn from_json(value: &JsonValue) -> Result<Self, Error>;
+}
+
+/// Converts the member of Object with the specified key by `FromJson`. A missing member
+/// is converted from null, so that it is accepted by `Option`, otherwise returns an error
+/// naming the key.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{from_member, JsonValue};
+///
+/// let value = JsonValue::from_text(r#"{"id": 1}"#).unwrap();
+/// let object = value.try_as_object().unwrap();
+///
+/// assert_eq!(from_member::<u8>(object, "id").unwrap(), 1);
+/// assert_eq!(from_member::<Option<u8>>(object, "name").unwrap(), None);
+/// assert!(from_member::<u8>(object, "name").is_err());
+/// ```
+pub fn from_member<T: FromJson>(object: &Object, key: &str) -> Result<T, Error> {
+    match object.get(key) {
+        Some(value) => T::from_json(value),
+        None => T::from_json(&JsonValue::Null)
+            .map_err(|_| Error::Custom(format!("missing field `{key}`"))),
+    }
+}
+
+impl ToJson for JsonValue {
+    fn to_json(&self) -> JsonValue {
+        self.clone()
+    }
+}
+
+impl FromJson for JsonValue {
+    fn from_json(value: &JsonValue) -> Result<Self, Error> {
+        Ok(value.clone())
+    }
+}
+
+impl ToJson for bool {
+    fn to_json(&self) -> JsonValue {
+        JsonValue::Boolean(*self)
+    }
+}
+
+impl FromJson for bool {
+    fn from_json(value: &JsonValue) -> Result<Self, Error> {
+        value.try_as_boolean().copied()
+    }
+}
+
+impl ToJson for str {
+    fn to_json(&self) -> JsonValue {
+        JsonValue::new_string(self)
+    }
+}
+
+impl ToJson for String {
+    fn to_json(&self) -> JsonValue {
+        JsonValue::String(self.into())
+    }
+}
+
+impl FromJson for String {
+    fn from_json(value: &JsonValue) -> Result<Self, Error> {
+        value.try_as_string().map(|s| String::from(s.as_str()))
+    }
+}
+
+macro_rules! json_convert_integer {
+    ($($t: tt),* $(,)?) => {
+        $(
+            impl ToJson for $t {
+                fn to_json(&self) -> JsonValue {
+                    JsonValue::Number(Number::from(*self))
+                }
+            }
+
+            impl FromJson for $t {
+                fn from_json(value: &JsonValue) -> Result<Self, Error> {
+                    let integer = match value.try_as_number()? {
+                        Number::Unsigned(u) => i128::from(*u),
+                        Number::Signed(i) => i128::from(*i),
+                        Number::Float(_) => return Err(Error::TypeTransform),
+                    };
+                    $t::try_from(integer).map_err(|_| Error::TypeTransform)
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! json_convert_float {
+    ($($t: tt),* $(,)?) => {
+        $(
+            impl ToJson for $t {
+                fn to_json(&self) -> JsonValue {
+                    JsonValue::Number(Number::from(*self))
+                }
+            }
+
+            impl FromJson for $t {
+                fn from_json(value: &JsonValue) -> Result<Self, Error> {
+                    // The precision of f32 may be lost, the same as `as`.
+                    Ok(value.try_as_number()?.try_as_f64()? as $t)
+                }
+            }
+        )*
+    }
+}
+
+json_convert_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+json_convert_float!(f32, f64);
+
+impl<T: ToJson + ?Sized> ToJson for &T {
+    fn to_json(&self) -> JsonValue {
+        (**self).to_json()
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for Box<T> {
+    fn to_json(&self) -> JsonValue {
+        (**self).to_json()
+    }
+}
+
+impl<T: FromJson> FromJson for Box<T> {
+    fn from_json(value: &JsonValue) -> Result<Self, Error> {
+        T::from_json(value).map(Box::new)
+    }
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+    /// None is converted to null.
+    fn to_json(&self) -> JsonValue {
+        match self {
+            Some(t) => t.to_json(),
+            None => JsonValue::Null,
+        }
+    }
+}
+
+impl<T: FromJson> FromJson for Option<T> {
+    /// Null is converted to None.
+    fn from_json(value: &JsonValue) -> Result<Self, Error> {
+        match value {
+            JsonValue::Null => Ok(None),
+            value => T::from_json(value).map(Some),
+        }
+    }
+}
+
+impl<T: ToJson> ToJson for [T] {
+    fn to_json(&self) -> JsonValue {
+        let mut array = Array::new();
+        for t in self {
+            array.push(t.to_json());
+        }
+        JsonValue::Array(array)
+    }
+}
+
+impl<T: ToJson, const N: usize> ToJson for [T; N] {
+    fn to_json(&self) -> JsonValue {
+        self.as_slice().to_json()
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn to_json(&self) -> JsonValue {
+        self.as_slice().to_json()
+    }
+}
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json(value: &JsonValue) -> Result<Self, Error> {
+        value.try_as_array()?.iter().map(T::from_json).collect()
+    }
+}
+
+impl<T: ToJson> ToJson for BTreeMap<String, T> {
+    fn to_json(&self) -> JsonValue {
+        let mut object = Object::new();
+        for (key, t) in self {
+            object.insert(key.clone(), t.to_json());
+        }
+        JsonValue::Object(object)
+    }
+}
+
+impl<T: FromJson> FromJson for BTreeMap<String, T> {
+    fn from_json(value: &JsonValue) -> Result<Self, Error> {
+        let object = value.try_as_object()?;
+        object
+            .iter()
+            .map(|(key, value)| Ok((String::from(key.as_str()), T::from_json(value)?)))
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ToJson> ToJson for HashMap<String, T> {
+    fn to_json(&self) -> JsonValue {
+        let mut object = Object::new();
+        for (key, t) in self {
+            object.insert(key.clone(), t.to_json());
+        }
+        JsonValue::Object(object)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: FromJson> FromJson for HashMap<String, T> {
+    fn from_json(value: &JsonValue) -> Result<Self, Error> {
+        let object = value.try_as_object()?;
+        object
+            .iter()
+            .map(|(key, value)| Ok((String::from(key.as_str()), T::from_json(value)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod ut_convert {
+    use crate::{from_member, FromJson, JsonValue, ToJson};
+    use std::collections::BTreeMap;
+    #[cfg(feature = "std")]
+    use std::collections::HashMap;
+
+    /// UT test for `ToJson` and `FromJson` of primitives.
+    ///
+    /// # Title
+    /// ut_convert_primitives
+    ///
+    /// # Brief
+    /// 1. Converts some primitives to `JsonValue`s and back.
+    /// 2. Converts some `JsonValue`s of wrong types or out of range.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_convert_primitives() {
+        assert!(bool::from_json(&true.to_json()).unwrap());
+        assert_eq!(u8::from_json(&200u8.to_json()).unwrap(), 200);
+        assert_eq!(i64::from_json(&i64::MIN.to_json()).unwrap(), i64::MIN);
+        assert_eq!(u64::from_json(&u64::MAX.to_json()).unwrap(), u64::MAX);
+        assert_eq!(u16::from_json(&5i32.to_json()).unwrap(), 5);
+        assert_eq!(f64::from_json(&1.5f32.to_json()).unwrap(), 1.5);
+        assert_eq!(f32::from_json(&3.to_json()).unwrap(), 3.0);
+        assert_eq!(String::from_json(&"a".to_json()).unwrap(), "a");
+
+        assert!(u8::from_json(&256.to_json()).is_err());
+        assert!(u8::from_json(&(-1).to_json()).is_err());
+        assert!(i32::from_json(&1.5.to_json()).is_err());
+        assert!(bool::from_json(&JsonValue::Null).is_err());
+        assert!(String::from_json(&1.to_json()).is_err());
+    }
+
+    /// UT test for `ToJson` and `FromJson` of containers.
+    ///
+    /// # Title
+    /// ut_convert_containers
+    ///
+    /// # Brief
+    /// 1. Converts some containers to `JsonValue`s and back.
+    /// 2. Calls `from_member` with existing and missing keys.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_convert_containers() {
+        let value = vec![Some(1), None].to_json();
+        assert_eq!(value.to_compact_string().unwrap(), "[1,null]");
+        assert_eq!(
+            Vec::<Option<u8>>::from_json(&value).unwrap(),
+            vec![Some(1), None]
+        );
+        assert!(Vec::<u8>::from_json(&value).is_err());
+        assert_eq!([1, 2].to_json(), [1, 2][..].to_json());
+
+        let map = BTreeMap::from([(String::from("a"), vec![true])]);
+        let value = map.to_json();
+        assert_eq!(value.to_compact_string().unwrap(), r#"{"a":[true]}"#);
+        assert_eq!(BTreeMap::from_json(&value).unwrap(), map);
+        #[cfg(feature = "std")]
+        {
+            let map = HashMap::<String, Vec<bool>>::from_json(&value).unwrap();
+            assert_eq!(map.to_json(), value);
+        }
+        assert_eq!(*Box::<JsonValue>::from_json(&value).unwrap(), value);
+
+        let object = value.try_as_object().unwrap();
+        assert_eq!(from_member::<Vec<bool>>(object, "a").unwrap(), vec![true]);
+        assert_eq!(from_member::<Option<u8>>(object, "b").unwrap(), None);
+        let error = from_member::<u8>(object, "b").unwrap_err();
+        assert_eq!(error.to_string(), "missing field `b`");
+        assert!(from_member::<u8>(object, "a").is_err());
+    }
+}
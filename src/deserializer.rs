@@ -11,21 +11,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::reader::{BytesReader, Cacheable, IoReader, SliceReader};
+use crate::reader::{Borrowable, BytesReader, Cacheable, ChainReader, SliceReader};
+#[cfg(feature = "std")]
+use crate::reader::{IoReader, IterReader};
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::str::FromStr;
 use serde::de;
+use serde::de::value::BorrowedStrDeserializer;
+#[cfg(feature = "std")]
+use serde::de::DeserializeOwned;
 use serde::de::{
-    DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
-    VariantAccess, Visitor,
+    DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor,
 };
 use serde::Deserialize;
+#[cfg(feature = "std")]
 use std::io::Read;
 
-use crate::{consts::*, error::*, states::*, Number, ParseError::*};
-
-#[cfg(feature = "c_adapter")]
-type JsonString = CString;
-#[cfg(not(feature = "c_adapter"))]
-type JsonString = String;
+use crate::{
+    base64, error::*, states::*, BytesFormat, JsonValue, Number, ParseConfig, ParseError::*,
+    ParseStats,
+};
 
 impl Number {
     fn visit<'de, V>(self, visitor: V) -> Result<V::Value, Error>
@@ -47,6 +55,14 @@ where
 {
     pub(crate) reader: R,
     pub(crate) recursion_depth: u32,
+    pub(crate) config: ParseConfig,
+    // Estimated bytes allocated for the parsed values, see `ParseConfig::max_alloc_bytes`.
+    pub(crate) allocated: usize,
+    pub(crate) bytes_format: BytesFormat,
+    // Buffer for decoding strings with escape characters.
+    pub(crate) scratch: Vec<u8>,
+    // Statistics of the tree parser, which are only collected if requested.
+    pub(crate) stats: Option<ParseStats>,
 }
 
 impl<R> Deserializer<R>
@@ -61,17 +77,50 @@ where
         Deserializer {
             reader,
             recursion_depth: 0,
+            config: ParseConfig::default(),
+            allocated: 0,
+            bytes_format: BytesFormat::Array,
+            scratch: Vec::new(),
+            stats: None,
         }
     }
+
+    /// Sets the representation of byte slices expected by `deserialize_bytes`.
+    pub(crate) fn with_bytes_format(mut self, format: BytesFormat) -> Self {
+        self.bytes_format = format;
+        self
+    }
+
+    /// Sets the limits applied while parsing.
+    pub(crate) fn with_config(mut self, config: ParseConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    // Enters an array or an object.
+    fn enter_container(&mut self) -> Result<(), Error> {
+        self.recursion_depth += 1;
+        check_recursion(self)
+    }
 }
 
 /// Creates an instance of Deserializer from reader.
+#[cfg(feature = "std")]
 impl<R: Read> Deserializer<IoReader<R>> {
     pub fn new_from_io(reader: R) -> Self {
         Deserializer::new(IoReader::new(reader))
     }
 }
 
+/// Creates an instance of Deserializer from a fallible byte iterator, such as
+/// the output of a decompressor or a decoder.
+#[cfg(feature = "std")]
+impl<I: Iterator<Item = std::io::Result<u8>>> Deserializer<IterReader<I>> {
+    pub fn new_from_iter(iter: I) -> Self {
+        Deserializer::new(IterReader::new_from_iter(iter))
+    }
+}
+
 /// Creates an instance of Deserializer from slice.
 impl<'a> Deserializer<SliceReader<'a>> {
     pub fn new_from_slice(slice: &'a [u8]) -> Self {
@@ -79,7 +128,19 @@ impl<'a> Deserializer<SliceReader<'a>> {
     }
 }
 
+/// Creates an instance of Deserializer from several slices.
+impl<'a> Deserializer<ChainReader<'a>> {
+    pub fn new_from_slices(slices: &[&'a [u8]]) -> Self {
+        Deserializer::new(ChainReader::new(slices))
+    }
+}
+
 /// Deserializes an instance of type `T` from an IO stream of JSON.
+///
+/// Internally and adjacently tagged enums, such as `#[serde(tag = "type")]`, are
+/// supported even if the tag is not the first member, since the other members are
+/// buffered until the tag is read. Errors in the buffered members are reported at the
+/// end of the object.
 /// # Example
 /// ```not run
 /// use serde::Deserialize;
@@ -101,13 +162,94 @@ impl<'a> Deserializer<SliceReader<'a>> {
 /// let file = File::open("./test.txt").unwrap();
 /// assert_eq!(expected, from_reader(file).unwrap());
 /// ```
+///
+/// The tag of an internally tagged enum can be after the other members:
+/// ```
+/// use serde::Deserialize;
+/// use ylong_json::from_reader;
+///
+/// #[derive(Deserialize, PartialEq, Debug)]
+/// #[serde(tag = "type")]
+/// enum Shape {
+///     Circle { radius: f64 },
+///     Square { side: f64 },
+/// }
+///
+/// let text = r#"{"radius": 1.5, "type": "Circle"}"#;
+/// let shape: Shape = from_reader(text.as_bytes()).unwrap();
+/// assert_eq!(shape, Shape::Circle { radius: 1.5 });
+/// ```
+#[cfg(feature = "std")]
 pub fn from_reader<R, T>(reader: R) -> Result<T, Error>
 where
     R: Read,
     T: DeserializeOwned,
 {
-    let mut deserializer = Deserializer::new_from_io(reader);
-    let t = T::deserialize(&mut deserializer)?;
+    from_reader_with_bytes_format(reader, BytesFormat::Array)
+}
+
+/// The same as `from_reader`, but byte slices requested by `deserialize_bytes`
+/// are decoded according to the given `BytesFormat`.
+#[cfg(feature = "std")]
+pub fn from_reader_with_bytes_format<R, T>(reader: R, format: BytesFormat) -> Result<T, Error>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    let mut deserializer = Deserializer::new_from_io(reader).with_bytes_format(format);
+    let t = T::deserialize(&mut deserializer).map_err(|e| deserializer.fix_position(e))?;
+    match eat_whitespace_until_not!(deserializer) {
+        None => Ok(t),
+        _ => Err(Error::Parsing(ParsingUnfinished)),
+    }
+}
+
+/// The same as `from_reader`, but the limits in the given `ParseConfig` are
+/// applied while parsing.
+///
+/// # Example
+/// ```
+/// use ylong_json::{from_reader_with_config, Error, ParseConfig};
+///
+/// let config = ParseConfig::default().max_document_bytes(8);
+/// let result = from_reader_with_config::<_, Vec<u32>>("[1, 2, 3]".as_bytes(), config);
+/// assert!(matches!(result, Err(Error::ExceedDocumentLimit)));
+/// ```
+#[cfg(feature = "std")]
+pub fn from_reader_with_config<R, T>(reader: R, config: ParseConfig) -> Result<T, Error>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    let mut deserializer = Deserializer::new_from_io(reader).with_config(config);
+    let t = T::deserialize(&mut deserializer).map_err(|e| deserializer.fix_position(e))?;
+    match eat_whitespace_until_not!(deserializer) {
+        None => Ok(t),
+        _ => Err(Error::Parsing(ParsingUnfinished)),
+    }
+}
+
+/// Deserializes an instance of type `T` from a fallible byte iterator of JSON.
+///
+/// This is useful for sources which produce bytes one by one, such as
+/// decompressors and decoders. An error returned by the iterator stops the
+/// parsing and is returned as `Error::Reader`.
+/// # Example
+/// ```
+/// use ylong_json::from_iter;
+///
+/// let bytes = "[1, 2, 3]".bytes().map(Ok);
+/// let value: Vec<u32> = from_iter(bytes).unwrap();
+/// assert_eq!(value, vec![1, 2, 3]);
+/// ```
+#[cfg(feature = "std")]
+pub fn from_iter<I, T>(iter: I) -> Result<T, Error>
+where
+    I: IntoIterator<Item = std::io::Result<u8>>,
+    T: DeserializeOwned,
+{
+    let mut deserializer = Deserializer::new_from_iter(iter.into_iter());
+    let t = T::deserialize(&mut deserializer).map_err(|e| deserializer.fix_position(e))?;
     match eat_whitespace_until_not!(deserializer) {
         None => Ok(t),
         _ => Err(Error::Parsing(ParsingUnfinished)),
@@ -115,6 +257,9 @@ where
 }
 
 /// Deserializes an instance of type `T` from bytes.
+///
+/// Strings without escape characters are borrowed from the input, so `T` can
+/// contain `&str` or `Cow<str>` fields which point into it.
 /// # Example
 /// ```
 /// use serde::Deserialize;
@@ -139,8 +284,115 @@ pub fn from_slice<'a, T>(slice: &'a [u8]) -> Result<T, Error>
 where
     T: Deserialize<'a>,
 {
-    let mut deserializer = Deserializer::new_from_slice(slice);
-    let t = T::deserialize(&mut deserializer)?;
+    from_slice_with_bytes_format(slice, BytesFormat::Array)
+}
+
+/// The same as `from_slice`, but byte slices requested by `deserialize_bytes`
+/// are decoded according to the given `BytesFormat`.
+///
+/// # Example
+/// ```
+/// use serde::de::{Deserialize, Deserializer, Error, Visitor};
+/// use std::fmt::Formatter;
+/// use ylong_json::{from_slice_with_bytes_format, Base64Alphabet, BytesFormat};
+///
+/// #[derive(PartialEq, Debug)]
+/// struct Bytes(Vec<u8>);
+///
+/// impl<'de> Deserialize<'de> for Bytes {
+///     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+///         struct BytesVisitor;
+///
+///         impl<'de> Visitor<'de> for BytesVisitor {
+///             type Value = Bytes;
+///
+///             fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+///                 f.write_str("bytes")
+///             }
+///
+///             fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Bytes, E> {
+///                 Ok(Bytes(v))
+///             }
+///         }
+///         deserializer.deserialize_byte_buf(BytesVisitor)
+///     }
+/// }
+///
+/// let format = BytesFormat::Base64(Base64Alphabet::Standard);
+/// let bytes: Bytes = from_slice_with_bytes_format(b"\"aGVsbG8=\"", format).unwrap();
+/// assert_eq!(bytes, Bytes(b"hello".to_vec()));
+/// ```
+pub fn from_slice_with_bytes_format<'a, T>(slice: &'a [u8], format: BytesFormat) -> Result<T, Error>
+where
+    T: Deserialize<'a>,
+{
+    let mut deserializer = Deserializer::new_from_slice(slice).with_bytes_format(format);
+    let t = T::deserialize(&mut deserializer).map_err(|e| deserializer.fix_position(e))?;
+    match eat_whitespace_until_not!(deserializer) {
+        None => Ok(t),
+        _ => {
+            unexpected_character!(&mut deserializer)
+        }
+    }
+}
+
+/// The same as `from_slice`, but the limits in the given `ParseConfig` are
+/// applied while parsing.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use ylong_json::{from_slice_with_config, Error, ParseConfig};
+///
+/// let text = br#"{"a": 1, "b": 2}"#;
+/// let config = ParseConfig::default().max_object_members(1);
+/// let result = from_slice_with_config::<HashMap<String, u32>>(text, config);
+/// assert!(matches!(result, Err(Error::ExceedObjectLimit)));
+/// ```
+pub fn from_slice_with_config<'a, T>(slice: &'a [u8], config: ParseConfig) -> Result<T, Error>
+where
+    T: Deserialize<'a>,
+{
+    let mut deserializer = Deserializer::new_from_slice(slice).with_config(config);
+    let t = T::deserialize(&mut deserializer).map_err(|e| deserializer.fix_position(e))?;
+    match eat_whitespace_until_not!(deserializer) {
+        None => Ok(t),
+        _ => {
+            unexpected_character!(&mut deserializer)
+        }
+    }
+}
+
+/// Deserializes an instance of type `T` from several slices, which are read
+/// as one JSON text in order without being joined.
+///
+/// Strings without escape characters are borrowed from the input if they do
+/// not cross slices.
+/// # Example
+/// ```
+/// use serde::Deserialize;
+/// use ylong_json::from_slices;
+///
+/// #[derive(Deserialize, PartialEq, Debug)]
+/// struct Test {
+///     int: u32,
+///     seq: Vec<String>,
+/// }
+///
+/// let header = r#"{"int":1,"seq":["ab"#.as_bytes();
+/// let body = r#"cd","efgh"]}"#.as_bytes();
+/// let expected = Test {
+///     int: 1,
+///     seq: vec![String::from("abcd"), String::from("efgh")],
+/// };
+/// assert_eq!(expected, from_slices(&[header, body]).unwrap())
+/// ```
+pub fn from_slices<'a, T>(slices: &[&'a [u8]]) -> Result<T, Error>
+where
+    T: Deserialize<'a>,
+{
+    let mut deserializer = Deserializer::new_from_slices(slices);
+    let t = T::deserialize(&mut deserializer).map_err(|e| deserializer.fix_position(e))?;
     match eat_whitespace_until_not!(deserializer) {
         None => Ok(t),
         _ => {
@@ -150,6 +402,9 @@ where
 }
 
 /// Deserializes an instance of type `T` from str.
+///
+/// Strings without escape characters are borrowed from the input, so `T` can
+/// contain `&str` or `Cow<str>` fields which point into it.
 /// # Example
 /// ```
 /// use serde::Deserialize;
@@ -177,6 +432,24 @@ where
     from_slice(str.as_bytes())
 }
 
+/// The same as `from_str`, but byte slices requested by `deserialize_bytes`
+/// are decoded according to the given `BytesFormat`.
+pub fn from_str_with_bytes_format<'a, T>(str: &'a str, format: BytesFormat) -> Result<T, Error>
+where
+    T: Deserialize<'a>,
+{
+    from_slice_with_bytes_format(str.as_bytes(), format)
+}
+
+/// The same as `from_str`, but the limits in the given `ParseConfig` are
+/// applied while parsing.
+pub fn from_str_with_config<'a, T>(str: &'a str, config: ParseConfig) -> Result<T, Error>
+where
+    T: Deserialize<'a>,
+{
+    from_slice_with_config(str.as_bytes(), config)
+}
+
 impl<R> Deserializer<R>
 where
     R: BytesReader + Cacheable,
@@ -234,13 +507,73 @@ where
         };
 
         match peek_ch {
-            b'-' => parse_number(self)?.visit(visitor),
-            b'0'..=b'9' => parse_number(self)?.visit(visitor),
+            b'-' | b'+' | b'0'..=b'9' => parse_number(self)?
+                .visit(visitor)
+                .map_err(|e| self.fix_position(e)),
             _ => unexpected_character!(self),
         }
     }
 
-    fn de_parse_string(&mut self) -> Result<JsonString, Error> {
+    // Parses an integer of type `T` directly from the text of the number, so a value
+    // out of the range of `T` is reported with its text by `Error::NumberOutOfRange`. Numbers with a fraction or an
+    // exponent are visited as usual.
+    fn de_parse_integer<'de, V, T>(
+        &mut self,
+        visitor: V,
+        visit: fn(V, T) -> Result<V::Value, Error>,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+        T: FromStr,
+    {
+        match eat_whitespace_until_not!(self) {
+            Some(b'-' | b'+' | b'0'..=b'9') => {}
+            Some(_) => return unexpected_character!(self),
+            None => return Err(Error::Parsing(ParsingUnfinished)),
+        }
+        let shape = scan_number(self)?;
+        if !shape.is_integer() {
+            let number = cached_number(self, shape)?;
+            self.reader.end_caching();
+            return number.visit(visitor).map_err(|e| self.fix_position(e));
+        }
+
+        let position = self.reader.position();
+        // The contents of u8 have been checked, so the unchecked method can be used here.
+        let str = unsafe { core::str::from_utf8_unchecked(self.reader.cached_slice().unwrap()) };
+        // `-0` is a valid value of the unsigned types as well.
+        let digits = match str.strip_prefix('-') {
+            Some(digits) if digits.bytes().all(|b| b == b'0') => digits,
+            _ => str,
+        };
+        let integer = match digits.parse::<T>() {
+            Ok(integer) => integer,
+            Err(_) => {
+                let integer = str.to_string();
+                return Err(Error::NumberOutOfRange(
+                    None,
+                    integer,
+                    type_name::<T>(),
+                    position,
+                ));
+            }
+        };
+        self.reader.end_caching();
+        visit(visitor, integer).map_err(|e| self.fix_position(e))
+    }
+
+    // Attaches the current position of the reader to an error raised by a visitor
+    // through `Error::custom`, such as a type mismatch or a missing field. The entry
+    // functions attach it as well, since the members of internally or adjacently tagged
+    // enums are buffered by serde and deserialized after the whole object has been read.
+    fn fix_position(&self, error: Error) -> Error {
+        match error {
+            Error::Custom(msg) => Error::CustomAt(msg, self.reader.position()),
+            e => e,
+        }
+    }
+
+    fn de_parse_string(&mut self) -> Result<String, Error> {
         match self.peek_char()? {
             Some(b'"') => self.discard_char(),
             _ => return unexpected_character!(self),
@@ -249,9 +582,9 @@ where
     }
 }
 
-impl<'de, 'a, R> de::Deserializer<'de> for &'a mut Deserializer<R>
+impl<'de, R> de::Deserializer<'de> for &mut Deserializer<R>
 where
-    R: BytesReader + Cacheable,
+    R: BytesReader + Cacheable + Borrowable<'de>,
 {
     type Error = Error;
 
@@ -271,8 +604,7 @@ where
             b'n' => self.deserialize_unit(visitor),
             b't' | b'f' => self.deserialize_bool(visitor),
             b'"' => self.deserialize_str(visitor),
-            b'0'..=b'9' => self.deserialize_u64(visitor),
-            b'-' => self.deserialize_i64(visitor),
+            b'+' | b'-' | b'0'..=b'9' => self.de_parse_number(visitor),
             b'[' => self.deserialize_seq(visitor),
             b'{' => self.deserialize_map(visitor),
             _ => unexpected_character!(self),
@@ -283,63 +615,78 @@ where
     where
         V: Visitor<'de>,
     {
-        visitor.visit_bool(self.parse_bool()?)
+        let value = self.parse_bool()?;
+        visitor.visit_bool(value).map_err(|e| self.fix_position(e))
     }
 
     fn deserialize_i8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.de_parse_number(visitor)
+        self.de_parse_integer(visitor, V::visit_i8)
     }
 
     fn deserialize_i16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.de_parse_number(visitor)
+        self.de_parse_integer(visitor, V::visit_i16)
     }
 
     fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.de_parse_number(visitor)
+        self.de_parse_integer(visitor, V::visit_i32)
     }
 
     fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.de_parse_number(visitor)
+        self.de_parse_integer(visitor, V::visit_i64)
     }
 
     fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.de_parse_number(visitor)
+        self.de_parse_integer(visitor, V::visit_u8)
     }
 
     fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.de_parse_number(visitor)
+        self.de_parse_integer(visitor, V::visit_u16)
     }
 
     fn deserialize_u32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.de_parse_number(visitor)
+        self.de_parse_integer(visitor, V::visit_u32)
     }
 
     fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.de_parse_number(visitor)
+        self.de_parse_integer(visitor, V::visit_u64)
+    }
+
+    fn deserialize_i128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.de_parse_integer(visitor, V::visit_i128)
+    }
+
+    fn deserialize_u128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.de_parse_integer(visitor, V::visit_u128)
     }
 
     fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -367,12 +714,17 @@ where
     where
         V: Visitor<'de>,
     {
-        let str = self.de_parse_string()?;
-        #[cfg(feature = "c_adapter")]
-        return visitor.visit_str(str.to_str()?);
-
-        #[cfg(not(feature = "c_adapter"))]
-        visitor.visit_str(str.as_str())
+        match self.peek_char()? {
+            Some(b'"') => self.discard_char(),
+            _ => return unexpected_character!(self),
+        }
+        // Strings without escape characters are borrowed from the input if possible.
+        // Otherwise, the visitor copies the decoded string only if it needs to own it.
+        let result = match parse_str(self)? {
+            Reference::Borrowed(str) => visitor.visit_borrowed_str(str),
+            Reference::Copied(str) => visitor.visit_str(str),
+        };
+        result.map_err(|e| self.fix_position(e))
     }
 
     fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -395,8 +747,18 @@ where
 
         match peek_ch {
             b'"' => {
-                let v = parse_string_inner(self)?;
-                visitor.visit_bytes(&v)
+                self.discard_char();
+                let bytes_format = self.bytes_format;
+                // Visitors always receive owned bytes, so only the Base64 text is read
+                // without being copied.
+                let result = match parse_str_bytes(self)? {
+                    Reference::Borrowed(v) | Reference::Copied(v) => match bytes_format {
+                        BytesFormat::Array => visitor.visit_byte_buf(v.to_vec()),
+                        BytesFormat::Base64(alphabet) => base64::decode(v, alphabet)
+                            .and_then(|bytes| visitor.visit_byte_buf(bytes)),
+                    },
+                };
+                result.map_err(|e| self.fix_position(e))
             }
             b'[' => self.deserialize_seq(visitor),
             _ => unexpected_character!(self),
@@ -425,7 +787,7 @@ where
             b'n' => {
                 self.discard_char();
                 match_str!(self, b"ull");
-                visitor.visit_none()
+                visitor.visit_none().map_err(|e| self.fix_position(e))
             }
             _ => visitor.visit_some(self),
         }
@@ -446,7 +808,7 @@ where
             b'n' => {
                 self.discard_char();
                 match_str!(self, b"ull");
-                visitor.visit_unit()
+                visitor.visit_unit().map_err(|e| self.fix_position(e))
             }
             _ => unexpected_character!(self),
         }
@@ -488,7 +850,11 @@ where
         match peek_ch {
             b'[' => {
                 self.discard_char();
-                let value = visitor.visit_seq(SeqAssistant::new(self))?;
+                self.enter_container()?;
+                let value = visitor
+                    .visit_seq(SeqAssistant::new(self))
+                    .map_err(|e| self.fix_position(e))?;
+                self.recursion_depth -= 1;
 
                 let peek_ch_inner = match eat_whitespace_until_not!(self) {
                     Some(ch) => ch,
@@ -542,7 +908,11 @@ where
         match peek_ch {
             b'{' => {
                 self.discard_char();
-                let value = visitor.visit_map(SeqAssistant::new(self))?;
+                self.enter_container()?;
+                let value = visitor
+                    .visit_map(SeqAssistant::new(self))
+                    .map_err(|e| self.fix_position(e))?;
+                self.recursion_depth -= 1;
 
                 let peek_ch_inner = match eat_whitespace_until_not!(self) {
                     Some(ch) => ch,
@@ -593,17 +963,19 @@ where
 
         match peek_ch {
             b'"' => {
-                #[cfg(feature = "c_adapter")]
-                return visitor
-                    .visit_enum(self.de_parse_string()?.into_string()?.into_deserializer());
-
-                #[cfg(not(feature = "c_adapter"))]
-                visitor.visit_enum(self.de_parse_string()?.into_deserializer())
+                let variant = self.de_parse_string()?;
+                visitor
+                    .visit_enum(variant.into_deserializer())
+                    .map_err(|e| self.fix_position(e))
             }
             _ => {
                 if self.next_char()? == Some(b'{') {
+                    self.enter_container()?;
                     eat_whitespace_until_not!(self);
-                    let value = visitor.visit_enum(EnumAssistant::new(self))?;
+                    let value = visitor
+                        .visit_enum(EnumAssistant::new(self))
+                        .map_err(|e| self.fix_position(e))?;
+                    self.recursion_depth -= 1;
                     eat_whitespace_until_not!(self);
 
                     if self.next_char()? == Some(b'}') {
@@ -625,11 +997,13 @@ where
         self.deserialize_str(visitor)
     }
 
+    // The ignored value is skipped at the token level instead of being deserialized.
     fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.deserialize_any(visitor)
+        skip_value(self)?;
+        visitor.visit_unit().map_err(|e| self.fix_position(e))
     }
 }
 
@@ -639,6 +1013,12 @@ where
 {
     deserializer: &'a mut Deserializer<R>,
     is_first: bool,
+    // Number of elements or members which have been read.
+    count: usize,
+    // Keys which have been read, only if duplicate keys are rejected.
+    keys: BTreeSet<String>,
+    // The key of the member being read, which is named by `Error::NumberOutOfRange`.
+    key: String,
 }
 
 impl<'a, R: 'a> SeqAssistant<'a, R>
@@ -649,13 +1029,26 @@ where
         SeqAssistant {
             deserializer,
             is_first: true,
+            count: 0,
+            keys: BTreeSet::new(),
+            key: String::new(),
+        }
+    }
+
+    // Records a key of the object, which must not have been read before.
+    fn insert_key(&mut self, key: &str) -> Result<(), Error> {
+        if self.keys.contains(key) {
+            let position = self.deserializer.reader.position();
+            return Err(Error::Parsing(DuplicateKey(position, key.to_string())));
         }
+        self.keys.insert(key.to_string());
+        Ok(())
     }
 }
 
 impl<'de, 'a, R> SeqAccess<'de> for SeqAssistant<'a, R>
 where
-    R: BytesReader + Cacheable,
+    R: BytesReader + Cacheable + Borrowable<'de>,
 {
     type Error = Error;
 
@@ -675,10 +1068,7 @@ where
                     Some(ch)
                 } else {
                     let position = self.deserializer.reader.position();
-                    return Err(Error::Parsing(MissingComma(
-                        position.line(),
-                        position.column(),
-                    )));
+                    return Err(Error::Parsing(MissingComma(position)));
                 }
             }
             None => return Err(Error::Parsing(ParsingUnfinished)),
@@ -687,12 +1077,16 @@ where
         match peek_ch {
             Some(b']') => {
                 let position = self.deserializer.reader.position();
-                Err(Error::Parsing(TrailingComma(
-                    position.line(),
-                    position.column(),
-                )))
+                Err(Error::Parsing(TrailingComma(position)))
+            }
+            Some(_) => {
+                self.count += 1;
+                if self.count > self.deserializer.config.max_array_len {
+                    return Err(Error::ExceedArrayLimit);
+                }
+                check_alloc(self.deserializer, core::mem::size_of::<JsonValue>())?;
+                Ok(Some(seed.deserialize(&mut *self.deserializer)?))
             }
-            Some(_) => Ok(Some(seed.deserialize(&mut *self.deserializer)?)),
             None => Err(Error::Parsing(ParsingUnfinished)),
         }
     }
@@ -700,7 +1094,7 @@ where
 
 impl<'de, 'a, R> MapAccess<'de> for SeqAssistant<'a, R>
 where
-    R: BytesReader + Cacheable,
+    R: BytesReader + Cacheable + Borrowable<'de>,
 {
     type Error = Error;
 
@@ -720,10 +1114,7 @@ where
                     Some(ch)
                 } else {
                     let position = self.deserializer.reader.position();
-                    return Err(Error::Parsing(MissingComma(
-                        position.line(),
-                        position.column(),
-                    )));
+                    return Err(Error::Parsing(MissingComma(position)));
                 }
             }
             None => {
@@ -732,13 +1123,41 @@ where
         };
 
         match peek_ch {
-            Some(b'"') => Ok(Some(seed.deserialize(&mut *self.deserializer)?)),
+            Some(b'"') => {
+                self.count += 1;
+                if self.count > self.deserializer.config.max_object_members {
+                    return Err(Error::ExceedObjectLimit);
+                }
+                check_alloc(self.deserializer, core::mem::size_of::<JsonValue>())?;
+                // The key is read here instead of by the seed, so that it can be recorded
+                // and checked.
+                self.deserializer.discard_char();
+                let reject_duplicate = self.deserializer.config.reject_duplicate_keys;
+                match parse_str(self.deserializer)? {
+                    Reference::Borrowed(key) => {
+                        self.key.clear();
+                        self.key.push_str(key);
+                        if reject_duplicate {
+                            self.insert_key(key)?;
+                        }
+                        seed.deserialize(BorrowedStrDeserializer::new(key))
+                            .map(Some)
+                    }
+                    Reference::Copied(key) => {
+                        self.key.clear();
+                        self.key.push_str(key);
+                        if reject_duplicate {
+                            let key = self.key.clone();
+                            self.insert_key(&key)?;
+                        }
+                        seed.deserialize(self.key.as_str().into_deserializer())
+                            .map(Some)
+                    }
+                }
+            }
             Some(b'}') => {
                 let position = self.deserializer.reader.position();
-                Err(Error::Parsing(TrailingComma(
-                    position.line(),
-                    position.column(),
-                )))
+                Err(Error::Parsing(TrailingComma(position)))
             }
             // Object key must be String.
             _ => unexpected_character!(self.deserializer),
@@ -754,13 +1173,17 @@ where
                 self.deserializer.discard_char();
                 eat_whitespace_until_not!(self.deserializer);
                 seed.deserialize(&mut *self.deserializer)
+                    .map_err(|e| match e {
+                        // Names the innermost field only.
+                        Error::NumberOutOfRange(None, integer, ty, position) => {
+                            Error::NumberOutOfRange(Some(self.key.clone()), integer, ty, position)
+                        }
+                        e => e,
+                    })
             }
             Some(_ch) => {
                 let position = self.deserializer.reader.position();
-                Err(Error::Parsing(MissingColon(
-                    position.line(),
-                    position.column(),
-                )))
+                Err(Error::Parsing(MissingColon(position)))
             }
             None => Err(Error::Parsing(ParsingUnfinished)),
         }
@@ -785,7 +1208,7 @@ where
 
 impl<'de, 'a, R: 'a> EnumAccess<'de> for EnumAssistant<'a, R>
 where
-    R: BytesReader + Cacheable,
+    R: BytesReader + Cacheable + Borrowable<'de>,
 {
     type Error = Error;
     type Variant = Self;
@@ -808,7 +1231,7 @@ where
 
 impl<'de, 'a, R: 'a> VariantAccess<'de> for EnumAssistant<'a, R>
 where
-    R: BytesReader + Cacheable,
+    R: BytesReader + Cacheable + Borrowable<'de>,
 {
     type Error = Error;
 
@@ -842,9 +1265,9 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod ut_test_for_deserializer {
-    use crate::deserializer::{from_slice, from_str};
+    use crate::deserializer::{from_iter, from_reader, from_slice, from_slices, from_str};
     use serde::Deserialize;
     use std::borrow::Cow;
     use std::collections::HashMap;
@@ -922,10 +1345,10 @@ mod ut_test_for_deserializer {
 
         let slice_map = r#"{ "apple" : 3 }"#.as_bytes();
         let mut expected = HashMap::new();
-        expected.insert("appple", 3);
+        expected.insert(Cow::from("apple"), 3);
         assert_eq!(
             expected,
-            crate::from_slice::<HashMap<&str, i32>>(slice_map).unwrap()
+            crate::from_slice::<HashMap<Cow<str>, i32>>(slice_map).unwrap()
         );
     }
 
@@ -1029,6 +1452,49 @@ mod ut_test_for_deserializer {
         assert!(res.is_err());
     }
 
+    /// UT test for the position of serde errors during deserialization.
+    ///
+    /// # Title
+    /// ut_deserialize_error_position
+    ///
+    /// # Brief
+    /// 1.Uses deserializer::from_str and from_reader methods to deserialize texts which
+    ///   raise errors in visitors, such as a missing field.
+    /// 2.Checks if the test results are correct.
+    #[test]
+    fn ut_deserialize_error_position() {
+        #[derive(Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Test {
+            id: u8,
+            name: String,
+        }
+
+        fn position(text: &str) -> (String, usize, usize) {
+            let error = from_str::<Vec<Test>>(text).unwrap_err();
+            let reader_error = from_reader::<_, Vec<Test>>(text.as_bytes()).unwrap_err();
+            assert_eq!(error.to_string(), reader_error.to_string());
+            let position = error.position().unwrap();
+            assert_eq!(reader_error.position(), Some(position));
+            (error.to_string(), position.line(), position.column())
+        }
+
+        let (msg, line, column) =
+            position("[\n  {\"id\": 1, \"name\": \"a\"},\n  {\"id\": 300}\n]");
+        assert_eq!(
+            msg,
+            "Number Error: 300 is out of the range of u8 in field \"id\" at line 3 column 13"
+        );
+        assert_eq!((line, column), (3, 13));
+
+        let (msg, line, column) = position("[\n  {\"id\": 1}\n]");
+        assert_eq!(msg, "missing field `name` at line 2 column 11");
+        assert_eq!((line, column), (2, 11));
+
+        let res = from_str::<Vec<Test>>("[1");
+        assert!(res.unwrap_err().position().is_some());
+    }
+
     /// UT test to deserialize struct
     ///
     /// # Title
@@ -1175,4 +1641,734 @@ mod ut_test_for_deserializer {
         let res = from_slice::<E>(slice_abnormal);
         assert!(res.is_err());
     }
+
+    /// UT test to deserialize internally and adjacently tagged enums
+    ///
+    /// # Title
+    /// ut_deserialize_tagged_enum
+    ///
+    /// # Brief
+    /// 1.Uses deserializer::from_reader, from_iter and from_slices methods to deserialize
+    /// tagged enums whose tags are at any position.
+    /// 2.Checks if the test results are correct.
+    /// 3.Checks if the errors in the buffered members have positions.
+    #[test]
+    fn ut_deserialize_tagged_enum() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        #[serde(tag = "type")]
+        enum Internal {
+            Struct { id: u32, name: String },
+            Newtype(Inner),
+            Unit,
+            Nested { children: Vec<Internal> },
+        }
+
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Inner {
+            value: i64,
+            ratio: Option<f64>,
+        }
+
+        #[derive(Deserialize, PartialEq, Debug)]
+        #[serde(tag = "t", content = "c")]
+        enum Adjacent {
+            Struct { id: u32 },
+            Newtype(String),
+            Unit,
+        }
+
+        fn check<T>(text: &str, expected: T)
+        where
+            T: serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+        {
+            assert_eq!(from_reader::<_, T>(text.as_bytes()).unwrap(), expected);
+            assert_eq!(from_iter::<_, T>(text.bytes().map(Ok)).unwrap(), expected);
+            let (head, tail) = text.as_bytes().split_at(text.len() / 2);
+            assert_eq!(from_slices::<T>(&[head, tail]).unwrap(), expected);
+        }
+
+        let expected = Internal::Struct {
+            id: 1,
+            name: String::from("a\"b"),
+        };
+        check(r#"{"type": "Struct", "id": 1, "name": "a\"b"}"#, expected);
+        let expected = Internal::Struct {
+            id: 1,
+            name: String::from("a\"b"),
+        };
+        check(
+            r#" { "name" : "a\"b" , "id" : 1 , "type" : "Struct" } "#,
+            expected,
+        );
+        let expected = Internal::Newtype(Inner {
+            value: -2,
+            ratio: Some(0.5),
+        });
+        check(
+            r#"{"value": -2, "type": "Newtype", "ratio": 0.5}"#,
+            expected,
+        );
+        check(r#"{"type": "Unit"}"#, Internal::Unit);
+        let expected = Internal::Nested {
+            children: vec![Internal::Unit, Internal::Nested { children: vec![] }],
+        };
+        let text = r#"{"children": [{"type": "Unit"}, {"children": [], "type": "Nested"}], "type": "Nested"}"#;
+        check(text, expected);
+
+        check(
+            r#"{"t": "Struct", "c": {"id": 1}}"#,
+            Adjacent::Struct { id: 1 },
+        );
+        check(
+            r#"{"c": {"id": 1}, "t": "Struct"}"#,
+            Adjacent::Struct { id: 1 },
+        );
+        let expected = Adjacent::Newtype(String::from("a"));
+        check(r#"{"c": "a", "t": "Newtype"}"#, expected);
+        check(r#"{"t": "Unit"}"#, Adjacent::Unit);
+
+        // The following is the test for abnormal input of JSON.
+        let text = "{\n\"id\": \"1\", \"name\": \"a\", \"type\": \"Struct\"}";
+        let error = from_reader::<_, Internal>(text.as_bytes()).unwrap_err();
+        let position = error.position().unwrap();
+        assert_eq!((position.line(), position.column()), (2, 42));
+        assert_eq!(
+            from_str::<Internal>(text).unwrap_err().to_string(),
+            error.to_string()
+        );
+
+        let text = r#"{"id": 1, "name": "a"}"#;
+        assert!(from_reader::<_, Internal>(text.as_bytes()).is_err());
+        let text = r#"{"id": 1, "name": "a", "type": "Other"}"#;
+        assert!(from_reader::<_, Internal>(text.as_bytes()).is_err());
+        let text = r#"{"id": 1, "type": "Struct", "name": "a""#;
+        assert!(from_reader::<_, Internal>(text.as_bytes()).is_err());
+        let text = r#"{"c": {"id": 1}}"#;
+        assert!(from_reader::<_, Adjacent>(text.as_bytes()).is_err());
+    }
+
+    /// UT test to deserialize bytes
+    ///
+    /// # Title
+    /// ut_deserialize_bytes
+    ///
+    /// # Brief
+    /// 1.Uses deserializer::from_slice_with_bytes_format method to deserialize bytes.
+    /// 2.Checks if the test results are correct.
+    #[test]
+    fn ut_deserialize_bytes() {
+        use crate::{from_slice_with_bytes_format, Base64Alphabet, BytesFormat};
+        use serde::de::{Deserializer, Error, SeqAccess, Visitor};
+        use std::fmt::Formatter;
+
+        #[derive(PartialEq, Debug)]
+        struct Bytes(Vec<u8>);
+
+        impl<'de> Deserialize<'de> for Bytes {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct BytesVisitor;
+
+                impl<'de> Visitor<'de> for BytesVisitor {
+                    type Value = Bytes;
+
+                    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                        f.write_str("bytes")
+                    }
+
+                    fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Bytes, E> {
+                        Ok(Bytes(v))
+                    }
+
+                    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Bytes, A::Error> {
+                        let mut v = Vec::new();
+                        while let Some(byte) = seq.next_element()? {
+                            v.push(byte);
+                        }
+                        Ok(Bytes(v))
+                    }
+                }
+                deserializer.deserialize_bytes(BytesVisitor)
+            }
+        }
+        let standard = BytesFormat::Base64(Base64Alphabet::Standard);
+        let url_safe = BytesFormat::Base64(Base64Alphabet::UrlSafe);
+
+        assert_eq!(
+            from_slice::<Bytes>(br#""abc""#).unwrap(),
+            Bytes(b"abc".to_vec())
+        );
+        assert_eq!(
+            from_slice::<Bytes>(b"[251, 255, 0]").unwrap(),
+            Bytes(vec![0xfb, 0xff, 0x00])
+        );
+        assert_eq!(
+            from_slice_with_bytes_format::<Bytes>(br#""+/8A""#, standard).unwrap(),
+            Bytes(vec![0xfb, 0xff, 0x00])
+        );
+        assert_eq!(
+            from_slice_with_bytes_format::<Bytes>(br#""-_8A""#, url_safe).unwrap(),
+            Bytes(vec![0xfb, 0xff, 0x00])
+        );
+        assert_eq!(
+            from_slice_with_bytes_format::<Vec<Bytes>>(br#"["YQ==", [1]]"#, standard).unwrap(),
+            vec![Bytes(b"a".to_vec()), Bytes(vec![1])]
+        );
+        assert!(from_slice_with_bytes_format::<Bytes>(br#""-_8A""#, standard).is_err());
+    }
+
+    /// UT test to deserialize struct with ignored fields
+    ///
+    /// # Title
+    /// ut_deserialize_ignored_any
+    ///
+    /// # Brief
+    /// 1.Uses deserializer::from_str method to deserialize a narrow struct from a wide JSON.
+    /// 2.Checks if the test results are correct.
+    #[test]
+    fn ut_deserialize_ignored_any() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Narrow {
+            id: u32,
+            name: String,
+        }
+
+        let str = r#"{
+            "ignored_object": {"a": [1, {"b": "]}"}], "c": "\"{["},
+            "id": 1,
+            "ignored_array": [[], {}, [true, false, null], "\\", -1.5e3],
+            "ignored_string": "abc\"def",
+            "ignored_number": -12.5,
+            "ignored_literal": null,
+            "name": "narrow"
+        }"#;
+        let expected = Narrow {
+            id: 1,
+            name: String::from("narrow"),
+        };
+        assert_eq!(expected, from_str(str).unwrap());
+
+        // Mismatched brackets.
+        let str = r#"{"ignored": [1, 2}, "id": 1, "name": "narrow"}"#;
+        assert!(from_str::<Narrow>(str).is_err());
+
+        // Unfinished string.
+        let str = r#"{"id": 1, "name": "narrow", "ignored": ["abc]}"#;
+        assert!(from_str::<Narrow>(str).is_err());
+
+        // Illegal literal.
+        let str = r#"{"id": 1, "name": "narrow", "ignored": nul}"#;
+        assert!(from_str::<Narrow>(str).is_err());
+
+        // Missing value.
+        let str = r#"{"id": 1, "name": "narrow", "ignored": }"#;
+        assert!(from_str::<Narrow>(str).is_err());
+
+        // Ignored values are checked as `JsonValue::from_text` does.
+        for ignored in [
+            r#"[1 2 }{ ]"#,
+            r#"[1, 2,]"#,
+            r#"[, 1]"#,
+            r#"{"a" 1}"#,
+            r#"{"a": 1,}"#,
+            r#"{1: 2}"#,
+            r#"{"a": 1 "b": 2}"#,
+            r#"[abc]"#,
+        ] {
+            assert!(crate::JsonValue::from_text(ignored).is_err());
+            let str = format!(r#"{{"id": 1, "name": "narrow", "unknown": {ignored}}}"#);
+            assert!(from_str::<Narrow>(&str).is_err(), "{ignored}");
+        }
+    }
+
+    /// UT test to deserialize strings borrowed from the input
+    ///
+    /// # Title
+    /// ut_deserialize_borrowed_str
+    ///
+    /// # Brief
+    /// 1.Uses deserializer::from_str method to deserialize structs with borrowed fields.
+    /// 2.Uses deserializer::from_reader method to deserialize strings.
+    /// 3.Checks if the test results are correct.
+    #[test]
+    fn ut_deserialize_borrowed_str() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Borrowed<'a> {
+            name: &'a str,
+            #[serde(borrow)]
+            text: Cow<'a, str>,
+        }
+
+        let str = r#"{"name": "abc", "text": "def"}"#;
+        let value: Borrowed = from_str(str).unwrap();
+        assert_eq!(value.name, "abc");
+        assert!(matches!(value.text, Cow::Borrowed("def")));
+
+        // Strings with escape characters are copied.
+        let str = r#"{"name": "abc", "text": "d\"e\u0066"}"#;
+        let value: Borrowed = from_str(str).unwrap();
+        assert!(matches!(value.text, Cow::Owned(ref s) if s == "d\"ef"));
+
+        // A `&str` can not hold a string with escape characters.
+        let str = r#"{"name": "a\nc", "text": "def"}"#;
+        assert!(from_str::<Borrowed>(str).is_err());
+
+        // Strings read from an IO stream can only be copied.
+        let value: Vec<String> = from_reader(r#"["abc", "d\te"]"#.as_bytes()).unwrap();
+        assert_eq!(value, vec![String::from("abc"), String::from("d\te")]);
+    }
+
+    /// UT test to check how strings are handed to visitors
+    ///
+    /// # Title
+    /// ut_deserialize_str_reference
+    ///
+    /// # Brief
+    /// 1.Uses deserializer::from_str and from_reader methods with a visitor which records the called method.
+    /// 2.Checks if strings are borrowed without escape characters, and are lent from the scratch buffer otherwise.
+    #[test]
+    fn ut_deserialize_str_reference() {
+        use serde::de::{Deserializer, Visitor};
+        use std::fmt::Formatter;
+
+        #[derive(Debug, PartialEq)]
+        enum Received {
+            Borrowed(String),
+            Lent(String),
+            Owned(String),
+        }
+
+        struct RecordVisitor;
+
+        impl<'de> Visitor<'de> for RecordVisitor {
+            type Value = Received;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Received, E> {
+                Ok(Received::Borrowed(v.to_string()))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Received, E> {
+                Ok(Received::Lent(v.to_string()))
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Received, E> {
+                Ok(Received::Owned(v))
+            }
+        }
+
+        struct Str(Received);
+
+        impl<'de> Deserialize<'de> for Str {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(RecordVisitor).map(Str)
+            }
+        }
+
+        let value: Str = from_str(r#""abc""#).unwrap();
+        assert_eq!(value.0, Received::Borrowed(String::from("abc")));
+        let value: Str = from_str(r#""a\tb\u0063d""#).unwrap();
+        assert_eq!(value.0, Received::Lent(String::from("a\tbcd")));
+        let value: Str = from_reader(r#""abc""#.as_bytes()).unwrap();
+        assert_eq!(value.0, Received::Lent(String::from("abc")));
+
+        // Owned strings are still copied once from the scratch buffer.
+        let value: Vec<String> = from_str(r#"["a\"b", "c"]"#).unwrap();
+        assert_eq!(value, vec![String::from("a\"b"), String::from("c")]);
+    }
+
+    /// UT test to deserialize from several slices
+    ///
+    /// # Title
+    /// ut_deserialize_from_slices
+    ///
+    /// # Brief
+    /// 1.Uses deserializer::from_slices method to deserialize structs whose strings, literals and numbers cross slices.
+    /// 2.Checks if strings in one slice are borrowed, and strings across slices are copied.
+    /// 3.Checks if the position of an error counts the bytes of all previous slices.
+    #[test]
+    fn ut_deserialize_from_slices() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Borrowed<'a> {
+            #[serde(borrow)]
+            name: Cow<'a, str>,
+            #[serde(borrow)]
+            text: Cow<'a, str>,
+            flag: bool,
+            num: f64,
+        }
+
+        let slices: [&[u8]; 5] = [
+            br#"{"name": "abc", "te"#,
+            br#"xt": "d"#,
+            br#"ef", "flag": tr"#,
+            br#"ue, "num": 1.2"#,
+            br#"5e1}  "#,
+        ];
+        let value: Borrowed = from_slices(&slices).unwrap();
+        assert!(matches!(value.name, Cow::Borrowed("abc")));
+        assert!(matches!(value.text, Cow::Owned(ref s) if s == "def"));
+        assert!(value.flag);
+        assert_eq!(value.num, 12.5);
+
+        let value: Vec<String> = from_slices(&[br#"["a\"#, br#"u00"#, br#"62c"]"#]).unwrap();
+        assert_eq!(value, vec![String::from("abc")]);
+
+        let slices: [&[u8]; 2] = [b"[1,\n", b" 2 x]"];
+        let error = from_slices::<Vec<u8>>(&slices).unwrap_err();
+        assert_eq!(
+            error.position().map(|p| (p.line(), p.column(), p.offset())),
+            Some((2, 4, 7))
+        );
+        assert!(from_slices::<Vec<u8>>(&[b"[1]", b" 2"]).is_err());
+    }
+
+    /// UT test to deserialize from a fallible byte iterator
+    ///
+    /// # Title
+    /// ut_deserialize_from_iter
+    ///
+    /// # Brief
+    /// 1.Uses deserializer::from_iter method to deserialize a struct from a byte iterator.
+    /// 2.Uses deserializer::from_iter method with an iterator which returns an error.
+    /// 3.Checks if the test results are correct.
+    #[test]
+    fn ut_deserialize_from_iter() {
+        use crate::Error;
+        use std::io::{Error as IoError, ErrorKind};
+
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Test {
+            name: String,
+            seq: Vec<u32>,
+        }
+
+        let text = r#"{"name": "a\tb", "seq": [1, 2]}"#;
+        let value: Test = from_iter(text.bytes().map(Ok)).unwrap();
+        assert_eq!(
+            value,
+            Test {
+                name: String::from("a\tb"),
+                seq: vec![1, 2],
+            }
+        );
+
+        let bytes = b"[1, 2".iter().map(|b| Ok(*b));
+        let failed = bytes.chain([Err(IoError::from(ErrorKind::InvalidData))]);
+        assert!(matches!(
+            from_iter::<_, Vec<u32>>(failed),
+            Err(Error::Reader(_))
+        ));
+        assert!(from_iter::<_, Vec<u32>>("[1] 2".bytes().map(Ok)).is_err());
+    }
+
+    /// UT test to deserialize with the limits of a `ParseConfig`
+    ///
+    /// # Title
+    /// ut_deserialize_with_config
+    ///
+    /// # Brief
+    /// 1.Uses deserializer::from_slice_with_config and from_reader_with_config methods with limits.
+    /// 2.Checks if the limits are the same as the tree parser, including ignored values.
+    #[test]
+    fn ut_deserialize_with_config() {
+        use crate::{from_reader_with_config, from_slice_with_config, Error, ParseConfig};
+
+        #[derive(Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Test {
+            seq: Vec<Vec<u32>>,
+            name: String,
+        }
+
+        fn check(text: &str, config: ParseConfig, expected: fn(&Error) -> bool) {
+            let from_slice = from_slice_with_config::<Test>(text.as_bytes(), config);
+            assert!(expected(&from_slice.unwrap_err()));
+            let from_reader = from_reader_with_config::<_, Test>(text.as_bytes(), config);
+            assert!(expected(&from_reader.unwrap_err()));
+        }
+
+        let text = r#"{"seq": [[1, 2], []], "name": "abc", "ignored": [[[1]]]}"#;
+        let config = ParseConfig {
+            max_depth: 4,
+            max_document_bytes: text.len(),
+            max_string_bytes: 7,
+            max_array_len: 2,
+            max_object_members: 3,
+            ..ParseConfig::default()
+        };
+        assert!(from_slice_with_config::<Test>(text.as_bytes(), config).is_ok());
+        assert!(from_reader_with_config::<_, Test>(text.as_bytes(), config).is_ok());
+
+        let limited = ParseConfig {
+            max_depth: 3,
+            ..config
+        };
+        check(text, limited, |e| matches!(e, Error::ExceedRecursionLimit));
+        let limited = ParseConfig {
+            max_document_bytes: text.len() - 1,
+            ..config
+        };
+        check(text, limited, |e| matches!(e, Error::ExceedDocumentLimit));
+        let limited = ParseConfig {
+            max_string_bytes: 6,
+            ..config
+        };
+        check(text, limited, |e| matches!(e, Error::ExceedStringLimit));
+        let limited = ParseConfig {
+            max_array_len: 1,
+            ..config
+        };
+        check(text, limited, |e| matches!(e, Error::ExceedArrayLimit));
+        let limited = ParseConfig {
+            max_object_members: 2,
+            ..config
+        };
+        check(text, limited, |e| matches!(e, Error::ExceedObjectLimit));
+    }
+
+    /// UT test to deserialize in the RFC 8259 mode
+    ///
+    /// # Title
+    /// ut_deserialize_rfc8259
+    ///
+    /// # Brief
+    /// 1.Uses deserializer::from_slice_with_config method with duplicate keys rejected.
+    /// 2.Uses deserializer::from_slice_with_config method on invalid UTF-8 in ignored values.
+    /// 3.Checks if the test results are correct.
+    #[test]
+    fn ut_deserialize_rfc8259() {
+        use crate::{from_slice_with_config, Error, ParseConfig, ParseError};
+        use serde::de::IgnoredAny;
+
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Test<'a> {
+            name: &'a str,
+            value: u32,
+        }
+
+        let config = ParseConfig {
+            strict_rfc8259: true,
+            reject_duplicate_keys: true,
+            ..ParseConfig::default()
+        };
+        let text = br#"{"name": "abc", "value": 1, "ignored": {"a": 1, "a": 2}}"#;
+        let value: Test = from_slice_with_config(text, config).unwrap();
+        assert_eq!(
+            value,
+            Test {
+                name: "abc",
+                value: 1
+            }
+        );
+
+        let text = br#"{"name": "abc", "value": 1, "name": "def"}"#;
+        let error = from_slice_with_config::<Test>(text, config).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parsing(ParseError::DuplicateKey(_, ref key)) if key == "name"
+        ));
+        let text = br#"{"key": 1, "key": 2}"#;
+        let error = from_slice_with_config::<HashMap<String, u32>>(text, config).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parsing(ParseError::DuplicateKey(_, ref key)) if key == "key"
+        ));
+
+        let text = b"{\"name\": \"abc\", \"value\": 1, \"ignored\": [\"\xff\"]}";
+        assert!(from_slice_with_config::<Test>(text, ParseConfig::default()).is_ok());
+        // Strings which are deserialized are checked in all modes.
+        assert!(from_slice::<String>(b"\"\xff\"").is_err());
+        assert!(from_slice::<&str>(b"\"\xc3\"").is_err());
+        assert!(from_slice::<String>(b"\"\\n\xc3\"").is_err());
+        assert!(from_slice::<HashMap<String, u32>>(b"{\"\xff\": 1}").is_err());
+        assert!(matches!(
+            from_slice_with_config::<Test>(text, config),
+            Err(Error::Parsing(ParseError::InvalidUtf8Bytes(_)))
+        ));
+        assert!(from_slice_with_config::<IgnoredAny>(b"[\"\xff\"]", config).is_err());
+        assert!(from_slice_with_config::<f64>(b"1e400", config).is_err());
+    }
+
+    /// UT test for the `number_overflow` policy of the deserializer.
+    ///
+    /// # Title
+    /// ut_deserialize_number_overflow
+    ///
+    /// # Brief
+    /// 1. Uses deserializer::from_slice_with_config with each policy on an oversized integer.
+    /// 2. Checks if the integer is rejected by default and deserialized as `f64` otherwise.
+    #[test]
+    fn ut_deserialize_number_overflow() {
+        use crate::{from_slice_with_config, Error, NumberOverflow, ParseConfig};
+
+        let text = b"[1, 100000000000000000000]";
+        assert!(matches!(
+            from_slice_with_config::<Vec<f64>>(text, ParseConfig::default()),
+            Err(Error::ParseNumber)
+        ));
+        let config = ParseConfig {
+            number_overflow: NumberOverflow::Float,
+            ..ParseConfig::default()
+        };
+        let value = from_slice_with_config::<Vec<f64>>(text, config).unwrap();
+        assert_eq!(value, vec![1.0, 1e20]);
+        assert!(from_slice_with_config::<Vec<u64>>(text, config).is_err());
+    }
+
+    /// UT test for the leniency flags of numbers in the deserializer.
+    ///
+    /// # Title
+    /// ut_deserialize_number_leniency
+    ///
+    /// # Brief
+    /// 1. Uses deserializer::from_slice_with_config on numbers with a leading plus or zeros.
+    /// 2. Checks if the numbers are accepted only when the flags are set.
+    #[test]
+    fn ut_deserialize_number_leniency() {
+        use crate::{from_slice, from_slice_with_config, ParseConfig};
+
+        let text = b"{\"port\": +8080, \"mode\": 0644}";
+        assert!(from_slice::<HashMap<String, u32>>(text).is_err());
+        let config = ParseConfig {
+            allow_leading_plus: true,
+            allow_leading_zeros: true,
+            ..ParseConfig::default()
+        };
+        let map = from_slice_with_config::<HashMap<String, u32>>(text, config).unwrap();
+        assert_eq!(map["port"], 8080);
+        assert_eq!(map["mode"], 644);
+    }
+
+    /// UT test for deserializing integers smaller than 64 bits.
+    ///
+    /// # Title
+    /// ut_deserialize_small_integers
+    ///
+    /// # Brief
+    /// 1. Uses deserializer::from_str on integers at and beyond the bounds of the types.
+    /// 2. Checks if the values in range are deserialized.
+    /// 3. Checks if the values out of range are rejected with their text and position.
+    #[test]
+    fn ut_deserialize_small_integers() {
+        use crate::{from_str, Error};
+
+        assert_eq!(
+            from_str::<Vec<i8>>("[-128, 127, -0]").unwrap(),
+            [-128, 127, 0]
+        );
+        assert_eq!(from_str::<Vec<u8>>("[0, 255, -0]").unwrap(), [0, 255, 0]);
+        assert_eq!(from_str::<i16>("-32768").unwrap(), i16::MIN);
+        assert_eq!(from_str::<u16>("65535").unwrap(), u16::MAX);
+        assert_eq!(from_str::<i32>("-2147483648").unwrap(), i32::MIN);
+        assert_eq!(from_str::<u32>("4294967295").unwrap(), u32::MAX);
+
+        let error = from_str::<Vec<u8>>("[1, 256]").unwrap_err();
+        match &error {
+            Error::NumberOutOfRange(None, integer, "u8", position) => {
+                assert_eq!(integer, "256");
+                assert_eq!(position.column(), 8);
+            }
+            _ => panic!("unexpected error: {error:?}"),
+        }
+        let error = from_str::<i8>("-99999999999999999999999").unwrap_err();
+        assert!(error.to_string().contains("-99999999999999999999999"));
+        assert!(from_str::<u32>("-1").is_err());
+        assert!(from_str::<i16>("32768").is_err());
+        assert!(from_str::<u16>("1.0").is_err());
+        assert!(from_str::<u8>("1e2").is_err());
+        assert!(from_str::<i32>("\"1\"").is_err());
+    }
+
+    /// UT test for deserializing 64-bit and 128-bit integers out of range.
+    ///
+    /// # Title
+    /// ut_deserialize_large_integers
+    ///
+    /// # Brief
+    /// 1. Uses deserializer::from_str on integers beyond the range of `i64` or `u64`.
+    /// 2. Checks if the errors name the innermost field, the integer and the type.
+    /// 3. Checks if the integers are deserialized into `i128` and `u128`.
+    #[test]
+    fn ut_deserialize_large_integers() {
+        use crate::{from_str, Error, ErrorKind};
+
+        #[derive(Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            id: i64,
+        }
+
+        #[derive(Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Outer {
+            inner: Inner,
+            size: u64,
+        }
+
+        let text = r#"{"inner": {"id": 18446744073709551615}, "size": 1}"#;
+        let error = from_str::<Outer>(text).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Number);
+        match &error {
+            Error::NumberOutOfRange(Some(field), integer, "i64", _) => {
+                assert_eq!(field, "id");
+                assert_eq!(integer, "18446744073709551615");
+            }
+            _ => panic!("unexpected error: {error:?}"),
+        }
+
+        let text = r#"{"inner": {"id": 1}, "size": -1}"#;
+        let error = from_str::<Outer>(text).unwrap_err();
+        assert!(matches!(
+            &error,
+            Error::NumberOutOfRange(Some(field), integer, "u64", _) if field == "size" && integer == "-1"
+        ));
+
+        assert_eq!(from_str::<u64>("18446744073709551615").unwrap(), u64::MAX);
+        assert_eq!(from_str::<i64>("-9223372036854775808").unwrap(), i64::MIN);
+        assert_eq!(
+            from_str::<Vec<u128>>("[18446744073709551616, 0]").unwrap(),
+            [u64::MAX as u128 + 1, 0]
+        );
+        assert_eq!(
+            from_str::<i128>("-9223372036854775809").unwrap(),
+            i64::MIN as i128 - 1
+        );
+        assert!(from_str::<u128>("-1").is_err());
+        assert!(from_str::<i64>("1e3").is_err());
+    }
+
+    /// UT test for the memory limit of the deserializer.
+    ///
+    /// # Title
+    /// ut_deserialize_memory_limit
+    ///
+    /// # Brief
+    /// 1. Uses deserializer::from_slice_with_config with a small `max_alloc_bytes`.
+    /// 2. Checks if texts with many strings or elements are rejected.
+    #[test]
+    fn ut_deserialize_memory_limit() {
+        use crate::{from_slice_with_config, Error, JsonValue, ParseConfig};
+        use core::mem::size_of;
+
+        let config = ParseConfig {
+            max_alloc_bytes: size_of::<JsonValue>() * 4,
+            ..ParseConfig::default()
+        };
+        let text = br#"["abcdefgh", "abcdefgh"]"#;
+        assert!(from_slice_with_config::<Vec<String>>(text, config).is_ok());
+
+        let text = format!("[{}]", ["[]"; 5].join(","));
+        assert!(matches!(
+            from_slice_with_config::<Vec<Vec<u8>>>(text.as_bytes(), config),
+            Err(Error::ExceedMemoryLimit)
+        ));
+        let text = format!("[\"{}\"]", "\\u00e9".repeat(size_of::<JsonValue>() * 4));
+        assert!(matches!(
+            from_slice_with_config::<Vec<String>>(text.as_bytes(), config),
+            Err(Error::ExceedMemoryLimit)
+        ));
+    }
 }
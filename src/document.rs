@@ -0,0 +1,877 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::scan::{skip_string, whitespace_len};
+use crate::value::{pointer_index, unescape_pointer_token};
+use crate::{Error, JsonPointer, JsonValue};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::ops::Range;
+use core::str::FromStr;
+
+/// A JSON text which keeps its comments and formatting, such as a configuration
+/// file edited by users.
+///
+/// Comments in the `//` and `/* */` forms are accepted wherever whitespace is.
+/// `Document::set` and `Document::remove` only rewrite the bytes of the edited
+/// member, so the comments, the order of the members and the indentation of
+/// the rest of the text are written back as they were read. `Document::update`
+/// modifies the value directly and writes back only the modified members, and
+/// `Document::raw` gets the original text of a value, such as a number in its
+/// original notation.
+///
+/// New values are written in the compact format. A new member is appended to
+/// its Object or Array, on a new line with the indentation of the last member
+/// if the last member ends its line, otherwise on the same line.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{Document, JsonValue};
+///
+/// let text = r#"{
+///     // The level of the logs.
+///     "level": "info",
+///     "targets": ["console"] /* more targets? */
+/// }"#;
+/// let mut document = Document::parse(text).unwrap();
+/// document.set("/level", &JsonValue::new_string("debug")).unwrap();
+/// document.set("/targets/-", &JsonValue::new_string("file")).unwrap();
+/// document.set("/rotate", &JsonValue::new_boolean(true)).unwrap();
+///
+/// assert_eq!(document.value()["level"], "debug".into());
+/// assert_eq!(
+///     document.as_str(),
+///     r#"{
+///     // The level of the logs.
+///     "level": "debug",
+///     "targets": ["console", "file"], /* more targets? */
+///     "rotate": true
+/// }"#
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Document {
+    text: String,
+    value: JsonValue,
+}
+
+/// A member of an Object or an element of an Array in the text.
+struct Member {
+    /// The start of the key, or of the value of an element.
+    start: usize,
+    /// The key with its quotes.
+    key: Option<Range<usize>>,
+    value: Range<usize>,
+    /// The position of the comma after the member.
+    comma: Option<usize>,
+}
+
+impl Document {
+    /// Parses a JSON text which may contain comments.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Document;
+    ///
+    /// let document = Document::parse("[1, /* two */ 2] // numbers").unwrap();
+    /// assert_eq!(document.value()[1], 2.into());
+    /// assert!(Document::parse("[1] /* unterminated").is_err());
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        Ok(Self {
+            value: parse_text(text)?,
+            text: String::from(text),
+        })
+    }
+
+    /// Gets the JsonValue of the text without the comments.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Document;
+    ///
+    /// let document = Document::parse(r#"{"a": 1 // one
+    /// }"#).unwrap();
+    /// assert_eq!(document.value()["a"], 1.into());
+    /// ```
+    pub fn value(&self) -> &JsonValue {
+        &self.value
+    }
+
+    /// Gets the text, including the comments.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Document;
+    ///
+    /// let document = Document::parse("[1] // one").unwrap();
+    /// assert_eq!(document.as_str(), "[1] // one");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Converts the Document into its text.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Document;
+    ///
+    /// let document = Document::parse("[1] // one").unwrap();
+    /// assert_eq!(document.into_string(), "[1] // one");
+    /// ```
+    pub fn into_string(self) -> String {
+        self.text
+    }
+
+    /// Sets the value at the JSON Pointer (RFC 6901), such as "/a/b/0".
+    ///
+    /// The value is replaced if it exists. Otherwise, a missing member of an
+    /// Object is appended, and the index equal to the length of an Array, or
+    /// "-", appends an element. The empty pointer replaces the whole value.
+    ///
+    /// Returns `Error::IndexNotFound` if the parent of the value does not exist,
+    /// or is not an Object or Array, or the pointer is malformed.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Document, JsonValue};
+    ///
+    /// let mut document = Document::parse(r#"{"a": [1] /* a */}"#).unwrap();
+    /// document.set("/a/0", &JsonValue::from(2)).unwrap();
+    /// document.set("/b", &JsonValue::from(3)).unwrap();
+    /// assert_eq!(document.as_str(), r#"{"a": [2], "b": 3 /* a */}"#);
+    ///
+    /// assert!(document.set("/c/d", &JsonValue::from(4)).is_err());
+    /// ```
+    pub fn set(&mut self, pointer: &str, value: &JsonValue) -> Result<(), Error> {
+        let not_found = || Error::IndexNotFound(String::from(pointer));
+        let tokens = pointer_tokens(pointer).ok_or_else(not_found)?;
+        let encoded = value.to_compact_string()?;
+        let bytes = self.text.as_bytes();
+
+        let Some((last, parents)) = tokens.split_last() else {
+            let root = self.find(&[]).ok_or_else(not_found)?;
+            return self.edit(&[(root, encoded)]);
+        };
+        let parent = self.find(parents).ok_or_else(not_found)?;
+        let (members, _) = container(bytes, parent.start).ok_or_else(not_found)?;
+        if let Some(i) = find_member(bytes, &members, last) {
+            return self.edit(&[(members[i].value.clone(), encoded)]);
+        }
+
+        let member = if bytes[parent.start] == b'{' {
+            let key = JsonValue::new_string(last).to_compact_string()?;
+            format!("{key}: {encoded}")
+        } else if pointer_index(last)
+            .ok_or_else(not_found)?
+            .unwrap_or(members.len())
+            == members.len()
+        {
+            encoded
+        } else {
+            return Err(not_found());
+        };
+        let edits = append_edits(bytes, &members, parent.start, member);
+        self.edit(&edits)
+    }
+
+    /// Removes the value at the JSON Pointer (RFC 6901), and returns it.
+    ///
+    /// The comments around the member are kept, and the line of the member is
+    /// removed if nothing else is left on it. Returns None if the value does not
+    /// exist, or the pointer is empty or malformed.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Document;
+    ///
+    /// let mut document = Document::parse(r#"{
+    ///     "a": 1, // one
+    ///     "b": 2
+    /// }"#).unwrap();
+    /// assert_eq!(document.remove("/b").unwrap(), 2.into());
+    /// assert_eq!(document.as_str(), r#"{
+    ///     "a": 1 // one
+    /// }"#);
+    /// assert!(document.remove("/b").is_none());
+    /// ```
+    pub fn remove(&mut self, pointer: &str) -> Option<JsonValue> {
+        let tokens = pointer_tokens(pointer)?;
+        let (last, parents) = tokens.split_last()?;
+        let parent = self.find(parents)?;
+        let (members, _) = container(self.text.as_bytes(), parent.start)?;
+        let i = find_member(self.text.as_bytes(), &members, last)?;
+        let removed = parse_text(&self.text[members[i].value.clone()]).ok()?;
+
+        // The comma after the member is removed, or the one before it if it is the last.
+        // They are removed together with the spaces between the members on the same line.
+        let bytes = self.text.as_bytes();
+        let inline = |range: Range<usize>| {
+            bytes[range]
+                .iter()
+                .all(|b| matches!(b, b' ' | b'\t' | b','))
+        };
+        let member = members[i].start..members[i].value.end;
+        let (range, next) = match (members[i].comma, i.checked_sub(1)) {
+            (Some(_), _) if inline(member.end..members[i + 1].start) => {
+                (member.start..members[i + 1].start, None)
+            }
+            (Some(comma), _) => (member, Some(comma..comma + 1)),
+            (None, Some(prev)) if inline(members[prev].value.end..member.start) => {
+                (members[prev].value.end..member.end, None)
+            }
+            (None, Some(prev)) => {
+                let comma = members[prev].comma?;
+                (comma..comma + 1, Some(member))
+            }
+            (None, None) => (member, None),
+        };
+        let mut text = self.text.clone();
+        // The later range is removed first, so that the position of the other one is kept.
+        for range in next.into_iter().chain(Some(range)) {
+            let range = extend_to_line(text.as_bytes(), range);
+            text.replace_range(range, "");
+        }
+        self.reparse(text).ok()?;
+        Some(removed)
+    }
+
+    /// Modifies the value by `f`, and writes back only the modified members to the text,
+    /// the same as calling `Document::set` and `Document::remove` for each of them. The
+    /// rest of the text, including the comments, is kept.
+    ///
+    /// Members of Objects are compared by keys, and elements of Arrays by indexes, so an
+    /// element inserted in the middle of an Array rewrites the elements after it. The
+    /// order of the existing members in the text is kept. If an error occurs, the
+    /// Document is not changed.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Document, JsonValue};
+    ///
+    /// let mut document = Document::parse(r#"{
+    ///     "name": "a", // the name
+    ///     "ports": [80, 443],
+    ///     "debug": true
+    /// }"#).unwrap();
+    /// document
+    ///     .update(|value| {
+    ///         value["ports"][1] = JsonValue::from(8443);
+    ///         value.remove("debug");
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(document.as_str(), r#"{
+    ///     "name": "a", // the name
+    ///     "ports": [80, 8443]
+    /// }"#);
+    /// ```
+    pub fn update<F: FnOnce(&mut JsonValue)>(&mut self, f: F) -> Result<(), Error> {
+        let mut value = self.value.clone();
+        f(&mut value);
+        let mut edits = Vec::new();
+        diff(&self.value, &value, &mut JsonPointer::new(), &mut edits);
+        if edits.is_empty() {
+            return Ok(());
+        }
+
+        let mut document = self.clone();
+        for edit in edits {
+            match edit {
+                Edit::Set(pointer, value) => document.set(&pointer, value)?,
+                Edit::Remove(pointer) => {
+                    document
+                        .remove(&pointer)
+                        .ok_or(Error::IndexNotFound(pointer))?;
+                }
+            }
+        }
+        *self = document;
+        Ok(())
+    }
+
+    /// Gets the original text of the value at the JSON Pointer (RFC 6901), without the
+    /// whitespace and comments around it. Returns None if the value does not exist.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Document;
+    ///
+    /// let document = Document::parse(r#"{"a": [1.50, "\u0041"] /* a */}"#).unwrap();
+    /// assert_eq!(document.raw("/a/0"), Some("1.50"));
+    /// assert_eq!(document.raw("/a/1"), Some(r#""\u0041""#));
+    /// assert_eq!(document.raw("/b"), None);
+    /// ```
+    pub fn raw(&self, pointer: &str) -> Option<&str> {
+        self.span(pointer).map(|span| &self.text[span])
+    }
+
+    /// Gets the range in bytes of the original text of the value at the JSON Pointer
+    /// (RFC 6901), see `Document::raw`. Returns None if the value does not exist.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Document;
+    ///
+    /// let document = Document::parse("// list\n[1, [2]]").unwrap();
+    /// assert_eq!(document.span(""), Some(8..16));
+    /// assert_eq!(document.span("/1/0"), Some(13..14));
+    /// ```
+    pub fn span(&self, pointer: &str) -> Option<Range<usize>> {
+        self.find(&pointer_tokens(pointer)?)
+    }
+
+    /// Finds the value referenced by the tokens in the text.
+    fn find(&self, tokens: &[Cow<str>]) -> Option<Range<usize>> {
+        find_value(self.text.as_bytes(), tokens)
+    }
+
+    /// Replaces the ranges of the text in order, and parses the result.
+    fn edit(&mut self, edits: &[(Range<usize>, String)]) -> Result<(), Error> {
+        let mut text = self.text.clone();
+        // The edits are applied backwards, so that the positions of the earlier ones are kept.
+        for (range, replacement) in edits.iter().rev() {
+            text.replace_range(range.clone(), replacement);
+        }
+        self.reparse(text)
+    }
+
+    fn reparse(&mut self, text: String) -> Result<(), Error> {
+        self.value = parse_text(&text)?;
+        self.text = text;
+        Ok(())
+    }
+}
+
+impl FromStr for Document {
+    type Err = Error;
+
+    /// Parses a JSON text which may contain comments, the same as `Document::parse`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::str::FromStr;
+    /// use ylong_json::Document;
+    ///
+    /// let document = Document::from_str("true // yes").unwrap();
+    /// assert_eq!(*document.value(), true.into());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Display for Document {
+    /// Writes the text, including the comments.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Document;
+    ///
+    /// let document = Document::parse("[1] // one").unwrap();
+    /// assert_eq!(document.to_string(), "[1] // one");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// An edit of the text found by `diff`.
+enum Edit<'a> {
+    Set(String, &'a JsonValue),
+    Remove(String),
+}
+
+/// Finds the edits which change the `old` value at the pointer to the `new` one.
+fn diff<'a>(
+    old: &JsonValue,
+    new: &'a JsonValue,
+    pointer: &mut JsonPointer,
+    edits: &mut Vec<Edit<'a>>,
+) {
+    if old.eq_ordered(new) {
+        return;
+    }
+    match (old, new) {
+        (JsonValue::Object(old), JsonValue::Object(new)) => {
+            for (key, _) in old.iter() {
+                if !new.contains_key(key) {
+                    pointer.push(key.as_str());
+                    edits.push(Edit::Remove(pointer.to_string()));
+                    pointer.pop();
+                }
+            }
+            for (key, value) in new.iter() {
+                pointer.push(key.as_str());
+                match old.get(key) {
+                    Some(old) => diff(old, value, pointer, edits),
+                    None => edits.push(Edit::Set(pointer.to_string(), value)),
+                }
+                pointer.pop();
+            }
+        }
+        (JsonValue::Array(old), JsonValue::Array(new)) => {
+            let mut olds = old.iter();
+            for (i, value) in new.iter().enumerate() {
+                pointer.push_index(i);
+                match olds.next() {
+                    Some(old) => diff(old, value, pointer, edits),
+                    None => edits.push(Edit::Set(pointer.to_string(), value)),
+                }
+                pointer.pop();
+            }
+            // The extra elements are removed from the end, so that the indexes are kept.
+            for i in (new.len()..old.len()).rev() {
+                pointer.push_index(i);
+                edits.push(Edit::Remove(pointer.to_string()));
+                pointer.pop();
+            }
+        }
+        _ => edits.push(Edit::Set(pointer.to_string(), new)),
+    }
+}
+
+/// Parses the text after replacing its comments with spaces, which keeps the
+/// positions of errors. An unterminated comment is left to fail the parsing.
+fn parse_text(text: &str) -> Result<JsonValue, Error> {
+    let mut bytes = Vec::from(text.as_bytes());
+    let mut pos = 0;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'"' => pos = string_end(&bytes, pos).unwrap_or(bytes.len()),
+            b'/' => {
+                let end = comment_end(&bytes, pos);
+                for byte in bytes[pos..end].iter_mut().filter(|b| **b != b'\n') {
+                    *byte = b' ';
+                }
+                pos = end.max(pos + 1);
+            }
+            _ => pos += 1,
+        }
+    }
+    JsonValue::from_text(bytes)
+}
+
+/// Splits the JSON Pointer into unescaped reference tokens.
+pub(crate) fn pointer_tokens(pointer: &str) -> Option<Vec<Cow<'_, str>>> {
+    if pointer.is_empty() {
+        return Some(Vec::new());
+    }
+    pointer
+        .strip_prefix('/')?
+        .split('/')
+        .map(unescape_pointer_token)
+        .collect()
+}
+
+/// Finds the range of the value referenced by the tokens in the text, which may
+/// contain comments. Only the Objects and Arrays on the path are checked, and
+/// None is returned if they are not valid or the value does not exist.
+pub(crate) fn find_value(bytes: &[u8], tokens: &[Cow<str>]) -> Option<Range<usize>> {
+    let start = skip_trivia(bytes, 0);
+    let mut range = start..value_end(bytes, start)?;
+    for token in tokens {
+        let (members, _) = container(bytes, range.start)?;
+        let i = find_member(bytes, &members, token)?;
+        range = members[i].value.clone();
+    }
+    Some(range)
+}
+
+/// Finds the member referenced by the token. The last one is found for a
+/// duplicate key, which is the one kept by the value.
+fn find_member(bytes: &[u8], members: &[Member], token: &str) -> Option<usize> {
+    match members.first() {
+        Some(Member { key: Some(_), .. }) => members.iter().rposition(|member| {
+            let key = member.key.clone().unwrap();
+            let raw = &bytes[key.start + 1..key.end - 1];
+            if raw.contains(&b'\\') {
+                matches!(JsonValue::from_text(&bytes[key]), Ok(JsonValue::String(s)) if s == token)
+            } else {
+                raw == token.as_bytes()
+            }
+        }),
+        _ => pointer_index(token)?.filter(|i| *i < members.len()),
+    }
+}
+
+/// Gets the edits appending the member to the Object or Array which starts at `open`.
+fn append_edits(
+    bytes: &[u8],
+    members: &[Member],
+    open: usize,
+    member: String,
+) -> Vec<(Range<usize>, String)> {
+    let Some(last) = members.last() else {
+        return vec![(open + 1..open + 1, member)];
+    };
+    let end = last.value.end;
+    let Some(newline) = line_break(bytes, end) else {
+        return vec![(end..end, format!(", {member}"))];
+    };
+    let line = bytes[..last.start]
+        .iter()
+        .rposition(|b| *b == b'\n')
+        .map_or(0, |p| p + 1);
+    let indent_len = bytes[line..]
+        .iter()
+        .position(|b| !matches!(b, b' ' | b'\t'))
+        .unwrap_or(0);
+    // The indentation only contains ASCII characters.
+    let indent = String::from_utf8_lossy(&bytes[line..line + indent_len]);
+    let (at, eol) = match newline.checked_sub(1) {
+        Some(cr) if bytes[cr] == b'\r' => (cr, "\r\n"),
+        _ => (newline, "\n"),
+    };
+    vec![
+        (end..end, String::from(",")),
+        (at..at, format!("{eol}{indent}{member}")),
+    ]
+}
+
+/// Finds the line break after `pos` if there are only whitespace and comments before it.
+fn line_break(bytes: &[u8], mut pos: usize) -> Option<usize> {
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\n' => return Some(pos),
+            b' ' | b'\t' | b'\r' => pos += 1,
+            b'/' if comment_end(bytes, pos) > pos => pos = comment_end(bytes, pos),
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// Extends the range to its whole line if nothing else is left on the line, or
+/// over the spaces after it if it is the first on the line.
+fn extend_to_line(bytes: &[u8], range: Range<usize>) -> Range<usize> {
+    let start = bytes[..range.start]
+        .iter()
+        .rposition(|b| *b == b'\n')
+        .map_or(0, |p| p + 1);
+    if !bytes[start..range.start]
+        .iter()
+        .all(|b| matches!(b, b' ' | b'\t'))
+    {
+        return range;
+    }
+    let end = range.end
+        + bytes[range.end..]
+            .iter()
+            .position(|b| !matches!(b, b' ' | b'\t'))
+            .unwrap_or(bytes.len() - range.end);
+    match &bytes[end..] {
+        [b'\n', ..] => start..end + 1,
+        [b'\r', b'\n', ..] => start..end + 2,
+        _ => range.start..end,
+    }
+}
+
+/// Skips the whitespace and comments from `pos`.
+fn skip_trivia(bytes: &[u8], mut pos: usize) -> usize {
+    loop {
+        pos += whitespace_len(&bytes[pos..]);
+        match comment_end(bytes, pos) {
+            end if end > pos => pos = end,
+            _ => return pos,
+        }
+    }
+}
+
+/// Gets the end of the comment which starts at `pos`, which is the line break
+/// of a line comment. Returns `pos` if it is not the start of a terminated comment.
+fn comment_end(bytes: &[u8], pos: usize) -> usize {
+    let rest = &bytes[pos..];
+    if rest.starts_with(b"//") {
+        rest.iter()
+            .position(|b| *b == b'\n')
+            .map_or(bytes.len(), |p| pos + p)
+    } else if rest.starts_with(b"/*") {
+        rest[2..]
+            .windows(2)
+            .position(|w| w == b"*/")
+            .map_or(pos, |p| pos + p + 4)
+    } else {
+        pos
+    }
+}
+
+/// Gets the end of the string which starts at `pos`, after the closing quote.
+/// Returns None if the string is not terminated.
+fn string_end(bytes: &[u8], pos: usize) -> Option<usize> {
+    skip_string(bytes, pos + 1).map(|close| close + 1)
+}
+
+/// Gets the end of the value which starts at `pos`. Returns None if the value
+/// is missing, or is an unterminated string or an invalid Object or Array. The
+/// contents of numbers and literals are not checked.
+fn value_end(bytes: &[u8], pos: usize) -> Option<usize> {
+    match *bytes.get(pos)? {
+        b'"' => string_end(bytes, pos),
+        b'{' | b'[' => container(bytes, pos).map(|(_, close)| close + 1),
+        b',' | b':' | b']' | b'}' | b'/' => None,
+        _ => Some(
+            bytes[pos..]
+                .iter()
+                .position(|b| matches!(b, b' ' | b'\t' | b'\r' | b'\n' | b',' | b']' | b'}' | b'/'))
+                .map_or(bytes.len(), |p| pos + p),
+        ),
+    }
+}
+
+/// Gets the members of the Object or Array which starts at `pos`, and the
+/// position of its closing bracket. Returns None if it is not an Object or an
+/// Array, or if its keys, colons, commas or brackets are not valid.
+fn container(bytes: &[u8], pos: usize) -> Option<(Vec<Member>, usize)> {
+    let (is_object, close) = match bytes[pos] {
+        b'{' => (true, b'}'),
+        b'[' => (false, b']'),
+        _ => return None,
+    };
+    let mut members = Vec::new();
+    let mut pos = skip_trivia(bytes, pos + 1);
+    while *bytes.get(pos)? != close {
+        let start = pos;
+        let key = match is_object {
+            true if bytes[start] == b'"' => {
+                let end = string_end(bytes, start)?;
+                pos = skip_trivia(bytes, end);
+                if *bytes.get(pos)? != b':' {
+                    return None;
+                }
+                pos = skip_trivia(bytes, pos + 1);
+                Some(start..end)
+            }
+            true => return None,
+            false => None,
+        };
+        let end = value_end(bytes, pos)?;
+        let value = pos..end;
+        pos = skip_trivia(bytes, end);
+        let comma = match *bytes.get(pos)? {
+            b',' => {
+                let comma = pos;
+                pos = skip_trivia(bytes, comma + 1);
+                Some(comma)
+            }
+            b if b == close => None,
+            _ => return None,
+        };
+        members.push(Member {
+            start,
+            key,
+            value,
+            comma,
+        });
+    }
+    Some((members, pos))
+}
+
+#[cfg(test)]
+mod ut_document {
+    use crate::{Document, Error, JsonValue};
+
+    /// UT test for `Document::parse`.
+    ///
+    /// # Title
+    /// ut_document_parse
+    ///
+    /// # Brief
+    /// 1. Parses some texts with comments, including wrong ones.
+    /// 2. Checks if the values, the texts and the errors are correct.
+    #[test]
+    fn ut_document_parse() {
+        let text = "/* head */ {\"a/*\": \"//\", // line\n \"b\": [1 /* in */, 2]} // tail";
+        let document = Document::parse(text).unwrap();
+        assert_eq!(
+            *document.value(),
+            JsonValue::from_text(r#"{"a/*": "//", "b": [1, 2]}"#).unwrap()
+        );
+        assert_eq!(document.as_str(), text);
+        assert_eq!(document.to_string(), text);
+
+        assert!(Document::parse("[1] /* unterminated").is_err());
+        assert!(Document::parse("[1] / 2").is_err());
+        // The positions of errors are the same as in the original text.
+        let error = Document::parse("/* a\nb */ [1 2]").unwrap_err();
+        match error {
+            Error::Parsing(e) => assert_eq!(e.position().unwrap().line(), 2),
+            _ => panic!("unexpected error {error}"),
+        }
+    }
+
+    /// UT test for `Document::set`.
+    ///
+    /// # Title
+    /// ut_document_set
+    ///
+    /// # Brief
+    /// 1. Sets some values of a `Document` with comments.
+    /// 2. Checks if the edited texts keep the comments and the formatting.
+    #[test]
+    fn ut_document_set() {
+        let text = "{\r\n  // name\r\n  \"name\": \"a\", // trailing\r\n  \"list\": [\r\n    1\r\n  ],\r\n  \"empty\": {},\r\n  \"a~b\": null\r\n}";
+        let mut document = Document::parse(text).unwrap();
+        document.set("/name", &JsonValue::new_string("b")).unwrap();
+        document.set("/list/1", &JsonValue::from(2)).unwrap();
+        document.set("/list/0", &JsonValue::from(0)).unwrap();
+        document.set("/empty/x", &JsonValue::from(true)).unwrap();
+        document.set("/a~0b", &JsonValue::from(1)).unwrap();
+        document
+            .set("/new", &JsonValue::from_text("[1,{}]").unwrap())
+            .unwrap();
+        assert_eq!(
+            document.as_str(),
+            "{\r\n  // name\r\n  \"name\": \"b\", // trailing\r\n  \"list\": [\r\n    0,\r\n    2\r\n  ],\r\n  \"empty\": {\"x\": true},\r\n  \"a~b\": 1,\r\n  \"new\": [1,{}]\r\n}"
+        );
+        assert_eq!(document.value()["new"][0], 1.into());
+
+        assert!(document.set("/list/5", &JsonValue::Null).is_err());
+        assert!(document.set("/list/x", &JsonValue::Null).is_err());
+        assert!(document.set("/name/x", &JsonValue::Null).is_err());
+        assert!(document.set("/x/y", &JsonValue::Null).is_err());
+        assert!(document.set("x", &JsonValue::Null).is_err());
+
+        let mut document = Document::parse("// root\n1").unwrap();
+        document.set("", &JsonValue::new_string("\"")).unwrap();
+        assert_eq!(document.as_str(), "// root\n\"\\\"\"");
+    }
+
+    /// UT test for `Document::remove`.
+    ///
+    /// # Title
+    /// ut_document_remove
+    ///
+    /// # Brief
+    /// 1. Removes some values of a `Document` with comments.
+    /// 2. Checks if the edited texts keep the comments and the formatting.
+    #[test]
+    fn ut_document_remove() {
+        let text = r#"{
+    "a": 1, // a
+    /* b */ "b": [1, 2, 3],
+    "c": {"d": 4}
+}"#;
+        let mut document = Document::parse(text).unwrap();
+        assert_eq!(document.remove("/b/1").unwrap(), 2.into());
+        assert_eq!(document.remove("/b/1").unwrap(), 3.into());
+        assert_eq!(document.remove("/c/d").unwrap(), 4.into());
+        assert_eq!(document.remove("/a").unwrap(), 1.into());
+        assert_eq!(
+            document.as_str(),
+            r#"{
+    // a
+    /* b */ "b": [1],
+    "c": {}
+}"#
+        );
+        assert_eq!(
+            document.remove("/c").unwrap(),
+            JsonValue::Object(Default::default())
+        );
+        assert_eq!(
+            document.remove("/b").unwrap(),
+            JsonValue::from_text("[1]").unwrap()
+        );
+        assert_eq!(document.as_str(), "{\n    // a\n    /* b */ \n}");
+
+        assert!(document.remove("/b").is_none());
+        assert!(document.remove("").is_none());
+        assert!(document.remove("b").is_none());
+    }
+
+    /// UT test for `Document::update`.
+    ///
+    /// # Title
+    /// ut_document_update
+    ///
+    /// # Brief
+    /// 1. Updates the value of a `Document` with comments in several ways.
+    /// 2. Checks if only the modified members are written back.
+    /// 3. Checks if a failed update does not change the `Document`.
+    #[test]
+    fn ut_document_update() {
+        let text = r#"{
+    "a/b": {"c~": 1, "d": 2}, // keys with escapes
+    "list": [1, 2, 3], /* list */
+    "keep": [ 1.50 ],
+    "type": null
+}"#;
+        let mut document = Document::parse(text).unwrap();
+        document.update(|_| {}).unwrap();
+        assert_eq!(document.as_str(), text);
+
+        document
+            .update(|value| {
+                value["a/b"]["c~"] = JsonValue::from(10);
+                value["a/b"].remove("d");
+                value["list"].remove(2);
+                value["list"].remove(0);
+                value["type"] = JsonValue::from_text(r#"{"x": []}"#).unwrap();
+                value["new"] = JsonValue::from(true);
+            })
+            .unwrap();
+        assert_eq!(
+            document.as_str(),
+            r#"{
+    "a/b": {"c~": 10}, // keys with escapes
+    "list": [2], /* list */
+    "keep": [ 1.50 ],
+    "type": {"x":[]},
+    "new": true
+}"#
+        );
+
+        document
+            .update(|value| {
+                value["list"] = JsonValue::from_text("[2, 3, 4]").unwrap();
+            })
+            .unwrap();
+        assert_eq!(document.raw("/list"), Some("[2, 3, 4]"));
+
+        let before = document.clone();
+        let result = document.update(|value| {
+            value["new"] = JsonValue::from(false);
+            value["type"] = JsonValue::from(f64::NAN);
+        });
+        assert!(result.is_err());
+        assert_eq!(document.as_str(), before.as_str());
+        assert_eq!(document.value(), before.value());
+    }
+
+    /// UT test for `Document::raw` and `Document::span`.
+    ///
+    /// # Title
+    /// ut_document_raw
+    ///
+    /// # Brief
+    /// 1. Gets the original texts of some values of a `Document`.
+    /// 2. Checks if the texts and ranges are correct.
+    #[test]
+    fn ut_document_raw() {
+        let text = "/* c */ {\"a\": 1e2, \"b\" : [true, \"x\\ty\"] // b\n}";
+        let document = Document::parse(text).unwrap();
+        assert_eq!(document.raw(""), Some(&text[8..]));
+        assert_eq!(document.raw("/a"), Some("1e2"));
+        assert_eq!(document.raw("/b"), Some("[true, \"x\\ty\"]"));
+        assert_eq!(document.raw("/b/1"), Some("\"x\\ty\""));
+        assert_eq!(document.span("/b/0"), Some(26..30));
+        assert_eq!(document.raw("/b/2"), None);
+        assert_eq!(document.raw("/a/0"), None);
+        assert_eq!(document.raw("a"), None);
+    }
+}
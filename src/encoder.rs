@@ -11,10 +11,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{consts::*, Array, Error, JsonValue, Number, Object};
-#[cfg(feature = "c_adapter")]
-use std::ffi::CString;
-use std::io::Write;
+use crate::writer::Write;
+use crate::{consts::*, Array, EncodeConfig, Error, JsonValue, NonFiniteFloat, Number, Object};
+use alloc::vec::Vec;
 
 // todo: Considers extracting Encoder traits.
 
@@ -25,12 +24,39 @@ pub(crate) struct FormattedEncoder<'a, W: Write> {
     output: &'a mut W,
     /// The current number of nested layers
     tab: usize,
+    /// The maximum width of the lines on which arrays and objects are kept. If it is 0,
+    /// only arrays without nested arrays or objects are kept on one line.
+    max_width: usize,
+    /// The column where the value being encoded starts, counted in bytes.
+    column: usize,
+    /// The current number of nested arrays and objects.
+    depth: u32,
+    config: EncodeConfig,
 }
 
 impl<'a, W: Write> FormattedEncoder<'a, W> {
     /// Creates
     pub(crate) fn new(output: &'a mut W) -> Self {
-        Self { output, tab: 0 }
+        Self {
+            output,
+            tab: 0,
+            max_width: 0,
+            column: 0,
+            depth: 0,
+            config: EncodeConfig::default(),
+        }
+    }
+
+    /// Keeps arrays and objects on one line if they fit within `max_width` columns.
+    pub(crate) fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the options of encoding.
+    pub(crate) fn with_config(mut self, config: EncodeConfig) -> Self {
+        self.config = config;
+        self
     }
 
     /// Encodes
@@ -60,6 +86,15 @@ impl<'a, W: Write> FormattedEncoder<'a, W> {
         Ok(())
     }
 
+    /// Enters an array or object, checking the depth limit.
+    fn enter(&mut self) -> Result<(), Error> {
+        if self.depth >= self.config.max_depth {
+            return Err(Error::ExceedRecursionLimit);
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
     /// Encodes Null
     fn encode_null(&mut self) -> Result<(), Error> {
         encode_null(self.output)
@@ -72,39 +107,39 @@ impl<'a, W: Write> FormattedEncoder<'a, W> {
 
     /// Encodes Number
     fn encode_number(&mut self, number: &Number) -> Result<(), Error> {
-        encode_number(self.output, number)
+        encode_number(self.output, number, self.config.non_finite_float)
     }
 
     /// Encodes Key
     fn encode_key(&mut self, key: &str) -> Result<(), Error> {
-        encode_string(self.output, key)
+        encode_string(self.output, key, &self.config)
     }
 
     /// Encodes String
-    #[cfg(feature = "c_adapter")]
-    fn encode_string(&mut self, string: &CString) -> Result<(), Error> {
-        encode_string(self.output, unsafe {
-            core::str::from_utf8_unchecked(string.as_bytes())
-        })
-    }
-
-    /// Encodes String
-    #[cfg(not(feature = "c_adapter"))]
     fn encode_string(&mut self, string: &str) -> Result<(), Error> {
-        encode_string(self.output, string)
+        encode_string(self.output, string, &self.config)
     }
 
     /// Encodes Array
     fn encode_array(&mut self, array: &Array) -> Result<(), Error> {
+        self.enter()?;
         // Check whether multiple lines are required. If array or object
-        // exists in the array value, multiple lines are required.
-        let mut multiple_line = false;
-        for v in array.iter() {
-            if v.is_array() | v.is_object() {
-                multiple_line = true;
-                break;
+        // exists in the array value, multiple lines are required, unless
+        // the whole array fits within `max_width`.
+        let multiple_line = if self.max_width == 0 {
+            array.iter().any(|v| v.is_array() | v.is_object())
+        } else if array.is_empty() {
+            false
+        } else {
+            let limit = self.max_width.saturating_sub(self.column);
+            // The depth of this array is counted already.
+            let depth = self.config.max_depth - self.depth + 1;
+            if array_width(array, limit, depth, &self.config).is_some() {
+                self.depth -= 1;
+                return encode_array_inline(self.output, array, &self.config);
             }
-        }
+            true
+        };
 
         self.output.write_all(LEFT_SQUARE_BRACKET_STR)?;
         if multiple_line {
@@ -117,6 +152,7 @@ impl<'a, W: Write> FormattedEncoder<'a, W> {
                     self.output.write_all(LINE_FEED_STR)?;
                     self.add_tab()?;
                 }
+                self.column = self.tab * FOUR_SPACES_STR.len();
                 self.encode_value(v)?;
             }
             self.output.write_all(LINE_FEED_STR)?;
@@ -132,11 +168,23 @@ impl<'a, W: Write> FormattedEncoder<'a, W> {
             }
         }
         self.output.write_all(RIGHT_SQUARE_BRACKET_STR)?;
+        self.depth -= 1;
         Ok(())
     }
 
     /// Encodes Object
     fn encode_object(&mut self, object: &Object) -> Result<(), Error> {
+        self.enter()?;
+        if self.max_width != 0 && !object.is_empty() {
+            let limit = self.max_width.saturating_sub(self.column);
+            // The depth of this object is counted already.
+            let depth = self.config.max_depth - self.depth + 1;
+            if object_width(object, limit, depth, &self.config).is_some() {
+                self.depth -= 1;
+                return encode_object_inline(self.output, object, &self.config);
+            }
+        }
+
         self.output.write_all(LEFT_CURLY_BRACKET_STR)?;
         self.tab += 1;
         for (u, (k, v)) in object.iter().enumerate() {
@@ -148,6 +196,11 @@ impl<'a, W: Write> FormattedEncoder<'a, W> {
             self.encode_key(k)?;
             self.output.write_all(COLON_STR)?;
             self.output.write_all(SPACE_STR)?;
+            if self.max_width != 0 {
+                let key = string_width(k, usize::MAX, &self.config).unwrap_or_default();
+                self.column =
+                    self.tab * FOUR_SPACES_STR.len() + key + COLON_STR.len() + SPACE_STR.len();
+            }
             self.encode_value(v)?;
         }
         self.tab -= 1;
@@ -157,6 +210,7 @@ impl<'a, W: Write> FormattedEncoder<'a, W> {
             self.add_tab()?;
         }
         self.output.write_all(RIGHT_CURLY_BRACKET_STR)?;
+        self.depth -= 1;
         Ok(())
     }
 }
@@ -165,12 +219,25 @@ impl<'a, W: Write> FormattedEncoder<'a, W> {
 /// used to output a JsonValue instance in JSON format to a specified location.
 pub(crate) struct CompactEncoder<'a, W: Write> {
     output: &'a mut W,
+    /// The current number of nested arrays and objects.
+    depth: u32,
+    config: EncodeConfig,
 }
 
 impl<'a, W: Write> CompactEncoder<'a, W> {
     /// Creates
     pub(crate) fn new(output: &'a mut W) -> Self {
-        Self { output }
+        Self {
+            output,
+            depth: 0,
+            config: EncodeConfig::default(),
+        }
+    }
+
+    /// Sets the options of encoding.
+    pub(crate) fn with_config(mut self, config: EncodeConfig) -> Self {
+        self.config = config;
+        self
     }
 
     /// Encodes
@@ -190,6 +257,15 @@ impl<'a, W: Write> CompactEncoder<'a, W> {
         }
     }
 
+    /// Enters an array or object, checking the depth limit.
+    fn enter(&mut self) -> Result<(), Error> {
+        if self.depth >= self.config.max_depth {
+            return Err(Error::ExceedRecursionLimit);
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
     /// Encodes Null
     fn encode_null(&mut self) -> Result<(), Error> {
         encode_null(self.output)
@@ -202,30 +278,22 @@ impl<'a, W: Write> CompactEncoder<'a, W> {
 
     /// Encodes Number
     fn encode_number(&mut self, number: &Number) -> Result<(), Error> {
-        encode_number(self.output, number)
+        encode_number(self.output, number, self.config.non_finite_float)
     }
 
     /// Encodes Key
     fn encode_key(&mut self, key: &str) -> Result<(), Error> {
-        encode_string(self.output, key)
+        encode_string(self.output, key, &self.config)
     }
 
     /// Encodes String
-    #[cfg(feature = "c_adapter")]
-    fn encode_string(&mut self, string: &CString) -> Result<(), Error> {
-        encode_string(self.output, unsafe {
-            std::str::from_utf8_unchecked(string.as_bytes())
-        })
-    }
-
-    /// Encodes String
-    #[cfg(not(feature = "c_adapter"))]
     fn encode_string(&mut self, string: &str) -> Result<(), Error> {
-        encode_string(self.output, string)
+        encode_string(self.output, string, &self.config)
     }
 
     /// Encodes Array
     fn encode_array(&mut self, array: &Array) -> Result<(), Error> {
+        self.enter()?;
         self.output.write_all(LEFT_SQUARE_BRACKET_STR)?;
         for (n, v) in array.iter().enumerate() {
             if n != 0 {
@@ -234,11 +302,13 @@ impl<'a, W: Write> CompactEncoder<'a, W> {
             self.encode_value(v)?;
         }
         self.output.write_all(RIGHT_SQUARE_BRACKET_STR)?;
+        self.depth -= 1;
         Ok(())
     }
 
     /// Encodes Object
     fn encode_object(&mut self, object: &Object) -> Result<(), Error> {
+        self.enter()?;
         self.output.write_all(LEFT_CURLY_BRACKET_STR)?;
         for (u, (k, v)) in object.iter().enumerate() {
             if u != 0 {
@@ -249,10 +319,230 @@ impl<'a, W: Write> CompactEncoder<'a, W> {
             self.encode_value(v)?;
         }
         self.output.write_all(RIGHT_CURLY_BRACKET_STR)?;
+        self.depth -= 1;
         Ok(())
     }
 }
 
+/// JSON encoder that elides long strings and large collections, used to output
+/// bounded summaries of JsonValue instances, such as for logging.
+///
+/// The output looks like compact JSON with spaces after separators, but it is not
+/// valid JSON if anything is elided, such as `[1, 2, … 998 more]`.
+pub(crate) struct SummaryEncoder<'a, W: Write> {
+    output: &'a mut W,
+    /// The maximum number of characters kept in a string or a key.
+    max_len: usize,
+    /// The maximum number of elements or members kept in a collection.
+    max_items: usize,
+}
+
+impl<'a, W: Write> SummaryEncoder<'a, W> {
+    /// Creates
+    pub(crate) fn new(output: &'a mut W, max_len: usize, max_items: usize) -> Self {
+        Self {
+            output,
+            max_len,
+            max_items,
+        }
+    }
+
+    /// Encodes
+    pub(crate) fn encode(&mut self, value: &JsonValue) -> Result<(), Error> {
+        self.encode_value(value)
+    }
+
+    /// Encodes JsonValue
+    fn encode_value(&mut self, value: &JsonValue) -> Result<(), Error> {
+        match value {
+            JsonValue::Null => encode_null(self.output),
+            JsonValue::Boolean(boolean) => encode_boolean(self.output, *boolean),
+            // Summaries are not valid JSON anyway, so non-finite floats are kept readable.
+            JsonValue::Number(number) => {
+                encode_number(self.output, number, NonFiniteFloat::Literal)
+            }
+            JsonValue::String(string) => self.encode_string(string),
+            JsonValue::Array(array) => self.encode_array(array),
+            JsonValue::Object(object) => self.encode_object(object),
+        }
+    }
+
+    /// Encodes String, keeping at most `max_len` characters.
+    fn encode_string(&mut self, string: &str) -> Result<(), Error> {
+        let (kept, elided) = match string.char_indices().nth(self.max_len) {
+            Some((index, _)) => (&string[..index], true),
+            None => (string, false),
+        };
+        self.output.write_all(QUOTATION_MARK_STR)?;
+        encode_string_inner(self.output, kept)?;
+        if elided {
+            self.output.write_all(ELLIPSIS_STR)?;
+        }
+        self.output.write_all(QUOTATION_MARK_STR)?;
+        Ok(())
+    }
+
+    /// Encodes the number of elided items of a collection.
+    fn encode_elided(&mut self, len: usize) -> Result<(), Error> {
+        if len > self.max_items {
+            if self.max_items != 0 {
+                self.output.write_all(COMMA_STR)?;
+                self.output.write_all(SPACE_STR)?;
+            }
+            self.output.write_all(ELLIPSIS_STR)?;
+            write!(self.output, " {} more", len - self.max_items)?;
+        }
+        Ok(())
+    }
+
+    /// Encodes Array, keeping at most `max_items` elements.
+    fn encode_array(&mut self, array: &Array) -> Result<(), Error> {
+        self.output.write_all(LEFT_SQUARE_BRACKET_STR)?;
+        for (n, v) in array.iter().take(self.max_items).enumerate() {
+            if n != 0 {
+                self.output.write_all(COMMA_STR)?;
+                self.output.write_all(SPACE_STR)?;
+            }
+            self.encode_value(v)?;
+        }
+        self.encode_elided(array.len())?;
+        self.output.write_all(RIGHT_SQUARE_BRACKET_STR)?;
+        Ok(())
+    }
+
+    /// Encodes Object, keeping at most `max_items` members.
+    fn encode_object(&mut self, object: &Object) -> Result<(), Error> {
+        self.output.write_all(LEFT_CURLY_BRACKET_STR)?;
+        for (u, (k, v)) in object.iter().take(self.max_items).enumerate() {
+            if u != 0 {
+                self.output.write_all(COMMA_STR)?;
+                self.output.write_all(SPACE_STR)?;
+            }
+            self.encode_string(k)?;
+            self.output.write_all(COLON_STR)?;
+            self.output.write_all(SPACE_STR)?;
+            self.encode_value(v)?;
+        }
+        self.encode_elided(object.len())?;
+        self.output.write_all(RIGHT_CURLY_BRACKET_STR)?;
+        Ok(())
+    }
+}
+
+// Gets the width of a value on one line with spaces after separators, or `None`
+// if it is wider than `limit`, or has more than `depth` nested arrays and objects.
+fn value_width(
+    value: &JsonValue,
+    limit: usize,
+    depth: u32,
+    config: &EncodeConfig,
+) -> Option<usize> {
+    let width = match value {
+        JsonValue::Null => NULL_STR.len(),
+        JsonValue::Boolean(true) => TRUE_STR.len(),
+        JsonValue::Boolean(false) => FALSE_STR.len(),
+        JsonValue::Number(number) => {
+            let mut vec = Vec::new();
+            encode_number(&mut vec, number, config.non_finite_float).ok()?;
+            vec.len()
+        }
+        JsonValue::String(string) => return string_width(string, limit, config),
+        JsonValue::Array(array) => return array_width(array, limit, depth, config),
+        JsonValue::Object(object) => return object_width(object, limit, depth, config),
+    };
+    (width <= limit).then_some(width)
+}
+
+fn string_width(string: &str, limit: usize, config: &EncodeConfig) -> Option<usize> {
+    // Escaping never makes a string shorter, so long strings are not encoded.
+    if string.len().saturating_add(2) > limit {
+        return None;
+    }
+    let mut vec = Vec::new();
+    encode_string(&mut vec, string, config).ok()?;
+    (vec.len() <= limit).then_some(vec.len())
+}
+
+fn array_width(array: &Array, limit: usize, depth: u32, config: &EncodeConfig) -> Option<usize> {
+    let depth = depth.checked_sub(1)?;
+    // The brackets, and ", " between elements.
+    let mut width = 2;
+    for (n, v) in array.iter().enumerate() {
+        if n != 0 {
+            width += 2;
+        }
+        width += value_width(v, limit.checked_sub(width)?, depth, config)?;
+    }
+    (width <= limit).then_some(width)
+}
+
+fn object_width(object: &Object, limit: usize, depth: u32, config: &EncodeConfig) -> Option<usize> {
+    let depth = depth.checked_sub(1)?;
+    // The brackets, ", " between members and ": " after keys.
+    let mut width = 2;
+    for (u, (k, v)) in object.iter().enumerate() {
+        if u != 0 {
+            width += 2;
+        }
+        width += string_width(k, limit.checked_sub(width)?, config)? + 2;
+        width += value_width(v, limit.checked_sub(width)?, depth, config)?;
+    }
+    (width <= limit).then_some(width)
+}
+
+// Encodes a value on one line with spaces after separators.
+fn encode_value_inline(
+    writer: &mut dyn Write,
+    value: &JsonValue,
+    config: &EncodeConfig,
+) -> Result<(), Error> {
+    match value {
+        JsonValue::Null => encode_null(writer),
+        JsonValue::Boolean(boolean) => encode_boolean(writer, *boolean),
+        JsonValue::Number(number) => encode_number(writer, number, config.non_finite_float),
+        JsonValue::String(string) => encode_string(writer, string, config),
+        JsonValue::Array(array) => encode_array_inline(writer, array, config),
+        JsonValue::Object(object) => encode_object_inline(writer, object, config),
+    }
+}
+
+fn encode_array_inline(
+    writer: &mut dyn Write,
+    array: &Array,
+    config: &EncodeConfig,
+) -> Result<(), Error> {
+    writer.write_all(LEFT_SQUARE_BRACKET_STR)?;
+    for (n, v) in array.iter().enumerate() {
+        if n != 0 {
+            writer.write_all(COMMA_STR)?;
+            writer.write_all(SPACE_STR)?;
+        }
+        encode_value_inline(writer, v, config)?;
+    }
+    writer.write_all(RIGHT_SQUARE_BRACKET_STR)?;
+    Ok(())
+}
+
+fn encode_object_inline(
+    writer: &mut dyn Write,
+    object: &Object,
+    config: &EncodeConfig,
+) -> Result<(), Error> {
+    writer.write_all(LEFT_CURLY_BRACKET_STR)?;
+    for (u, (k, v)) in object.iter().enumerate() {
+        if u != 0 {
+            writer.write_all(COMMA_STR)?;
+            writer.write_all(SPACE_STR)?;
+        }
+        encode_string(writer, k, config)?;
+        writer.write_all(COLON_STR)?;
+        writer.write_all(SPACE_STR)?;
+        encode_value_inline(writer, v, config)?;
+    }
+    writer.write_all(RIGHT_CURLY_BRACKET_STR)?;
+    Ok(())
+}
+
 #[inline]
 fn encode_null(writer: &mut dyn Write) -> Result<(), Error> {
     writer.write_all(NULL_STR)?;
@@ -270,19 +560,70 @@ fn encode_boolean(writer: &mut dyn Write, boolean: bool) -> Result<(), Error> {
 }
 
 #[inline]
-pub(crate) fn encode_number(writer: &mut dyn Write, number: &Number) -> Result<(), Error> {
-    write!(writer, "{number}")?;
+fn encode_number(
+    writer: &mut dyn Write,
+    number: &Number,
+    non_finite: NonFiniteFloat,
+) -> Result<(), Error> {
+    match number {
+        Number::Float(f) => encode_float(writer, *f, non_finite),
+        number => {
+            write!(writer, "{number}")?;
+            Ok(())
+        }
+    }
+}
+
+/// Encodes a float, where NaN and infinite ones are handled by the policy.
+pub(crate) fn encode_float(
+    writer: &mut dyn Write,
+    float: f64,
+    non_finite: NonFiniteFloat,
+) -> Result<(), Error> {
+    if float.is_finite() {
+        write!(writer, "{float:?}")?;
+        return Ok(());
+    }
+    match non_finite {
+        NonFiniteFloat::Error => return Err(Error::NonFiniteFloat),
+        NonFiniteFloat::Null => writer.write_all(NULL_STR)?,
+        NonFiniteFloat::Literal if float.is_nan() => writer.write_all(NAN_STR)?,
+        NonFiniteFloat::Literal if float > 0.0 => writer.write_all(INFINITY_STR)?,
+        NonFiniteFloat::Literal => writer.write_all(NEG_INFINITY_STR)?,
+    }
     Ok(())
 }
 
 #[inline]
-fn encode_string(writer: &mut dyn Write, string: &str) -> Result<(), Error> {
+fn encode_string(writer: &mut dyn Write, string: &str, config: &EncodeConfig) -> Result<(), Error> {
     writer.write_all(QUOTATION_MARK_STR)?;
-    encode_string_inner(writer, string)?;
+    match &config.escape_table {
+        Some(table) => encode_string_with_table(writer, string, table)?,
+        None => encode_string_inner(writer, string)?,
+    }
     writer.write_all(QUOTATION_MARK_STR)?;
     Ok(())
 }
 
+/// Encodes a string, where the characters marked in the table are also escaped
+/// as `\uXXXX`, unless they are always escaped.
+fn encode_string_with_table(
+    writer: &mut dyn Write,
+    string: &str,
+    table: &[bool; 256],
+) -> Result<(), Error> {
+    let mut start = 0;
+    for (i, ch) in string.char_indices() {
+        let code = ch as usize;
+        if code < table.len() && table[code] && !(ch.is_ascii() && ESCAPE[code]) {
+            encode_string_inner(writer, &string[start..i])?;
+            write!(writer, "\\u{number:0>width$x}", number = code, width = 4)?;
+            start = i + ch.len_utf8();
+        }
+    }
+    encode_string_inner(writer, &string[start..])
+}
+
 #[cfg(feature = "ascii_only")]
 pub(crate) fn encode_string_inner(writer: &mut dyn Write, string: &str) -> Result<(), Error> {
     let bytes = string.as_bytes();
@@ -365,7 +706,11 @@ pub(crate) fn encode_string_inner(writer: &mut dyn Write, string: &str) -> Resul
 
 #[cfg(test)]
 mod ut_encoder {
-    use crate::{CompactEncoder, FormattedEncoder, JsonValue};
+    use crate::{
+        Array, CompactEncoder, EncodeConfig, Error, FormattedEncoder, JsonValue, NonFiniteFloat,
+        Object, SummaryEncoder,
+    };
+    #[cfg(feature = "std")]
     use std::io::Write;
 
     struct StringWriter {
@@ -380,6 +725,16 @@ mod ut_encoder {
         }
     }
 
+    #[cfg(not(feature = "std"))]
+    impl crate::writer::Write for StringWriter {
+        fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+            self.string
+                .push_str(unsafe { std::str::from_utf8_unchecked(buf) });
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "std")]
     impl Write for StringWriter {
         fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
             self.string
@@ -506,4 +861,241 @@ mod ut_encoder {
             "{\"object\":{\"key1\":1,\"key2\":2}}",
         );
     }
+
+    /// UT test for `SummaryEncoder`.
+    ///
+    /// # Title
+    /// ut_summary_encoder
+    ///
+    /// # Brief
+    /// 1. Creates some `JsonValue`s with long strings and large collections.
+    /// 2. Uses `SummaryEncoder` with different limits to encode them.
+    /// 3. Checks if the results are correct.
+    #[test]
+    fn ut_summary_encoder() {
+        fn summary(text: &str, max_len: usize, max_items: usize) -> String {
+            let value = JsonValue::from_text(text).unwrap();
+            let mut writer = StringWriter::new();
+            let mut encoder = SummaryEncoder::new(&mut writer, max_len, max_items);
+            assert!(encoder.encode(&value).is_ok());
+            writer.string
+        }
+
+        assert_eq!(summary("[1,2,3]", 8, 3), "[1, 2, 3]");
+        assert_eq!(summary("[1,2,3,4,5]", 8, 2), "[1, 2, … 3 more]");
+        assert_eq!(summary("[1,2,3]", 8, 0), "[… 3 more]");
+        assert_eq!(summary("[]", 8, 0), "[]");
+        assert_eq!(
+            summary(r#"{"a":"abcdef","b":[true,null],"c":{}}"#, 3, 2),
+            r#"{"a": "abc…", "b": [true, null], … 1 more}"#
+        );
+        #[cfg(not(feature = "ascii_only"))]
+        assert_eq!(summary("\"\\u4e2d\\u6587\"", 1, 1), "\"\\u4e2d…\"");
+        #[cfg(feature = "ascii_only")]
+        assert_eq!(summary("\"\\u4e2d\\u6587\"", 1, 1), "\"中…\"");
+        assert_eq!(summary("\"a\\nb\"", 2, 1), "\"a\\n…\"");
+        assert_eq!(summary("\"abc\"", 3, 1), "\"abc\"");
+    }
+
+    /// UT test for `FormattedEncoder::with_max_width`.
+    ///
+    /// # Title
+    /// ut_formatted_encoder_with_max_width
+    ///
+    /// # Brief
+    /// 1. Creates some `JsonValue`s with nested arrays and objects.
+    /// 2. Uses `FormattedEncoder` with different widths to encode them.
+    /// 3. Checks if the containers which fit are kept on one line.
+    #[test]
+    fn ut_formatted_encoder_with_max_width() {
+        fn format(text: &str, max_width: usize) -> String {
+            let value = JsonValue::from_text(text).unwrap();
+            let mut writer = StringWriter::new();
+            let mut encoder = FormattedEncoder::new(&mut writer).with_max_width(max_width);
+            assert!(encoder.encode(&value).is_ok());
+            writer.string
+        }
+
+        assert_eq!(format("[[1,2],[3,4]]", 16), "[[1, 2], [3, 4]]\n");
+        assert_eq!(
+            format("[[1,2],[3,4]]", 15),
+            "[\n    [1, 2],\n    [3, 4]\n]\n"
+        );
+        assert_eq!(format("[1,2,3]", 4), "[\n    1,\n    2,\n    3\n]\n");
+        assert_eq!(format("{\"a\":[]}", 9), "{\"a\": []}\n");
+        assert_eq!(format("{\"a\":{}}", 1), "{\n    \"a\": {}\n}\n");
+        // "key" starts at column 4, and its value at column 11.
+        assert_eq!(
+            format("{\"key\":{\"x\":1},\"other\":0}", 20),
+            "{\n    \"key\": {\"x\": 1},\n    \"other\": 0\n}\n"
+        );
+        assert_eq!(
+            format("{\"key\":{\"x\":1},\"other\":0}", 18),
+            "{\n    \"key\": {\n        \"x\": 1\n    },\n    \"other\": 0\n}\n"
+        );
+        assert_eq!(
+            format("[\"a\\nb\",true,null,-1.5]", 26),
+            "[\"a\\nb\", true, null, -1.5]\n"
+        );
+    }
+
+    /// UT test for encoding non-finite floats.
+    ///
+    /// # Title
+    /// ut_encoder_non_finite_float
+    ///
+    /// # Brief
+    /// 1. Creates a `JsonValue` with NaN and infinite floats.
+    /// 2. Uses the encoders with different policies to encode it.
+    /// 3. Checks if the results are correct.
+    #[test]
+    fn ut_encoder_non_finite_float() {
+        fn config(non_finite_float: NonFiniteFloat) -> EncodeConfig {
+            EncodeConfig::default().non_finite_float(non_finite_float)
+        }
+
+        let value = JsonValue::Array(array!(1.5, f64::NAN, f64::INFINITY, f64::NEG_INFINITY));
+        let mut writer = StringWriter::new();
+        let result = CompactEncoder::new(&mut writer).encode(&value);
+        assert!(matches!(result, Err(Error::NonFiniteFloat)));
+
+        let mut writer = StringWriter::new();
+        let mut encoder =
+            CompactEncoder::new(&mut writer).with_config(config(NonFiniteFloat::Null));
+        assert!(encoder.encode(&value).is_ok());
+        assert_eq!(writer.string, "[1.5,null,null,null]");
+
+        let mut writer = StringWriter::new();
+        let mut encoder = FormattedEncoder::new(&mut writer)
+            .with_max_width(80)
+            .with_config(config(NonFiniteFloat::Literal));
+        assert!(encoder.encode(&value).is_ok());
+        assert_eq!(writer.string, "[1.5, NaN, Infinity, -Infinity]\n");
+
+        let mut writer = StringWriter::new();
+        let mut encoder = FormattedEncoder::new(&mut writer).with_max_width(80);
+        assert!(encoder.encode(&value).is_err());
+
+        let mut writer = StringWriter::new();
+        let mut encoder = SummaryEncoder::new(&mut writer, 10, 10);
+        assert!(encoder.encode(&value).is_ok());
+        assert_eq!(writer.string, "[1.5, NaN, Infinity, -Infinity]");
+
+        assert!(crate::to_string(&f64::NAN).is_err());
+        assert_eq!(crate::to_string(&1.5f32).unwrap(), "1.5");
+    }
+
+    /// UT test for the depth limit of encoders.
+    ///
+    /// # Title
+    /// ut_encoder_depth_limit
+    ///
+    /// # Brief
+    /// 1. Builds `JsonValue`s with deeply nested arrays and objects.
+    /// 2. Uses the encoders with different depth limits to encode them.
+    /// 3. Checks if the values deeper than the limits fail.
+    #[test]
+    fn ut_encoder_depth_limit() {
+        fn nested(depth: usize) -> JsonValue {
+            let mut value = JsonValue::Null;
+            for i in 0..depth {
+                value = if i % 2 == 0 {
+                    JsonValue::Array(array!(value))
+                } else {
+                    JsonValue::Object(object!("a" => value))
+                };
+            }
+            value
+        }
+
+        fn encode(value: &JsonValue, max_depth: u32, max_width: Option<usize>) -> bool {
+            let config = EncodeConfig {
+                max_depth,
+                ..EncodeConfig::default()
+            };
+            let mut writer = StringWriter::new();
+            let result = match max_width {
+                Some(width) => FormattedEncoder::new(&mut writer)
+                    .with_max_width(width)
+                    .with_config(config)
+                    .encode(value),
+                None => CompactEncoder::new(&mut writer)
+                    .with_config(config)
+                    .encode(value),
+            };
+            match result {
+                Ok(()) => true,
+                Err(Error::ExceedRecursionLimit) => false,
+                Err(e) => panic!("unexpected error {e}"),
+            }
+        }
+
+        let value = nested(4);
+        for width in [None, Some(0), Some(80)] {
+            assert!(encode(&value, 4, width));
+            assert!(!encode(&value, 3, width));
+        }
+        // The depth of an array kept on one line is checked before it is encoded.
+        let value = JsonValue::Array(array!(nested(3), "a".repeat(40)));
+        assert!(encode(&value, 4, Some(40)));
+        assert!(!encode(&value, 3, Some(40)));
+
+        let value = nested(1000);
+        assert!(matches!(
+            value.to_compact_string(),
+            Err(Error::ExceedRecursionLimit)
+        ));
+        assert!(matches!(
+            value.to_formatted_string(),
+            Err(Error::ExceedRecursionLimit)
+        ));
+    }
+
+    /// UT test for encoding strings with an escape table.
+    ///
+    /// # Title
+    /// ut_encoder_escape_table
+    ///
+    /// # Brief
+    /// 1. Creates an escape table which marks some characters.
+    /// 2. Uses the encoders with the table to encode strings and keys.
+    /// 3. Checks if the marked characters are escaped.
+    #[test]
+    fn ut_encoder_escape_table() {
+        let mut table = [false; 256];
+        for ch in ['\x7f', '|', '\u{85}', '"', '\n'] {
+            table[ch as usize] = true;
+        }
+        let config = EncodeConfig {
+            escape_table: Some(table),
+            ..EncodeConfig::default()
+        };
+
+        let value = JsonValue::Object(object!("a|b" => "x\x7fy|\u{85}\"\n中"));
+        let mut writer = StringWriter::new();
+        let mut encoder = CompactEncoder::new(&mut writer).with_config(config);
+        assert!(encoder.encode(&value).is_ok());
+        #[cfg(not(feature = "ascii_only"))]
+        assert_eq!(
+            writer.string,
+            r#"{"a\u007cb":"x\u007fy\u007c\u0085\"\n\u4e2d"}"#
+        );
+        #[cfg(feature = "ascii_only")]
+        assert_eq!(
+            writer.string,
+            r#"{"a\u007cb":"x\u007fy\u007c\u0085\"\n中"}"#
+        );
+
+        // The width of the escaped strings is counted for the inline formatting.
+        let value = JsonValue::Array(array!("||", "||"));
+        let mut writer = StringWriter::new();
+        let mut encoder = FormattedEncoder::new(&mut writer)
+            .with_max_width(24)
+            .with_config(config);
+        assert!(encoder.encode(&value).is_ok());
+        assert_eq!(
+            writer.string,
+            "[\n    \"\\u007c\\u007c\",\n    \"\\u007c\\u007c\"\n]\n"
+        );
+    }
 }
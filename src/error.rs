@@ -11,16 +11,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use alloc::boxed::Box;
+use alloc::ffi::IntoStringError;
+use alloc::string::{FromUtf8Error, String, ToString};
 use core::fmt::{Debug, Display, Formatter, Result};
-use std::ffi::IntoStringError;
-use std::string::FromUtf8Error;
+
+pub use crate::reader::Position;
 
 /// Errors during parsing.
+///
+/// More variants may be added in later versions, so matching on the category
+/// of an error should use `Error::kind` instead of listing the variants.
+#[non_exhaustive]
 pub enum Error {
     /// Parsing error.
     Parsing(ParseError),
 
     /// Io error.
+    #[cfg(feature = "std")]
     Io(std::io::Error),
 
     /// Parse number error.
@@ -33,7 +41,7 @@ pub enum Error {
     TypeTransform,
 
     /// Reader error.
-    Reader(Box<dyn std::error::Error>),
+    Reader(Box<dyn core::error::Error>),
 
     /// Incorrect serde usage error.
     IncorrectSerdeUsage,
@@ -41,47 +49,235 @@ pub enum Error {
     /// Used to convert serde-related errors.
     Custom(String),
 
-    /// Exceeds the recursion limit.
+    /// Serde-related errors raised during deserialization, with the position of the
+    /// reader when the error occurs.
+    CustomAt(String, Position),
+
+    /// Exceeds the recursion limit while parsing or encoding.
     ExceedRecursionLimit,
+
+    /// Exceeds the limit on the length of the whole text.
+    ExceedDocumentLimit,
+
+    /// Exceeds the limit on the length of a string.
+    ExceedStringLimit,
+
+    /// Exceeds the limit on the number of elements in an array.
+    ExceedArrayLimit,
+
+    /// Exceeds the limit on the number of members in an object.
+    ExceedObjectLimit,
+
+    /// Exceeds the limit on the memory allocated for the parsed values.
+    ExceedMemoryLimit,
+
+    /// The key or subscript does not exist (index, such as `["key"]` or `[3]`).
+    IndexNotFound(String),
+
+    /// The value is not an Object or Array which the index applies to (index, value type).
+    IndexTypeMismatch(String, &'static str),
+
+    /// A NaN or infinite float is encoded, which JSON does not allow.
+    NonFiniteFloat,
+
+    /// The text is not a valid JSON Pointer or URI fragment of it (text).
+    InvalidPointer(String),
+
+    /// An integer does not fit in the type it is deserialized into (field, integer, type,
+    /// position). The field is the key of the innermost object member holding the integer,
+    /// or `None` if it is not held by an object.
+    NumberOutOfRange(Option<String>, String, &'static str, Position),
 }
 
 /// The specific location and character of the error during parsing.
+#[non_exhaustive]
 pub enum ParseError {
-    /// Undesired character (line number, character number, current character)
-    UnexpectedCharacter(usize, usize, char),
+    /// Undesired character (position, current character)
+    UnexpectedCharacter(Position, char),
 
-    /// Illegal UTF-8 character (line number)
-    InvalidUtf8Bytes(usize),
+    /// Illegal UTF-8 character (position)
+    InvalidUtf8Bytes(Position),
 
-    /// Undesired end-of-file character (line number)
-    UnexpectedEndOfJson(usize),
+    /// Undesired end-of-file character (position)
+    UnexpectedEndOfJson(Position),
 
-    /// Expected Eof but not received (line number)
-    TrailingBytes(usize),
+    /// Expected Eof but not received (position)
+    TrailingBytes(Position),
 
     /// The input sequence has not yet been parsed.
     ParsingUnfinished,
 
-    /// There is an extra comma after the last value in an array or map (line number, character number)
-    TrailingComma(usize, usize),
+    /// A key appears more than once in an object (position, key)
+    DuplicateKey(Position, String),
+
+    /// There is an extra comma after the last value in an array or map (position)
+    TrailingComma(Position),
+
+    /// A colon is missing (position)
+    MissingColon(Position),
+
+    /// A comma is missing (position)
+    MissingComma(Position),
+}
+
+/// The category of an `Error`, which is stable across versions unlike the
+/// variants of `Error`.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{ErrorKind, JsonValue};
+///
+/// let error = JsonValue::from_text("[1,").unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::Eof);
+/// assert_eq!(error.code(), 2);
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The text is not valid JSON, such as an unexpected character. (code 1)
+    Syntax = 1,
+    /// The text ends before the value is finished. (code 2)
+    Eof = 2,
+    /// A limit is exceeded, such as the nesting depth or a `ParseConfig` limit. (code 3)
+    Limit = 3,
+    /// Bytes are not valid UTF-8, or unicode escapes are invalid. (code 4)
+    Utf8 = 4,
+    /// A number can not be represented, in the text or in the output. (code 5)
+    Number = 5,
+    /// The input can not be read, or the output can not be written. (code 6)
+    Io = 6,
+    /// The data does not match the expected type, such as by serde. (code 7)
+    Data = 7,
+    /// An index or a key does not exist, or does not apply to the value. (code 8)
+    Index = 8,
+}
 
-    /// A colon is missing (line number, character number)
-    MissingColon(usize, usize),
+impl ErrorKind {
+    /// Gets the numeric code of the kind, which never changes for a kind.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::ErrorKind;
+    ///
+    /// assert_eq!(ErrorKind::Syntax.code(), 1);
+    /// assert_eq!(ErrorKind::Index.code(), 8);
+    /// ```
+    pub fn code(self) -> u32 {
+        self as u32
+    }
+}
 
-    /// A comma is missing (line number, character number)
-    MissingComma(usize, usize),
+impl ParseError {
+    /// Gets the position where the error occurs, or `None` if it is unknown.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Error, JsonValue};
+    ///
+    /// if let Err(Error::Parsing(e)) = JsonValue::from_text("[1,]") {
+    ///     let position = e.position().unwrap();
+    ///     assert_eq!((position.line(), position.column()), (1, 4));
+    /// }
+    /// ```
+    pub fn position(&self) -> Option<&Position> {
+        match self {
+            Self::UnexpectedCharacter(position, _)
+            | Self::InvalidUtf8Bytes(position)
+            | Self::UnexpectedEndOfJson(position)
+            | Self::TrailingBytes(position)
+            | Self::TrailingComma(position)
+            | Self::MissingColon(position)
+            | Self::MissingComma(position)
+            | Self::DuplicateKey(position, _) => Some(position),
+            Self::ParsingUnfinished => None,
+        }
+    }
 }
 
 impl Error {
-    pub(crate) fn new_reader<E: Into<Box<dyn std::error::Error>>>(e: E) -> Self {
+    /// Gets the position where the error occurs, or `None` if it is unknown.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::from_str;
+    ///
+    /// let error = from_str::<Vec<u8>>("[1,\n 300]").unwrap_err();
+    /// let position = error.position().unwrap();
+    /// assert_eq!((position.line(), position.column()), (2, 5));
+    /// ```
+    pub fn position(&self) -> Option<&Position> {
+        match self {
+            Self::Parsing(e) => e.position(),
+            Self::CustomAt(_, position) | Self::NumberOutOfRange(_, _, _, position) => {
+                Some(position)
+            }
+            _ => None,
+        }
+    }
+
+    /// Gets the category of the error.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{ErrorKind, JsonValue, ParseConfig};
+    ///
+    /// let error = JsonValue::from_text("[1 2]").unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::Syntax);
+    ///
+    /// let config = ParseConfig::default().max_depth(1);
+    /// let error = JsonValue::from_text_with_config("[[1]]", config).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::Limit);
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Parsing(ParseError::UnexpectedEndOfJson(_)) => ErrorKind::Eof,
+            Self::Parsing(ParseError::InvalidUtf8Bytes(_)) => ErrorKind::Utf8,
+            Self::Parsing(_) => ErrorKind::Syntax,
+            #[cfg(feature = "std")]
+            Self::Io(_) => ErrorKind::Io,
+            Self::Reader(_) => ErrorKind::Io,
+            Self::ParseNumber | Self::NonFiniteFloat | Self::NumberOutOfRange(..) => {
+                ErrorKind::Number
+            }
+            Self::Utf8Transform => ErrorKind::Utf8,
+            Self::TypeTransform | Self::IncorrectSerdeUsage => ErrorKind::Data,
+            Self::Custom(_) | Self::CustomAt(_, _) => ErrorKind::Data,
+            Self::ExceedRecursionLimit
+            | Self::ExceedDocumentLimit
+            | Self::ExceedStringLimit
+            | Self::ExceedArrayLimit
+            | Self::ExceedObjectLimit
+            | Self::ExceedMemoryLimit => ErrorKind::Limit,
+            Self::IndexNotFound(_) | Self::IndexTypeMismatch(_, _) | Self::InvalidPointer(_) => {
+                ErrorKind::Index
+            }
+        }
+    }
+
+    /// Gets the numeric code of the category of the error, the same as
+    /// `ErrorKind::code`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let error = JsonValue::from_text("[1 2]").unwrap_err();
+    /// assert_eq!(error.code(), 1);
+    /// ```
+    pub fn code(&self) -> u32 {
+        self.kind().code()
+    }
+
+    pub(crate) fn new_reader<E: Into<Box<dyn core::error::Error>>>(e: E) -> Self {
         Error::Reader(e.into())
     }
 }
 
 impl Debug for ParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
-            Self::UnexpectedCharacter(line, pos, unexpected) => {
+            Self::UnexpectedCharacter(position, unexpected) => {
+                let (line, pos) = (position.line(), position.column());
                 write!(
                     f,
                     "[Line]: {line}, [Pos]: {pos}, [Error]: Unexpected character: "
@@ -98,33 +294,46 @@ impl Debug for ParseError {
                     write!(f, "{unexpected:?}.")
                 }
             }
-            Self::InvalidUtf8Bytes(line) => {
+            Self::InvalidUtf8Bytes(position) => {
+                let line = position.line();
                 write!(f, "[line]: {line}, [Error]: Invalid UTF-8 byte.")
             }
-            Self::UnexpectedEndOfJson(line) => {
+            Self::UnexpectedEndOfJson(position) => {
+                let line = position.line();
                 write!(f, "[Line]: {line}, [Error]: Unexpected end of json.")
             }
-            Self::TrailingBytes(line) => {
+            Self::TrailingBytes(position) => {
+                let line = position.line();
                 write!(f, "[Line]: {line}, [Error]: Expected end of json but not.")
             }
             Self::ParsingUnfinished => {
                 write!(f, "[Error]: Value has not been fully deserialized.")
             }
-            Self::TrailingComma(line, pos) => {
+            Self::TrailingComma(position) => {
+                let (line, pos) = (position.line(), position.column());
                 write!(
                     f,
                     "[Line]: {line}, [Pos]: {pos}, [Error]: Has a comma after the last value in an array or map."
                 )
             }
-            Self::MissingColon(line, pos) => {
+            Self::MissingColon(position) => {
+                let (line, pos) = (position.line(), position.column());
                 write!(f, "[Line]: {line}, [Pos]: {pos}, [Error]: A colon is missing between key and value.")
             }
-            Self::MissingComma(line, pos) => {
+            Self::MissingComma(position) => {
+                let (line, pos) = (position.line(), position.column());
                 write!(
                     f,
                     "[Line]: {line}, [Pos]: {pos}, [Error]: A comma is missing before next value."
                 )
             }
+            Self::DuplicateKey(position, key) => {
+                let (line, pos) = (position.line(), position.column());
+                write!(
+                    f,
+                    "[Line]: {line}, [Pos]: {pos}, [Error]: Duplicate key: {key:?}."
+                )
+            }
         }
     }
 }
@@ -133,14 +342,46 @@ impl Debug for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
             Self::Parsing(e) => write!(f, "Parse Error: {e:?}"),
+            #[cfg(feature = "std")]
             Self::Io(e) => write!(f, "Io Error: {e:?}"),
             Self::ParseNumber => write!(f, "Parse Number Error"),
             Self::TypeTransform => write!(f, "Type Transform Error"),
             Self::Utf8Transform => write!(f, "Utf8 Transform Error"),
             Self::IncorrectSerdeUsage => write!(f, "Incorrect Serde Usage Error"),
             Self::Custom(s) => write!(f, "{s}"),
+            Self::CustomAt(s, position) => write!(
+                f,
+                "{s} at line {} column {}",
+                position.line(),
+                position.column()
+            ),
             Self::Reader(e) => write!(f, "Reader Error:{e:?}"),
             Self::ExceedRecursionLimit => write!(f, "Exceed the recursion limit"),
+            Self::ExceedDocumentLimit => write!(f, "Exceed the document length limit"),
+            Self::ExceedStringLimit => write!(f, "Exceed the string length limit"),
+            Self::ExceedArrayLimit => write!(f, "Exceed the array length limit"),
+            Self::ExceedObjectLimit => write!(f, "Exceed the object member limit"),
+            Self::ExceedMemoryLimit => write!(f, "Exceed the memory limit"),
+            Self::IndexNotFound(index) => write!(f, "Index Error: {index} does not exist"),
+            Self::IndexTypeMismatch(index, kind) => {
+                write!(f, "Index Error: cannot index {kind} by {index}")
+            }
+            Self::NonFiniteFloat => write!(f, "Non-finite float cannot be encoded"),
+            Self::InvalidPointer(pointer) => {
+                write!(f, "Index Error: {pointer:?} is not a valid JSON Pointer")
+            }
+            Self::NumberOutOfRange(field, integer, ty, position) => {
+                write!(f, "Number Error: {integer} is out of the range of {ty}")?;
+                if let Some(field) = field {
+                    write!(f, " in field {field:?}")?;
+                }
+                write!(
+                    f,
+                    " at line {} column {}",
+                    position.line(),
+                    position.column()
+                )
+            }
         }
     }
 }
@@ -163,13 +404,24 @@ impl From<core::str::Utf8Error> for Error {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::Io(e)
     }
 }
 
-impl std::error::Error for Error {}
+impl core::error::Error for Error {
+    /// Gets the underlying error of an IO or reader error.
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            Self::Io(e) => Some(e),
+            Self::Reader(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 impl serde::ser::Error for Error {
     fn custom<T>(msg: T) -> Self
@@ -203,7 +455,8 @@ impl From<IntoStringError> for Error {
 
 #[cfg(test)]
 mod ut_error {
-    use crate::{Error, ParseError};
+    use crate::{Error, JsonValue, ParseError, Position};
+    use std::error::Error as _;
     use std::ffi::CString;
     use std::io::ErrorKind;
 
@@ -221,7 +474,7 @@ mod ut_error {
         assert_eq!(
             format!(
                 "{:?}",
-                Error::Parsing(ParseError::UnexpectedCharacter(1, 1, 'a'))
+                Error::Parsing(ParseError::UnexpectedCharacter(Position::new(1, 1), 'a'))
             ),
             "Parse Error: [Line]: 1, [Pos]: 1, [Error]: Unexpected character: 'a'.",
         );
@@ -229,7 +482,10 @@ mod ut_error {
         assert_eq!(
             format!(
                 "{:?}",
-                Error::Parsing(ParseError::UnexpectedCharacter(1, 1, '\u{8}'))
+                Error::Parsing(ParseError::UnexpectedCharacter(
+                    Position::new(1, 1),
+                    '\u{8}'
+                ))
             ),
             "Parse Error: [Line]: 1, [Pos]: 1, [Error]: Unexpected character: '\\b'.",
         );
@@ -237,7 +493,10 @@ mod ut_error {
         assert_eq!(
             format!(
                 "{:?}",
-                Error::Parsing(ParseError::UnexpectedCharacter(1, 1, '\u{b}'))
+                Error::Parsing(ParseError::UnexpectedCharacter(
+                    Position::new(1, 1),
+                    '\u{b}'
+                ))
             ),
             "Parse Error: [Line]: 1, [Pos]: 1, [Error]: Unexpected character: '\\v'.",
         );
@@ -245,23 +504,35 @@ mod ut_error {
         assert_eq!(
             format!(
                 "{:?}",
-                Error::Parsing(ParseError::UnexpectedCharacter(1, 1, '\u{c}'))
+                Error::Parsing(ParseError::UnexpectedCharacter(
+                    Position::new(1, 1),
+                    '\u{c}'
+                ))
             ),
             "Parse Error: [Line]: 1, [Pos]: 1, [Error]: Unexpected character: '\\f'.",
         );
 
         assert_eq!(
-            format!("{:?}", Error::Parsing(ParseError::InvalidUtf8Bytes(1))),
+            format!(
+                "{:?}",
+                Error::Parsing(ParseError::InvalidUtf8Bytes(Position::new(1, 1)))
+            ),
             "Parse Error: [line]: 1, [Error]: Invalid UTF-8 byte.",
         );
 
         assert_eq!(
-            format!("{:?}", Error::Parsing(ParseError::UnexpectedEndOfJson(1))),
+            format!(
+                "{:?}",
+                Error::Parsing(ParseError::UnexpectedEndOfJson(Position::new(1, 1)))
+            ),
             "Parse Error: [Line]: 1, [Error]: Unexpected end of json.",
         );
 
         assert_eq!(
-            format!("{:?}", Error::Parsing(ParseError::TrailingBytes(1))),
+            format!(
+                "{:?}",
+                Error::Parsing(ParseError::TrailingBytes(Position::new(1, 1)))
+            ),
             "Parse Error: [Line]: 1, [Error]: Expected end of json but not.",
         );
 
@@ -270,6 +541,7 @@ mod ut_error {
             "Parse Error: [Error]: Value has not been fully deserialized.",
         );
 
+        #[cfg(feature = "std")]
         assert_eq!(
             format!(
                 "{:?}",
@@ -300,11 +572,49 @@ mod ut_error {
             "Custom Error",
         );
 
+        assert_eq!(
+            format!(
+                "{:?}",
+                Error::CustomAt(String::from("Custom Error"), Position::new(2, 3))
+            ),
+            "Custom Error at line 2 column 3",
+        );
+
         assert_eq!(
             format!("{:?}", Error::ExceedRecursionLimit),
             "Exceed the recursion limit",
         );
 
+        assert_eq!(
+            format!("{:?}", Error::ExceedDocumentLimit),
+            "Exceed the document length limit",
+        );
+
+        assert_eq!(
+            format!("{:?}", Error::ExceedStringLimit),
+            "Exceed the string length limit",
+        );
+
+        assert_eq!(
+            format!("{:?}", Error::ExceedArrayLimit),
+            "Exceed the array length limit",
+        );
+
+        assert_eq!(
+            format!("{:?}", Error::ExceedObjectLimit),
+            "Exceed the object member limit",
+        );
+
+        assert_eq!(
+            format!("{:?}", Error::ExceedMemoryLimit),
+            "Exceed the memory limit",
+        );
+
+        assert_eq!(
+            format!("{:?}", Error::NonFiniteFloat),
+            "Non-finite float cannot be encoded",
+        );
+
         assert_eq!(
             format!(
                 "{:?}",
@@ -316,7 +626,7 @@ mod ut_error {
         assert_eq!(
             format!(
                 "{}",
-                Error::Parsing(ParseError::UnexpectedCharacter(1, 1, 'a'))
+                Error::Parsing(ParseError::UnexpectedCharacter(Position::new(1, 1), 'a'))
             ),
             "Parse Error: [Line]: 1, [Pos]: 1, [Error]: Unexpected character: 'a'.",
         );
@@ -324,7 +634,10 @@ mod ut_error {
         assert_eq!(
             format!(
                 "{}",
-                Error::Parsing(ParseError::UnexpectedCharacter(1, 1, '\u{8}'))
+                Error::Parsing(ParseError::UnexpectedCharacter(
+                    Position::new(1, 1),
+                    '\u{8}'
+                ))
             ),
             "Parse Error: [Line]: 1, [Pos]: 1, [Error]: Unexpected character: '\\b'.",
         );
@@ -332,7 +645,10 @@ mod ut_error {
         assert_eq!(
             format!(
                 "{}",
-                Error::Parsing(ParseError::UnexpectedCharacter(1, 1, '\u{b}'))
+                Error::Parsing(ParseError::UnexpectedCharacter(
+                    Position::new(1, 1),
+                    '\u{b}'
+                ))
             ),
             "Parse Error: [Line]: 1, [Pos]: 1, [Error]: Unexpected character: '\\v'.",
         );
@@ -340,26 +656,39 @@ mod ut_error {
         assert_eq!(
             format!(
                 "{}",
-                Error::Parsing(ParseError::UnexpectedCharacter(1, 1, '\u{c}'))
+                Error::Parsing(ParseError::UnexpectedCharacter(
+                    Position::new(1, 1),
+                    '\u{c}'
+                ))
             ),
             "Parse Error: [Line]: 1, [Pos]: 1, [Error]: Unexpected character: '\\f'.",
         );
 
         assert_eq!(
-            format!("{}", Error::Parsing(ParseError::InvalidUtf8Bytes(1))),
+            format!(
+                "{}",
+                Error::Parsing(ParseError::InvalidUtf8Bytes(Position::new(1, 1)))
+            ),
             "Parse Error: [line]: 1, [Error]: Invalid UTF-8 byte.",
         );
 
         assert_eq!(
-            format!("{}", Error::Parsing(ParseError::UnexpectedEndOfJson(1))),
+            format!(
+                "{}",
+                Error::Parsing(ParseError::UnexpectedEndOfJson(Position::new(1, 1)))
+            ),
             "Parse Error: [Line]: 1, [Error]: Unexpected end of json.",
         );
 
         assert_eq!(
-            format!("{}", Error::Parsing(ParseError::TrailingBytes(1))),
+            format!(
+                "{}",
+                Error::Parsing(ParseError::TrailingBytes(Position::new(1, 1)))
+            ),
             "Parse Error: [Line]: 1, [Error]: Expected end of json but not.",
         );
 
+        #[cfg(feature = "std")]
         assert_eq!(
             format!("{}", Error::Io(std::io::Error::from(ErrorKind::AddrInUse))),
             "Io Error: Kind(AddrInUse)",
@@ -371,6 +700,19 @@ mod ut_error {
 
         assert_eq!(format!("{}", Error::TypeTransform), "Type Transform Error",);
 
+        assert_eq!(
+            format!(
+                "{}",
+                Error::NumberOutOfRange(
+                    Some(String::from("id")),
+                    String::from("-1"),
+                    "u64",
+                    Position::new(2, 10)
+                )
+            ),
+            "Number Error: -1 is out of the range of u64 in field \"id\" at line 2 column 10",
+        );
+
         assert_eq!(
             format!(
                 "{}",
@@ -392,10 +734,14 @@ mod ut_error {
     #[test]
     fn ut_error_from() {
         assert_eq!(
-            format!("{}", Error::from(ParseError::TrailingBytes(1))),
+            format!(
+                "{}",
+                Error::from(ParseError::TrailingBytes(Position::new(1, 1)))
+            ),
             "Parse Error: [Line]: 1, [Error]: Expected end of json but not.",
         );
 
+        #[cfg(feature = "std")]
         assert_eq!(
             format!(
                 "{}",
@@ -435,4 +781,92 @@ mod ut_error {
             "Type Transform Error",
         );
     }
+
+    /// UT test for `ParseError::position`.
+    ///
+    /// # Title
+    /// ut_parse_error_position
+    ///
+    /// # Brief
+    /// 1. Parses some wrong JSON texts.
+    /// 2. Calls `ParseError::position` on the errors.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_parse_error_position() {
+        fn position(text: &str) -> Option<(usize, usize, usize)> {
+            match JsonValue::from_text(text) {
+                Err(Error::Parsing(e)) => e.position().map(|p| (p.line(), p.column(), p.offset())),
+                _ => panic!("expected a parsing error"),
+            }
+        }
+
+        assert_eq!(position("[1,\n 2 x]"), Some((2, 4, 7)));
+        assert_eq!(position("{\"a\" 1}"), Some((1, 6, 5)));
+        assert_eq!(position("[1] 2"), Some((1, 5, 4)));
+        assert_eq!(position("\n[1,"), Some((2, 4, 4)));
+        assert_eq!(
+            ParseError::MissingComma(Position::new(3, 2)).position(),
+            Some(&Position::new(3, 2))
+        );
+        assert_eq!(ParseError::ParsingUnfinished.position(), None);
+    }
+
+    /// UT test for `Error::kind`, `Error::code` and `Error::source`.
+    ///
+    /// # Title
+    /// ut_error_kind
+    ///
+    /// # Brief
+    /// 1. Creates some `Error`s, including ones which wrap IO errors.
+    /// 2. Calls `Error::kind`, `Error::code` and `Error::source` on the errors.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_error_kind() {
+        use crate::ErrorKind as Kind;
+
+        let cases = [
+            (JsonValue::from_text("[1 2]").unwrap_err(), Kind::Syntax),
+            (JsonValue::from_text("[1,").unwrap_err(), Kind::Eof),
+            (JsonValue::from_text("[1] 2").unwrap_err(), Kind::Syntax),
+            (
+                JsonValue::from_text("[18446744073709551616]").unwrap_err(),
+                Kind::Number,
+            ),
+            (
+                JsonValue::from_text("[".repeat(1000)).unwrap_err(),
+                Kind::Limit,
+            ),
+            (Error::ExceedMemoryLimit, Kind::Limit),
+            (Error::Utf8Transform, Kind::Utf8),
+            (Error::NonFiniteFloat, Kind::Number),
+            (
+                Error::NumberOutOfRange(None, String::from("256"), "u8", Position::new(1, 4)),
+                Kind::Number,
+            ),
+            (Error::TypeTransform, Kind::Data),
+            (Error::Custom(String::from("a")), Kind::Data),
+            (Error::IndexNotFound(String::from("[0]")), Kind::Index),
+            (Error::InvalidPointer(String::from("a")), Kind::Index),
+        ];
+        #[cfg(feature = "std")]
+        let cases = cases.into_iter().chain([(
+            Error::Io(std::io::Error::from(ErrorKind::NotFound)),
+            Kind::Io,
+        )]);
+        for (error, kind) in cases {
+            assert_eq!(error.kind(), kind, "{error}");
+            assert_eq!(error.code(), kind as u32);
+        }
+        assert_eq!(Kind::Syntax.code(), 1);
+        assert_eq!(Kind::Index.code(), 8);
+
+        #[cfg(feature = "std")]
+        {
+            let error = Error::Io(std::io::Error::other("disk"));
+            assert_eq!(error.source().unwrap().to_string(), "disk");
+        }
+        let error = Error::new_reader(std::io::Error::other("pipe"));
+        assert_eq!(error.source().unwrap().to_string(), "pipe");
+        assert!(Error::TypeTransform.source().is_none());
+    }
 }
@@ -0,0 +1,193 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Array, JsonValue, Number, Object};
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Bounds of the random JsonValues generated by `JsonValue::arbitrary_with_config`,
+/// which keep the generated values small enough for property-based tests and fuzzing.
+///
+/// The generated floats are always finite, so every generated value can be encoded
+/// and parsed back to an equal value.
+///
+/// # Examples
+/// ```
+/// use arbitrary::Unstructured;
+/// use ylong_json::{ArbitraryConfig, JsonValue};
+///
+/// let config = ArbitraryConfig {
+///     max_depth: 2,
+///     ..ArbitraryConfig::default()
+/// };
+/// let mut u = Unstructured::new(&[7; 256]);
+/// let value = JsonValue::arbitrary_with_config(&mut u, config).unwrap();
+/// assert!(value.stats().max_depth <= 2);
+///
+/// let text = value.to_compact_string().unwrap();
+/// assert_eq!(JsonValue::from_text(text).unwrap(), value);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArbitraryConfig {
+    /// The maximum nesting depth of arrays and objects.
+    pub max_depth: u32,
+    /// The maximum number of elements in an array or members in an object.
+    pub max_len: usize,
+    /// The maximum length in characters of a string or a key.
+    pub max_string_chars: usize,
+}
+
+impl Default for ArbitraryConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: 8,
+            max_len: 16,
+            max_string_chars: 32,
+        }
+    }
+}
+
+impl JsonValue {
+    /// Generates a random JsonValue from the raw data in `u`, within the bounds of
+    /// `config`. Once the data runs out, only empty arrays, objects and strings,
+    /// `null`, `false` and `0` are generated, so the generation always ends.
+    ///
+    /// # Examples
+    /// ```
+    /// use arbitrary::Unstructured;
+    /// use ylong_json::{ArbitraryConfig, JsonValue};
+    ///
+    /// let mut u = Unstructured::new(&[]);
+    /// let value = JsonValue::arbitrary_with_config(&mut u, ArbitraryConfig::default());
+    /// assert_eq!(value.unwrap(), JsonValue::Null);
+    /// ```
+    pub fn arbitrary_with_config(
+        u: &mut Unstructured<'_>,
+        config: ArbitraryConfig,
+    ) -> Result<Self> {
+        arbitrary_value(u, &config, 0)
+    }
+}
+
+impl<'a> Arbitrary<'a> for JsonValue {
+    /// Generates a random JsonValue within the bounds of `ArbitraryConfig::default`.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Self::arbitrary_with_config(u, ArbitraryConfig::default())
+    }
+}
+
+fn arbitrary_value(
+    u: &mut Unstructured<'_>,
+    config: &ArbitraryConfig,
+    depth: u32,
+) -> Result<JsonValue> {
+    // Arrays and objects are only chosen below the maximum depth.
+    let max_kind = if depth < config.max_depth { 5 } else { 3 };
+    Ok(match u.int_in_range(0..=max_kind)? {
+        0 => JsonValue::Null,
+        1 => JsonValue::Boolean(u.arbitrary()?),
+        2 => JsonValue::Number(arbitrary_number(u)?),
+        3 => JsonValue::String(arbitrary_string(u, config)?),
+        4 => {
+            let mut array = Array::new();
+            for _ in 0..u.int_in_range(0..=config.max_len)? {
+                array.push(arbitrary_value(u, config, depth + 1)?);
+            }
+            JsonValue::Array(array)
+        }
+        _ => {
+            let mut object = Object::new();
+            for _ in 0..u.int_in_range(0..=config.max_len)? {
+                let key = arbitrary_string(u, config)?;
+                object.insert(key, arbitrary_value(u, config, depth + 1)?);
+            }
+            JsonValue::Object(object)
+        }
+    })
+}
+
+fn arbitrary_number(u: &mut Unstructured<'_>) -> Result<Number> {
+    Ok(match u.int_in_range(0..=2)? {
+        0 => Number::Unsigned(u.arbitrary()?),
+        1 => Number::Signed(u.arbitrary()?),
+        _ => {
+            let float: f64 = u.arbitrary()?;
+            Number::Float(if float.is_finite() { float } else { 0.0 })
+        }
+    })
+}
+
+fn arbitrary_string(u: &mut Unstructured<'_>, config: &ArbitraryConfig) -> Result<String> {
+    let text: &str = u.arbitrary()?;
+    Ok(text.chars().take(config.max_string_chars).collect())
+}
+
+#[cfg(test)]
+mod ut_generator {
+    use crate::{ArbitraryConfig, JsonValue};
+    use arbitrary::{Arbitrary, Unstructured};
+
+    /// Generates pseudo-random bytes by a linear congruential generator.
+    fn random_bytes(seed: u64, len: usize) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (state >> 56) as u8
+            })
+            .collect()
+    }
+
+    /// UT test for `JsonValue::arbitrary_with_config`.
+    ///
+    /// # Title
+    /// ut_arbitrary_with_config
+    ///
+    /// # Brief
+    /// 1. Generates `JsonValue`s from some pseudo-random bytes with different configs.
+    /// 2. Checks if the values are within the bounds of the configs.
+    /// 3. Checks if the values are the same after encoding and parsing.
+    #[test]
+    fn ut_arbitrary_with_config() {
+        let configs = [
+            ArbitraryConfig::default(),
+            ArbitraryConfig {
+                max_depth: 0,
+                ..ArbitraryConfig::default()
+            },
+            ArbitraryConfig {
+                max_depth: 3,
+                max_len: 2,
+                max_string_chars: 1,
+            },
+        ];
+        for seed in 0..64 {
+            let bytes = random_bytes(seed, 4096);
+            for config in configs {
+                let mut u = Unstructured::new(&bytes);
+                let value = JsonValue::arbitrary_with_config(&mut u, config).unwrap();
+                let stats = value.stats();
+                assert!(stats.max_depth <= config.max_depth);
+                assert!(stats.max_array_len <= config.max_len);
+                assert!(stats.max_object_members <= config.max_len);
+
+                let text = value.to_compact_string().unwrap();
+                assert_eq!(JsonValue::from_text(&text).unwrap(), value, "{text}");
+            }
+        }
+
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(JsonValue::arbitrary(&mut u).unwrap(), JsonValue::Null);
+    }
+}
@@ -15,8 +15,20 @@
 //! This library provides a convenient macro-based API for creating
 //! JSON values, and utilities for reading and writing JSON data
 //! from various sources.
+//!
+//! Without the `std` feature (enabled by default), ylong_json only depends on
+//! `core` and `alloc`. In this case, JSON text is read from byte slices and
+//! written into `String`s, and the `std::io` based interfaces are unavailable.
+//!
+//! On `wasm32-unknown-unknown`, which has neither a C runtime nor a file system,
+//! the C interface of the `c_adapter` feature and the file based interfaces are
+//! not compiled. The slice based interfaces, such as `JsonValue::from_text`,
+//! `from_slice` and `to_vec`, behave the same as on other targets.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
-// TODO: 1) Isolates ylong_json no_std.
 // TODO: 2) Handles illegal Utf-8 bytes.
 // TODO: 3) Refactors ylong_json.
 // TODO: 4) JsonValue provides 'Contains' methods。
@@ -37,6 +49,9 @@ macro_rules! array {
 }
 
 /// Creates an object with at least one but any number of key-value pairs.
+///
+/// The keys can be anything that converts into `Key`. String literals are borrowed, so
+/// they allocate nothing.
 #[macro_export]
 macro_rules! object {
     () => ({
@@ -45,40 +60,104 @@ macro_rules! object {
     ($($k: expr => $v: expr);+ $(;)?) => ({
         let mut object = Object::new();
         $(
-           object.insert(String::from($k), $v.into());
+           object.insert($k, $v.into());
         )*
         object
     });
 }
 
+mod base64;
+#[cfg(feature = "compress")]
+mod compress;
+mod config;
 mod consts;
+mod convert;
+mod document;
 mod encoder;
 mod error;
+#[cfg(feature = "arbitrary")]
+mod generator;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod pointer;
 mod reader;
+mod scan;
+mod splice;
+mod stats;
+#[cfg(feature = "test_util")]
+mod test_util;
 #[macro_use]
 mod states;
+#[cfg(feature = "std")]
+mod transcode;
 mod value;
+mod writer;
 
-pub use error::{Error, ParseError};
-pub use value::{Array, Index, JsonValue, Number, Object};
+pub use base64::{Base64Alphabet, BytesFormat};
+#[cfg(feature = "compress")]
+pub use compress::{from_deflate_reader, from_gzip_reader, to_deflate_writer, to_gzip_writer};
+pub use config::{EncodeConfig, NonFiniteFloat, NumberOverflow, ParseConfig};
+pub use convert::{from_member, FromJson, ToJson};
+pub use document::Document;
+pub use error::{Error, ErrorKind, ParseError, Position};
+#[cfg(feature = "arbitrary")]
+pub use generator::ArbitraryConfig;
+pub use parser::Parser;
+pub use pointer::{JsonPointer, PathIter, RelativeTarget};
+pub use splice::splice;
+pub use stats::{DuplicateKey, NodeCounts, ParseStats, ValueStats};
+#[cfg(feature = "test_util")]
+pub use test_util::json_diff;
+#[cfg(feature = "std")]
+pub use transcode::{transcode, TranscodeAction, TranscodeEvent};
+#[cfg(any(
+    feature = "list_object",
+    feature = "vec_object",
+    feature = "index_object"
+))]
+pub use value::OrderedObject;
+pub use value::{
+    Array, ArrayBuilder, Index, JsonString, JsonValue, Key, Number, Object, ObjectBuilder,
+    SharedValue,
+};
+#[cfg(feature = "derive")]
+pub use ylong_json_derive::{FromJson, ToJson};
 
-pub(crate) use encoder::{CompactEncoder, FormattedEncoder};
-pub(crate) use states::start_parsing;
+pub(crate) use encoder::{CompactEncoder, FormattedEncoder, SummaryEncoder};
+pub(crate) use states::{start_parsing, start_partial_parsing};
 
-#[cfg(feature = "c_adapter")]
+#[cfg(all(
+    feature = "c_adapter",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
 mod adapter;
-#[cfg(feature = "c_adapter")]
+#[cfg(all(
+    feature = "c_adapter",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
 pub use adapter::*;
 
 mod deserializer;
 #[cfg(any(feature = "list_array", feature = "list_object"))]
 mod linked_list;
+mod parser;
 mod serializer_compact;
 
 #[cfg(any(feature = "list_array", feature = "list_object"))]
-pub(crate) use linked_list::{Cursor, CursorMut, LinkedList};
+pub(crate) use linked_list::LinkedList;
 #[cfg(any(feature = "list_array", feature = "list_object"))]
-pub use linked_list::{Iter, IterMut, Node};
+pub use linked_list::{Cursor, CursorMut, Drain, ExtractIf, Iter, IterMut, Node};
 
-pub use deserializer::{from_reader, from_slice, from_str};
-pub use serializer_compact::{to_string, to_writer};
+#[cfg(feature = "std")]
+pub use deserializer::{
+    from_iter, from_reader, from_reader_with_bytes_format, from_reader_with_config,
+};
+pub use deserializer::{
+    from_slice, from_slice_with_bytes_format, from_slice_with_config, from_slices, from_str,
+    from_str_with_bytes_format, from_str_with_config,
+};
+pub use serializer_compact::{
+    to_chunks, to_string, to_string_with_bytes_format, to_vec, to_vec_with_bytes_format,
+};
+#[cfg(feature = "std")]
+pub use serializer_compact::{to_writer, to_writer_with_bytes_format};
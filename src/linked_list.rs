@@ -11,28 +11,144 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::consts::NODE_CHUNK_SIZE;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+#[cfg(feature = "c_adapter")]
+use core::any::TypeId;
+use core::cmp::Ordering as CmpOrdering;
 use core::fmt::{Debug, Formatter};
+use core::iter::FusedIterator;
 use core::marker::PhantomData;
-use core::ptr::null;
+use core::ptr::{self, null};
+#[cfg(feature = "c_adapter")]
+use std::{collections::BTreeMap, sync::Mutex};
 
 // todo: Considers deleting PhantomData.
 
+/// Handles of the nodes handed out through the C interface. A handle leaves the table when
+/// its node is released, and handles are never reused while they are in the table, so a
+/// handle of a removed node, or of a node of a dropped list, is detected even if a new node
+/// takes the same slot. The type of the node is recorded as well, so an array node can not
+/// be used as an object node.
+#[cfg(feature = "c_adapter")]
+static EXPORTED_NODES: Mutex<NodeHandles> = Mutex::new(NodeHandles {
+    last: 0,
+    nodes: BTreeMap::new(),
+});
+
+#[cfg(feature = "c_adapter")]
+struct NodeHandles {
+    // The last handle handed out. Handles start from 1, so 0 means none.
+    last: usize,
+    // Maps handles to the addresses and the types of their nodes.
+    nodes: BTreeMap<usize, (usize, TypeId)>,
+}
+
+/// Node allocator of a linked list, which allocates nodes in chunks whose memory never moves.
+/// The first chunk holds one node, and each following chunk doubles the previous one until
+/// it reaches `max_chunk`. Released nodes are linked through their `next` pointers into a
+/// free list for reuse, and the chunks are kept until the list is dropped.
+struct NodeSlab<T> {
+    // Each chunk keeps its length 0 and uses its capacity as raw slots.
+    chunks: Vec<Vec<Node<T>>>,
+    max_chunk: usize,
+    // Number of slots used in the last chunk.
+    used: usize,
+    free: *const Node<T>,
+    // Number of released nodes, which tells whether the pointers to nodes may be dangling.
+    #[cfg(all(feature = "list_object", feature = "std"))]
+    releases: usize,
+}
+
+impl<T> NodeSlab<T> {
+    /// Creates an empty NodeSlab whose chunks hold at most `max_chunk` nodes.
+    const fn new(max_chunk: usize) -> Self {
+        NodeSlab {
+            chunks: Vec::new(),
+            max_chunk,
+            used: 0,
+            free: null(),
+            #[cfg(all(feature = "list_object", feature = "std"))]
+            releases: 0,
+        }
+    }
+
+    /// Moves the node into a free slot and returns the pointer to it.
+    fn alloc(&mut self, node: Node<T>) -> *const Node<T> {
+        let slot = if !self.free.is_null() {
+            let slot = self.free as *mut Node<T>;
+            // A free slot only holds the pointer to the next free slot.
+            self.free = unsafe { ptr::addr_of!((*slot).next).read() };
+            slot
+        } else {
+            let chunk = match self.chunks.last_mut() {
+                Some(chunk) if self.used < chunk.capacity() => chunk,
+                last => {
+                    let size = last
+                        .map_or(1, |chunk| chunk.capacity().saturating_mul(2))
+                        .min(self.max_chunk);
+                    self.chunks.push(Vec::with_capacity(size));
+                    self.used = 0;
+                    // The chunk has just been pushed.
+                    let index = self.chunks.len() - 1;
+                    &mut self.chunks[index]
+                }
+            };
+            let slot = unsafe { chunk.as_mut_ptr().add(self.used) };
+            self.used += 1;
+            slot
+        };
+        unsafe { slot.write(node) };
+        slot
+    }
+
+    /// Moves the node out of its slot and puts the slot into the free list.
+    ///
+    /// # Safety
+    /// The node must be allocated by this slab and not released yet.
+    unsafe fn release(&mut self, node: *const Node<T>) -> Node<T> {
+        let slot = node as *mut Node<T>;
+        let node = slot.read();
+        #[cfg(feature = "c_adapter")]
+        if node.handle != 0 {
+            let mut exported = EXPORTED_NODES.lock().unwrap_or_else(|e| e.into_inner());
+            exported.nodes.remove(&node.handle);
+        }
+        ptr::addr_of_mut!((*slot).next).write(self.free);
+        self.free = slot;
+        #[cfg(all(feature = "list_object", feature = "std"))]
+        {
+            self.releases = self.releases.wrapping_add(1);
+        }
+        node
+    }
+}
+
 /// Linked list implementation, provides two sets of methods for getting nodes and members.
-/// Only tail insertion, reading, and eject are supported.
+/// Supports tail insertion, insertion at a cursor, reading, and eject.
 pub(crate) struct LinkedList<T> {
     head: *const Node<T>,
     tail: *const Node<T>,
     len: usize,
+    slab: NodeSlab<T>,
     marker: PhantomData<Box<Node<T>>>,
 }
 
 impl<T> LinkedList<T> {
     /// Creates LinkedList.
     pub(crate) const fn new() -> Self {
+        Self::with_chunk_size(NODE_CHUNK_SIZE)
+    }
+
+    /// Creates LinkedList whose nodes are allocated in chunks of at most `size` nodes.
+    /// 0 is treated as 1.
+    pub(crate) const fn with_chunk_size(size: usize) -> Self {
         LinkedList {
             head: null(),
             tail: null(),
             len: 0,
+            slab: NodeSlab::new(if size == 0 { 1 } else { size }),
             marker: PhantomData,
         }
     }
@@ -51,12 +167,12 @@ impl<T> LinkedList<T> {
 
     /// Inserts an element at the end of the list
     pub(crate) fn push_back(&mut self, value: T) {
-        let mut node = Box::new(Node::new(value));
+        let mut node = Node::new(value);
         unsafe {
             // Sets prev to LinkedList.tail
             node.prev = self.tail;
             // Gets an internal element pointer.
-            let node = Box::leak(node) as *const Node<T>;
+            let node = self.slab.alloc(node);
 
             if self.tail.is_null() {
                 self.head = node;
@@ -75,7 +191,7 @@ impl<T> LinkedList<T> {
             None
         } else {
             unsafe {
-                let node = Box::from_raw(self.tail as *mut Node<T>);
+                let node = self.slab.release(self.tail);
                 self.tail = node.prev;
 
                 if self.tail.is_null() {
@@ -112,6 +228,28 @@ impl<T> LinkedList<T> {
         }
     }
 
+    /// Gets an iterator which removes and yields the elements matching `pred` in one pass.
+    /// The elements which are not yielded, including those left after the iterator is
+    /// dropped early, remain in the list.
+    #[inline]
+    pub(crate) fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        ExtractIf {
+            cursor: self.cursor_front_mut(),
+            pred,
+        }
+    }
+
+    /// Gets an iterator which removes and yields `count` elements from `start`. The
+    /// elements which are not yielded are removed as well when the iterator is dropped.
+    pub(crate) fn drain(&mut self, start: usize, count: usize) -> Drain<'_, T> {
+        let mut cursor = self.cursor_front_mut();
+        let remaining = if cursor.seek(start) { count } else { 0 };
+        Drain { cursor, remaining }
+    }
+
     /// Gets the normal cursor of the list and sets the starting point to the list header.
     #[inline]
     pub(crate) fn cursor_front(&self) -> Cursor<'_, T> {
@@ -133,7 +271,6 @@ impl<T> LinkedList<T> {
     }
 
     /// Gets the normal cursor of the list and sets the starting point to the end of the list.
-    #[cfg(feature = "list_array")]
     #[inline]
     pub(crate) fn cursor_back(&self) -> Cursor<'_, T> {
         Cursor {
@@ -144,7 +281,6 @@ impl<T> LinkedList<T> {
     }
 
     /// Gets the variable cursor of the list and sets the start to the end of the list.
-    #[cfg(feature = "list_array")]
     #[inline]
     pub(crate) fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
         CursorMut {
@@ -202,6 +338,115 @@ impl<T> LinkedList<T> {
         }
     }
 
+    /// Sorts the list with a stable merge sort, which only relinks the nodes, so the
+    /// pointers to nodes remain valid. It takes O(n log n) time and O(1) extra space.
+    /// If `compare` panics, the list keeps all its elements in an unspecified order.
+    pub(crate) fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> CmpOrdering,
+    {
+        let len = self.len;
+        let mut sort = MergeSort {
+            merged_head: null(),
+            merged_tail: null(),
+            left: null(),
+            right: null(),
+            rest: self.head,
+            list: self,
+        };
+
+        let mut width = 1;
+        while width < len {
+            while !sort.rest.is_null() {
+                unsafe {
+                    sort.left = sort.rest;
+                    sort.right = MergeSort::cut(sort.left, width);
+                    sort.rest = MergeSort::cut(sort.right, width);
+
+                    while !sort.left.is_null() && !sort.right.is_null() {
+                        // Takes the left one if they are equal, so that the sort is stable.
+                        let node = if compare(&(*sort.left).element, &(*sort.right).element)
+                            == CmpOrdering::Greater
+                        {
+                            MergeSort::pop(&mut sort.right)
+                        } else {
+                            MergeSort::pop(&mut sort.left)
+                        };
+                        sort.append(node);
+                    }
+                    let remaining = if sort.left.is_null() {
+                        &mut sort.right
+                    } else {
+                        &mut sort.left
+                    };
+                    let remaining = core::mem::replace(remaining, null());
+                    sort.append(remaining);
+                }
+            }
+            sort.rest = core::mem::replace(&mut sort.merged_head, null());
+            sort.merged_tail = null();
+            width *= 2;
+        }
+    }
+
+    /// Gets the number of nodes released by the list so far. If it is unchanged, the
+    /// pointers to nodes obtained before are still valid.
+    #[cfg(all(feature = "list_object", feature = "std"))]
+    #[inline]
+    pub(crate) fn releases(&self) -> usize {
+        self.slab.releases
+    }
+
+    /// Gets a mutable reference to the node, and sets its parent to the current list.
+    ///
+    /// # Safety
+    /// The node must belong to this list.
+    #[cfg(feature = "list_object")]
+    pub(crate) unsafe fn node_mut(&mut self, node: *const Node<T>) -> &mut Node<T> {
+        let node = &mut *(node as *mut Node<T>);
+        node.parent = self as *const LinkedList<T>;
+        node
+    }
+
+    /// Removes the node from the list and returns its element.
+    ///
+    /// # Safety
+    /// The node must belong to this list.
+    #[cfg(any(feature = "list_object", feature = "c_adapter"))]
+    pub(crate) unsafe fn remove_node(&mut self, node: *const Node<T>) -> T {
+        self.unlink_node(node);
+        self.slab.release(node).element
+    }
+
+    /// Gets the node at `index`, walking from the nearest one of the head, the tail
+    /// and `from`, which is the node at `from_index` or null. Returns null if `index`
+    /// is out of range.
+    fn locate(&self, from: *const Node<T>, from_index: usize, index: usize) -> *const Node<T> {
+        if index >= self.len {
+            return null();
+        }
+
+        let (mut node, mut steps, mut forward) = (self.head, index, true);
+        if self.len - 1 - index < steps {
+            (node, steps, forward) = (self.tail, self.len - 1 - index, false);
+        }
+        if !from.is_null() && from_index.abs_diff(index) < steps {
+            (node, steps, forward) = (from, from_index.abs_diff(index), index > from_index);
+        }
+
+        while steps != 0 {
+            node = unsafe {
+                if forward {
+                    (*node).next
+                } else {
+                    (*node).prev
+                }
+            };
+            steps -= 1;
+        }
+        node
+    }
+
     /// Removes a node from the linked list.
     pub(crate) unsafe fn unlink_node(&mut self, node: *const Node<T>) {
         let node = &mut (*(node as *mut Node<T>));
@@ -256,7 +501,7 @@ impl<T: PartialEq> PartialEq for LinkedList<T> {
 
 impl<T: Clone> Clone for LinkedList<T> {
     fn clone(&self) -> Self {
-        let mut new_list = LinkedList::new();
+        let mut new_list = LinkedList::with_chunk_size(self.slab.max_chunk);
         for item in self.iter() {
             new_list.push_back(item.clone());
         }
@@ -272,8 +517,12 @@ impl<T> Drop for LinkedList<T> {
     }
 }
 
-// We need to use static to store the JsonValue, so we need to make the LinkedList implement Send and Sync.
-// However, when using this list, locking is still required under concurrent conditions.
+// SAFETY: The list owns its nodes, which live in the chunks of its slab and are only
+// reached through the list, so moving the list moves the ownership of every element, the
+// same as `Vec<T>`. Nodes are only changed through `&mut LinkedList<T>`, including the
+// nodes handed out by `Node::remove_self` and the C interface, and a shared list has no
+// interior mutability, so sharing it only shares `&T`. The registry of exported nodes of
+// the C interface is a global protected by a mutex.
 unsafe impl<T: Send> Send for LinkedList<T> {}
 unsafe impl<T: Sync> Sync for LinkedList<T> {}
 
@@ -282,6 +531,9 @@ pub struct Node<T> {
     next: *const Node<T>,
     prev: *const Node<T>,
     parent: *const LinkedList<T>,
+    // The handle of the node handed out through the C interface, or 0 if there is none.
+    #[cfg(feature = "c_adapter")]
+    handle: usize,
     element: T,
 }
 
@@ -292,6 +544,8 @@ impl<T> Node<T> {
             next: null(),
             prev: null(),
             parent: null(),
+            #[cfg(feature = "c_adapter")]
+            handle: 0,
             element,
         }
     }
@@ -301,21 +555,147 @@ impl<T> Node<T> {
         self.element
     }
 
+    /// Gets a common reference to an internal member of a linked list node.
+    #[cfg(feature = "list_object")]
+    pub(crate) fn get_element(&self) -> &T {
+        &self.element
+    }
+
     /// Gets a common reference to an internal member of a linked list node.
     pub(crate) fn get_element_mut(&mut self) -> &mut T {
         &mut self.element
     }
 
+    /// Registers the node as handed out through the C interface, and returns its handle,
+    /// which is never 0 and can be resolved by `Node::from_exported` later.
+    #[cfg(feature = "c_adapter")]
+    pub(crate) fn export(&mut self) -> usize
+    where
+        T: 'static,
+    {
+        if self.handle == 0 {
+            let mut exported = EXPORTED_NODES.lock().unwrap_or_else(|e| e.into_inner());
+            let mut handle = exported.last;
+            loop {
+                handle = handle.wrapping_add(1);
+                if handle != 0 && !exported.nodes.contains_key(&handle) {
+                    break;
+                }
+            }
+            exported.last = handle;
+            let node = self as *mut Node<T> as usize;
+            exported
+                .nodes
+                .insert(handle, (node, TypeId::of::<Node<T>>()));
+            self.handle = handle;
+        }
+        self.handle
+    }
+
+    /// Gets a mutable reference to the node of a handle returned by `Node::export`.
+    /// Returns None if the node has been removed, its list has been dropped, or it is
+    /// not a `Node<T>`.
+    ///
+    /// # Safety
+    /// The list of the node must not be moved or accessed concurrently.
+    #[cfg(feature = "c_adapter")]
+    pub(crate) unsafe fn from_exported<'a>(handle: usize) -> Option<&'a mut Node<T>>
+    where
+        T: 'static,
+    {
+        let exported = EXPORTED_NODES.lock().unwrap_or_else(|e| e.into_inner());
+        match exported.nodes.get(&handle) {
+            Some(&(node, type_id)) if type_id == TypeId::of::<Node<T>>() => {
+                Some(&mut *(node as *mut Node<T>))
+            }
+            _ => None,
+        }
+    }
+
     /// Removes the node itself from the linked list and returns the member below.
     #[cfg(feature = "c_adapter")]
     pub(crate) fn remove_self(&mut self) -> Option<T> {
         let list = unsafe { &mut *(self.parent as *mut LinkedList<T>) };
-        let mut cursor = CursorMut {
-            index: 0,
-            current: self as *const Node<T>,
-            list,
-        };
-        cursor.remove_current()
+        unsafe { Some(list.remove_node(self as *const Node<T>)) }
+    }
+}
+
+/// State of `LinkedList::sort_by`. All the nodes are in the chains of merged nodes, left
+/// nodes, right nodes and the rest nodes, which are linked by `next` pointers and end with
+/// null. It relinks the chains into the list when dropped, even if the sort panics.
+struct MergeSort<'a, T> {
+    list: &'a mut LinkedList<T>,
+    merged_head: *const Node<T>,
+    merged_tail: *const Node<T>,
+    left: *const Node<T>,
+    right: *const Node<T>,
+    rest: *const Node<T>,
+}
+
+impl<T> MergeSort<'_, T> {
+    /// Cuts the chain after `n` nodes and returns the chain behind them.
+    unsafe fn cut(mut node: *const Node<T>, n: usize) -> *const Node<T> {
+        for _ in 1..n {
+            if node.is_null() {
+                break;
+            }
+            node = (*node).next;
+        }
+        if node.is_null() {
+            return null();
+        }
+        let rest = (*node).next;
+        (*(node as *mut Node<T>)).next = null();
+        rest
+    }
+
+    /// Takes the first node out of the chain.
+    unsafe fn pop(chain: &mut *const Node<T>) -> *const Node<T> {
+        let node = *chain;
+        *chain = (*node).next;
+        (*(node as *mut Node<T>)).next = null();
+        node
+    }
+
+    /// Appends a chain to the merged nodes.
+    unsafe fn append(&mut self, chain: *const Node<T>) {
+        if chain.is_null() {
+            return;
+        }
+        if self.merged_tail.is_null() {
+            self.merged_head = chain;
+        } else {
+            (*(self.merged_tail as *mut Node<T>)).next = chain;
+        }
+        let mut tail = chain;
+        while !(*tail).next.is_null() {
+            tail = (*tail).next;
+        }
+        self.merged_tail = tail;
+    }
+}
+
+impl<T> Drop for MergeSort<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            let left = core::mem::replace(&mut self.left, null());
+            let right = core::mem::replace(&mut self.right, null());
+            let rest = core::mem::replace(&mut self.rest, null());
+            self.append(left);
+            self.append(right);
+            self.append(rest);
+
+            // Restores the `prev` pointers along the chain.
+            let mut prev = null();
+            let mut node = self.merged_head;
+            while !node.is_null() {
+                (*(node as *mut Node<T>)).prev = prev;
+                prev = node;
+                node = (*node).next;
+            }
+            self.list.head = self.merged_head;
+            self.list.tail = prev;
+        }
     }
 }
 
@@ -368,6 +748,14 @@ impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
     }
 }
 
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+// SAFETY: An Iter only reads the elements, like `&LinkedList<T>`.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
 /// A variable iterator of a linked list.
 pub struct IterMut<'a, T: 'a> {
     head: *const Node<T>,
@@ -418,19 +806,105 @@ impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
     }
 }
 
-/// A common cursor for a linked list. When the list is empty,
-/// it points to a virtual location (pointing to a node that does not actually exist).
-pub(crate) struct Cursor<'a, T: 'a> {
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+// SAFETY: An IterMut hands out each element once, like `&mut LinkedList<T>`.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+/// An iterator which removes and yields the elements of a linked list matching a predicate.
+pub struct ExtractIf<'a, T: 'a, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    cursor: CursorMut<'a, T>,
+    pred: F,
+}
+
+impl<T, F> Iterator for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            // The cursor reaches the virtual position after the tail.
+            if (self.pred)(self.cursor.current()?) {
+                return self.cursor.remove_current();
+            }
+            self.cursor.move_next();
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match self.cursor.index() {
+            Some(index) => self.cursor.list.len() - index,
+            None => 0,
+        };
+        (0, Some(remaining))
+    }
+}
+
+/// An iterator which removes and yields a range of elements of a linked list. The rest of
+/// the range is removed when it is dropped.
+pub struct Drain<'a, T: 'a> {
+    cursor: CursorMut<'a, T>,
+    remaining: usize,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.cursor.remove_current()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> FusedIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+/// A common cursor for a linked list, which is obtained by `cursor_front` or `cursor_back`
+/// of `Array` and `Object` with `list_array` and `list_object` features.
+///
+/// Besides the elements, the cursor can point to a virtual location (pointing to a node
+/// that does not actually exist) between the tail and the head of the list. Moving to the
+/// next element from the tail, or to the previous element from the head, reaches the
+/// virtual location. When the list is empty, the cursor always points to it.
+pub struct Cursor<'a, T: 'a> {
     index: usize,
     current: *const Node<T>,
     list: &'a LinkedList<T>,
 }
 
+// SAFETY: A Cursor only reads the list it borrows, like `&LinkedList<T>`.
+unsafe impl<T: Sync> Send for Cursor<'_, T> {}
+unsafe impl<T: Sync> Sync for Cursor<'_, T> {}
+
 impl<'a, T> Cursor<'a, T> {
     /// Gets the position the cursor is pointing to.
     /// If the cursor points to a virtual position, return None.
     #[inline]
-    pub(crate) fn index(&self) -> Option<usize> {
+    pub fn index(&self) -> Option<usize> {
         if self.current.is_null() {
             return None;
         }
@@ -439,7 +913,7 @@ impl<'a, T> Cursor<'a, T> {
 
     /// The cursor moves back.
     #[inline]
-    pub(crate) fn move_next(&mut self) {
+    pub fn move_next(&mut self) {
         if self.current.is_null() {
             self.current = self.list.head;
             self.index = 0;
@@ -450,9 +924,8 @@ impl<'a, T> Cursor<'a, T> {
     }
 
     /// The cursor moves forward.
-    #[cfg(feature = "list_array")]
     #[inline]
-    pub(crate) fn move_prev(&mut self) {
+    pub fn move_prev(&mut self) {
         if self.current.is_null() {
             self.current = self.list.tail;
             self.index = self.list.len().saturating_sub(1);
@@ -462,9 +935,10 @@ impl<'a, T> Cursor<'a, T> {
         }
     }
 
-    /// Gets the cursor.
+    /// Gets a reference to the current element.
+    /// If the cursor points to a virtual position, return None.
     #[inline]
-    pub(crate) fn current(&self) -> Option<&'a T> {
+    pub fn current(&self) -> Option<&'a T> {
         if self.current.is_null() {
             None
         } else {
@@ -472,6 +946,16 @@ impl<'a, T> Cursor<'a, T> {
         }
     }
 
+    /// Moves the cursor to the element at `index`, walking from the nearest one of the
+    /// head, the tail and the current position. If `index` is out of range, the cursor
+    /// moves to the virtual position and returns false.
+    #[inline]
+    pub fn seek(&mut self, index: usize) -> bool {
+        self.current = self.list.locate(self.current, self.index, index);
+        self.index = index;
+        !self.current.is_null()
+    }
+
     /// Gets a reference to the current node.
     #[inline]
     pub(crate) fn current_node(&self) -> Option<&'a Node<T>> {
@@ -489,25 +973,33 @@ impl<'a, T> Cursor<'a, T> {
     }
 }
 
-pub(crate) struct CursorMut<'a, T: 'a> {
+/// A mutable cursor for a linked list, which can insert and remove elements at its position
+/// in O(1) time. It also points to a virtual location between the tail and the head of the
+/// list, the same as `Cursor`.
+pub struct CursorMut<'a, T: 'a> {
     index: usize,
     current: *const Node<T>,
     list: &'a mut LinkedList<T>,
 }
 
+// SAFETY: A CursorMut changes the list it borrows exclusively, like `&mut LinkedList<T>`.
+unsafe impl<T: Send> Send for CursorMut<'_, T> {}
+unsafe impl<T: Sync> Sync for CursorMut<'_, T> {}
+
 impl<'a, T> CursorMut<'a, T> {
-    /// Gets the index.
+    /// Gets the position the cursor is pointing to.
+    /// If the cursor points to a virtual position, return None.
     #[inline]
-    pub(crate) fn index(&self) -> Option<usize> {
+    pub fn index(&self) -> Option<usize> {
         if self.current.is_null() {
             return None;
         }
         Some(self.index)
     }
 
-    /// The cursor moves beck.
+    /// The cursor moves back.
     #[inline]
-    pub(crate) fn move_next(&mut self) {
+    pub fn move_next(&mut self) {
         if self.current.is_null() {
             self.current = self.list.head;
             self.index = 0;
@@ -518,9 +1010,8 @@ impl<'a, T> CursorMut<'a, T> {
     }
 
     /// The cursor moves forward.
-    #[cfg(feature = "list_array")]
     #[inline]
-    pub(crate) fn move_prev(&mut self) {
+    pub fn move_prev(&mut self) {
         if self.current.is_null() {
             self.current = self.list.tail;
             self.index = self.list.len().saturating_sub(1);
@@ -531,9 +1022,9 @@ impl<'a, T> CursorMut<'a, T> {
     }
 
     /// Gets a mutable reference to the current element.
-    #[cfg(feature = "list_object")]
+    /// If the cursor points to a virtual position, return None.
     #[inline]
-    pub(crate) fn current(&mut self) -> Option<&mut T> {
+    pub fn current(&mut self) -> Option<&mut T> {
         if self.current.is_null() {
             None
         } else {
@@ -541,6 +1032,16 @@ impl<'a, T> CursorMut<'a, T> {
         }
     }
 
+    /// Moves the cursor to the element at `index`, walking from the nearest one of the
+    /// head, the tail and the current position. If `index` is out of range, the cursor
+    /// moves to the virtual position and returns false.
+    #[inline]
+    pub fn seek(&mut self, index: usize) -> bool {
+        self.current = self.list.locate(self.current, self.index, index);
+        self.index = index;
+        !self.current.is_null()
+    }
+
     /// Gets a mutable reference to the current node.
     #[inline]
     pub(crate) fn current_node(&mut self) -> Option<&'a mut Node<T>> {
@@ -555,9 +1056,10 @@ impl<'a, T> CursorMut<'a, T> {
         }
     }
 
-    /// Deletes the node to which the cursor is pointing.
+    /// Deletes the node to which the cursor is pointing and returns its element.
+    /// The cursor then points to the next node.
     #[inline]
-    pub(crate) fn remove_current(&mut self) -> Option<T> {
+    pub fn remove_current(&mut self) -> Option<T> {
         if self.current.is_null() {
             return None;
         }
@@ -566,14 +1068,69 @@ impl<'a, T> CursorMut<'a, T> {
         unsafe {
             self.current = (*unlinked_node).next;
             self.list.unlink_node(unlinked_node);
-            let unlinked_node = Box::from_raw(unlinked_node as *mut Node<T>);
+            let unlinked_node = self.list.slab.release(unlinked_node);
             Some(unlinked_node.element)
         }
     }
+
+    /// Inserts an element before the node to which the cursor is pointing.
+    /// If the cursor points to the virtual position, the element is inserted at the end.
+    pub fn insert_before(&mut self, value: T) {
+        if self.current.is_null() {
+            self.list.push_back(value);
+            return;
+        }
+
+        let mut node = Node::new(value);
+        unsafe {
+            let current = &mut *(self.current as *mut Node<T>);
+            node.prev = current.prev;
+            node.next = self.current;
+            let node = self.list.slab.alloc(node);
+
+            if current.prev.is_null() {
+                self.list.head = node;
+            } else {
+                (*(current.prev as *mut Node<T>)).next = node;
+            }
+            current.prev = node;
+        }
+        self.list.len += 1;
+        self.index += 1;
+    }
+
+    /// Inserts an element after the node to which the cursor is pointing.
+    /// If the cursor points to the virtual position, the element is inserted at the front.
+    pub fn insert_after(&mut self, value: T) {
+        let mut node = Node::new(value);
+        unsafe {
+            let next = if self.current.is_null() {
+                self.list.head
+            } else {
+                (*self.current).next
+            };
+            node.prev = self.current;
+            node.next = next;
+            let node = self.list.slab.alloc(node);
+
+            if self.current.is_null() {
+                self.list.head = node;
+            } else {
+                (*(self.current as *mut Node<T>)).next = node;
+            }
+            if next.is_null() {
+                self.list.tail = node;
+            } else {
+                (*(next as *mut Node<T>)).prev = node;
+            }
+        }
+        self.list.len += 1;
+    }
 }
 
 #[cfg(test)]
 mod ut_linked_list {
+    use crate::consts::NODE_CHUNK_SIZE;
     use crate::LinkedList;
 
     /// UT test for `LinkedList::pop_back`.
@@ -615,6 +1172,44 @@ mod ut_linked_list {
         assert_eq!(iter.next(), None);
     }
 
+    /// UT test for `Iter` and `IterMut` as double-ended and exact-size iterators.
+    ///
+    /// # Title
+    /// ut_linked_list_iter_double_ended
+    ///
+    /// # Brief
+    /// 1. Creates a `LinkedList`.
+    /// 2. Iterates over it from both ends, and calls `len` and `rposition`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_linked_list_iter_double_ended() {
+        let mut list = LinkedList::new();
+        for i in 1..=4i32 {
+            list.push_back(i);
+        }
+
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [4, 3, 2, 1]);
+        assert_eq!(list.iter().rposition(|x| *x < 3), Some(1));
+
+        let mut iter_mut = list.iter_mut();
+        assert_eq!(iter_mut.len(), 4);
+        *iter_mut.next_back().unwrap() = 40;
+        assert_eq!(iter_mut.len(), 3);
+        assert_eq!(iter_mut.rposition(|x| *x == 1), Some(0));
+        assert_eq!(list.iter_mut().rev().last(), Some(&mut 1));
+    }
+
     /// UT test for `LinkedList::back`.
     ///
     /// # Title
@@ -823,7 +1418,6 @@ mod ut_linked_list {
     /// 1. Creates a `LinkedList` and a `Cursor`.
     /// 2. Calls `Cursor::move_prev`.
     /// 3. Checks if the test results are correct.
-    #[cfg(feature = "list_array")]
     #[test]
     fn ut_cursor_move_prev() {
         let mut list = LinkedList::new();
@@ -918,7 +1512,6 @@ mod ut_linked_list {
     /// 1. Creates a `LinkedList` and a `CursorMut`.
     /// 2. Calls `CursorMut::move_prev`.
     /// 3. Checks if the test results are correct.
-    #[cfg(feature = "list_array")]
     #[test]
     fn ut_cursor_mut_move_prev() {
         let mut list = LinkedList::new();
@@ -943,7 +1536,6 @@ mod ut_linked_list {
     /// 1. Creates a `LinkedList` and a `CursorMut`.
     /// 2. Calls `CursorMut::current`.
     /// 3. Checks if the test results are correct.
-    #[cfg(feature = "list_object")]
     #[test]
     fn ut_cursor_mut_current() {
         let mut list = LinkedList::new();
@@ -974,4 +1566,339 @@ mod ut_linked_list {
         assert_eq!(cursor.remove_current(), Some(1));
         assert_eq!(cursor.remove_current(), None);
     }
+
+    /// UT test for `Cursor::seek`.
+    ///
+    /// # Title
+    /// ut_cursor_seek
+    ///
+    /// # Brief
+    /// 1. Creates a `LinkedList` and a `Cursor`.
+    /// 2. Calls `Cursor::seek` with indexes in and out of range.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_cursor_seek() {
+        let mut list = LinkedList::new();
+        for i in 0..10i32 {
+            list.push_back(i);
+        }
+
+        let mut cursor = list.cursor_front();
+        for index in [3, 8, 7, 0, 9, 5, 5] {
+            assert!(cursor.seek(index));
+            assert_eq!(cursor.index(), Some(index));
+            assert_eq!(cursor.current(), Some(&(index as i32)));
+        }
+
+        assert!(!cursor.seek(10));
+        assert_eq!(cursor.index(), None);
+        assert!(cursor.seek(1));
+        assert_eq!(cursor.current(), Some(&1));
+
+        let empty = LinkedList::<i32>::new();
+        assert!(!empty.cursor_front().seek(0));
+    }
+
+    /// UT test for `CursorMut::seek`.
+    ///
+    /// # Title
+    /// ut_cursor_mut_seek
+    ///
+    /// # Brief
+    /// 1. Creates a `LinkedList` and a `CursorMut`.
+    /// 2. Calls `CursorMut::seek` and changes the current elements.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_cursor_mut_seek() {
+        let mut list = LinkedList::new();
+        for i in 0..5i32 {
+            list.push_back(i);
+        }
+
+        let mut cursor = list.cursor_back_mut();
+        assert!(cursor.seek(1));
+        *cursor.current().unwrap() = 10;
+        assert!(cursor.seek(3));
+        *cursor.current().unwrap() = 30;
+        assert!(!cursor.seek(5));
+        assert_eq!(cursor.current(), None);
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 10, 2, 30, 4]);
+    }
+
+    /// UT test for `CursorMut::insert_before`.
+    ///
+    /// # Title
+    /// ut_cursor_mut_insert_before
+    ///
+    /// # Brief
+    /// 1. Creates a `LinkedList` and a `CursorMut`.
+    /// 2. Calls `CursorMut::insert_before` at the head, in the middle and at the virtual position.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_cursor_mut_insert_before() {
+        let mut list = LinkedList::new();
+        list.push_back(2i32);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.insert_before(0);
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(cursor.current(), Some(&mut 2));
+
+        cursor.move_prev();
+        cursor.move_next();
+        cursor.insert_before(1);
+        cursor.move_next();
+        cursor.insert_before(3);
+        assert_eq!(cursor.index(), None);
+
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [3, 2, 1, 0]);
+    }
+
+    /// UT test for `CursorMut::insert_after`.
+    ///
+    /// # Title
+    /// ut_cursor_mut_insert_after
+    ///
+    /// # Brief
+    /// 1. Creates a `LinkedList` and a `CursorMut`.
+    /// 2. Calls `CursorMut::insert_after` on an empty list, at the tail, in the middle and
+    ///    at the virtual position.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_cursor_mut_insert_after() {
+        let mut list = LinkedList::new();
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.insert_after(1i32);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+
+        cursor.insert_after(3);
+        cursor.insert_after(2);
+        assert_eq!(cursor.index(), Some(0));
+
+        assert!(cursor.seek(2));
+        cursor.move_next();
+        cursor.insert_after(0);
+
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [3, 2, 1, 0]);
+    }
+
+    /// UT test for `LinkedList::extract_if`.
+    ///
+    /// # Title
+    /// ut_linked_list_extract_if
+    ///
+    /// # Brief
+    /// 1. Creates a `LinkedList`.
+    /// 2. Calls `LinkedList::extract_if` and consumes the iterator fully or partially.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_linked_list_extract_if() {
+        let mut list = LinkedList::new();
+        for i in 0..10i32 {
+            list.push_back(i);
+        }
+
+        let mut extract = list.extract_if(|x| *x % 3 == 0);
+        assert_eq!(extract.size_hint(), (0, Some(10)));
+        let extracted: Vec<_> = extract.by_ref().collect();
+        assert_eq!(extracted, [0, 3, 6, 9]);
+        assert_eq!(extract.next(), None);
+        assert_eq!(list.len(), 6);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 4, 5, 7, 8]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            [8, 7, 5, 4, 2, 1]
+        );
+
+        // The elements after an early stop are kept, and the predicate can change elements.
+        let mut extract = list.extract_if(|x| {
+            *x *= 10;
+            *x > 30
+        });
+        assert_eq!(extract.next(), Some(40));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [10, 20, 5, 7, 8]);
+
+        assert_eq!(list.extract_if(|_| true).count(), 5);
+        assert!(list.is_empty());
+        assert_eq!(list.extract_if(|_| true).next(), None);
+    }
+
+    /// UT test for the node slab of `LinkedList`.
+    ///
+    /// # Title
+    /// ut_linked_list_node_slab
+    ///
+    /// # Brief
+    /// 1. Creates a `LinkedList`.
+    /// 2. Pushes, removes and inserts elements.
+    /// 3. Checks if the chunks grow as expected and the released nodes are reused.
+    #[test]
+    fn ut_linked_list_node_slab() {
+        let mut list = LinkedList::new();
+        for i in 0..7i32 {
+            list.push_back(i);
+        }
+        let capacities: Vec<_> = list.slab.chunks.iter().map(|c| c.capacity()).collect();
+        assert_eq!(capacities.iter().sum::<usize>(), 7);
+        assert!(capacities.windows(2).all(|w| w[0] <= w[1]));
+        let chunks = list.slab.chunks.len();
+
+        let mut cursor = list.cursor_front_mut();
+        assert!(cursor.seek(3));
+        assert_eq!(cursor.remove_current(), Some(3));
+        cursor.insert_before(30);
+        assert_eq!(list.pop_back(), Some(6));
+        list.push_back(60);
+        assert_eq!(list.slab.chunks.len(), chunks);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            [0, 1, 2, 30, 4, 5, 60]
+        );
+
+        list.push_back(7);
+        assert_eq!(list.slab.chunks.len(), chunks + 1);
+        assert!(list.slab.chunks[chunks].capacity() <= NODE_CHUNK_SIZE);
+
+        let cloned = list.clone();
+        assert_eq!(cloned, list);
+        drop(list);
+        assert_eq!(cloned.len(), 8);
+
+        let mut list = LinkedList::with_chunk_size(2);
+        for i in 0..7i32 {
+            list.push_back(i);
+        }
+        let capacities: Vec<_> = list.slab.chunks.iter().map(|c| c.capacity()).collect();
+        assert!(capacities.iter().all(|c| *c <= 2));
+        let cloned = list.clone();
+        assert_eq!(cloned.slab.max_chunk, 2);
+
+        let mut list = LinkedList::with_chunk_size(0);
+        list.push_back(0);
+        list.push_back(1);
+        assert_eq!(list.slab.chunks.len(), 2);
+    }
+
+    /// UT test for `LinkedList::sort_by`.
+    ///
+    /// # Title
+    /// ut_linked_list_sort_by
+    ///
+    /// # Brief
+    /// 1. Creates some `LinkedList`s.
+    /// 2. Calls `LinkedList::sort_by` on them, with a comparator which panics for one.
+    /// 3. Checks if the elements are sorted stably and the nodes are not moved.
+    #[test]
+    fn ut_linked_list_sort_by() {
+        let mut empty = LinkedList::new();
+        empty.sort_by(|a: &i32, b| a.cmp(b));
+        assert!(empty.is_empty());
+
+        let mut list = LinkedList::new();
+        for key in [5, 3, 9, 3, 1, 5, 8, 0, 3, 7, 2] {
+            list.push_back((key, list.len()));
+        }
+        let first = list.iter().next().unwrap() as *const (i32, usize);
+        list.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            [
+                (0, 7),
+                (1, 4),
+                (2, 10),
+                (3, 1),
+                (3, 3),
+                (3, 8),
+                (5, 0),
+                (5, 5),
+                (7, 9),
+                (8, 6),
+                (9, 2)
+            ]
+        );
+        assert_eq!(list.iter().nth(6).unwrap() as *const _, first);
+        assert_eq!(list.iter().next_back(), Some(&(9, 2)));
+
+        list.sort_by(|a, b| b.cmp(a));
+        assert_eq!(list.iter().next(), Some(&(9, 2)));
+        assert_eq!(
+            list.iter().rev().map(|e| e.0).collect::<Vec<_>>(),
+            [0, 1, 2, 3, 3, 3, 5, 5, 7, 8, 9]
+        );
+
+        let mut count = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                count += 1;
+                assert!(count < 10);
+                a.cmp(b)
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(list.len(), 11);
+        assert_eq!(list.iter().count(), 11);
+        assert_eq!(list.iter().rev().count(), 11);
+        list.sort_by(|a, b| a.cmp(b));
+        assert_eq!(list.iter().next(), Some(&(0, 7)));
+    }
+
+    /// UT test for `Send` and `Sync` of `LinkedList` and its iterators and cursors.
+    ///
+    /// # Title
+    /// ut_linked_list_send_sync
+    ///
+    /// # Brief
+    /// 1. Checks if the types are `Send` and `Sync` at compile time.
+    /// 2. Moves a list to another thread and changes it there.
+    /// 3. Shares a list between threads and reads it.
+    #[test]
+    fn ut_linked_list_send_sync() {
+        use crate::{Cursor, CursorMut, Drain, ExtractIf, Iter, IterMut};
+        use std::sync::Arc;
+        use std::thread;
+
+        fn check<T: Send + Sync>() {}
+        check::<LinkedList<String>>();
+        check::<Iter<'_, String>>();
+        check::<IterMut<'_, String>>();
+        check::<Cursor<'_, String>>();
+        check::<CursorMut<'_, String>>();
+        check::<Drain<'_, String>>();
+        check::<ExtractIf<'_, String, fn(&mut String) -> bool>>();
+
+        let mut list = LinkedList::new();
+        for i in 0..100 {
+            list.push_back(i.to_string());
+        }
+        let mut list = thread::spawn(move || {
+            list.cursor_front_mut().remove_current();
+            list.push_back(String::from("100"));
+            list
+        })
+        .join()
+        .unwrap();
+        list.push_back(String::from("101"));
+
+        let list = Arc::new(list);
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let list = list.clone();
+                thread::spawn(move || {
+                    list.iter()
+                        .map(|s| s.parse::<usize>().unwrap())
+                        .sum::<usize>()
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), (1..=101).sum::<usize>());
+        }
+    }
 }
@@ -0,0 +1,284 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parallel parsing of a top-level array.
+//!
+//! The text is first scanned once to find the top-level commas. This scan only
+//! tracks strings and bracket depth, and does not check anything else. The
+//! elements are then split into contiguous groups, and every group is parsed
+//! on its own thread. If the text is not a top-level array, or any element
+//! fails to parse, the whole text is parsed again on the current thread, so
+//! the result and the error are the same as `JsonValue::from_text`.
+//!
+//! Every element is parsed with the given `ParseConfig`. The limits on the
+//! whole text, which are the length of the text, the length of the top-level
+//! array and the allocated bytes in total, are checked by this module.
+//!
+//! The groups run on scoped threads of `std` rather than on a thread pool such
+//! as `rayon`. The number of threads is given by the caller for every call, and
+//! each thread parses one large group, so a pool would save little but add a
+//! dependency to the crate.
+
+use crate::consts::{
+    COMMA, LEFT_CURLY_BRACKET, LEFT_SQUARE_BRACKET, QUOTATION_MARK, RIGHT_CURLY_BRACKET,
+    RIGHT_SQUARE_BRACKET,
+};
+use crate::deserializer::Deserializer;
+use crate::scan::{skip_string, whitespace_len};
+use crate::{start_parsing, Array, Error, JsonValue, ParseConfig};
+use core::mem::size_of;
+use core::ops::Range;
+use std::panic::resume_unwind;
+use std::thread;
+
+pub(crate) fn parse_parallel(
+    slice: &[u8],
+    threads: usize,
+    config: ParseConfig,
+) -> Result<JsonValue, Error> {
+    // The top-level array is the first level of nesting.
+    if threads > 1 && config.max_depth > 0 && slice.len() <= config.max_document_bytes {
+        if let Some(elements) = split_elements(slice) {
+            if elements.len() <= config.max_array_len {
+                if let Some(array) = parse_elements(slice, &elements, threads, config) {
+                    return Ok(JsonValue::Array(array));
+                }
+            }
+        }
+    }
+    start_parsing(&mut Deserializer::new_from_slice(slice).with_config(config))
+}
+
+// Parses the elements on at most `threads` threads. Returns `None` if any element fails,
+// or if the allocated bytes of all elements exceed the limit.
+fn parse_elements(
+    slice: &[u8],
+    elements: &[Range<usize>],
+    threads: usize,
+    config: ParseConfig,
+) -> Option<Array> {
+    let group_len = elements.len().div_ceil(threads).max(1);
+    let groups = thread::scope(|scope| {
+        let handles: Vec<_> = elements
+            .chunks(group_len)
+            .map(|group| {
+                scope.spawn(move || {
+                    group
+                        .iter()
+                        .map(|range| parse_element(&slice[range.clone()], config).ok())
+                        .collect::<Option<Vec<_>>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap_or_else(|e| resume_unwind(e)))
+            .collect::<Option<Vec<_>>>()
+    })?;
+
+    // Every element of the top-level array is counted as `parse_array` does.
+    let allocated = groups.iter().flatten().fold(0usize, |sum, (_, allocated)| {
+        sum.saturating_add(*allocated)
+            .saturating_add(size_of::<JsonValue>())
+    });
+    if allocated > config.max_alloc_bytes {
+        return None;
+    }
+
+    #[cfg(feature = "list_array")]
+    let mut array = Array::with_node_chunk_size(config.node_chunk_size);
+    #[cfg(not(feature = "list_array"))]
+    let mut array = Array::new();
+    for (value, _) in groups.into_iter().flatten() {
+        array.push(value);
+    }
+    Some(array)
+}
+
+// Parses an element, and returns it with the bytes allocated for it.
+fn parse_element(slice: &[u8], config: ParseConfig) -> Result<(JsonValue, usize), Error> {
+    let mut deserializer = Deserializer::new_from_slice(slice).with_config(config);
+    // Elements are already inside the top-level array.
+    deserializer.recursion_depth = 1;
+    let value = start_parsing(&mut deserializer)?;
+    Ok((value, deserializer.allocated))
+}
+
+// Finds the ranges of the top-level array elements. Returns `None` if the text is
+// not a top-level array. The elements themselves are not checked here.
+fn split_elements(slice: &[u8]) -> Option<Vec<Range<usize>>> {
+    let mut pos = whitespace_len(slice);
+    if slice.get(pos) != Some(&LEFT_SQUARE_BRACKET) {
+        return None;
+    }
+    pos += 1;
+
+    let mut elements = Vec::new();
+    let mut start = pos;
+    let mut depth = 1usize;
+    while pos < slice.len() {
+        match slice[pos] {
+            QUOTATION_MARK => pos = skip_string(slice, pos + 1)?,
+            LEFT_SQUARE_BRACKET | LEFT_CURLY_BRACKET => depth += 1,
+            RIGHT_SQUARE_BRACKET | RIGHT_CURLY_BRACKET => {
+                depth -= 1;
+                if depth == 0 {
+                    if slice[pos] != RIGHT_SQUARE_BRACKET {
+                        return None;
+                    }
+                    // `[]` has no elements, while the empty last element of `[1,]` is kept
+                    // so that it fails to parse.
+                    if !elements.is_empty() || start + whitespace_len(&slice[start..pos]) != pos {
+                        elements.push(start..pos);
+                    }
+                    pos += 1;
+                    return (pos + whitespace_len(&slice[pos..]) == slice.len())
+                        .then_some(elements);
+                }
+            }
+            COMMA if depth == 1 => {
+                elements.push(start..pos);
+                start = pos + 1;
+            }
+            _ => {}
+        }
+        pos += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod ut_parallel {
+    use crate::parallel::{parse_parallel, split_elements};
+    use crate::{JsonValue, ParseConfig};
+
+    /// UT test for `split_elements`.
+    ///
+    /// # Title
+    /// ut_split_elements
+    ///
+    /// # Brief
+    /// 1. Creates some texts.
+    /// 2. Calls `split_elements`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_split_elements() {
+        assert_eq!(split_elements(b" [ ] "), Some(vec![]));
+        assert_eq!(split_elements(b"[1, 2]"), Some(vec![1..2, 3..5]));
+        assert_eq!(
+            split_elements(br#"[1, "a,\"]", [2, {"b": 3}] ]"#),
+            Some(vec![1..2, 3..11, 12..27])
+        );
+        assert_eq!(split_elements(b"[1,]"), Some(vec![1..2, 3..3]));
+        assert_eq!(split_elements(b"{}"), None);
+        assert_eq!(split_elements(b"[1}"), None);
+        assert_eq!(split_elements(b"[1] 2"), None);
+        assert_eq!(split_elements(b"[[1]"), None);
+        assert_eq!(split_elements(br#"["\"]"#), None);
+    }
+
+    /// UT test for `parse_parallel`.
+    ///
+    /// # Title
+    /// ut_parse_parallel
+    ///
+    /// # Brief
+    /// 1. Creates some texts.
+    /// 2. Calls `parse_parallel` with different numbers of threads.
+    /// 3. Checks if the results are the same as `JsonValue::from_text`.
+    #[test]
+    fn ut_parse_parallel() {
+        let texts = [
+            r#"[]"#,
+            r#"[1, "a,\"]", [2, {"b": 3}], null, true, 1.5e3]"#,
+            r#"{"key": [1, 2]}"#,
+            r#"[1, 2,]"#,
+            r#"[1 2]"#,
+            r#"[1, {"a": ]}]"#,
+        ];
+        for text in texts {
+            let expected = JsonValue::from_text(text).map_err(|e| e.to_string());
+            for threads in [0, 1, 2, 3, 8] {
+                let value = parse_parallel(text.as_bytes(), threads, ParseConfig::default())
+                    .map_err(|e| e.to_string());
+                assert_eq!(value, expected);
+            }
+        }
+
+        // The recursion limit counts the top-level array.
+        for depth in [127, 128, 129] {
+            let element = format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+            let text = format!("[1, {element}, 2]");
+            let expected = JsonValue::from_text(&text).map_err(|e| e.to_string());
+            let value = parse_parallel(text.as_bytes(), 2, ParseConfig::default())
+                .map_err(|e| e.to_string());
+            assert_eq!(value, expected);
+        }
+    }
+    /// UT test for `parse_parallel` with a `ParseConfig`.
+    ///
+    /// # Title
+    /// ut_parse_parallel_with_config
+    ///
+    /// # Brief
+    /// 1. Creates some texts and configs.
+    /// 2. Calls `parse_parallel` with the configs.
+    /// 3. Checks if the results are the same as `JsonValue::from_text_with_config`.
+    #[test]
+    fn ut_parse_parallel_with_config() {
+        let text = r#"[1, "abcdef", [2, 3], {"a": 4, "b": +5}, 06]"#;
+        let lenient = ParseConfig::default()
+            .allow_leading_plus(true)
+            .allow_leading_zeros(true);
+        let configs = [
+            ParseConfig::default(),
+            lenient,
+            lenient.max_depth(0),
+            lenient.max_depth(1),
+            lenient.max_depth(2),
+            lenient.max_document_bytes(text.len() - 1),
+            lenient.max_string_bytes(5),
+            lenient.max_array_len(4),
+            lenient.max_array_len(5),
+            lenient.max_object_members(1),
+            lenient.max_alloc_bytes(64),
+            lenient.max_alloc_bytes(1024),
+            lenient.max_alloc_bytes(usize::MAX),
+        ];
+        for config in configs {
+            let expected =
+                JsonValue::from_text_with_config(text, config).map_err(|e| e.to_string());
+            for threads in [1, 2, 5] {
+                let value =
+                    parse_parallel(text.as_bytes(), threads, config).map_err(|e| e.to_string());
+                assert_eq!(value, expected);
+            }
+        }
+
+        // The allocated bytes are counted over all elements.
+        let text = format!("[{}]", vec![r#""abcdefgh""#; 64].join(", "));
+        let value = JsonValue::from_text(&text).unwrap();
+        for max in [0, 64 * 8, usize::MAX] {
+            let config = ParseConfig::default().max_alloc_bytes(max);
+            let expected =
+                JsonValue::from_text_with_config(&text, config).map_err(|e| e.to_string());
+            let result = parse_parallel(text.as_bytes(), 4, config).map_err(|e| e.to_string());
+            assert_eq!(result, expected);
+            if max == usize::MAX {
+                assert_eq!(result, Ok(value.clone()));
+            } else {
+                assert!(result.is_err());
+            }
+        }
+    }
+}
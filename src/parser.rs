@@ -0,0 +1,203 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::deserializer::Deserializer;
+use crate::reader::{BytesReader, SliceReader};
+use crate::states::{check_document, read_error_char};
+use crate::{start_parsing, BytesFormat, Error, JsonValue, ParseConfig, ParseError};
+use alloc::vec::Vec;
+use core::mem::take;
+use serde::Deserialize;
+
+/// A reusable parser for many small JSON texts.
+///
+/// `JsonValue::from_text` and `from_slice` create a new deserializer for every
+/// call. A `Parser` keeps the buffer used for decoding strings with escape
+/// characters between calls, so it only needs to grow to the longest such
+/// string once. The parsed values still own their strings, so they are
+/// allocated as usual.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{JsonValue, Parser};
+///
+/// let mut parser = Parser::new();
+/// for text in [r#"{"key": "a\tb"}"#, r#"{"key": "c\td"}"#] {
+///     let value = parser.parse_next(text.as_bytes()).unwrap();
+///     assert!(value["key"].try_as_string().is_ok());
+/// }
+/// ```
+#[derive(Default)]
+pub struct Parser {
+    scratch: Vec<u8>,
+    bytes_format: BytesFormat,
+    config: ParseConfig,
+}
+
+impl Parser {
+    /// Creates a new Parser.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Parser;
+    ///
+    /// let parser = Parser::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the representation of byte slices expected by `deserialize_bytes`
+    /// in `Parser::deserialize_next`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Base64Alphabet, BytesFormat, Parser};
+    ///
+    /// let parser = Parser::new().with_bytes_format(BytesFormat::Base64(Base64Alphabet::Standard));
+    /// ```
+    pub fn with_bytes_format(mut self, format: BytesFormat) -> Self {
+        self.bytes_format = format;
+        self
+    }
+
+    /// Sets the limits applied while parsing by both `Parser::parse_next` and
+    /// `Parser::deserialize_next`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Error, ParseConfig, Parser};
+    ///
+    /// let mut parser = Parser::new().with_config(ParseConfig::strict());
+    /// let text = "[".repeat(33) + &"]".repeat(33);
+    /// assert!(matches!(parser.parse_next(text.as_bytes()), Err(Error::ExceedRecursionLimit)));
+    /// ```
+    pub fn with_config(mut self, config: ParseConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Parses a JSON text into a JsonValue, the same as `JsonValue::from_text`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Parser};
+    ///
+    /// let mut parser = Parser::new();
+    /// let value = parser.parse_next(br#"{"key": "value"}"#).unwrap();
+    /// assert_eq!(value["key"], "value".into());
+    /// ```
+    pub fn parse_next(&mut self, slice: &[u8]) -> Result<JsonValue, Error> {
+        self.with_deserializer(slice, start_parsing)
+    }
+
+    /// Deserializes a JSON text into an instance of type `T`, the same as
+    /// `from_slice_with_bytes_format` with the bytes format of this Parser.
+    ///
+    /// # Examples
+    /// ```
+    /// use serde::Deserialize;
+    /// use ylong_json::Parser;
+    ///
+    /// #[derive(Deserialize, PartialEq, Debug)]
+    /// struct Test {
+    ///     int: u32,
+    ///     seq: Vec<String>,
+    /// }
+    ///
+    /// let mut parser = Parser::new();
+    /// let test: Test = parser.deserialize_next(br#"{"int":1,"seq":["a\nb"]}"#).unwrap();
+    /// assert_eq!(test, Test { int: 1, seq: vec![String::from("a\nb")] });
+    /// ```
+    pub fn deserialize_next<'de, T>(&mut self, slice: &'de [u8]) -> Result<T, Error>
+    where
+        T: Deserialize<'de>,
+    {
+        self.with_deserializer(slice, |deserializer| {
+            let t = T::deserialize(&mut *deserializer)?;
+            match eat_whitespace_until_not!(deserializer) {
+                None => Ok(t),
+                _ => unexpected_character!(deserializer),
+            }
+        })
+    }
+
+    // Runs `f` with a deserializer which borrows the scratch buffer of this Parser.
+    fn with_deserializer<'de, T>(
+        &mut self,
+        slice: &'de [u8],
+        f: impl FnOnce(&mut Deserializer<SliceReader<'de>>) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let mut deserializer = Deserializer::new_from_slice(slice)
+            .with_bytes_format(self.bytes_format)
+            .with_config(self.config);
+        deserializer.scratch = take(&mut self.scratch);
+        let result = f(&mut deserializer);
+        self.scratch = deserializer.scratch;
+        result
+    }
+}
+
+#[cfg(test)]
+mod ut_parser {
+    use crate::{from_slice, JsonValue, Parser};
+
+    /// UT test for `Parser::parse_next`.
+    ///
+    /// # Title
+    /// ut_parser_parse_next
+    ///
+    /// # Brief
+    /// 1. Creates a `Parser`.
+    /// 2. Calls `Parser::parse_next` on several texts, including wrong ones.
+    /// 3. Checks if the results are the same as `JsonValue::from_text`.
+    #[test]
+    fn ut_parser_parse_next() {
+        let mut parser = Parser::new();
+        let texts = [
+            r#"{"key": "a\"b中", "array": ["\n", "plain"]}"#,
+            r#""unterminated\n"#,
+            r#"["\\", "😀"]"#,
+            r#"[1] 2"#,
+        ];
+        for text in texts {
+            let expected = JsonValue::from_text(text).map_err(|e| e.to_string());
+            let value = parser
+                .parse_next(text.as_bytes())
+                .map_err(|e| e.to_string());
+            assert_eq!(value, expected);
+        }
+    }
+
+    /// UT test for `Parser::deserialize_next`.
+    ///
+    /// # Title
+    /// ut_parser_deserialize_next
+    ///
+    /// # Brief
+    /// 1. Creates a `Parser`.
+    /// 2. Calls `Parser::deserialize_next` on several texts, including wrong ones.
+    /// 3. Checks if the results are the same as `from_slice`.
+    #[test]
+    fn ut_parser_deserialize_next() {
+        let mut parser = Parser::new();
+        let texts = [r#"["a\tb", "c"]"#, r#"["a\qb"]"#, r#"["a"] ,"#];
+        for text in texts {
+            let expected = from_slice::<Vec<String>>(text.as_bytes()).map_err(|e| e.to_string());
+            let value = parser
+                .deserialize_next::<Vec<String>>(text.as_bytes())
+                .map_err(|e| e.to_string());
+            assert_eq!(value, expected);
+        }
+    }
+}
@@ -0,0 +1,619 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::value::{pointer_index, pointer_step, unescape_pointer_token};
+use crate::{Error, JsonValue};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter, Result as FmtResult, Write};
+use core::str::FromStr;
+
+/// A JSON Pointer (RFC 6901) which is built segment by segment, such as "/a/b~1c/0".
+///
+/// The segments are stored unescaped, and '~' and '/' in them are escaped as "~0" and
+/// "~1" only when the pointer is displayed, so that keys of any content can be pushed.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{JsonPointer, JsonValue};
+///
+/// let mut pointer = JsonPointer::new();
+/// pointer.push("a/b");
+/// pointer.push_index(1);
+/// assert_eq!(pointer.to_string(), "/a~1b/1");
+///
+/// let value = JsonValue::from_text(r#"{"a/b": [1, 2]}"#).unwrap();
+/// assert_eq!(*pointer.resolve(&value).unwrap(), 2.into());
+///
+/// let pointer: JsonPointer = "#/a~1b/0".parse().unwrap();
+/// assert_eq!(pointer.segments().collect::<Vec<_>>(), ["a/b", "0"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct JsonPointer {
+    segments: Vec<String>,
+}
+
+impl JsonPointer {
+    /// Creates a JsonPointer to the root, which is displayed as "".
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonPointer;
+    ///
+    /// let pointer = JsonPointer::new();
+    /// assert!(pointer.is_root());
+    /// assert_eq!(pointer.to_string(), "");
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a JSON Pointer, such as "/a/b", or its URI fragment, such as "#/a/b"
+    /// where the characters may be percent-encoded.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonPointer;
+    ///
+    /// let pointer = JsonPointer::parse("/a~0b/c d").unwrap();
+    /// assert_eq!(pointer.segments().collect::<Vec<_>>(), ["a~b", "c d"]);
+    /// let pointer = JsonPointer::parse("#/a~0b/c%20d").unwrap();
+    /// assert_eq!(pointer.segments().collect::<Vec<_>>(), ["a~b", "c d"]);
+    ///
+    /// assert!(JsonPointer::parse("a/b").is_err());
+    /// assert!(JsonPointer::parse("/a~2").is_err());
+    /// assert!(JsonPointer::parse("#/a%2").is_err());
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidPointer(String::from(text));
+        let decoded = match text.strip_prefix('#') {
+            Some(fragment) => Cow::Owned(percent_decode(fragment).ok_or_else(invalid)?),
+            None => Cow::Borrowed(text),
+        };
+        let mut pointer = Self::new();
+        if decoded.is_empty() {
+            return Ok(pointer);
+        }
+        for token in decoded.strip_prefix('/').ok_or_else(invalid)?.split('/') {
+            let segment = unescape_pointer_token(token).ok_or_else(invalid)?;
+            pointer.segments.push(segment.into_owned());
+        }
+        Ok(pointer)
+    }
+
+    /// Appends a segment, which is the key of an Object member or the index of an
+    /// Array element. It is escaped when the pointer is displayed.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonPointer;
+    ///
+    /// let mut pointer = JsonPointer::new();
+    /// pointer.push("~a").push("b/c");
+    /// assert_eq!(pointer.to_string(), "/~0a/b~1c");
+    /// ```
+    pub fn push<S: Into<String>>(&mut self, segment: S) -> &mut Self {
+        self.segments.push(segment.into());
+        self
+    }
+
+    /// Appends the index of an Array element as a segment.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonPointer;
+    ///
+    /// let mut pointer = JsonPointer::new();
+    /// pointer.push("a").push_index(0);
+    /// assert_eq!(pointer.to_string(), "/a/0");
+    /// ```
+    pub fn push_index(&mut self, index: usize) -> &mut Self {
+        self.push(index.to_string())
+    }
+
+    /// Removes the last segment and returns it, or `None` if it points to the root.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonPointer;
+    ///
+    /// let mut pointer = JsonPointer::parse("/a/b").unwrap();
+    /// assert_eq!(pointer.pop().unwrap(), "b");
+    /// assert_eq!(pointer.to_string(), "/a");
+    /// ```
+    pub fn pop(&mut self) -> Option<String> {
+        self.segments.pop()
+    }
+
+    /// Gets the unescaped segments in order.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonPointer;
+    ///
+    /// let pointer = JsonPointer::parse("/a~1b/0").unwrap();
+    /// assert_eq!(pointer.segments().collect::<Vec<_>>(), ["a/b", "0"]);
+    /// ```
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().map(String::as_str)
+    }
+
+    /// Checks whether it points to the root, which means it has no segments.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonPointer;
+    ///
+    /// assert!(JsonPointer::parse("").unwrap().is_root());
+    /// assert!(!JsonPointer::parse("/").unwrap().is_root());
+    /// ```
+    pub fn is_root(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Gets the value which it points to, the same as `JsonValue::pointer`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonPointer, JsonValue};
+    ///
+    /// let value = JsonValue::from_text(r#"{"a": [1, 2]}"#).unwrap();
+    /// let pointer = JsonPointer::parse("/a/0").unwrap();
+    /// assert_eq!(*pointer.resolve(&value).unwrap(), 1.into());
+    /// ```
+    pub fn resolve<'a>(&self, value: &'a JsonValue) -> Option<&'a JsonValue> {
+        self.segments
+            .iter()
+            .try_fold(value, |value, segment| pointer_step(value, segment))
+    }
+
+    /// Gets the URI fragment of it, such as "#/a%20b", where the characters which are
+    /// not allowed in URI fragments are percent-encoded.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonPointer;
+    ///
+    /// let mut pointer = JsonPointer::new();
+    /// pointer.push("a b").push("%");
+    /// assert_eq!(pointer.to_uri_fragment(), "#/a%20b/%25");
+    /// ```
+    pub fn to_uri_fragment(&self) -> String {
+        let mut fragment = String::from("#");
+        for byte in self.to_string().bytes() {
+            if byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@/?".contains(&byte) {
+                fragment.push(char::from(byte));
+            } else {
+                let _ = write!(fragment, "%{byte:02X}");
+            }
+        }
+        fragment
+    }
+}
+
+impl Display for JsonPointer {
+    /// Writes the JSON Pointer, where '~' and '/' in the segments are escaped.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for segment in &self.segments {
+            f.write_char('/')?;
+            for ch in segment.chars() {
+                match ch {
+                    '~' => f.write_str("~0")?,
+                    '/' => f.write_str("~1")?,
+                    ch => f.write_char(ch)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for JsonPointer {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Decodes the "%XX" sequences in a URI fragment, returning `None` if they are
+/// malformed or the decoded bytes are not UTF-8.
+fn percent_decode(fragment: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(fragment.len());
+    let mut iter = fragment.bytes();
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let high = char::from(iter.next()?).to_digit(16)?;
+            let low = char::from(iter.next()?).to_digit(16)?;
+            bytes.push((high * 16 + low) as u8);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// The result of evaluating a Relative JSON Pointer by `JsonValue::relative_pointer`.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{JsonValue, RelativeTarget};
+///
+/// let value = JsonValue::from_text(r#"{"a": [1, 2]}"#).unwrap();
+/// match value.relative_pointer("/a/0", "0+1").unwrap() {
+///     RelativeTarget::Value(value) => assert_eq!(*value, 2.into()),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum RelativeTarget<'a> {
+    /// The value referenced by a pointer which ends with a JSON Pointer.
+    Value(&'a JsonValue),
+    /// The key of the Object member referenced by a pointer which ends with "#".
+    Key(String),
+    /// The index of the Array element referenced by a pointer which ends with "#".
+    Index(usize),
+}
+
+/// An iterator over every value in a JsonValue with its JSON Pointer, created by
+/// `JsonValue::iter_paths`.
+///
+/// The values are yielded in depth-first order, where an array or object comes before
+/// its elements or members.
+pub struct PathIter<'a> {
+    root: Option<&'a JsonValue>,
+    // The children of the arrays and objects being visited, from the outermost one.
+    frames: Vec<Children<'a>>,
+    // The pointer to the array or object of the last frame.
+    pointer: JsonPointer,
+}
+
+type Children<'a> = Box<dyn Iterator<Item = (String, &'a JsonValue)> + 'a>;
+
+impl<'a> PathIter<'a> {
+    pub(crate) fn new(root: &'a JsonValue) -> Self {
+        Self {
+            root: Some(root),
+            frames: Vec::new(),
+            pointer: JsonPointer::new(),
+        }
+    }
+
+    /// Starts visiting the children of `value` if it is an array or object.
+    fn enter(&mut self, value: &'a JsonValue) -> bool {
+        let children: Children<'a> = match value {
+            JsonValue::Array(array) => Box::new(
+                array
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| (i.to_string(), value)),
+            ),
+            JsonValue::Object(object) => Box::new(
+                object
+                    .iter()
+                    .map(|(key, value)| (String::from(key.as_str()), value)),
+            ),
+            _ => return false,
+        };
+        self.frames.push(children);
+        true
+    }
+}
+
+impl<'a> Iterator for PathIter<'a> {
+    type Item = (JsonPointer, &'a JsonValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(root) = self.root.take() {
+            self.enter(root);
+            return Some((JsonPointer::new(), root));
+        }
+        loop {
+            match self.frames.last_mut()?.next() {
+                Some((segment, value)) => {
+                    self.pointer.push(segment);
+                    let pointer = self.pointer.clone();
+                    if !self.enter(value) {
+                        self.pointer.pop();
+                    }
+                    return Some((pointer, value));
+                }
+                None => {
+                    self.frames.pop();
+                    self.pointer.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Evaluates the Relative JSON Pointer `relative` at the location `current` of `root`.
+pub(crate) fn resolve_relative<'a>(
+    root: &'a JsonValue,
+    current: &str,
+    relative: &str,
+) -> Option<RelativeTarget<'a>> {
+    // The values from the root to the current location, and the tokens leading to them.
+    let mut values = vec![root];
+    let mut tokens: Vec<Cow<str>> = Vec::new();
+    if !current.is_empty() {
+        for token in current.strip_prefix('/')?.split('/') {
+            let token = unescape_pointer_token(token)?;
+            values.push(pointer_step(values.last()?, &token)?);
+            tokens.push(token);
+        }
+    }
+
+    let (up, rest) = split_integer(relative)?;
+    let depth = tokens.len().checked_sub(up)?;
+    values.truncate(depth + 1);
+    tokens.truncate(depth);
+
+    let rest = match rest.as_bytes().first() {
+        Some(&sign @ (b'+' | b'-')) => {
+            let (offset, rest) = split_integer(&rest[1..])?;
+            let JsonValue::Array(array) = values.get(values.len().checked_sub(2)?)? else {
+                return None;
+            };
+            let index = pointer_index(tokens.last()?)??;
+            let index = match sign {
+                b'+' => index.checked_add(offset)?,
+                _ => index.checked_sub(offset)?,
+            };
+            *values.last_mut()? = array.get(index)?;
+            *tokens.last_mut()? = Cow::Owned(index.to_string());
+            rest
+        }
+        _ => rest,
+    };
+
+    if rest == "#" {
+        // The root has neither a key nor an index.
+        let token = tokens.pop()?;
+        return match values[values.len() - 2] {
+            JsonValue::Array(_) => pointer_index(&token)?.map(RelativeTarget::Index),
+            _ => Some(RelativeTarget::Key(token.into_owned())),
+        };
+    }
+    values.last()?.pointer(rest).map(RelativeTarget::Value)
+}
+
+/// Resolves the JSON Pointers in `pointers` from `root`, where malformed pointers are
+/// resolved to `None`.
+pub(crate) fn select_pointers<'a>(
+    root: &'a JsonValue,
+    pointers: &[&str],
+) -> Vec<Option<&'a JsonValue>> {
+    let mut results = vec![None; pointers.len()];
+    let mut parsed: Vec<(Vec<Cow<str>>, usize)> = pointers
+        .iter()
+        .enumerate()
+        .filter_map(|(i, pointer)| Some((split_pointer(pointer)?, i)))
+        .collect();
+    // After sorting, a pointer shares the longest common prefix with the previous one,
+    // and the values along the prefix are not looked up again.
+    parsed.sort_unstable();
+
+    let mut values = vec![Some(root)];
+    let mut previous: &[Cow<str>] = &[];
+    for (tokens, i) in &parsed {
+        let common = previous
+            .iter()
+            .zip(tokens)
+            .take_while(|(a, b)| a == b)
+            .count();
+        values.truncate(common + 1);
+        for token in &tokens[common..] {
+            let value = values.last().copied().flatten();
+            values.push(value.and_then(|value| pointer_step(value, token)));
+        }
+        results[*i] = values.last().copied().flatten();
+        previous = tokens;
+    }
+    results
+}
+
+/// Splits a JSON Pointer into the unescaped reference tokens.
+fn split_pointer(pointer: &str) -> Option<Vec<Cow<'_, str>>> {
+    if pointer.is_empty() {
+        return Some(Vec::new());
+    }
+    pointer
+        .strip_prefix('/')?
+        .split('/')
+        .map(unescape_pointer_token)
+        .collect()
+}
+
+/// Splits a non-negative integer without leading zeros from the start of the text.
+fn split_integer(text: &str) -> Option<(usize, &str)> {
+    let len = text.bytes().take_while(u8::is_ascii_digit).count();
+    let (digits, rest) = text.split_at(len);
+    if digits.is_empty() || (digits.len() > 1 && digits.starts_with('0')) {
+        return None;
+    }
+    Some((digits.parse().ok()?, rest))
+}
+
+#[cfg(test)]
+mod ut_pointer {
+    use crate::{Error, JsonPointer, JsonValue, RelativeTarget};
+
+    /// UT test for building, parsing and displaying `JsonPointer`.
+    ///
+    /// # Title
+    /// ut_json_pointer
+    ///
+    /// # Brief
+    /// 1. Builds some `JsonPointer`s with special characters and displays them.
+    /// 2. Parses the displayed pointers and URI fragments back.
+    /// 3. Parses some malformed pointers.
+    /// 4. Checks if the test results are correct.
+    #[test]
+    fn ut_json_pointer() {
+        let mut pointer = JsonPointer::new();
+        pointer
+            .push("a/b")
+            .push("~1")
+            .push("")
+            .push("é ?")
+            .push_index(3);
+        assert_eq!(pointer.to_string(), "/a~1b/~01//é ?/3");
+        assert_eq!(pointer.to_uri_fragment(), "#/a~1b/~01//%C3%A9%20?/3");
+        assert_eq!(JsonPointer::parse(&pointer.to_string()).unwrap(), pointer);
+        assert_eq!(
+            JsonPointer::parse(&pointer.to_uri_fragment()).unwrap(),
+            pointer
+        );
+
+        let text = r#"{"a/b": {"~1": {"": {"é ?": [0, 1, 2, 3]}}}}"#;
+        let value = JsonValue::from_text(text).unwrap();
+        assert_eq!(*pointer.resolve(&value).unwrap(), 3.into());
+        assert_eq!(value.pointer(&pointer.to_string()), pointer.resolve(&value));
+        assert_eq!(pointer.pop().unwrap(), "3");
+        pointer.push("4");
+        assert!(pointer.resolve(&value).is_none());
+
+        assert!(JsonPointer::parse("#").unwrap().is_root());
+        assert_eq!(JsonPointer::parse("#/%7e0").unwrap().to_string(), "/~0");
+        for text in ["a", "/~", "/~a", "#a", "#/%", "#/%zz", "#/%ff"] {
+            let error = text.parse::<JsonPointer>().unwrap_err();
+            assert!(matches!(error, Error::InvalidPointer(ref s) if s == text));
+        }
+    }
+
+    /// UT test for `JsonValue::select_pointers`.
+    ///
+    /// # Title
+    /// ut_select_pointers
+    ///
+    /// # Brief
+    /// 1. Selects some pointers with common prefixes, duplicates and missing values.
+    /// 2. Checks if the results are the same as `JsonValue::pointer` in the same order.
+    #[test]
+    fn ut_select_pointers() {
+        let text = r#"{"a": {"b": [1, {"c": 2}], "b~": 3}, "d": null, "": 4}"#;
+        let value = JsonValue::from_text(text).unwrap();
+        let pointers = [
+            "/a/b/1/c", "/d", "/a/b/0", "/a/b~0", "", "/a/b/1/c", "/a/x/0", "/a/b/2", "/", "a",
+            "/a/b/01", "/a/b/1", "/d/0", "/a~",
+        ];
+        let results = value.select_pointers(&pointers);
+        assert_eq!(results.len(), pointers.len());
+        for (pointer, result) in pointers.iter().zip(results) {
+            assert_eq!(result, value.pointer(pointer), "{pointer}");
+        }
+        assert_eq!(*value.select_pointers(&["/a/b/1/c"])[0].unwrap(), 2.into());
+        assert!(value.select_pointers(&[]).is_empty());
+    }
+
+    /// UT test for `JsonValue::iter_paths`.
+    ///
+    /// # Title
+    /// ut_iter_paths
+    ///
+    /// # Brief
+    /// 1. Iterates a nested value with empty containers and special keys.
+    /// 2. Checks if every value is yielded once in depth-first order.
+    /// 3. Checks if every pointer resolves to the yielded value.
+    #[test]
+    fn ut_iter_paths() {
+        let text = r#"{"a": [[], {"b/c": null}, 1], "d": {}, "e~": "x"}"#;
+        let value = JsonValue::from_text(text).unwrap();
+        let mut paths = Vec::new();
+        for (pointer, found) in value.iter_paths() {
+            assert!(core::ptr::eq(pointer.resolve(&value).unwrap(), found));
+            paths.push(pointer.to_string());
+        }
+        // The keys are sorted, so the order is the same for every Object type.
+        let expected = ["", "/a", "/a/0", "/a/1", "/a/1/b~1c", "/a/2", "/d", "/e~0"];
+        assert_eq!(paths, expected);
+
+        let scalar = JsonValue::from(1);
+        let all: Vec<_> = scalar.iter_paths().collect();
+        assert_eq!(all, [(JsonPointer::new(), &scalar)]);
+    }
+
+    /// UT test for `JsonValue::relative_pointer`.
+    ///
+    /// # Title
+    /// ut_relative_pointer
+    ///
+    /// # Brief
+    /// 1. Evaluates the examples of the Relative JSON Pointer draft.
+    /// 2. Evaluates some malformed or unreachable relative pointers.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_relative_pointer() {
+        let text = r#"{"foo": ["bar", "baz"], "highly": {"nested": {"objects": true}}}"#;
+        let value = JsonValue::from_text(text).unwrap();
+        let bar = JsonValue::new_string("bar");
+        let baz = JsonValue::new_string("baz");
+        let yes = JsonValue::Boolean(true);
+
+        let cases = [
+            ("/foo/1", "0", RelativeTarget::Value(&baz)),
+            ("/foo/1", "1/0", RelativeTarget::Value(&bar)),
+            ("/foo/1", "0-1", RelativeTarget::Value(&bar)),
+            (
+                "/foo/1",
+                "2/highly/nested/objects",
+                RelativeTarget::Value(&yes),
+            ),
+            ("/foo/1", "0#", RelativeTarget::Index(1)),
+            ("/foo/1", "0-1#", RelativeTarget::Index(0)),
+            ("/foo/1", "1#", RelativeTarget::Key(String::from("foo"))),
+            ("/highly/nested", "0/objects", RelativeTarget::Value(&yes)),
+            (
+                "/highly/nested",
+                "1/nested/objects",
+                RelativeTarget::Value(&yes),
+            ),
+            ("/highly/nested", "2/foo/0", RelativeTarget::Value(&bar)),
+            (
+                "/highly/nested",
+                "0#",
+                RelativeTarget::Key(String::from("nested")),
+            ),
+            (
+                "/highly/nested",
+                "1#",
+                RelativeTarget::Key(String::from("highly")),
+            ),
+            ("", "0", RelativeTarget::Value(&value)),
+        ];
+        for (current, relative, target) in cases {
+            assert_eq!(value.relative_pointer(current, relative).unwrap(), target);
+        }
+
+        let cases = [
+            ("/foo/1", "3"),
+            ("/foo/1", "0+1"),
+            ("/foo/1", "0-2"),
+            ("/foo/1", "1+1"),
+            ("/foo/1", "01"),
+            ("/foo/1", "-1"),
+            ("/foo/1", "0/bar"),
+            ("/foo/1", "0foo"),
+            ("/foo/1", "2#"),
+            ("/foo/2", "0"),
+            ("foo", "0"),
+        ];
+        for (current, relative) in cases {
+            assert!(value.relative_pointer(current, relative).is_none());
+        }
+    }
+}
@@ -0,0 +1,399 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{Borrowable, BytesReader, Cacheable, NBytesReadable, Position};
+use crate::scan::{unescaped_len, whitespace_len};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+
+/// Reader for reading several slices as one continuous text. This reader
+/// implements `BytesReader` trait, `Cacheable` trait and `Borrowable` trait.
+///
+/// let header = "Hello ";
+/// let body = "World";
+/// let mut reader = ChainReader::new(&[header.as_bytes(), body.as_bytes()]);
+/// assert_eq!(reader.next_n(7), Ok(Some("Hello W".as_bytes())));
+pub(crate) struct ChainReader<'a> {
+    slices: Vec<&'a [u8]>, // Non-empty slices only.
+    len: usize,            // The total length of all slices.
+    slice: usize,          // The slice which the cursor is in.
+    cur: usize,            // A cursor to the next character in the slice.
+    idx: usize,            // A counter of all bytes that have been read.
+    cache: Option<Cache>,  // A cache for storing accumulated characters.
+    buf: Vec<u8>,          // Buffer for joining bytes across slices.
+}
+
+// A cache implementation for `ChainReader`. We save the starting position,
+// and the cached bytes are joined only if they cross slices.
+struct Cache {
+    slice: usize,
+    index: usize,
+    offset: usize,
+}
+
+impl<'a> ChainReader<'a> {
+    /// Create a new `ChainReader` from the given slices.
+    ///
+    /// let reader = ChainReader::new(&["Hello ".as_bytes(), "World".as_bytes()]);
+    pub(crate) fn new(slices: &[&'a [u8]]) -> Self {
+        let slices: Vec<&'a [u8]> = slices.iter().copied().filter(|s| !s.is_empty()).collect();
+        Self {
+            len: slices.iter().map(|s| s.len()).sum(),
+            slices,
+            slice: 0,
+            cur: 0,
+            idx: 0,
+            cache: None,
+            buf: Vec::new(),
+        }
+    }
+
+    // Moves the cursor forward by `n` bytes. The caller makes sure that
+    // there are enough bytes remaining.
+    fn advance(&mut self, mut n: usize) {
+        self.idx += n;
+        while n > 0 {
+            let rest = self.slices[self.slice].len() - self.cur;
+            if n < rest {
+                self.cur += n;
+                return;
+            }
+            n -= rest;
+            self.slice += 1;
+            self.cur = 0;
+        }
+    }
+
+    // Gets `n` bytes from the given position, which are borrowed from the
+    // slice if they do not cross slices, or joined into the buffer otherwise.
+    // The caller makes sure that there are enough bytes remaining.
+    fn bytes(&mut self, mut slice: usize, mut index: usize, n: usize) -> &[u8] {
+        match self.slices.get(slice).copied() {
+            Some(s) if index + n <= s.len() => return &s[index..index + n],
+            None => return &[],
+            _ => {}
+        }
+        self.buf.clear();
+        while self.buf.len() < n {
+            let s = self.slices[slice];
+            let end = core::cmp::min(s.len(), index + n - self.buf.len());
+            self.buf.extend_from_slice(&s[index..end]);
+            slice += 1;
+            index = 0;
+        }
+        &self.buf
+    }
+}
+
+impl<'a> BytesReader for ChainReader<'a> {
+    type Error = Infallible; // Use Infallible because no error will be returned in ChainReader.
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<u8>, Self::Error> {
+        let ch = self.peek()?;
+        self.discard();
+        Ok(ch)
+    }
+
+    #[inline]
+    fn peek(&mut self) -> Result<Option<u8>, Self::Error> {
+        Ok(self.slices.get(self.slice).map(|s| s[self.cur]))
+    }
+
+    #[inline]
+    fn discard(&mut self) {
+        if self.idx < self.len {
+            self.advance(1);
+        }
+    }
+
+    #[inline]
+    fn index(&self) -> usize {
+        self.idx
+    }
+
+    fn position(&self) -> Position {
+        // The traversal method is used to calculate the `Position`, which
+        // is expensive, and it is not recommended to call it frequently.
+        let mut position = Position {
+            line: 1,
+            column: 1,
+            offset: self.idx,
+        };
+        let mut rest = self.idx;
+        for s in self.slices.iter() {
+            for ch in s.iter().take(rest) {
+                match ch {
+                    b'\n' => {
+                        position.line += 1;
+                        position.column = 1;
+                    }
+                    _ => {
+                        position.column += 1;
+                    }
+                }
+            }
+            rest = rest.saturating_sub(s.len());
+        }
+        position
+    }
+
+    fn discard_whitespace(&mut self) -> Result<Option<u8>, Self::Error> {
+        while let Some(s) = self.slices.get(self.slice).copied() {
+            let n = whitespace_len(&s[self.cur..]);
+            self.advance(n);
+            if self.cur != 0 || n == 0 {
+                break;
+            }
+        }
+        self.peek()
+    }
+
+    fn discard_unescaped(&mut self) -> Result<Option<u8>, Self::Error> {
+        while let Some(s) = self.slices.get(self.slice).copied() {
+            let n = unescaped_len(&s[self.cur..]);
+            self.advance(n);
+            if self.cur != 0 || n == 0 {
+                break;
+            }
+        }
+        self.peek()
+    }
+}
+
+impl<'a> Cacheable for ChainReader<'a> {
+    #[inline]
+    fn start_caching(&mut self) {
+        self.cache = Some(Cache {
+            slice: self.slice,
+            index: self.cur,
+            offset: self.idx,
+        });
+    }
+
+    #[inline]
+    fn cached_len(&mut self) -> Option<usize> {
+        self.cache.as_ref().map(|c| self.idx - c.offset)
+    }
+
+    fn cached_slice(&mut self) -> Option<&[u8]> {
+        let (slice, index, n) = match self.cache.as_ref() {
+            Some(c) => (c.slice, c.index, self.idx - c.offset),
+            None => return None,
+        };
+        Some(self.bytes(slice, index, n))
+    }
+
+    #[inline]
+    #[cfg(test)]
+    fn cached_data(&mut self) -> Option<Vec<u8>> {
+        self.cached_slice().map(|s| s.to_vec())
+    }
+
+    #[inline]
+    fn end_caching(&mut self) {
+        self.cache = None;
+    }
+
+    #[inline]
+    #[cfg(test)]
+    fn take_cached_data(&mut self) -> Option<Vec<u8>> {
+        let data = self.cached_data();
+        self.cache = None;
+        data
+    }
+}
+
+impl<'a> Borrowable<'a> for ChainReader<'a> {
+    // The cached bytes are borrowed only if they are in one slice.
+    fn cached_borrowed_slice(&mut self) -> Option<&'a [u8]> {
+        let cache = self.cache.as_ref()?;
+        let n = self.idx - cache.offset;
+        match self.slices.get(cache.slice).copied() {
+            Some(s) if cache.index + n <= s.len() => Some(&s[cache.index..cache.index + n]),
+            None => Some(&[]),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> NBytesReadable for ChainReader<'a> {
+    #[cfg(test)]
+    fn next_n(&mut self, n: usize) -> Result<Option<&[u8]>, Self::Error> {
+        if self.idx + n > self.len {
+            return Ok(None);
+        }
+        let (slice, index) = (self.slice, self.cur);
+        self.advance(n);
+        Ok(Some(self.bytes(slice, index, n)))
+    }
+
+    fn peek_n(&mut self, n: usize) -> Result<Option<&[u8]>, Self::Error> {
+        if self.idx + n > self.len {
+            return Ok(None);
+        }
+        Ok(Some(self.bytes(self.slice, self.cur, n)))
+    }
+
+    fn discard_n(&mut self, n: usize) {
+        if self.idx + n > self.len {
+            return;
+        }
+        self.advance(n);
+    }
+}
+
+#[cfg(test)]
+mod ut_chain_reader {
+    use super::{Borrowable, BytesReader, Cacheable, ChainReader, NBytesReadable};
+
+    /// UT test case for `ChainReader::new`.
+    ///
+    /// # Title
+    /// ut_chain_reader_new
+    ///
+    /// # Brief
+    /// 1. Call `ChainReader::new` with some empty slices.
+    /// 2. Check that the empty slices are dropped and the length is correct.
+    #[test]
+    fn ut_chain_reader_new() {
+        let reader = ChainReader::new(&[b"", b"ab", b"", b"c"]);
+        assert_eq!(reader.slices.len(), 2);
+        assert_eq!(reader.len, 3);
+        assert_eq!(reader.index(), 0);
+        assert!(reader.cache.is_none());
+    }
+
+    /// UT test case for `ChainReader::next` and `ChainReader::peek`.
+    ///
+    /// # Title
+    /// ut_chain_reader_next_peek
+    ///
+    /// # Brief
+    /// 1. Create a `ChainReader` with several slices.
+    /// 2. Call `ChainReader::next` and `ChainReader::peek` across the slices.
+    /// 3. Check if the test results are correct.
+    #[test]
+    fn ut_chain_reader_next_peek() {
+        let mut reader = ChainReader::new(&[b"a", b"", b"bc"]);
+        assert_eq!(reader.peek(), Ok(Some(b'a')));
+        assert_eq!(reader.next(), Ok(Some(b'a')));
+        assert_eq!(reader.peek(), Ok(Some(b'b')));
+        assert_eq!(reader.next(), Ok(Some(b'b')));
+        assert_eq!(reader.next(), Ok(Some(b'c')));
+        assert_eq!(reader.next(), Ok(None));
+        assert_eq!(reader.peek(), Ok(None));
+        reader.discard();
+        assert_eq!(reader.index(), 3);
+    }
+
+    /// UT test case for `ChainReader::position`.
+    ///
+    /// # Title
+    /// ut_chain_reader_position
+    ///
+    /// # Brief
+    /// 1. Create a `ChainReader` with a line feed in the first slice.
+    /// 2. Call `ChainReader::position` after reading into the second slice.
+    /// 3. Check if the test results are correct.
+    #[test]
+    fn ut_chain_reader_position() {
+        let mut reader = ChainReader::new(&[b"a\nb", b"cd"]);
+        reader.discard_n(4);
+        let position = reader.position();
+        assert_eq!(position.line(), 2);
+        assert_eq!(position.column(), 3);
+        assert_eq!(position.offset(), 4);
+    }
+
+    /// UT test case for `ChainReader::discard_whitespace` and
+    /// `ChainReader::discard_unescaped`.
+    ///
+    /// # Title
+    /// ut_chain_reader_discard_in_bulk
+    ///
+    /// # Brief
+    /// 1. Create a `ChainReader` whose whitespace and string cross slices.
+    /// 2. Call `ChainReader::discard_whitespace` and `ChainReader::discard_unescaped`.
+    /// 3. Check if the test results are correct.
+    #[test]
+    fn ut_chain_reader_discard_in_bulk() {
+        let mut reader = ChainReader::new(&[b"  ", b" \n", b"\tab", b"c", b"d\""]);
+        assert_eq!(reader.discard_whitespace(), Ok(Some(b'a')));
+        assert_eq!(reader.index(), 5);
+        assert_eq!(reader.discard_unescaped(), Ok(Some(b'"')));
+        assert_eq!(reader.index(), 9);
+    }
+
+    /// UT test case for the caching interfaces of `ChainReader`.
+    ///
+    /// # Title
+    /// ut_chain_reader_caching
+    ///
+    /// # Brief
+    /// 1. Create a `ChainReader` with several slices.
+    /// 2. Cache bytes in one slice and across slices.
+    /// 3. Check if the cached bytes are borrowed only if they are in one slice.
+    #[test]
+    fn ut_chain_reader_caching() {
+        let mut reader = ChainReader::new(&[b"abc", b"de"]);
+        assert_eq!(reader.cached_slice(), None);
+
+        reader.start_caching();
+        reader.discard_n(3);
+        assert_eq!(reader.cached_len(), Some(3));
+        assert_eq!(reader.cached_borrowed_slice(), Some(&b"abc"[..]));
+        assert_eq!(reader.cached_slice(), Some(&b"abc"[..]));
+        reader.end_caching();
+        assert_eq!(reader.cached_len(), None);
+
+        let mut reader = ChainReader::new(&[b"abc", b"de"]);
+        reader.discard();
+        reader.start_caching();
+        reader.discard_n(3);
+        assert_eq!(reader.cached_borrowed_slice(), None);
+        assert_eq!(reader.cached_slice(), Some(&b"bcd"[..]));
+        assert_eq!(reader.cached_data(), Some(b"bcd".to_vec()));
+        assert_eq!(reader.take_cached_data(), Some(b"bcd".to_vec()));
+        assert_eq!(reader.take_cached_data(), None);
+
+        reader.discard();
+        reader.start_caching();
+        assert_eq!(reader.cached_borrowed_slice(), Some(&b""[..]));
+    }
+
+    /// UT test case for `ChainReader::next_n`, `ChainReader::peek_n` and
+    /// `ChainReader::discard_n`.
+    ///
+    /// # Title
+    /// ut_chain_reader_n_bytes
+    ///
+    /// # Brief
+    /// 1. Create a `ChainReader` with several slices.
+    /// 2. Read 'n' bytes in one slice and across slices.
+    /// 3. Check if the test results are correct.
+    #[test]
+    fn ut_chain_reader_n_bytes() {
+        let mut reader = ChainReader::new(&[b"tr", b"u", b"efalse"]);
+        assert_eq!(reader.peek_n(4), Ok(Some(&b"true"[..])));
+        assert_eq!(reader.next_n(4), Ok(Some(&b"true"[..])));
+        assert_eq!(reader.peek_n(6), Ok(None));
+        assert_eq!(reader.next_n(2), Ok(Some(&b"fa"[..])));
+        reader.discard_n(4);
+        assert_eq!(reader.index(), 6);
+        reader.discard_n(1);
+        assert_eq!(reader.next_n(2), Ok(Some(&b"se"[..])));
+        assert_eq!(reader.next_n(0), Ok(Some(&b""[..])));
+        assert_eq!(reader.next(), Ok(None));
+    }
+}
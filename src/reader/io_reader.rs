@@ -11,7 +11,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{BytesReader, Cacheable, Position};
+use super::{Borrowable, BytesReader, Cacheable, NBytesReadable, Position};
+use crate::scan::{unescaped_len, whitespace_len};
 use std::io::{Error, ErrorKind, Read, Result};
 
 /// Reader for reading I\O. This reader implements `BytesReader` trait and
@@ -81,7 +82,10 @@ impl<R: Read> IoReader<R> {
                     Ok(true)
                 },
                 Err(ref e) if e.kind() == ErrorKind::WouldBlock => continue,
-                Err(e) => Err(e),
+                Err(e) => unsafe {
+                    self.buf.set_len(0);
+                    Err(e)
+                },
             };
         }
     }
@@ -94,12 +98,59 @@ impl<R: Read> IoReader<R> {
             cacher.pre = 0;
         }
         let result = self.read_bytes();
-        if let Ok(true) = result {
-            self.cur = 0;
-        }
+        // The old bytes in buf have all been consumed, even if no new bytes
+        // are read. Otherwise `cur` would point outside the empty buf.
+        self.cur = 0;
         result
     }
 
+    // Makes sure that at least `n` unread bytes are in buf, unless the io
+    // ends first. The unread bytes are moved to the front of buf, and the
+    // bytes before them are saved to the cache first.
+    fn fill(&mut self, n: usize) -> Result<bool> {
+        if self.buf.len() - self.cur >= n {
+            return Ok(true);
+        }
+        self.update_cache();
+        self.buf.drain(..self.cur);
+        self.cur = 0;
+        if let Some(ref mut cacher) = self.cache {
+            cacher.pre = 0;
+        }
+
+        while self.buf.len() < n {
+            let len = self.buf.len();
+            self.buf.resize(len + 1024, 0);
+            match self.io.read(&mut self.buf[len..]) {
+                Ok(0) => {
+                    self.buf.truncate(len);
+                    return Ok(false);
+                }
+                Ok(read) => self.buf.truncate(len + read),
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => self.buf.truncate(len),
+                Err(e) => {
+                    self.buf.truncate(len);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    // Moves the cursor over `n` bytes in buf.
+    fn advance(&mut self, n: usize) {
+        for ch in &self.buf[self.cur..self.cur + n] {
+            if *ch == b'\n' {
+                self.pos.line += 1;
+                self.pos.column = 1;
+            } else {
+                self.pos.column += 1;
+            }
+        }
+        self.cur += n;
+        self.idx += n;
+    }
+
     // Every time a user calls a cache-related interface, the cache content
     // needs to be updated in time.
     fn update_cache(&mut self) {
@@ -180,7 +231,50 @@ impl<R: Read> BytesReader for IoReader<R> {
 
     #[inline]
     fn position(&self) -> Position {
-        self.pos.clone()
+        Position {
+            offset: self.idx,
+            ..self.pos.clone()
+        }
+    }
+
+    fn discard_whitespace(&mut self) -> Result<Option<u8>> {
+        loop {
+            let len = whitespace_len(&self.buf[self.cur..]);
+            for ch in &self.buf[self.cur..self.cur + len] {
+                if *ch == b'\n' {
+                    self.pos.line += 1;
+                    self.pos.column = 1;
+                } else {
+                    self.pos.column += 1;
+                }
+            }
+            self.cur += len;
+            self.idx += len;
+
+            if self.cur < self.buf.len() {
+                return Ok(Some(self.buf[self.cur]));
+            }
+            if !self.load()? {
+                return Ok(None);
+            }
+        }
+    }
+
+    fn discard_unescaped(&mut self) -> Result<Option<u8>> {
+        loop {
+            // These bytes contain no '\n', which is a control character.
+            let len = unescaped_len(&self.buf[self.cur..]);
+            self.cur += len;
+            self.idx += len;
+            self.pos.column += len;
+
+            if self.cur < self.buf.len() {
+                return Ok(Some(self.buf[self.cur]));
+            }
+            if !self.load()? {
+                return Ok(None);
+            }
+        }
     }
 }
 
@@ -206,6 +300,7 @@ impl<R: Read> Cacheable for IoReader<R> {
         self.cache.as_ref().map(|c| c.cache.as_slice())
     }
 
+    #[cfg(test)]
     fn cached_data(&mut self) -> Option<Vec<u8>> {
         self.update_cache();
         self.cache.as_ref().map(|c| c.cache.clone())
@@ -215,15 +310,49 @@ impl<R: Read> Cacheable for IoReader<R> {
         self.cache = None;
     }
 
+    #[cfg(test)]
     fn take_cached_data(&mut self) -> Option<Vec<u8>> {
         self.update_cache();
         self.cache.take().map(|c| c.cache)
     }
 }
 
+impl<R: Read> NBytesReadable for IoReader<R> {
+    #[cfg(test)]
+    fn next_n(&mut self, n: usize) -> Result<Option<&[u8]>> {
+        if !self.fill(n)? {
+            return Ok(None);
+        }
+        let start = self.cur;
+        self.advance(n);
+        Ok(Some(&self.buf[start..start + n]))
+    }
+
+    fn peek_n(&mut self, n: usize) -> Result<Option<&[u8]>> {
+        if !self.fill(n)? {
+            return Ok(None);
+        }
+        Ok(Some(&self.buf[self.cur..self.cur + n]))
+    }
+
+    fn discard_n(&mut self, n: usize) {
+        if let Ok(true) = self.fill(n) {
+            self.advance(n);
+        }
+    }
+}
+
+impl<'de, R: Read> Borrowable<'de> for IoReader<R> {
+    // The bytes are read into an internal buffer, so they can not be borrowed.
+    #[inline]
+    fn cached_borrowed_slice(&mut self) -> Option<&'de [u8]> {
+        None
+    }
+}
+
 #[cfg(test)]
 mod ut_io_reader {
-    use super::{BytesReader, Cacheable, IoReader};
+    use super::{BytesReader, Cacheable, IoReader, NBytesReadable};
     use std::cmp;
     use std::io::{ErrorKind, Read};
 
@@ -321,8 +450,8 @@ mod ut_io_reader {
     /// 2. Call `IoReader::next`.
     /// 3. Check the return value against the following conditions:
     ///     - If the end is not read, it returns `Ok(Some(..))`, and the index
-    ///     is moved backward; if the end is read, it returns `Ok(None)`, and
-    ///     the index is not moved.
+    ///       is moved backward; if the end is read, it returns `Ok(None)`, and
+    ///       the index is not moved.
     #[test]
     fn ut_io_reader_next() {
         // Use TestIo.
@@ -357,7 +486,7 @@ mod ut_io_reader {
     /// 2. Call `IoReader::peek`.
     /// 3. Check the return value against the following conditions:
     ///     - If the end is not read, it returns `Ok(Some(..))`; if the end is
-    ///     read, it returns `Ok(None)`.
+    ///       read, it returns `Ok(None)`.
     #[test]
     fn ut_io_reader_peek() {
         // Use TestIo.
@@ -390,7 +519,7 @@ mod ut_io_reader {
     /// 2. Call `IoReader::discard`.
     /// 3. Check `index` against the following conditions:
     ///     - If the end is not read, the index is moved backward; if the end is
-    ///     read, the index is not moved.
+    ///       read, the index is not moved.
     #[test]
     fn ut_io_reader_discard() {
         let io = TestIo::new(vec![1u8; 1]);
@@ -400,6 +529,57 @@ mod ut_io_reader {
         assert_eq!(io_reader.index(), 1);
         io_reader.discard();
         assert_eq!(io_reader.index(), 1);
+        assert_eq!(io_reader.peek().unwrap(), None);
+        assert_eq!(io_reader.next().unwrap(), None);
+    }
+
+    /// UT test case for `IoReader::discard_whitespace`.
+    ///
+    /// # Title
+    /// ut_io_reader_discard_whitespace
+    ///
+    /// # Brief
+    /// 1. Create a `IoReader` whose whitespace spans several internal buffers.
+    /// 2. Call `IoReader::discard_whitespace`.
+    /// 3. Check the return value, `index` and `position`.
+    #[test]
+    fn ut_io_reader_discard_whitespace() {
+        let mut vec = b" \n".repeat(1500);
+        vec.push(b'1');
+        let io = TestIo::new(vec);
+        let mut io_reader = IoReader::new(io);
+        assert_eq!(io_reader.discard_whitespace().unwrap(), Some(b'1'));
+        assert_eq!(io_reader.index(), 3000);
+        assert_eq!(io_reader.position().line(), 1501);
+        assert_eq!(io_reader.position().column(), 1);
+        io_reader.discard();
+        assert_eq!(io_reader.discard_whitespace().unwrap(), None);
+    }
+
+    /// UT test case for `IoReader::discard_unescaped`.
+    ///
+    /// # Title
+    /// ut_io_reader_discard_unescaped
+    ///
+    /// # Brief
+    /// 1. Create a `IoReader` whose string spans several internal buffers.
+    /// 2. Call `IoReader::start_caching` and `IoReader::discard_unescaped`.
+    /// 3. Check the return value, `index`, `position` and the cached bytes.
+    #[test]
+    fn ut_io_reader_discard_unescaped() {
+        let mut vec = b"abc".repeat(1000);
+        vec.extend_from_slice(b"\\n\"");
+        let io = TestIo::new(vec);
+        let mut io_reader = IoReader::new(io);
+        io_reader.start_caching();
+        assert_eq!(io_reader.discard_unescaped().unwrap(), Some(b'\\'));
+        assert_eq!(io_reader.index(), 3000);
+        assert_eq!(io_reader.position().column(), 3001);
+        assert_eq!(io_reader.cached_slice().unwrap(), b"abc".repeat(1000));
+        io_reader.discard();
+        assert_eq!(io_reader.discard_unescaped().unwrap(), Some(b'"'));
+        io_reader.discard();
+        assert_eq!(io_reader.discard_unescaped().unwrap(), None);
     }
 
     /// UT test case for `IoReader::index`.
@@ -428,8 +608,8 @@ mod ut_io_reader {
     /// 2. Call `IoReader::position`.
     /// 3. Check the return value against the following conditions:
     ///     - If `'\n'` is read, the line number will increase and the column
-    ///     number will return to 1; if other characters are read, the line
-    ///     number will remain unchanged and the column number will increase.
+    ///       number will return to 1; if other characters are read, the line
+    ///       number will remain unchanged and the column number will increase.
     #[test]
     fn ut_io_reader_position() {
         let io = TestIo::new(vec![1u8, b'\n', 2, b'\n', 3]);
@@ -466,6 +646,7 @@ mod ut_io_reader {
         let position = io_reader.position();
         assert_eq!(position.line(), 3);
         assert_eq!(position.column(), 2);
+        assert_eq!(position.offset(), 5);
         assert_eq!(io_reader.peek().unwrap(), None);
     }
 
@@ -503,7 +684,7 @@ mod ut_io_reader {
     /// 2. Call `IoReader::cached_len`.
     /// 3. Check the return value against the following conditions:
     ///     - Returns `None` if caching is not enabled, otherwise returns
-    ///     `Some(..)`.
+    ///       `Some(..)`.
     #[test]
     fn ut_io_reader_cached_len() {
         let io = TestIo::new(Vec::new());
@@ -523,7 +704,7 @@ mod ut_io_reader {
     /// 2. Call `IoReader::cached_slice`.
     /// 3. Check the return value against the following conditions:
     ///     - Returns `None` if caching is not enabled, otherwise returns
-    ///     `Some(..)`.
+    ///       `Some(..)`.
     #[test]
     fn ut_io_reader_cached_slice() {
         let io = TestIo::new(Vec::new());
@@ -556,7 +737,7 @@ mod ut_io_reader {
     /// 2. Call `IoReader::cached_data`.
     /// 3. Check the return value against the following conditions:
     ///     - Returns `None` if caching is not enabled, otherwise returns
-    ///     `Some(..)`.
+    ///       `Some(..)`.
     #[test]
     fn ut_io_reader_cached_data() {
         let io = TestIo::new(Vec::new());
@@ -603,4 +784,48 @@ mod ut_io_reader {
         assert_eq!(io_reader.take_cached_data(), Some(Vec::new()));
         assert!(io_reader.cache.is_none());
     }
+
+    /// UT test case for `IoReader::next_n`, `IoReader::peek_n` and `IoReader::discard_n`.
+    ///
+    /// # Title
+    /// ut_io_reader_n_bytes
+    ///
+    /// # Brief
+    /// 1. Create a `IoReader` whose io returns a few bytes at a time.
+    /// 2. Call `IoReader::next_n`, `IoReader::peek_n` and `IoReader::discard_n` across reads.
+    /// 3. Check if the return values, the position and the cache are correct.
+    #[test]
+    fn ut_io_reader_n_bytes() {
+        struct ChunkIo(Vec<u8>, usize);
+
+        impl Read for ChunkIo {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let len = cmp::min(cmp::min(buf.len(), 3), self.0.len() - self.1);
+                buf[..len].copy_from_slice(&self.0[self.1..self.1 + len]);
+                self.1 += len;
+                Ok(len)
+            }
+        }
+
+        let mut io_reader = IoReader::new(ChunkIo(b"ab\ncdefgh".to_vec(), 0));
+        assert_eq!(io_reader.next().unwrap(), Some(b'a'));
+        io_reader.start_caching();
+        assert_eq!(io_reader.peek_n(5).unwrap(), Some(b"b\ncde".as_slice()));
+        assert_eq!(io_reader.index(), 1);
+        assert_eq!(io_reader.next_n(3).unwrap(), Some(b"b\nc".as_slice()));
+        assert_eq!(io_reader.position().line(), 2);
+        assert_eq!(io_reader.position().column(), 2);
+        assert_eq!(io_reader.index(), 4);
+
+        io_reader.discard_n(2);
+        assert_eq!(io_reader.index(), 6);
+        assert_eq!(io_reader.cached_slice(), Some(b"b\ncde".as_slice()));
+        io_reader.end_caching();
+
+        assert_eq!(io_reader.peek_n(4).unwrap(), None);
+        io_reader.discard_n(4);
+        assert_eq!(io_reader.index(), 6);
+        assert_eq!(io_reader.next_n(3).unwrap(), Some(b"fgh".as_slice()));
+        assert_eq!(io_reader.next().unwrap(), None);
+    }
 }
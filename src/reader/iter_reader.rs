@@ -0,0 +1,113 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::IoReader;
+use std::io::{Error, Read, Result};
+
+/// Reader for reading a fallible byte iterator. The iterator is adapted to
+/// `Read`, so that `IoReader` can buffer and cache its bytes.
+///
+/// let bytes = "Hello World".bytes().map(Ok);
+/// let mut reader = IterReader::new(bytes);
+/// assert_eq!(reader.next().unwrap(), Some(b'H'));
+pub(crate) type IterReader<I> = IoReader<IterRead<I>>;
+
+impl<I: Iterator<Item = Result<u8>>> IterReader<I> {
+    /// Create a new `IterReader` from the given iterator.
+    pub(crate) fn new_from_iter(iter: I) -> Self {
+        IoReader::new(IterRead { iter, error: None })
+    }
+}
+
+/// An adapter which implements `Read` for a fallible byte iterator.
+pub(crate) struct IterRead<I> {
+    iter: I,
+    // An error which occurs after some bytes have been read. It is returned
+    // by the next read, so that the bytes before it are not lost.
+    error: Option<Error>,
+}
+
+impl<I: Iterator<Item = Result<u8>>> Read for IterRead<I> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+        let mut n = 0;
+        while n < buf.len() {
+            match self.iter.next() {
+                Some(Ok(byte)) => {
+                    buf[n] = byte;
+                    n += 1;
+                }
+                Some(Err(e)) if n == 0 => return Err(e),
+                Some(Err(e)) => {
+                    self.error = Some(e);
+                    break;
+                }
+                None => break,
+            }
+        }
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod ut_iter_reader {
+    use super::{IterRead, IterReader};
+    use crate::reader::BytesReader;
+    use std::io::{Error, ErrorKind, Read};
+
+    /// UT test case for `IterRead::read`.
+    ///
+    /// # Title
+    /// ut_iter_read_read
+    ///
+    /// # Brief
+    /// 1. Create an `IterRead` from an iterator which fails in the middle.
+    /// 2. Call `IterRead::read` with a buffer larger than the bytes before the error.
+    /// 3. Check if the bytes are returned before the error.
+    #[test]
+    fn ut_iter_read_read() {
+        let iter = vec![Ok(b'a'), Ok(b'b'), Err(Error::from(ErrorKind::InvalidData))];
+        let mut read = IterRead {
+            iter: iter.into_iter(),
+            error: None,
+        };
+        let mut buf = [0u8; 4];
+        assert_eq!(read.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf[..2], b"ab");
+        assert_eq!(
+            read.read(&mut buf).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert_eq!(read.read(&mut buf).unwrap(), 0);
+    }
+
+    /// UT test case for `IterReader`.
+    ///
+    /// # Title
+    /// ut_iter_reader
+    ///
+    /// # Brief
+    /// 1. Create an `IterReader` from a byte iterator.
+    /// 2. Call `IterReader::next` and `IterReader::peek`.
+    /// 3. Check if the test results are correct.
+    #[test]
+    fn ut_iter_reader() {
+        let mut reader = IterReader::new_from_iter(b"ab".iter().copied().map(Ok));
+        assert_eq!(reader.peek().unwrap(), Some(b'a'));
+        assert_eq!(reader.next().unwrap(), Some(b'a'));
+        assert_eq!(reader.next().unwrap(), Some(b'b'));
+        assert_eq!(reader.next().unwrap(), None);
+    }
+}
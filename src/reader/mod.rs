@@ -11,18 +11,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::consts::{ESCAPE, WHITE_SPACE_SET};
+use alloc::boxed::Box;
+#[cfg(test)]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 mod io_reader;
+#[cfg(feature = "std")]
 pub(crate) use io_reader::IoReader;
 
+#[cfg(feature = "std")]
+mod iter_reader;
+#[cfg(feature = "std")]
+pub(crate) use iter_reader::IterReader;
+
 mod slice_reader;
 pub(crate) use slice_reader::SliceReader;
 
+mod chain_reader;
+pub(crate) use chain_reader::ChainReader;
+
 /// `BytesReader` provides the basic byte read interface, such as `next`,
 /// `peek`, `index`. Users can obtain the next byte or the current read
 /// position according to these interfaces.
 pub(crate) trait BytesReader {
     /// Errors that may occur during reading, usually in the I\O process.
-    type Error: Into<Box<dyn std::error::Error>>;
+    type Error: Into<Box<dyn core::error::Error>>;
 
     /// Get the next character and move the cursor to the next place.
     fn next(&mut self) -> Result<Option<u8>, Self::Error>;
@@ -39,11 +54,35 @@ pub(crate) trait BytesReader {
 
     /// Get the current cursor position and return it as `Position`.
     fn position(&self) -> Position;
+
+    /// Discard the following JSON whitespace, and get the next character
+    /// without moving the cursor.
+    fn discard_whitespace(&mut self) -> Result<Option<u8>, Self::Error> {
+        loop {
+            match self.peek()? {
+                Some(ch) if WHITE_SPACE_SET.contains(&ch) => self.discard(),
+                x => return Ok(x),
+            }
+        }
+    }
+
+    /// Discard the following characters which can be copied into a JSON string
+    /// directly, and get the next character without moving the cursor. The next
+    /// character is `"`, `\`, a control character or `None`.
+    fn discard_unescaped(&mut self) -> Result<Option<u8>, Self::Error> {
+        loop {
+            match self.peek()? {
+                Some(ch) if !ESCAPE[ch as usize] => self.discard(),
+                x => return Ok(x),
+            }
+        }
+    }
 }
 
 /// `Cacheable` provides some byte cache interfaces for caching a portion of
-/// contiguous bytes in a byte stream.
-pub(crate) trait Cacheable: BytesReader {
+/// contiguous bytes in a byte stream. Cacheable readers also read 'n' bytes
+/// at one time, which the parser uses to match literals.
+pub(crate) trait Cacheable: NBytesReadable {
     /// Start the cache operation. This interface needs to be used with
     /// `end_caching` or `take_cached_data`.
     fn start_caching(&mut self);
@@ -61,6 +100,7 @@ pub(crate) trait Cacheable: BytesReader {
     /// Get a `Vec` of the cached bytes. Since the logic of caching operations
     /// is implementation-dependent, we provide an interface that uses mutable
     /// references here.
+    #[cfg(test)]
     fn cached_data(&mut self) -> Option<Vec<u8>>;
 
     /// End the cache operation. This interface needs to be used with
@@ -69,10 +109,21 @@ pub(crate) trait Cacheable: BytesReader {
 
     /// End the cache operation and return the cached bytes. This interface
     /// needs to be used with `start_caching`.
+    #[cfg(test)]
     fn take_cached_data(&mut self) -> Option<Vec<u8>>;
 }
 
+/// `Borrowable` provides the interface to borrow the cached bytes from the
+/// input directly, with the lifetime `'de` of the input itself.
+pub(crate) trait Borrowable<'de>: Cacheable {
+    /// Get a slice of the cached bytes which borrows from the input. Readers
+    /// which do not hold the whole input return `None`, and the cached bytes
+    /// need to be copied through `cached_slice`.
+    fn cached_borrowed_slice(&mut self) -> Option<&'de [u8]>;
+}
+
 /// `RemainderCountable` provides the interface related to the remainder.
+#[cfg(test)]
 pub(crate) trait RemainderCountable: BytesReader {
     /// Get the length of the remainder.
     fn remainder_len(&self) -> usize;
@@ -89,6 +140,7 @@ pub(crate) trait NBytesReadable: BytesReader {
     /// Read the next 'n' bytes and move the cursor to the next nth position.
     /// If there are not enough bytes remaining to satisfy 'n', return `None`
     /// and do nothing.
+    #[cfg(test)]
     fn next_n(&mut self, n: usize) -> Result<Option<&[u8]>, Self::Error>;
 
     /// Get the next 'n' bytes and do not move the cursor. If there are not
@@ -100,31 +152,85 @@ pub(crate) trait NBytesReadable: BytesReader {
     fn discard_n(&mut self, n: usize);
 }
 
-/// Position information which expressed in row and column.
-#[derive(Clone)]
-pub(crate) struct Position {
+/// The position of a character in a JSON text, expressed in line, column
+/// and byte offset.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{Error, JsonValue};
+///
+/// let error = JsonValue::from_text("[1,\n 2 x]").unwrap_err();
+/// if let Error::Parsing(e) = error {
+///     let position = e.position().unwrap();
+///     assert_eq!(position.line(), 2);
+///     assert_eq!(position.column(), 4);
+///     assert_eq!(position.offset(), 7);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Position {
     line: usize,
     column: usize,
+    offset: usize,
 }
 
 impl Position {
     /// Create a `Position` from the given line and column.
     #[inline]
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
     pub(crate) fn new(line: usize, column: usize) -> Self {
-        Self { line, column }
+        Self {
+            line,
+            column,
+            offset: 0,
+        }
     }
 
-    /// Get line.
+    /// Gets the line number, which starts from 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Error, JsonValue};
+    ///
+    /// if let Err(Error::Parsing(e)) = JsonValue::from_text("\n[1,") {
+    ///     assert_eq!(e.position().unwrap().line(), 2);
+    /// }
+    /// ```
     #[inline]
-    pub(crate) fn line(&self) -> usize {
+    pub fn line(&self) -> usize {
         self.line
     }
 
-    /// Get column.
+    /// Gets the column number, which starts from 1 and counts bytes
+    /// from the beginning of the line.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Error, JsonValue};
+    ///
+    /// if let Err(Error::Parsing(e)) = JsonValue::from_text("[1 2]") {
+    ///     assert_eq!(e.position().unwrap().column(), 4);
+    /// }
+    /// ```
     #[inline]
-    pub(crate) fn column(&self) -> usize {
+    pub fn column(&self) -> usize {
         self.column
     }
+
+    /// Gets the byte offset from the beginning of the text, which starts from 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Error, JsonValue};
+    ///
+    /// if let Err(Error::Parsing(e)) = JsonValue::from_text("[1 2]") {
+    ///     assert_eq!(e.position().unwrap().offset(), 3);
+    /// }
+    /// ```
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
 }
 
 #[cfg(test)]
@@ -176,6 +282,21 @@ mod ut_position {
         assert_eq!(position.column(), 1);
     }
 
+    /// UT test for `Position::offset`.
+    ///
+    /// # Title
+    /// ut_position_offset
+    ///
+    /// # Brief
+    /// 1. Create a `Position`.
+    /// 2. Call `Position::offset` to get the byte offset of `Position`.
+    /// 3. Check if the results are correct.
+    #[test]
+    fn ut_position_offset() {
+        let position = Position::new(1, 1);
+        assert_eq!(position.offset(), 0);
+    }
+
     /// UT test case for `Position::clone`.
     ///
     /// # Title
@@ -11,8 +11,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{BytesReader, Cacheable, NBytesReadable, Position, RemainderCountable};
-use std::convert::Infallible;
+#[cfg(test)]
+use super::RemainderCountable;
+use super::{Borrowable, BytesReader, Cacheable, NBytesReadable, Position};
+use crate::scan::{unescaped_len, whitespace_len};
+#[cfg(test)]
+use alloc::vec::Vec;
+use core::convert::Infallible;
 
 /// Reader for reading slices. This reader implements `BytesReader` trait,
 /// `Cacheable` trait and `Countable` trait.
@@ -85,7 +90,11 @@ impl<'a> BytesReader for SliceReader<'a> {
         // is expensive, and it is not recommended to call it frequently.
         let index = core::cmp::min(self.index, self.slice.len());
 
-        let mut position = Position { line: 1, column: 1 };
+        let mut position = Position {
+            line: 1,
+            column: 1,
+            offset: index,
+        };
         for i in 0..index {
             match self.slice[i] {
                 b'\n' => {
@@ -99,6 +108,18 @@ impl<'a> BytesReader for SliceReader<'a> {
         }
         position
     }
+
+    #[inline]
+    fn discard_whitespace(&mut self) -> Result<Option<u8>, Self::Error> {
+        self.index += whitespace_len(&self.slice[self.index..]);
+        self.peek()
+    }
+
+    #[inline]
+    fn discard_unescaped(&mut self) -> Result<Option<u8>, Self::Error> {
+        self.index += unescaped_len(&self.slice[self.index..]);
+        self.peek()
+    }
 }
 
 impl<'a> Cacheable for SliceReader<'a> {
@@ -118,6 +139,7 @@ impl<'a> Cacheable for SliceReader<'a> {
     }
 
     #[inline]
+    #[cfg(test)]
     fn cached_data(&mut self) -> Option<Vec<u8>> {
         self.cache
             .as_ref()
@@ -130,6 +152,7 @@ impl<'a> Cacheable for SliceReader<'a> {
     }
 
     #[inline]
+    #[cfg(test)]
     fn take_cached_data(&mut self) -> Option<Vec<u8>> {
         self.cache
             .take()
@@ -137,6 +160,15 @@ impl<'a> Cacheable for SliceReader<'a> {
     }
 }
 
+impl<'a> Borrowable<'a> for SliceReader<'a> {
+    #[inline]
+    fn cached_borrowed_slice(&mut self) -> Option<&'a [u8]> {
+        let slice = self.slice;
+        self.cache.as_ref().map(|c| &slice[c.0..self.index])
+    }
+}
+
+#[cfg(test)]
 impl<'a> RemainderCountable for SliceReader<'a> {
     #[inline]
     fn remainder_len(&self) -> usize {
@@ -155,6 +187,7 @@ impl<'a> RemainderCountable for SliceReader<'a> {
 }
 
 impl<'a> NBytesReadable for SliceReader<'a> {
+    #[cfg(test)]
     fn next_n(&mut self, n: usize) -> Result<Option<&[u8]>, Self::Error> {
         if self.index + n > self.slice.len() {
             return Ok(None);
@@ -210,8 +243,8 @@ mod ut_slice_reader {
     /// 2. Call `SliceReader::next`.
     /// 3. Check the return value against the following conditions:
     ///     - If the end is not read, it returns `Ok(Some(..))`, and the index
-    ///     is moved backward; if the end is read, it returns `Ok(None)`, and
-    ///     the index is not moved.
+    ///       is moved backward; if the end is read, it returns `Ok(None)`, and
+    ///       the index is not moved.
     #[test]
     fn ut_slice_reader_next() {
         let slice = "A";
@@ -230,7 +263,7 @@ mod ut_slice_reader {
     /// 2. Call `SliceReader::peek`.
     /// 3. Check the return value against the following conditions:
     ///     - If the end is not read, it returns `Ok(Some(..))`; if the end is
-    ///     read, it returns `Ok(None)`.
+    ///       read, it returns `Ok(None)`.
     #[test]
     fn ut_slice_reader_peek() {
         let slice = "A";
@@ -251,7 +284,7 @@ mod ut_slice_reader {
     /// 2. Call `SliceReader::discard`.
     /// 3. Check `index` against the following conditions:
     ///     - If the end is not read, the index is moved backward; if the end is
-    ///     read, the index is not moved.
+    ///       read, the index is not moved.
     #[test]
     fn ut_slice_reader_discard() {
         let slice = "A";
@@ -289,8 +322,8 @@ mod ut_slice_reader {
     /// 2. Call `SliceReader::position`.
     /// 3. Check the return value against the following conditions:
     ///     - If `'\n'` is read, the line number will increase and the column
-    ///     number will return to 1; if other characters are read, the line
-    ///     number will remain unchanged and the column number will increase.
+    ///       number will return to 1; if other characters are read, the line
+    ///       number will remain unchanged and the column number will increase.
     #[test]
     fn ut_slice_reader_position() {
         let slice = "A\nB";
@@ -318,6 +351,7 @@ mod ut_slice_reader {
         let position = slice_reader.position();
         assert_eq!(position.line(), 2);
         assert_eq!(position.column(), 2);
+        assert_eq!(position.offset(), 3);
     }
 
     /// UT test case for `SliceReader::start_caching`.
@@ -348,7 +382,7 @@ mod ut_slice_reader {
     /// 2. Call `SliceReader::cached_len`.
     /// 3. Check the return value against the following conditions:
     ///     - Returns `None` if caching is not enabled, otherwise returns
-    ///     `Some(..)`.
+    ///       `Some(..)`.
     #[test]
     fn ut_slice_reader_cached_len() {
         let slice = "A";
@@ -368,7 +402,7 @@ mod ut_slice_reader {
     /// 2. Call `SliceReader::cached_slice`.
     /// 3. Check the return value against the following conditions:
     ///     - Returns `None` if caching is not enabled, otherwise returns
-    ///     `Some(..)`.
+    ///       `Some(..)`.
     #[test]
     fn ut_slice_reader_cached_slice() {
         let slice = "A";
@@ -388,7 +422,7 @@ mod ut_slice_reader {
     /// 2. Call `SliceReader::cached_data`.
     /// 3. Check the return value against the following conditions:
     ///     - Returns `None` if caching is not enabled, otherwise returns
-    ///     `Some(..)`.
+    ///       `Some(..)`.
     #[test]
     fn ut_slice_reader_cached_data() {
         let slice = "A";
@@ -0,0 +1,217 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bulk scanning of byte slices, used by readers which hold contiguous bytes.
+//!
+//! With `simd` feature, 16 bytes are checked at one time by SSE2 on x86_64.
+//! SSE2 is always available on x86_64, so no runtime detection is needed. Other
+//! targets use the scalar implementation, which checks string bytes 8 at a time.
+
+use crate::consts::{ESCAPE, QUOTATION_MARK, REVERSE_SOLIDUS, WHITE_SPACE_SET};
+
+/// Returns the length of the leading JSON whitespace in `bytes`.
+#[inline]
+pub(crate) fn whitespace_len(bytes: &[u8]) -> usize {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    {
+        let len = simd::whitespace_len(bytes);
+        len + scalar_whitespace_len(&bytes[len..])
+    }
+
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    scalar_whitespace_len(bytes)
+}
+
+/// Returns the length of the leading bytes in `bytes` which can be copied
+/// into a JSON string directly, that is, the bytes before the first `"`, `\`
+/// or control character.
+#[inline]
+pub(crate) fn unescaped_len(bytes: &[u8]) -> usize {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    {
+        let len = simd::unescaped_len(bytes);
+        len + scalar_unescaped_len(&bytes[len..])
+    }
+
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    scalar_unescaped_len(bytes)
+}
+
+/// Returns the position of the closing quotation mark of the string whose content
+/// starts at `pos`, or `None` if the string is not closed. Escapes are skipped but
+/// not checked.
+pub(crate) fn skip_string(slice: &[u8], mut pos: usize) -> Option<usize> {
+    loop {
+        pos += unescaped_len(&slice[pos..]);
+        match *slice.get(pos)? {
+            QUOTATION_MARK => return Some(pos),
+            REVERSE_SOLIDUS => pos += 2,
+            _ => pos += 1,
+        }
+        if pos >= slice.len() {
+            return None;
+        }
+    }
+}
+
+#[inline]
+fn scalar_whitespace_len(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .position(|ch| !WHITE_SPACE_SET.contains(ch))
+        .unwrap_or(bytes.len())
+}
+
+// Checks 8 bytes at one time in a `u64`, and finds the exact position by
+// looking up `ESCAPE` only in the word which contains a special byte.
+#[inline]
+fn scalar_unescaped_len(bytes: &[u8]) -> usize {
+    const ONES: u64 = 0x0101_0101_0101_0101;
+    const HIGHS: u64 = 0x8080_8080_8080_8080;
+
+    // The high bit of a byte in the result is set if the byte in `word` is less
+    // than `n`. `n` must not be greater than 0x80.
+    #[inline]
+    fn less_than(word: u64, n: u8) -> u64 {
+        word.wrapping_sub(ONES * n as u64) & !word & HIGHS
+    }
+
+    let mut len = 0;
+    for chunk in bytes.chunks_exact(8) {
+        let word = u64::from_le_bytes(chunk.try_into().unwrap());
+        // A byte equals to `ch` if and only if the xor result is zero.
+        let special = less_than(word ^ (ONES * QUOTATION_MARK as u64), 1)
+            | less_than(word ^ (ONES * REVERSE_SOLIDUS as u64), 1)
+            | less_than(word, 0x20);
+        if special != 0 {
+            break;
+        }
+        len += 8;
+    }
+    len + bytes[len..]
+        .iter()
+        .position(|ch| ESCAPE[*ch as usize])
+        .unwrap_or(bytes.len() - len)
+}
+
+// Each function here checks 16 bytes at one time, and returns a length which is
+// not greater than the real one. The scalar implementation continues from it,
+// which also handles the tail shorter than 16 bytes.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd {
+    use crate::consts::{QUOTATION_MARK, REVERSE_SOLIDUS};
+    use core::arch::x86_64::*;
+
+    pub(super) fn whitespace_len(bytes: &[u8]) -> usize {
+        let mut i = 0;
+        // SSE2 is always available on x86_64.
+        unsafe {
+            let space = _mm_set1_epi8(b' ' as i8);
+            let ht = _mm_set1_epi8(b'\t' as i8);
+            let lf = _mm_set1_epi8(b'\n' as i8);
+            let cr = _mm_set1_epi8(b'\r' as i8);
+            while i + 16 <= bytes.len() {
+                let block = _mm_loadu_si128(bytes.as_ptr().add(i) as *const __m128i);
+                let whitespace = _mm_or_si128(
+                    _mm_or_si128(_mm_cmpeq_epi8(block, space), _mm_cmpeq_epi8(block, ht)),
+                    _mm_or_si128(_mm_cmpeq_epi8(block, lf), _mm_cmpeq_epi8(block, cr)),
+                );
+                let mask = !(_mm_movemask_epi8(whitespace) as u32) & 0xffff;
+                if mask != 0 {
+                    return i + mask.trailing_zeros() as usize;
+                }
+                i += 16;
+            }
+        }
+        i
+    }
+
+    pub(super) fn unescaped_len(bytes: &[u8]) -> usize {
+        let mut i = 0;
+        // SSE2 is always available on x86_64.
+        unsafe {
+            let quote = _mm_set1_epi8(QUOTATION_MARK as i8);
+            let backslash = _mm_set1_epi8(REVERSE_SOLIDUS as i8);
+            // A byte is a control character if and only if max(byte, 0x1F) == 0x1F.
+            let control = _mm_set1_epi8(0x1f);
+            while i + 16 <= bytes.len() {
+                let block = _mm_loadu_si128(bytes.as_ptr().add(i) as *const __m128i);
+                let special = _mm_or_si128(
+                    _mm_or_si128(
+                        _mm_cmpeq_epi8(block, quote),
+                        _mm_cmpeq_epi8(block, backslash),
+                    ),
+                    _mm_cmpeq_epi8(_mm_max_epu8(block, control), control),
+                );
+                let mask = _mm_movemask_epi8(special) as u32;
+                if mask != 0 {
+                    return i + mask.trailing_zeros() as usize;
+                }
+                i += 16;
+            }
+        }
+        i
+    }
+}
+
+#[cfg(test)]
+mod ut_scan {
+    use crate::scan::{unescaped_len, whitespace_len};
+
+    /// UT test for `whitespace_len`.
+    ///
+    /// # Title
+    /// ut_scan_whitespace_len
+    ///
+    /// # Brief
+    /// 1. Creates some byte slices with leading whitespace of different lengths.
+    /// 2. Calls `whitespace_len`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_scan_whitespace_len() {
+        assert_eq!(whitespace_len(b""), 0);
+        assert_eq!(whitespace_len(b"{}"), 0);
+        assert_eq!(whitespace_len(b" \t\r\n"), 4);
+        for n in [1, 15, 16, 17, 31, 32, 33, 100] {
+            let mut bytes: Vec<u8> = b" \n\r\t".iter().cycle().take(n).copied().collect();
+            assert_eq!(whitespace_len(&bytes), n);
+            bytes.extend_from_slice(b"  [1, 2]");
+            assert_eq!(whitespace_len(&bytes), n + 2);
+        }
+    }
+
+    /// UT test for `unescaped_len`.
+    ///
+    /// # Title
+    /// ut_scan_unescaped_len
+    ///
+    /// # Brief
+    /// 1. Creates some byte slices with special characters at different positions.
+    /// 2. Calls `unescaped_len`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_scan_unescaped_len() {
+        assert_eq!(unescaped_len(b""), 0);
+        assert_eq!(unescaped_len(b"\""), 0);
+        assert_eq!(unescaped_len("中文 text".as_bytes()), "中文 text".len());
+        for n in [0, 1, 15, 16, 17, 31, 32, 33, 100] {
+            for special in [b'"', b'\\', 0x00, 0x1f] {
+                let mut bytes = "a中".repeat(n).into_bytes();
+                let len = bytes.len();
+                bytes.push(special);
+                bytes.extend_from_slice(b"abc\"");
+                assert_eq!(unescaped_len(&bytes), len);
+            }
+        }
+    }
+}
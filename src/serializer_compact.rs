@@ -11,33 +11,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::encoder::encode_string_inner;
-use crate::{Error, Error::*};
+use crate::encoder::{encode_float, encode_string_inner};
+use crate::writer::{ChunkWriter, Write};
+use crate::{base64, BytesFormat, Error, Error::*, NonFiniteFloat};
+use alloc::string::String;
+use alloc::vec::Vec;
 use serde::{ser, ser::SerializeSeq, Serialize};
 
 /// A data format that can serialize any data structure supported by Serde.
 struct Serializer<W>
 where
-    W: std::io::Write,
+    W: Write,
 {
     writer: W,
     element_num: Vec<usize>, // Used to record the number of traveled elements in the sequence.
-}
-
-/// An auxiliary struct which implements Write trait used in 'to_string' function.
-struct AuxiliaryWriter {
-    output: Vec<u8>,
-}
-
-impl std::io::Write for AuxiliaryWriter {
-    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        self.output.extend_from_slice(buf);
-        Ok(buf.len())
-    }
-
-    fn flush(&mut self) -> std::io::Result<()> {
-        Ok(())
-    }
+    bytes_format: BytesFormat,
 }
 
 /// ylong_json::serializer_compact supports two functions to produce as output: 'to_string' and 'to_writer'.
@@ -47,27 +35,139 @@ pub fn to_string<T>(value: &T) -> Result<String, Error>
 where
     T: Serialize,
 {
-    let mut writer = AuxiliaryWriter { output: Vec::new() };
-    to_writer(value, &mut writer)?;
-    Ok(unsafe { String::from_utf8_unchecked(writer.output) })
+    to_string_with_bytes_format(value, BytesFormat::Array)
+}
+
+/// The to_vec function serialize an instance which implements the Serialize Trait to a byte
+/// vector and return. It does not depend on `std`, so it is available on every target.
+///
+/// # Examples
+/// ```
+/// use ylong_json::to_vec;
+///
+/// assert_eq!(to_vec(&vec![1, 2]).unwrap(), b"[1,2]");
+/// ```
+pub fn to_vec<T>(value: &T) -> Result<Vec<u8>, Error>
+where
+    T: Serialize,
+{
+    to_vec_with_bytes_format(value, BytesFormat::Array)
 }
 
 /// The to_writer function serialize an instance which implements the Serialize Trait and
 /// writes result into the writer passed in by the user, which needs to implement the std::io::Write.
+#[cfg(feature = "std")]
 pub fn to_writer<T, W>(value: &T, writer: &mut W) -> Result<(), Error>
 where
     T: Serialize,
     W: std::io::Write,
+{
+    to_writer_with_bytes_format(value, writer, BytesFormat::Array)
+}
+
+/// The to_chunks function serialize an instance which implements the Serialize Trait and
+/// passes the result to `callback` as chunks of `chunk_size` bytes, only the last chunk can
+/// be shorter. If `chunk_size` is 0, every piece written by the serializer is passed as a
+/// chunk without buffering. It does not depend on `std`.
+///
+/// An error returned by `callback` stops the serialization and is returned.
+///
+/// # Examples
+/// ```
+/// use ylong_json::to_chunks;
+///
+/// let mut chunks = Vec::new();
+/// to_chunks(&vec![10, 20, 30], 4, |chunk| {
+///     chunks.push(chunk.to_vec());
+///     Ok(())
+/// })
+/// .unwrap();
+/// assert_eq!(chunks, [&b"[10,"[..], b"20,3", b"0]"]);
+/// ```
+pub fn to_chunks<T, F>(value: &T, chunk_size: usize, callback: F) -> Result<(), Error>
+where
+    T: Serialize,
+    F: FnMut(&[u8]) -> Result<(), Error>,
+{
+    let writer = ChunkWriter::new(chunk_size, callback);
+    serialize_into(value, writer, BytesFormat::Array)?.finish()
+}
+
+/// The same as `to_string`, but byte slices passed to `serialize_bytes` are
+/// written according to the given `BytesFormat`.
+///
+/// # Examples
+/// ```
+/// use serde::{Serialize, Serializer};
+/// use ylong_json::{to_string_with_bytes_format, Base64Alphabet, BytesFormat};
+///
+/// struct Bytes(Vec<u8>);
+///
+/// impl Serialize for Bytes {
+///     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+///         serializer.serialize_bytes(&self.0)
+///     }
+/// }
+///
+/// let bytes = Bytes(b"hello".to_vec());
+/// let format = BytesFormat::Base64(Base64Alphabet::Standard);
+/// assert_eq!(
+///     to_string_with_bytes_format(&bytes, format).unwrap(),
+///     "\"aGVsbG8=\""
+/// );
+/// ```
+pub fn to_string_with_bytes_format<T>(value: &T, format: BytesFormat) -> Result<String, Error>
+where
+    T: Serialize,
+{
+    let output = to_vec_with_bytes_format(value, format)?;
+    Ok(unsafe { String::from_utf8_unchecked(output) })
+}
+
+/// The same as `to_vec`, but byte slices passed to `serialize_bytes` are
+/// written according to the given `BytesFormat`.
+pub fn to_vec_with_bytes_format<T>(value: &T, format: BytesFormat) -> Result<Vec<u8>, Error>
+where
+    T: Serialize,
+{
+    let mut output = Vec::new();
+    serialize_into(value, &mut output, format)?;
+    Ok(output)
+}
+
+/// The same as `to_writer`, but byte slices passed to `serialize_bytes` are
+/// written according to the given `BytesFormat`.
+#[cfg(feature = "std")]
+pub fn to_writer_with_bytes_format<T, W>(
+    value: &T,
+    writer: &mut W,
+    format: BytesFormat,
+) -> Result<(), Error>
+where
+    T: Serialize,
+    W: std::io::Write,
+{
+    serialize_into(value, writer, format)?;
+    Ok(())
+}
+
+/// Serializes `value` into any writer which implements the crate's own `Write` trait,
+/// and returns the writer.
+fn serialize_into<T, W>(value: &T, writer: W, format: BytesFormat) -> Result<W, Error>
+where
+    T: Serialize,
+    W: Write,
 {
     let mut serializer = Serializer {
         writer,
         element_num: Vec::new(),
+        bytes_format: format,
     };
     value.serialize(&mut serializer)?;
-    Ok(())
+    Ok(serializer.writer)
 }
 
-impl<'a, W: std::io::Write> ser::Serializer for &'a mut Serializer<W> {
+impl<W: Write> ser::Serializer for &mut Serializer<W> {
     // Using `Ok` to propagate the data structure around simplifies Serializers
     // which build in-memory data structures. Set 'ok=()' and write the serialization
     // result to the buffer contained by the instance.
@@ -137,8 +237,8 @@ impl<'a, W: std::io::Write> ser::Serializer for &'a mut Serializer<W> {
     }
 
     fn serialize_f64(self, v: f64) -> Result<(), Error> {
-        self.writer.write_fmt(format_args!("{v:?}"))?;
-        Ok(())
+        // NaN and infinite floats fail the serialization, the same as `EncodeConfig::default`.
+        encode_float(&mut self.writer, v, NonFiniteFloat::Error)
     }
 
     fn serialize_char(self, v: char) -> Result<(), Error> {
@@ -152,14 +252,24 @@ impl<'a, W: std::io::Write> ser::Serializer for &'a mut Serializer<W> {
         Ok(())
     }
 
-    // Serialize a byte array into an array of bytes.
+    // Serialize a byte array into an array of bytes or a base64 string.
     // Binary formats will typically represent byte arrays more compactly.
     fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
-        let mut seq = self.serialize_seq(Some(v.len()))?;
-        for byte in v {
-            seq.serialize_element(byte)?;
+        match self.bytes_format {
+            BytesFormat::Array => {
+                let mut seq = self.serialize_seq(Some(v.len()))?;
+                for byte in v {
+                    seq.serialize_element(byte)?;
+                }
+                seq.end()
+            }
+            BytesFormat::Base64(alphabet) => {
+                self.writer.write_all(b"\"")?;
+                base64::encode(&mut self.writer, v, alphabet)?;
+                self.writer.write_all(b"\"")?;
+                Ok(())
+            }
         }
-        seq.end()
     }
 
     // JSON `null` represent an absent optional.
@@ -306,7 +416,7 @@ impl<'a, W: std::io::Write> ser::Serializer for &'a mut Serializer<W> {
 
 impl<W> Serializer<W>
 where
-    W: std::io::Write,
+    W: Write,
 {
     // Serialize a single member of sequence or map.
     fn whether_to_add_comma(&mut self) -> Result<(), Error> {
@@ -336,7 +446,7 @@ where
 // Finally, one call to end the serializing of the compound type.
 //
 // This impl is SerializeSeq so these methods are called after calling 'serialize_seq' on Serializer.
-impl<'a, W: std::io::Write> ser::SerializeSeq for &'a mut Serializer<W> {
+impl<W: Write> ser::SerializeSeq for &mut Serializer<W> {
     // Must match the `Ok` type of the serializer.
     type Ok = ();
     // Must match the `Error` type of the serializer.
@@ -359,7 +469,7 @@ impl<'a, W: std::io::Write> ser::SerializeSeq for &'a mut Serializer<W> {
 }
 
 // Same thing but for tuples.
-impl<'a, W: std::io::Write> ser::SerializeTuple for &'a mut Serializer<W> {
+impl<W: Write> ser::SerializeTuple for &mut Serializer<W> {
     type Ok = ();
     type Error = Error;
 
@@ -378,7 +488,7 @@ impl<'a, W: std::io::Write> ser::SerializeTuple for &'a mut Serializer<W> {
 }
 
 // Same thing but for tuple structs.
-impl<'a, W: std::io::Write> ser::SerializeTupleStruct for &'a mut Serializer<W> {
+impl<W: Write> ser::SerializeTupleStruct for &mut Serializer<W> {
     type Ok = ();
     type Error = Error;
 
@@ -405,7 +515,7 @@ impl<'a, W: std::io::Write> ser::SerializeTupleStruct for &'a mut Serializer<W>
 //
 // So the `end` method in this impl is responsible for closing
 // both the `]` and the `}`.
-impl<'a, W: std::io::Write> ser::SerializeTupleVariant for &'a mut Serializer<W> {
+impl<W: Write> ser::SerializeTupleVariant for &mut Serializer<W> {
     type Ok = ();
     type Error = Error;
 
@@ -431,7 +541,7 @@ impl<'a, W: std::io::Write> ser::SerializeTupleVariant for &'a mut Serializer<W>
 // The 'serialize_entry' method allows the serializer to be optimized
 // for cases where both keys and values are available. In JSON, it doesn't
 // make a difference so the default behavior for `serialize_entry` is fine.
-impl<'a, W: std::io::Write> ser::SerializeMap for &'a mut Serializer<W> {
+impl<W: Write> ser::SerializeMap for &mut Serializer<W> {
     type Ok = ();
     type Error = Error;
 
@@ -463,7 +573,7 @@ impl<'a, W: std::io::Write> ser::SerializeMap for &'a mut Serializer<W> {
 }
 
 // A structure is like a map where the keys are restricted to a compile-time constant string.
-impl<'a, W: std::io::Write> ser::SerializeStruct for &'a mut Serializer<W> {
+impl<W: Write> ser::SerializeStruct for &mut Serializer<W> {
     type Ok = ();
     type Error = Error;
 
@@ -485,7 +595,7 @@ impl<'a, W: std::io::Write> ser::SerializeStruct for &'a mut Serializer<W> {
 
 // Similar to 'SerializeTupleVariant', the 'end' method here is responsible for
 // closing two curly braces opened by 'serialize_struct_variant'.
-impl<'a, W: std::io::Write> ser::SerializeStructVariant for &'a mut Serializer<W> {
+impl<W: Write> ser::SerializeStructVariant for &mut Serializer<W> {
     type Ok = ();
     type Error = Error;
 
@@ -707,4 +817,83 @@ mod ut_serializer {
         hash.insert("banana", 2);
         assert!(to_string(&hash).is_ok());
     }
+
+    /// UT test to serialize into a byte vector
+    ///
+    /// # Title
+    /// ut_serialize_to_vec
+    ///
+    /// # Brief
+    /// 1.Uses Serializer::to_vec and to_vec_with_bytes_format methods to serialize values.
+    /// 2.Checks if the test results are correct.
+    #[test]
+    fn ut_serialize_to_vec() {
+        use crate::Base64Alphabet;
+
+        let value = vec![String::from("a\n"), String::from("中")];
+        assert_eq!(
+            to_vec(&value).unwrap(),
+            to_string(&value).unwrap().into_bytes()
+        );
+        assert_eq!(to_vec(&()).unwrap(), b"null");
+
+        struct Bytes<'a>(&'a [u8]);
+
+        impl Serialize for Bytes<'_> {
+            fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(self.0)
+            }
+        }
+
+        let format = BytesFormat::Base64(Base64Alphabet::Standard);
+        assert_eq!(
+            to_vec_with_bytes_format(&Bytes(b"hello"), format).unwrap(),
+            br#""aGVsbG8=""#
+        );
+    }
+
+    /// UT test to serialize bytes
+    ///
+    /// # Title
+    /// ut_serialize_bytes
+    ///
+    /// # Brief
+    /// 1.Uses Serializer::to_string_with_bytes_format method to serialize bytes.
+    /// 2.Checks if the test results are correct.
+    #[test]
+    fn ut_serialize_bytes() {
+        use crate::Base64Alphabet;
+
+        struct Bytes<'a>(&'a [u8]);
+
+        impl Serialize for Bytes<'_> {
+            fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(self.0)
+            }
+        }
+
+        let value = Bytes(&[0xfb, 0xff, 0x00]);
+        assert_eq!(to_string(&value).unwrap(), "[251,255,0]");
+        assert_eq!(
+            to_string_with_bytes_format(&value, BytesFormat::Array).unwrap(),
+            "[251,255,0]"
+        );
+        assert_eq!(
+            to_string_with_bytes_format(&value, BytesFormat::Base64(Base64Alphabet::Standard))
+                .unwrap(),
+            r#""+/8A""#
+        );
+        assert_eq!(
+            to_string_with_bytes_format(&value, BytesFormat::Base64(Base64Alphabet::UrlSafe))
+                .unwrap(),
+            r#""-_8A""#
+        );
+
+        let value = vec![Bytes(b"a"), Bytes(b"")];
+        assert_eq!(
+            to_string_with_bytes_format(&value, BytesFormat::Base64(Base64Alphabet::Standard))
+                .unwrap(),
+            r#"["YQ==",""]"#
+        );
+    }
 }
@@ -0,0 +1,160 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Splicing of a value into a JSON text.
+//!
+//! The Objects and Arrays on the path to the replaced value are scanned by the
+//! scanner of `Document`, which only tracks strings, brackets, keys, colons and
+//! commas, and the bytes are copied without being parsed. If the path cannot be
+//! followed, the whole text is parsed to report the error the same as
+//! `JsonValue::from_text`.
+
+use crate::document::{find_value, pointer_tokens};
+use crate::{Error, JsonValue};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Replaces the value at the JSON Pointer (RFC 6901) in the JSON text `original`
+/// with `replacement`, and returns the new text. The replacement is written in the
+/// compact format, and the rest of the text is copied as it is, without being
+/// parsed or encoded again, which suits small edits of large persisted texts.
+///
+/// Only the brackets, keys, colons and commas of the Objects and Arrays on the path
+/// are checked, so other errors in the text, such as an invalid number, are not
+/// reported. If a key appears more than once in an object, the last member is
+/// replaced, the same as `Document`, which is the one kept by the Objects which
+/// de-duplicate keys. The empty pointer replaces the whole value, keeping the
+/// whitespace around it.
+///
+/// Returns `Error::InvalidPointer` if the pointer is malformed, `Error::IndexNotFound`
+/// if the value does not exist, or the parsing error of the text if the path
+/// cannot be followed.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{splice, JsonValue};
+///
+/// let text = br#"{"users": [{"name": "a", "age": 20}, {"name": "b", "age": 30}]}"#;
+/// let spliced = splice(text, "/users/1/age", &JsonValue::from(31)).unwrap();
+/// assert_eq!(
+///     spliced,
+///     br#"{"users": [{"name": "a", "age": 20}, {"name": "b", "age": 31}]}"#
+/// );
+///
+/// assert!(splice(text, "/users/2", &JsonValue::Null).is_err());
+/// ```
+pub fn splice(original: &[u8], pointer: &str, replacement: &JsonValue) -> Result<Vec<u8>, Error> {
+    let tokens =
+        pointer_tokens(pointer).ok_or_else(|| Error::InvalidPointer(String::from(pointer)))?;
+    let Some(range) = find_value(original, &tokens) else {
+        JsonValue::from_text(original)?;
+        return Err(Error::IndexNotFound(String::from(pointer)));
+    };
+    let encoded = replacement.to_compact_string()?;
+
+    let mut output = Vec::with_capacity(original.len() - range.len() + encoded.len());
+    output.extend_from_slice(&original[..range.start]);
+    output.extend_from_slice(encoded.as_bytes());
+    output.extend_from_slice(&original[range.end..]);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod ut_splice {
+    use crate::{splice, Error, JsonValue};
+
+    /// UT test for `splice`.
+    ///
+    /// # Title
+    /// ut_splice
+    ///
+    /// # Brief
+    /// 1. Splices some values into a text at different pointers.
+    /// 2. Checks if only the referenced values are replaced.
+    /// 3. Checks if the spliced texts are the same as setting the values after parsing.
+    #[test]
+    fn ut_splice() {
+        let text = "{\n  \"a\": [1, \"x]\\\"\", {\"b\": null}],\n  \"c/d\": {\"e~\": true},\n  \"f\\u0067\": -1.5e3,\n  \"h\": 0\n}\n";
+        let cases = [
+            ("/a/0", "{\n  \"a\": [[], \"x]\\\"\", {\"b\": null}],\n  \"c/d\": {\"e~\": true},\n  \"f\\u0067\": -1.5e3,\n  \"h\": 0\n}\n"),
+            ("/a/2/b", "{\n  \"a\": [1, \"x]\\\"\", {\"b\": []}],\n  \"c/d\": {\"e~\": true},\n  \"f\\u0067\": -1.5e3,\n  \"h\": 0\n}\n"),
+            ("/c~1d/e~0", "{\n  \"a\": [1, \"x]\\\"\", {\"b\": null}],\n  \"c/d\": {\"e~\": []},\n  \"f\\u0067\": -1.5e3,\n  \"h\": 0\n}\n"),
+            ("/fg", "{\n  \"a\": [1, \"x]\\\"\", {\"b\": null}],\n  \"c/d\": {\"e~\": true},\n  \"f\\u0067\": [],\n  \"h\": 0\n}\n"),
+            ("", "[]\n"),
+        ];
+        let replacement = JsonValue::from_text("[]").unwrap();
+        for (pointer, expected) in cases {
+            let spliced = splice(text.as_bytes(), pointer, &replacement).unwrap();
+            assert_eq!(String::from_utf8(spliced).unwrap(), expected, "{pointer}");
+        }
+
+        // The last member of a duplicate key is replaced.
+        let text = r#"{"a": [1], "a": 2}"#;
+        let spliced = splice(text.as_bytes(), "/a", &JsonValue::from(9)).unwrap();
+        assert_eq!(spliced, br#"{"a": [1], "a": 9}"#);
+
+        let text = r#" [ {"k": "v"} , 2 ] "#;
+        let spliced = splice(text.as_bytes(), "/0/k", &JsonValue::from("w\n")).unwrap();
+        assert_eq!(spliced, br#" [ {"k": "w\n"} , 2 ] "#);
+        let spliced = splice(text.as_bytes(), "/1", &JsonValue::from(3)).unwrap();
+        assert_eq!(spliced, br#" [ {"k": "v"} , 3 ] "#);
+        let mut value = JsonValue::from_text(text).unwrap();
+        value[1] = JsonValue::from(3);
+        assert_eq!(JsonValue::from_text(spliced).unwrap(), value);
+    }
+
+    /// UT test for the errors of `splice`.
+    ///
+    /// # Title
+    /// ut_splice_error
+    ///
+    /// # Brief
+    /// 1. Splices values into texts at missing or malformed pointers, and into invalid texts.
+    /// 2. Checks if the errors are correct.
+    #[test]
+    fn ut_splice_error() {
+        let text = br#"{"a": [1, 2], "b": "s"}"#;
+        let null = JsonValue::Null;
+        for pointer in ["/c", "/a/2", "/a/-", "/a/x", "/a/01", "/b/0", "/a/0/0"] {
+            let result = splice(text, pointer, &null);
+            assert!(matches!(result, Err(Error::IndexNotFound(_))), "{pointer}");
+        }
+        assert!(matches!(
+            splice(text, "a", &null),
+            Err(Error::InvalidPointer(_))
+        ));
+        assert!(matches!(
+            splice(text, "/a", &JsonValue::from(f64::NAN)),
+            Err(Error::NonFiniteFloat)
+        ));
+
+        let cases = [
+            (&b"{\"a\" 1}"[..], "/a"),
+            (b"[1 2]", "/1"),
+            (b"[\"a", "/0"),
+            (b"", ""),
+        ];
+        for (text, pointer) in cases {
+            let result = splice(text, pointer, &null);
+            assert!(matches!(result, Err(Error::Parsing(_))), "{pointer}");
+        }
+        // The first member of a duplicate key is not referenced.
+        let result = splice(br#"{"a": [1], "a": 2}"#, "/a/0", &null);
+        assert!(matches!(result, Err(Error::IndexNotFound(_))));
+
+        // Only the brackets, keys, colons and commas on the path are checked.
+        assert!(splice(br#"[1, tru, {"b": 1e}, "\x"] x"#, "/0", &null).is_ok());
+        let result = splice(b"[1, 2 3", "/0", &null);
+        assert!(matches!(result, Err(Error::Parsing(_))));
+    }
+}
@@ -11,22 +11,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::reader::Cacheable;
+use crate::reader::{Borrowable, Cacheable};
 use crate::{
-    consts::*, deserializer::Deserializer, Array, Error, JsonValue, Number, Object, ParseError,
+    consts::*, deserializer::Deserializer, Array, Error, JsonValue, Number, NumberOverflow, Object,
+    ParseError,
 };
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::convert::TryFrom;
-#[cfg(feature = "c_adapter")]
-pub use std::ffi::CString;
 
 macro_rules! unexpected_character {
     ($deserializer: expr) => {{
         let position = $deserializer.reader.position();
         match read_error_char($deserializer) {
-            Ok(Some(ch)) => {
-                Err(ParseError::UnexpectedCharacter(position.line(), position.column(), ch).into())
-            }
-            Ok(None) => Err(ParseError::InvalidUtf8Bytes(position.line()).into()),
+            Ok(Some(ch)) => Err(ParseError::UnexpectedCharacter(position, ch).into()),
+            Ok(None) => Err(ParseError::InvalidUtf8Bytes(position).into()),
             Err(e) => Err(e),
         }
     }};
@@ -34,18 +33,18 @@ macro_rules! unexpected_character {
 
 macro_rules! unexpected_eoj {
     ($deserializer: expr) => {
-        Err(ParseError::UnexpectedEndOfJson($deserializer.reader.position().line()).into())
+        Err(ParseError::UnexpectedEndOfJson($deserializer.reader.position()).into())
     };
 }
 
 macro_rules! eat_whitespace_until_not {
     ($deserializer: expr) => {{
-        loop {
-            match $deserializer.reader.peek().map_err(Error::new_reader)? {
-                Some(ch) if WHITE_SPACE_SET.contains(&ch) => $deserializer.reader.discard(),
-                x => break x,
-            }
-        }
+        let ch = $deserializer
+            .reader
+            .discard_whitespace()
+            .map_err(Error::new_reader)?;
+        check_document(&$deserializer)?;
+        ch
     }};
 }
 
@@ -62,11 +61,23 @@ macro_rules! eat_digits_until_not {
 
 macro_rules! match_str {
     ($deserializer: expr, $str: expr) => {{
-        for item in $str {
-            match $deserializer.reader.peek().map_err(Error::new_reader)? {
-                Some(ch) if ch == *item => $deserializer.reader.discard(),
-                Some(_) => return unexpected_character!($deserializer),
-                None => return unexpected_eoj!($deserializer),
+        let str: &[u8] = $str;
+        // The whole literal is compared at one time. If it does not match, the
+        // bytes are compared one by one to find the position of the error.
+        if $deserializer
+            .reader
+            .peek_n(str.len())
+            .map_err(Error::new_reader)?
+            == Some(str)
+        {
+            $deserializer.reader.discard_n(str.len());
+        } else {
+            for item in str {
+                match $deserializer.reader.peek().map_err(Error::new_reader)? {
+                    Some(ch) if ch == *item => $deserializer.reader.discard(),
+                    Some(_) => return unexpected_character!($deserializer),
+                    None => return unexpected_eoj!($deserializer),
+                }
             }
         }
     }};
@@ -75,13 +86,58 @@ macro_rules! match_str {
 pub(crate) fn check_recursion<R: Cacheable>(
     deserializer: &mut Deserializer<R>,
 ) -> Result<(), Error> {
-    if deserializer.recursion_depth > RECURSION_LIMIT {
+    if deserializer.recursion_depth > deserializer.config.max_depth {
         Err(Error::ExceedRecursionLimit)
     } else {
         Ok(())
     }
 }
 
+// Checks the length of the text read so far. It is called between tokens, so the
+// whole text is checked when the trailing whitespace is skipped.
+#[inline]
+pub(crate) fn check_document<R: Cacheable>(deserializer: &Deserializer<R>) -> Result<(), Error> {
+    if deserializer.reader.index() > deserializer.config.max_document_bytes {
+        Err(Error::ExceedDocumentLimit)
+    } else {
+        Ok(())
+    }
+}
+
+#[inline]
+fn check_string<R: Cacheable>(deserializer: &Deserializer<R>, len: usize) -> Result<(), Error> {
+    if len > deserializer.config.max_string_bytes {
+        Err(Error::ExceedStringLimit)
+    } else {
+        Ok(())
+    }
+}
+
+// Adds `bytes` to the estimated memory allocated for the parsed values.
+#[inline]
+pub(crate) fn check_alloc<R: Cacheable>(
+    deserializer: &mut Deserializer<R>,
+    bytes: usize,
+) -> Result<(), Error> {
+    deserializer.allocated = deserializer.allocated.saturating_add(bytes);
+    if deserializer.allocated > deserializer.config.max_alloc_bytes {
+        Err(Error::ExceedMemoryLimit)
+    } else {
+        Ok(())
+    }
+}
+
+// Checks the bytes of a string in the RFC 8259 mode. Bytes outside strings are
+// always ASCII, so the whole text is valid UTF-8 if all strings are.
+#[inline]
+fn check_utf8<R: Cacheable>(deserializer: &Deserializer<R>, bytes: &[u8]) -> Result<(), Error> {
+    if deserializer.config.strict_rfc8259 && core::str::from_utf8(bytes).is_err() {
+        Err(ParseError::InvalidUtf8Bytes(deserializer.reader.position()).into())
+    } else {
+        Ok(())
+    }
+}
+
 #[inline]
 pub(crate) fn start_parsing<R: Cacheable>(
     deserializer: &mut Deserializer<R>,
@@ -90,45 +146,61 @@ pub(crate) fn start_parsing<R: Cacheable>(
 
     // If the text is not finished, return TrailingBytes Error.
     if eat_whitespace_until_not!(deserializer).is_some() {
-        return Err(ParseError::TrailingBytes(deserializer.reader.position().line()).into());
+        return Err(ParseError::TrailingBytes(deserializer.reader.position()).into());
     }
     Ok(value)
 }
 
+// Parses the first value of the text and returns it with the number of bytes
+// consumed, which include the leading whitespace but not the trailing bytes.
+#[inline]
+pub(crate) fn start_partial_parsing<R: Cacheable>(
+    deserializer: &mut Deserializer<R>,
+) -> Result<(JsonValue, usize), Error> {
+    let value = parse_value(deserializer)?;
+    Ok((value, deserializer.reader.index()))
+}
+
 // Parses value.
-fn parse_value<R: Cacheable>(deserializer: &mut Deserializer<R>) -> Result<JsonValue, Error> {
-    match eat_whitespace_until_not!(deserializer) {
-        Some(ZERO..=NINE | MINUS) => Ok(JsonValue::Number(parse_number(deserializer)?)),
+pub(crate) fn parse_value<R: Cacheable>(
+    deserializer: &mut Deserializer<R>,
+) -> Result<JsonValue, Error> {
+    let value = match eat_whitespace_until_not!(deserializer) {
+        Some(ZERO..=NINE | MINUS | PLUS) => JsonValue::Number(parse_number(deserializer)?),
         Some(LEFT_CURLY_BRACKET) => {
             deserializer.reader.discard();
-            parse_object(deserializer)
+            parse_object(deserializer)?
         }
         Some(LEFT_SQUARE_BRACKET) => {
             deserializer.reader.discard();
-            parse_array(deserializer)
+            parse_array(deserializer)?
         }
         Some(QUOTATION_MARK) => {
             deserializer.reader.discard();
-            Ok(JsonValue::String(parse_string(deserializer)?))
+            JsonValue::from(parse_string(deserializer)?)
         }
         Some(T_LOWER) => {
             deserializer.reader.discard();
             match_str!(deserializer, TRUE_LEFT_STR);
-            Ok(JsonValue::Boolean(true))
+            JsonValue::Boolean(true)
         }
         Some(F_LOWER) => {
             deserializer.reader.discard();
             match_str!(deserializer, FALSE_LEFT_STR);
-            Ok(JsonValue::Boolean(false))
+            JsonValue::Boolean(false)
         }
         Some(N_LOWER) => {
             deserializer.reader.discard();
             match_str!(deserializer, NULL_LEFT_STR);
-            Ok(JsonValue::Null)
+            JsonValue::Null
         }
-        Some(_) => unexpected_character!(deserializer),
-        None => unexpected_eoj!(deserializer),
+        Some(_) => return unexpected_character!(deserializer),
+        None => return unexpected_eoj!(deserializer),
+    };
+    if let Some(stats) = deserializer.stats.as_mut() {
+        stats.record_value(&value);
     }
+    Ok(value)
 }
 
 // Parses object
@@ -142,9 +214,16 @@ fn parse_object<R: Cacheable>(deserializer: &mut Deserializer<R>) -> Result<Json
 
     deserializer.recursion_depth += 1;
     check_recursion(deserializer)?;
+    if let Some(stats) = deserializer.stats.as_mut() {
+        stats.record_depth(deserializer.recursion_depth);
+    }
 
     // Creates an Object to store key-value pairs.
+    #[cfg(feature = "list_object")]
+    let mut object = Object::with_node_chunk_size(deserializer.config.node_chunk_size);
+    #[cfg(not(feature = "list_object"))]
     let mut object = Object::new();
+    let mut members = 0;
     // The initial status is Start.
     let mut state = InnerState::Start;
 
@@ -159,8 +238,23 @@ fn parse_object<R: Cacheable>(deserializer: &mut Deserializer<R>) -> Result<Json
             // If "\" is encountered in the initial state or
             // if "," is already present, matches key-value pairs.
             (InnerState::Start | InnerState::AfterComma, Some(QUOTATION_MARK)) => {
+                members += 1;
+                if members > deserializer.config.max_object_members {
+                    return Err(Error::ExceedObjectLimit);
+                }
+                check_alloc(deserializer, core::mem::size_of::<JsonValue>())?;
                 deserializer.reader.discard();
-                let k = parse_key(deserializer)?;
+                let k = parse_string(deserializer)?;
+                if deserializer.config.reject_duplicate_keys && object.contains_key(&k) {
+                    let position = deserializer.reader.position();
+                    return Err(ParseError::DuplicateKey(position, k).into());
+                }
+                if let Some(stats) = deserializer.stats.as_mut() {
+                    stats.record_key(&k);
+                    if deserializer.config.record_duplicate_keys && object.contains_key(&k) {
+                        stats.record_duplicate_key(&k, deserializer.reader.position());
+                    }
+                }
 
                 // Matches ':'
                 match eat_whitespace_until_not!(deserializer) {
@@ -194,76 +288,159 @@ fn parse_object<R: Cacheable>(deserializer: &mut Deserializer<R>) -> Result<Json
 }
 
 // Parses string
-#[cfg(not(feature = "c_adapter"))]
 pub(crate) fn parse_string<R: Cacheable>(
     deserializer: &mut Deserializer<R>,
 ) -> Result<String, Error> {
     let vec = parse_string_inner(deserializer)?;
-    // Since the vec contents are all checked upon matching, the unchecked method is used directly here.
-    Ok(unsafe { String::from_utf8_unchecked(vec) })
-}
-
-#[cfg(feature = "c_adapter")]
-pub(crate) fn parse_string<R: Cacheable>(
-    deserializer: &mut Deserializer<R>,
-) -> Result<CString, Error> {
-    let vec = parse_string_inner(deserializer)?;
-    // Since the vec contents are all checked upon matching, the unchecked method is used directly here.
-    Ok(unsafe { CString::from_vec_unchecked(vec) })
+    into_string(deserializer, vec)
 }
 
-// Parses key
+// Converts the bytes of a parsed string. They are checked here in all modes,
+// because a `String` must be valid UTF-8.
 #[inline]
-fn parse_key<R: Cacheable>(deserializer: &mut Deserializer<R>) -> Result<String, Error> {
-    let vec = parse_string_inner(deserializer)?;
-    // Since the vec contents are all checked upon matching, the unchecked method is used directly here.
-    Ok(unsafe { String::from_utf8_unchecked(vec) })
+fn into_string<R: Cacheable>(
+    deserializer: &Deserializer<R>,
+    vec: Vec<u8>,
+) -> Result<String, Error> {
+    String::from_utf8(vec)
+        .map_err(|_| ParseError::InvalidUtf8Bytes(deserializer.reader.position()).into())
 }
 
 pub(crate) fn parse_string_inner<R: Cacheable>(
     deserializer: &mut Deserializer<R>,
 ) -> Result<Vec<u8>, Error> {
-    // Used to store strings.
-    let mut vec = Vec::new();
+    // Sets the starting position of the string.
+    deserializer.reader.start_caching();
+    parse_string_cached(deserializer)
+}
+
+// A string which is borrowed from the input, or decoded into the scratch buffer
+// of the deserializer.
+pub(crate) enum Reference<'b, 'c, T: ?Sized> {
+    Borrowed(&'b T),
+    Copied(&'c T),
+}
 
+// Parses a string which borrows from the input if the reader supports it and
+// the string contains no escape characters. Otherwise, the string is decoded
+// into the scratch buffer, and the caller decides whether to copy it out.
+pub(crate) fn parse_str<'de, 's, R: Borrowable<'de>>(
+    deserializer: &'s mut Deserializer<R>,
+) -> Result<Reference<'de, 's, str>, Error> {
+    // The bytes are only checked in the RFC 8259 mode while parsing, so they are
+    // checked here in all modes.
+    let result = match parse_str_bytes(deserializer)? {
+        Reference::Borrowed(slice) => core::str::from_utf8(slice).map(Reference::Borrowed),
+        // The copied bytes are in the scratch buffer.
+        Reference::Copied(_) => core::str::from_utf8(&deserializer.scratch).map(Reference::Copied),
+    };
+    match result {
+        Ok(str) => Ok(str),
+        Err(_) => Err(ParseError::InvalidUtf8Bytes(deserializer.reader.position()).into()),
+    }
+}
+
+// Parses the bytes of a string in the same way as `parse_str`.
+pub(crate) fn parse_str_bytes<'de, 's, R: Borrowable<'de>>(
+    deserializer: &'s mut Deserializer<R>,
+) -> Result<Reference<'de, 's, [u8]>, Error> {
     // Sets the starting position of the string.
     deserializer.reader.start_caching();
 
-    loop {
-        match deserializer.reader.peek().map_err(Error::new_reader)? {
-            Some(ch) => {
-                // Improves character recognition speed (reduce the number of comparisons) by looking up tables.
-                // If it is an ordinary character, skips it.
-                if !ESCAPE[ch as usize] {
-                    deserializer.reader.discard();
-                    continue;
-                }
-                match ch {
-                    // When '"' is encountered, the string is added to vec.
-                    QUOTATION_MARK => {
-                        vec.extend_from_slice(deserializer.reader.cached_slice().unwrap());
-                        deserializer.reader.end_caching();
-                        deserializer.reader.discard();
-                        break;
-                    }
-                    // When '\\' is encountered, matches escape character.
-                    REVERSE_SOLIDUS => {
-                        vec.extend_from_slice(deserializer.reader.cached_slice().unwrap());
-                        deserializer.reader.discard();
-                        parse_escape_character(deserializer, &mut vec)?;
-                        deserializer.reader.start_caching();
-                    }
+    // Ordinary characters are skipped in bulk.
+    if let Some(QUOTATION_MARK) = deserializer
+        .reader
+        .discard_unescaped()
+        .map_err(Error::new_reader)?
+    {
+        if let Some(slice) = deserializer.reader.cached_borrowed_slice() {
+            check_string(deserializer, slice.len())?;
+            check_utf8(deserializer, slice)?;
+            check_alloc(deserializer, slice.len())?;
+            deserializer.reader.end_caching();
+            deserializer.reader.discard();
+            return Ok(Reference::Borrowed(slice));
+        }
+    }
 
-                    _ => {
-                        // Other control characters are not output.
-                        return unexpected_character!(deserializer);
-                    }
-                }
+    // The cache is still active, so the bytes scanned above are kept. Only the
+    // segments between escape characters are appended to the scratch buffer.
+    let mut scratch = core::mem::take(&mut deserializer.scratch);
+    scratch.clear();
+    let result = decode_string(deserializer, &mut scratch);
+    deserializer.scratch = scratch;
+    result?;
+    check_alloc(deserializer, deserializer.scratch.len())?;
+    Ok(Reference::Copied(&deserializer.scratch))
+}
+
+// Parses the rest of a string. The caching must have been started at the
+// beginning of the string.
+fn parse_string_cached<R: Cacheable>(deserializer: &mut Deserializer<R>) -> Result<Vec<u8>, Error> {
+    // Strings without escape characters are copied at one time.
+    if let Some(QUOTATION_MARK) = deserializer
+        .reader
+        .discard_unescaped()
+        .map_err(Error::new_reader)?
+    {
+        let len = deserializer.reader.cached_len().unwrap();
+        check_string(deserializer, len)?;
+        check_alloc(deserializer, len)?;
+        let vec = deserializer.reader.cached_slice().unwrap().to_vec();
+        deserializer.reader.end_caching();
+        deserializer.reader.discard();
+        return Ok(vec);
+    }
+
+    // Otherwise, the string is decoded in the scratch buffer, which is kept by the
+    // deserializer and reused between strings, and then copied with its exact length.
+    let mut scratch = core::mem::take(&mut deserializer.scratch);
+    scratch.clear();
+    let result = decode_string(deserializer, &mut scratch).map(|_| scratch.to_vec());
+    deserializer.scratch = scratch;
+    let vec = result?;
+    check_alloc(deserializer, vec.len())?;
+    Ok(vec)
+}
+
+// Decodes the rest of a string into `vec`. The caching must have been started
+// at the beginning of the unread part.
+fn decode_string<R: Cacheable>(
+    deserializer: &mut Deserializer<R>,
+    vec: &mut Vec<u8>,
+) -> Result<(), Error> {
+    loop {
+        // Ordinary characters are skipped in bulk, so only '"', '\\' and
+        // control characters are matched here.
+        let start = vec.len();
+        match deserializer
+            .reader
+            .discard_unescaped()
+            .map_err(Error::new_reader)?
+        {
+            // When '"' is encountered, the string is added to vec.
+            Some(QUOTATION_MARK) => {
+                vec.extend_from_slice(deserializer.reader.cached_slice().unwrap());
+                check_string(deserializer, vec.len())?;
+                check_utf8(deserializer, &vec[start..])?;
+                deserializer.reader.end_caching();
+                deserializer.reader.discard();
+                return Ok(());
+            }
+            // When '\\' is encountered, matches escape character.
+            Some(REVERSE_SOLIDUS) => {
+                vec.extend_from_slice(deserializer.reader.cached_slice().unwrap());
+                check_string(deserializer, vec.len())?;
+                check_utf8(deserializer, &vec[start..])?;
+                deserializer.reader.discard();
+                parse_escape_character(deserializer, vec)?;
+                deserializer.reader.start_caching();
             }
+            // Other control characters are not output.
+            Some(_) => return unexpected_character!(deserializer),
             None => return unexpected_eoj!(deserializer),
         }
     }
-    Ok(vec)
 }
 
 // Parses escape characters.
@@ -298,15 +475,24 @@ fn parse_unicode<R: Cacheable>(
     deserializer: &mut Deserializer<R>,
     vec: &mut Vec<u8>,
 ) -> Result<(), Error> {
+    // Gets the value of a hexadecimal digit.
+    #[inline]
+    fn digit_value(ch: u8) -> Option<u16> {
+        match ch {
+            ZERO..=NINE => Some(ch as u16 - ZERO as u16),
+            A_LOWER..=F_LOWER => Some(ch as u16 - A_LOWER as u16 + 10),
+            A_UPPER..=F_UPPER => Some(ch as u16 - A_UPPER as u16 + 10),
+            _ => None,
+        }
+    }
+
     // Reads a hexadecimal number.
     #[inline]
     fn get_next_digit<R: Cacheable>(deserializer: &mut Deserializer<R>) -> Result<u16, Error> {
         if let Some(ch) = deserializer.reader.peek().map_err(Error::new_reader)? {
-            let result = match ch {
-                ZERO..=NINE => ch as u16 - ZERO as u16,
-                A_LOWER..=F_LOWER => ch as u16 - A_LOWER as u16 + 10,
-                A_UPPER..=F_UPPER => ch as u16 - A_UPPER as u16 + 10,
-                _ => return unexpected_character!(deserializer),
+            let result = match digit_value(ch) {
+                Some(value) => value,
+                None => return unexpected_character!(deserializer),
             };
             deserializer.reader.discard();
             return Ok(result);
@@ -319,6 +505,17 @@ fn parse_unicode<R: Cacheable>(
     fn get_next_four_digits<R: Cacheable>(
         deserializer: &mut Deserializer<R>,
     ) -> Result<u16, Error> {
+        // The four digits are read at one time. If any of them is invalid or
+        // missing, they are read one by one to find the position of the error.
+        if let Some(digits) = deserializer.reader.peek_n(4).map_err(Error::new_reader)? {
+            let value = digits.iter().try_fold(0u16, |value, ch| {
+                digit_value(*ch).map(|digit| value << 4 | digit)
+            });
+            if let Some(value) = value {
+                deserializer.reader.discard_n(4);
+                return Ok(value);
+            }
+        }
         Ok(get_next_digit(deserializer)? << 12
             | get_next_digit(deserializer)? << 8
             | get_next_digit(deserializer)? << 4
@@ -352,6 +549,32 @@ fn parse_unicode<R: Cacheable>(
 pub(crate) fn parse_number<R: Cacheable>(
     deserializer: &mut Deserializer<R>,
 ) -> Result<Number, Error> {
+    let shape = scan_number(deserializer)?;
+    let number = cached_number(deserializer, shape)?;
+    deserializer.reader.end_caching();
+    Ok(number)
+}
+
+/// Which parts a number has, which decide its representation.
+#[derive(Clone, Copy)]
+pub(crate) struct NumberShape {
+    pub(crate) neg: bool,
+    pub(crate) dot: bool,
+    pub(crate) exp: bool,
+}
+
+impl NumberShape {
+    /// Whether the number is written as an integer, without a fraction or exponent.
+    pub(crate) fn is_integer(&self) -> bool {
+        !self.dot && !self.exp
+    }
+}
+
+/// Matches a number and leaves its text in the cache of the reader, which is still
+/// caching afterwards. The caller gets the text by `cached_slice` and ends the caching.
+pub(crate) fn scan_number<R: Cacheable>(
+    deserializer: &mut Deserializer<R>,
+) -> Result<NumberShape, Error> {
     // Sets the starting position of the string.
     deserializer.reader.start_caching();
 
@@ -360,23 +583,28 @@ pub(crate) fn parse_number<R: Cacheable>(
     let mut dot = false;
     let mut exp = false;
 
-    // Matches '-', JSON syntax does not match '+'
-    if let Some(MINUS) = deserializer.reader.peek().map_err(Error::new_reader)? {
-        deserializer.reader.discard();
-        neg = true;
+    // Matches '-', JSON syntax does not match '+' unless it is allowed by the config.
+    // A '+' which is not allowed is left for the next match to report.
+    match deserializer.reader.peek().map_err(Error::new_reader)? {
+        Some(MINUS) => {
+            deserializer.reader.discard();
+            neg = true;
+        }
+        Some(PLUS) if deserializer.config.allow_leading_plus => deserializer.reader.discard(),
+        _ => {}
     }
     // `next_ch` temporarily saves unmatched characters after peek.
     // Used to reduce the number of repeated peeks.
     let mut next_ch = match deserializer.reader.peek().map_err(Error::new_reader)? {
         // The integer part cannot have a leading 0, so if it encounters a 0 here,
-        // it enters the value 0 state directly.
-        Some(ZERO) => {
+        // it enters the value 0 state directly, unless leading zeros are allowed.
+        Some(ZERO) if !deserializer.config.allow_leading_zeros => {
             deserializer.reader.discard();
             // The reason to peek here is to compare with
-            // Some(ONE... =NINE) branches keep the same return value.
+            // Some(ZERO..=NINE) branches keep the same return value.
             deserializer.reader.peek().map_err(Error::new_reader)?
         }
-        Some(ONE..=NINE) => {
+        Some(ZERO..=NINE) => {
             // Matches one digit character first. Ensure that there is at least one digit character.
             deserializer.reader.discard();
             // Matches as many numeric characters as possible.
@@ -420,22 +648,49 @@ pub(crate) fn parse_number<R: Cacheable>(
         // Matches the remaining numeric characters.
         eat_digits_until_not!(deserializer);
     }
+    Ok(NumberShape { neg, dot, exp })
+}
 
+/// Converts the number matched by `scan_number` from the cache of the reader.
+pub(crate) fn cached_number<R: Cacheable>(
+    deserializer: &mut Deserializer<R>,
+    shape: NumberShape,
+) -> Result<Number, Error> {
     // The contents of u8 have been checked, so the unchecked method can be used here.
+    let overflow = deserializer.config.number_overflow;
     let str =
         unsafe { core::str::from_utf8_unchecked(deserializer.reader.cached_slice().unwrap()) };
-    let number = match (neg, dot, exp) {
-        (false, false, false) => {
-            Number::Unsigned(str.parse::<u64>().map_err(|_| Error::ParseNumber)?)
-        }
-        (true, false, false) => Number::Signed(str.parse::<i64>().map_err(|_| Error::ParseNumber)?),
+    let number = match (shape.neg, shape.dot, shape.exp) {
+        (false, false, false) => match str.parse::<u64>() {
+            Ok(u) => Number::Unsigned(u),
+            Err(_) => parse_overflowed(overflow, str)?,
+        },
+        (true, false, false) => match str.parse::<i64>() {
+            Ok(i) => Number::Signed(i),
+            Err(_) => parse_overflowed(overflow, str)?,
+        },
         (_, _, _) => Number::Float(str.parse::<f64>().map_err(|_| Error::ParseNumber)?),
     };
-
-    deserializer.reader.end_caching();
+    // Numbers out of the range of f64 can not be written back into JSON, so they
+    // are rejected in the RFC 8259 mode.
+    if deserializer.config.strict_rfc8259 && matches!(number, Number::Float(f) if !f.is_finite()) {
+        return Err(Error::ParseNumber);
+    }
     Ok(number)
 }
 
+/// Handles an integer which does not fit in `u64` or `i64` according to the
+/// `overflow` policy. The digits have been checked by the caller.
+fn parse_overflowed(overflow: NumberOverflow, str: &str) -> Result<Number, Error> {
+    match overflow {
+        NumberOverflow::Error => Err(Error::ParseNumber),
+        NumberOverflow::Float => str
+            .parse::<f64>()
+            .map(Number::Float)
+            .map_err(|_| Error::ParseNumber),
+    }
+}
+
 fn parse_array<R: Cacheable>(deserializer: &mut Deserializer<R>) -> Result<JsonValue, Error> {
     enum InnerState {
         Start,
@@ -445,9 +700,16 @@ fn parse_array<R: Cacheable>(deserializer: &mut Deserializer<R>) -> Result<JsonV
 
     deserializer.recursion_depth += 1;
     check_recursion(deserializer)?;
+    if let Some(stats) = deserializer.stats.as_mut() {
+        stats.record_depth(deserializer.recursion_depth);
+    }
 
     // Creates an Array to store value.
+    #[cfg(feature = "list_array")]
+    let mut array = Array::with_node_chunk_size(deserializer.config.node_chunk_size);
+    #[cfg(not(feature = "list_array"))]
     let mut array = Array::new();
+    let mut len = 0;
     // The initial status is Start.
     let mut state = InnerState::Start;
 
@@ -458,6 +720,11 @@ fn parse_array<R: Cacheable>(deserializer: &mut Deserializer<R>) -> Result<JsonV
             // If in the initial state or "," has appeared,
             // matches key-value pairs when any character is encountered.
             (InnerState::Start | InnerState::AfterComma, _) => {
+                len += 1;
+                if len > deserializer.config.max_array_len {
+                    return Err(Error::ExceedArrayLimit);
+                }
+                check_alloc(deserializer, core::mem::size_of::<JsonValue>())?;
                 array.push(parse_value(deserializer)?);
 
                 // Here sets the state to NoComma.
@@ -481,6 +748,169 @@ fn parse_array<R: Cacheable>(deserializer: &mut Deserializer<R>) -> Result<JsonV
     Ok(JsonValue::Array(array))
 }
 
+// Skips a value at the token level without materializing it.
+// Containers are checked against the same grammar as `parse_value`, but their
+// members are not parsed into `JsonValue`s.
+pub(crate) fn skip_value<R: Cacheable>(deserializer: &mut Deserializer<R>) -> Result<(), Error> {
+    skip_nested_value(deserializer, 0)
+}
+
+// Skips a value inside `skipped` containers which are being skipped.
+fn skip_nested_value<R: Cacheable>(
+    deserializer: &mut Deserializer<R>,
+    skipped: usize,
+) -> Result<(), Error> {
+    match eat_whitespace_until_not!(deserializer) {
+        Some(LEFT_CURLY_BRACKET) => {
+            deserializer.reader.discard();
+            skip_object(deserializer, skipped + 1)
+        }
+        Some(LEFT_SQUARE_BRACKET) => {
+            deserializer.reader.discard();
+            skip_array(deserializer, skipped + 1)
+        }
+        Some(QUOTATION_MARK) => {
+            deserializer.reader.discard();
+            skip_string(deserializer)
+        }
+        Some(ZERO..=NINE | MINUS | PLUS) => parse_number(deserializer).map(|_| ()),
+        Some(T_LOWER) => {
+            deserializer.reader.discard();
+            match_str!(deserializer, TRUE_LEFT_STR);
+            Ok(())
+        }
+        Some(F_LOWER) => {
+            deserializer.reader.discard();
+            match_str!(deserializer, FALSE_LEFT_STR);
+            Ok(())
+        }
+        Some(N_LOWER) => {
+            deserializer.reader.discard();
+            match_str!(deserializer, NULL_LEFT_STR);
+            Ok(())
+        }
+        Some(_) => unexpected_character!(deserializer),
+        None => unexpected_eoj!(deserializer),
+    }
+}
+
+// Skips the rest of a string whose '"' has been discarded.
+fn skip_string<R: Cacheable>(deserializer: &mut Deserializer<R>) -> Result<(), Error> {
+    // Skipped strings are only cached to check their bytes in the RFC 8259 mode.
+    let strict = deserializer.config.strict_rfc8259;
+    loop {
+        if strict {
+            deserializer.reader.start_caching();
+        }
+        deserializer
+            .reader
+            .discard_unescaped()
+            .map_err(Error::new_reader)?;
+        if strict {
+            let valid = core::str::from_utf8(deserializer.reader.cached_slice().unwrap()).is_ok();
+            deserializer.reader.end_caching();
+            if !valid {
+                let position = deserializer.reader.position();
+                return Err(ParseError::InvalidUtf8Bytes(position).into());
+            }
+        }
+        match deserializer.reader.next().map_err(Error::new_reader)? {
+            Some(QUOTATION_MARK) => return Ok(()),
+            // The character after '\\' can never end the string.
+            Some(REVERSE_SOLIDUS) => {
+                if deserializer
+                    .reader
+                    .next()
+                    .map_err(Error::new_reader)?
+                    .is_none()
+                {
+                    return unexpected_eoj!(deserializer);
+                }
+            }
+            Some(_) => {}
+            None => return unexpected_eoj!(deserializer),
+        }
+    }
+}
+
+// Checks the depth of the containers being skipped, which are not counted by
+// `recursion_depth`.
+#[inline]
+fn check_skipped_depth<R: Cacheable>(
+    deserializer: &Deserializer<R>,
+    skipped: usize,
+) -> Result<(), Error> {
+    if deserializer.recursion_depth as usize + skipped > deserializer.config.max_depth as usize {
+        Err(Error::ExceedRecursionLimit)
+    } else {
+        Ok(())
+    }
+}
+
+// Skips the rest of an object whose '{' has been discarded.
+fn skip_object<R: Cacheable>(
+    deserializer: &mut Deserializer<R>,
+    skipped: usize,
+) -> Result<(), Error> {
+    check_skipped_depth(deserializer, skipped)?;
+    if let Some(RIGHT_CURLY_BRACKET) = eat_whitespace_until_not!(deserializer) {
+        deserializer.reader.discard();
+        return Ok(());
+    }
+    loop {
+        // Every member starts with a key, which is followed by ':'.
+        match eat_whitespace_until_not!(deserializer) {
+            Some(QUOTATION_MARK) => {
+                deserializer.reader.discard();
+                skip_string(deserializer)?;
+            }
+            Some(_) => return unexpected_character!(deserializer),
+            None => return unexpected_eoj!(deserializer),
+        }
+        match eat_whitespace_until_not!(deserializer) {
+            Some(COLON) => deserializer.reader.discard(),
+            Some(_) => return unexpected_character!(deserializer),
+            None => return unexpected_eoj!(deserializer),
+        }
+        skip_nested_value(deserializer, skipped)?;
+        match eat_whitespace_until_not!(deserializer) {
+            Some(COMMA) => deserializer.reader.discard(),
+            Some(RIGHT_CURLY_BRACKET) => {
+                deserializer.reader.discard();
+                return Ok(());
+            }
+            Some(_) => return unexpected_character!(deserializer),
+            None => return unexpected_eoj!(deserializer),
+        }
+    }
+}
+
+// Skips the rest of an array whose '[' has been discarded.
+fn skip_array<R: Cacheable>(
+    deserializer: &mut Deserializer<R>,
+    skipped: usize,
+) -> Result<(), Error> {
+    check_skipped_depth(deserializer, skipped)?;
+    if let Some(RIGHT_SQUARE_BRACKET) = eat_whitespace_until_not!(deserializer) {
+        deserializer.reader.discard();
+        return Ok(());
+    }
+    loop {
+        skip_nested_value(deserializer, skipped)?;
+        match eat_whitespace_until_not!(deserializer) {
+            Some(COMMA) => deserializer.reader.discard(),
+            Some(RIGHT_SQUARE_BRACKET) => {
+                deserializer.reader.discard();
+                return Ok(());
+            }
+            Some(_) => return unexpected_character!(deserializer),
+            None => return unexpected_eoj!(deserializer),
+        }
+    }
+}
+
+// Reads the character at which an error occurs. Returns None if the bytes are not
+// valid UTF-8.
 pub(crate) fn read_error_char<R: Cacheable>(
     deserializer: &mut Deserializer<R>,
 ) -> Result<Option<char>, Error> {
@@ -532,17 +962,22 @@ pub(crate) fn read_error_char<R: Cacheable>(
         }
         ch
     };
-    unsafe { Ok(Some(char::from_u32_unchecked(ch))) }
+    // The bytes are not checked above, so invalid UTF-8 may decode to a value which is
+    // not a char, such as a surrogate.
+    Ok(char::from_u32(ch))
 }
 
 #[cfg(test)]
 mod ut_states {
     use crate::reader::BytesReader;
     use crate::states::*;
+    #[cfg(feature = "std")]
     use std::io::{ErrorKind, Read};
 
+    #[cfg(feature = "std")]
     struct ErrorIo;
 
+    #[cfg(feature = "std")]
     impl Read for ErrorIo {
         fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
             Err(ErrorKind::AddrInUse.into())
@@ -572,8 +1007,11 @@ mod ut_states {
         let mut deserializer = Deserializer::new_from_slice(b"      ");
         assert_eq!(test_func(&mut deserializer).unwrap(), None);
 
-        let mut deserializer = Deserializer::new_from_io(ErrorIo);
-        assert!(test_func(&mut deserializer).is_err());
+        #[cfg(feature = "std")]
+        {
+            let mut deserializer = Deserializer::new_from_io(ErrorIo);
+            assert!(test_func(&mut deserializer).is_err());
+        }
     }
 
     /// UT test for macro `eat_digits_until_not`.
@@ -599,8 +1037,11 @@ mod ut_states {
         let mut deserializer = Deserializer::new_from_slice(b"1234");
         assert_eq!(test_func(&mut deserializer).unwrap(), None);
 
-        let mut deserializer = Deserializer::new_from_io(ErrorIo);
-        assert!(test_func(&mut deserializer).is_err());
+        #[cfg(feature = "std")]
+        {
+            let mut deserializer = Deserializer::new_from_io(ErrorIo);
+            assert!(test_func(&mut deserializer).is_err());
+        }
     }
 
     /// UT test for macro `match_str`.
@@ -625,8 +1066,11 @@ mod ut_states {
         let mut deserializer = Deserializer::new_from_slice(b"1234");
         assert!(test_func(&mut deserializer, b"1234").is_ok());
 
-        let mut deserializer = Deserializer::new_from_io(ErrorIo);
-        assert!(test_func(&mut deserializer, b"1234").is_err());
+        #[cfg(feature = "std")]
+        {
+            let mut deserializer = Deserializer::new_from_io(ErrorIo);
+            assert!(test_func(&mut deserializer, b"1234").is_err());
+        }
     }
 
     /// UT test for `start_parsing`.
@@ -672,6 +1116,13 @@ mod ut_states {
 
         let mut deserializer = Deserializer::new_from_slice(&[0xf0, 0xa4, 0xad]);
         assert_eq!(read_error_char(&mut deserializer).unwrap(), None);
+
+        // Bytes which decode to a surrogate or beyond U+10FFFF.
+        let mut deserializer = Deserializer::new_from_slice(&[0xed, 0xa0, 0x80]);
+        assert_eq!(read_error_char(&mut deserializer).unwrap(), None);
+
+        let mut deserializer = Deserializer::new_from_slice(&[0xf4, 0xbf, 0xbf, 0xbf]);
+        assert_eq!(read_error_char(&mut deserializer).unwrap(), None);
     }
 
     /// UT test for `parse_value`.
@@ -730,63 +1181,33 @@ mod ut_states {
         // 1.Enter a valid key (or String) and return a string.
         // 2.Enter an invalid key (or string) and return an Error message.
 
-        #[cfg(feature = "c_adapter")]
-        use std::ffi::CString;
-
         // Ensure that the previous '"' has been read before entering parse_string.
         // Empty string
         let str = "\"";
         let mut deserializer = Deserializer::new_from_slice(str.as_bytes());
-        #[cfg(not(feature = "c_adapter"))]
         assert_eq!(parse_string(&mut deserializer).unwrap(), String::from(""));
-        #[cfg(feature = "c_adapter")]
-        assert_eq!(
-            parse_string(&mut deserializer).unwrap(),
-            CString::new("").unwrap()
-        );
 
         // General character
         let str = "abcdefghijklmnopqrstuvwxyz1234567890-=~!@#$%^&*()_+[]{}|<>?:;'\"";
         let mut deserializer = Deserializer::new_from_slice(str.as_bytes());
-        #[cfg(not(feature = "c_adapter"))]
         assert_eq!(
             parse_string(&mut deserializer).unwrap(),
             String::from("abcdefghijklmnopqrstuvwxyz1234567890-=~!@#$%^&*()_+[]{}|<>?:;'"),
         );
-        #[cfg(feature = "c_adapter")]
-        assert_eq!(
-            parse_string(&mut deserializer).unwrap(),
-            CString::new("abcdefghijklmnopqrstuvwxyz1234567890-=~!@#$%^&*()_+[]{}|<>?:;'").unwrap(),
-        );
 
         // Escape character
         let str = r#"\/\\\"\uCAFE\uBABE\uAB98\uFCDE\ubcda\uef4A\b\f\n\r\t""#;
         let mut deserializer = Deserializer::new_from_slice(str.as_bytes());
-        #[cfg(not(feature = "c_adapter"))]
         assert_eq!(
             parse_string(&mut deserializer).unwrap(),
             String::from(
                 "/\\\"\u{CAFE}\u{BABE}\u{AB98}\u{FCDE}\u{bcda}\u{ef4A}\u{0008}\u{000c}\n\r\t"
             ),
         );
-        #[cfg(feature = "c_adapter")]
-        assert_eq!(
-            parse_string(&mut deserializer).unwrap(),
-            CString::new(
-                "/\\\"\u{CAFE}\u{BABE}\u{AB98}\u{FCDE}\u{bcda}\u{ef4A}\u{0008}\u{000c}\n\r\t"
-            )
-            .unwrap(),
-        );
 
         let str = r#"\uD852\uDF62""#;
         let mut deserializer = Deserializer::new_from_slice(str.as_bytes());
-        #[cfg(not(feature = "c_adapter"))]
         assert_eq!(parse_string(&mut deserializer).unwrap(), String::from("𤭢"),);
-        #[cfg(feature = "c_adapter")]
-        assert_eq!(
-            parse_string(&mut deserializer).unwrap(),
-            CString::new("𤭢").unwrap(),
-        );
 
         // Error scenes
         // 1.There are no trailing quotes to end a match (or encounter a terminator).
@@ -825,6 +1246,15 @@ mod ut_states {
         let str = "\u{0}";
         let mut deserializer = Deserializer::new_from_slice(str.as_bytes());
         assert!(parse_string(&mut deserializer).is_err());
+
+        // 6.Invalid UTF-8, which is rejected without the RFC 8259 mode.
+        for bytes in [&b"\xff\xfe\""[..], b"a\\n\xc3\"", b"\xe4\xb8\""] {
+            let mut deserializer = Deserializer::new_from_slice(bytes);
+            assert!(matches!(
+                parse_string(&mut deserializer),
+                Err(Error::Parsing(ParseError::InvalidUtf8Bytes(_)))
+            ));
+        }
     }
 
     /// UT test for `parse_number`.
@@ -962,6 +1392,19 @@ mod ut_states {
         let array = array!(1u8, 2u8, 3u8);
         assert_eq!(parse_array(&mut deserializer).unwrap(), array.into());
 
+        // The chunk size of the linked lists does not change the result.
+        let str = r#"[1, [2, 3, 4], {"a": 5, "b": 6, "c": 7}]"#;
+        for node_chunk_size in [0, 1, 3] {
+            let config = crate::ParseConfig {
+                node_chunk_size,
+                ..crate::ParseConfig::default()
+            };
+            assert_eq!(
+                JsonValue::from_text_with_config(str, config).unwrap(),
+                JsonValue::from_text(str).unwrap()
+            );
+        }
+
         // Error scenes.
         // 1.Encounter terminator too early.
         let str = "";
@@ -1112,4 +1555,259 @@ mod ut_states {
         let mut deserializer = Deserializer::new_from_slice(text);
         assert!(start_parsing(&mut deserializer).is_err());
     }
+
+    /// UT test for the limits of `ParseConfig`.
+    ///
+    /// # Title
+    /// ut_parse_config_limits
+    ///
+    /// # Brief
+    /// 1. Creates deserializers with limits from slices and IO.
+    /// 2. Calls `start_parsing` on texts which reach or exceed the limits.
+    /// 3. Checks if the results are correct.
+    #[test]
+    fn ut_parse_config_limits() {
+        use crate::ParseConfig;
+
+        fn parse(text: &str, config: ParseConfig) -> Result<JsonValue, Error> {
+            let from_slice = start_parsing(
+                &mut Deserializer::new_from_slice(text.as_bytes()).with_config(config),
+            );
+            #[cfg(feature = "std")]
+            {
+                let from_io = start_parsing(
+                    &mut Deserializer::new_from_io(text.as_bytes()).with_config(config),
+                );
+                assert_eq!(format!("{from_slice:?}"), format!("{from_io:?}"));
+            }
+            from_slice
+        }
+
+        let config = ParseConfig {
+            max_depth: 2,
+            ..ParseConfig::default()
+        };
+        assert!(parse(r#"{"a": [1]}"#, config).is_ok());
+        assert!(matches!(
+            parse(r#"{"a": [[]]}"#, config),
+            Err(Error::ExceedRecursionLimit)
+        ));
+
+        let config = ParseConfig {
+            max_document_bytes: 8,
+            ..ParseConfig::default()
+        };
+        assert!(parse("[1, 2]  ", config).is_ok());
+        assert!(matches!(
+            parse("[1, 2]   ", config),
+            Err(Error::ExceedDocumentLimit)
+        ));
+
+        let config = ParseConfig {
+            max_string_bytes: 3,
+            ..ParseConfig::default()
+        };
+        assert!(parse(r#"{"abc": "d\te"}"#, config).is_ok());
+        assert!(matches!(
+            parse(r#"["a\tbc"]"#, config),
+            Err(Error::ExceedStringLimit)
+        ));
+        assert!(matches!(
+            parse(r#"{"abcd": 1}"#, config),
+            Err(Error::ExceedStringLimit)
+        ));
+
+        let config = ParseConfig {
+            max_array_len: 2,
+            max_object_members: 2,
+            ..ParseConfig::default()
+        };
+        assert!(parse(r#"[[1, 2], {"a": 1, "a": 2}]"#, config).is_ok());
+        assert!(matches!(
+            parse("[1, 2, 3]", config),
+            Err(Error::ExceedArrayLimit)
+        ));
+        assert!(matches!(
+            parse(r#"{"a": 1, "a": 2, "b": 3}"#, config),
+            Err(Error::ExceedObjectLimit)
+        ));
+    }
+
+    /// UT test for the RFC 8259 mode of `ParseConfig`.
+    ///
+    /// # Title
+    /// ut_parse_config_rfc8259
+    ///
+    /// # Brief
+    /// 1. Creates deserializers in the RFC 8259 mode, and with duplicate keys rejected.
+    /// 2. Calls `start_parsing` on texts with invalid UTF-8, infinite numbers and duplicate keys.
+    /// 3. Checks if the results are correct.
+    #[test]
+    fn ut_parse_config_rfc8259() {
+        use crate::ParseConfig;
+
+        fn parse(text: &[u8], config: ParseConfig) -> Result<JsonValue, Error> {
+            let from_slice =
+                start_parsing(&mut Deserializer::new_from_slice(text).with_config(config));
+            #[cfg(feature = "std")]
+            {
+                let from_io =
+                    start_parsing(&mut Deserializer::new_from_io(text).with_config(config));
+                assert_eq!(format!("{from_slice:?}"), format!("{from_io:?}"));
+            }
+            from_slice
+        }
+
+        let strict = ParseConfig {
+            strict_rfc8259: true,
+            ..ParseConfig::default()
+        };
+        assert!(parse("[\"中文\\t😀\", 1e308]".as_bytes(), strict).is_ok());
+        for text in [
+            &b"[\"\xff\"]"[..],
+            b"[\"a\\t\xc0\xaf\"]",
+            b"{\"\xed\xa0\x80\": 1}",
+            b"[1e400]",
+            b"[-1e400]",
+        ] {
+            assert!(parse(text, strict).is_err());
+        }
+        assert!(matches!(
+            parse(b"[\"\xe4\xb8\"]", strict),
+            Err(Error::Parsing(ParseError::InvalidUtf8Bytes(_)))
+        ));
+        assert!(matches!(
+            parse(b"[1e400]", ParseConfig::default()),
+            Ok(JsonValue::Array(_))
+        ));
+
+        let unique = ParseConfig {
+            reject_duplicate_keys: true,
+            ..ParseConfig::default()
+        };
+        assert!(parse(br#"{"a": {"a": 1}, "b": [{"a": 2}]}"#, unique).is_ok());
+        let error = parse(br#"{"a": 1, "b": 2, "a": 3}"#, unique).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parsing(ParseError::DuplicateKey(ref position, ref key))
+                if key == "a" && position.column() == 21
+        ));
+        assert!(parse(br#"{"a": 1, "a": 3}"#, ParseConfig::default()).is_ok());
+    }
+
+    /// UT test for the `number_overflow` policy of `ParseConfig`.
+    ///
+    /// # Title
+    /// ut_parse_config_number_overflow
+    ///
+    /// # Brief
+    /// 1. Parses integers out of the range of `u64` and `i64` with each policy.
+    /// 2. Checks if they are rejected by default and converted to `f64` by `NumberOverflow::Float`.
+    #[test]
+    fn ut_parse_config_number_overflow() {
+        use crate::{Error, JsonValue, Number, NumberOverflow, ParseConfig};
+
+        let text = "[18446744073709551615, 18446744073709551616, -9223372036854775809]";
+        assert!(matches!(
+            JsonValue::from_text(text),
+            Err(Error::ParseNumber)
+        ));
+
+        let config = ParseConfig {
+            number_overflow: NumberOverflow::Float,
+            ..ParseConfig::default()
+        };
+        let value = JsonValue::from_text_with_config(text, config).unwrap();
+        assert_eq!(
+            value[0].try_as_number().unwrap(),
+            &Number::Unsigned(u64::MAX)
+        );
+        assert!(matches!(
+            value[1].try_as_number().unwrap(),
+            Number::Float(f) if *f == 18446744073709551616.0
+        ));
+        assert!(matches!(
+            value[2].try_as_number().unwrap(),
+            Number::Float(f) if *f == -9223372036854775809.0
+        ));
+    }
+
+    /// UT test for the leniency flags of numbers in `ParseConfig`.
+    ///
+    /// # Title
+    /// ut_parse_config_number_leniency
+    ///
+    /// # Brief
+    /// 1. Parses numbers with a leading plus or leading zeros with each flag.
+    /// 2. Checks if they are rejected by default and accepted when the flag is set.
+    #[test]
+    fn ut_parse_config_number_leniency() {
+        use crate::{JsonValue, Number, ParseConfig};
+
+        let text = "[+1, +1.5, 0123, -007, 00.5]";
+        assert!(JsonValue::from_text("[+1]").is_err());
+        assert!(JsonValue::from_text("[0123]").is_err());
+
+        let config = ParseConfig {
+            allow_leading_plus: true,
+            allow_leading_zeros: true,
+            ..ParseConfig::default()
+        };
+        let value = JsonValue::from_text_with_config(text, config).unwrap();
+        assert_eq!(value[0].try_as_number().unwrap(), &Number::Unsigned(1));
+        assert_eq!(value[1].try_as_number().unwrap(), &Number::Float(1.5));
+        assert_eq!(value[2].try_as_number().unwrap(), &Number::Unsigned(123));
+        assert_eq!(value[3].try_as_number().unwrap(), &Number::Signed(-7));
+        assert_eq!(value[4].try_as_number().unwrap(), &Number::Float(0.5));
+
+        let plus_only = ParseConfig {
+            allow_leading_plus: true,
+            ..ParseConfig::default()
+        };
+        assert!(JsonValue::from_text_with_config("[+1]", plus_only).is_ok());
+        assert!(JsonValue::from_text_with_config("[+01]", plus_only).is_err());
+        assert!(JsonValue::from_text_with_config("[+-1]", plus_only).is_err());
+        assert!(JsonValue::from_text_with_config("[-+1]", plus_only).is_err());
+        assert!(JsonValue::from_text_with_config("[+]", plus_only).is_err());
+    }
+
+    /// UT test for the memory limit of `ParseConfig`.
+    ///
+    /// # Title
+    /// ut_parse_config_memory_limit
+    ///
+    /// # Brief
+    /// 1. Parses texts with a small `max_alloc_bytes`.
+    /// 2. Checks if the allocated memory of strings and containers is counted.
+    #[test]
+    fn ut_parse_config_memory_limit() {
+        use crate::{Error, JsonValue, ParseConfig};
+        use core::mem::size_of;
+
+        let config = ParseConfig {
+            max_alloc_bytes: size_of::<JsonValue>() * 4,
+            ..ParseConfig::default()
+        };
+        let parse = |text: &str| JsonValue::from_text_with_config(text, config);
+
+        assert!(parse("[[], [], [], []]").is_ok());
+        assert!(matches!(
+            parse("[[], [], [], [], []]"),
+            Err(Error::ExceedMemoryLimit)
+        ));
+        assert!(matches!(
+            parse("[[[[[[]]]]]]"),
+            Err(Error::ExceedMemoryLimit)
+        ));
+        let long = "a".repeat(size_of::<JsonValue>() * 4);
+        assert!(matches!(
+            parse(&format!("{{\"{long}\": 1}}")),
+            Err(Error::ExceedMemoryLimit)
+        ));
+        let escaped = "\\n".repeat(size_of::<JsonValue>() * 4 + 1);
+        assert!(matches!(
+            parse(&format!("\"{escaped}\"")),
+            Err(Error::ExceedMemoryLimit)
+        ));
+    }
 }
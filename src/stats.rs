@@ -0,0 +1,300 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{JsonValue, Position};
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::time::Duration;
+
+/// Numbers of JSON values of each type.
+///
+/// # Examples
+/// ```
+/// use ylong_json::NodeCounts;
+///
+/// let counts = NodeCounts::default();
+/// assert_eq!(counts.total(), 0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NodeCounts {
+    /// Number of nulls.
+    pub null: usize,
+    /// Number of booleans.
+    pub boolean: usize,
+    /// Number of numbers.
+    pub number: usize,
+    /// Number of strings, not including the keys of objects.
+    pub string: usize,
+    /// Number of arrays.
+    pub array: usize,
+    /// Number of objects.
+    pub object: usize,
+}
+
+impl NodeCounts {
+    /// Gets the number of JSON values of all types.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::NodeCounts;
+    ///
+    /// let counts = NodeCounts { null: 1, number: 2, ..Default::default() };
+    /// assert_eq!(counts.total(), 3);
+    /// ```
+    pub fn total(&self) -> usize {
+        self.null + self.boolean + self.number + self.string + self.array + self.object
+    }
+
+    pub(crate) fn count(&mut self, value: &JsonValue) {
+        match value {
+            JsonValue::Null => self.null += 1,
+            JsonValue::Boolean(_) => self.boolean += 1,
+            JsonValue::Number(_) => self.number += 1,
+            JsonValue::String(_) => self.string += 1,
+            JsonValue::Array(_) => self.array += 1,
+            JsonValue::Object(_) => self.object += 1,
+        }
+    }
+}
+
+/// Statistics collected while parsing a JSON text.
+///
+/// # Examples
+/// ```
+/// use ylong_json::JsonValue;
+///
+/// let (value, stats) = JsonValue::from_text_with_stats(r#"{"key": [1, "value"]}"#).unwrap();
+/// assert_eq!(stats.max_depth, 2);
+/// assert_eq!(stats.node_counts.total(), 4);
+/// assert_eq!(stats.string_bytes, 8);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParseStats {
+    /// The maximum nesting depth of arrays and objects. It is 0 if the text is a single
+    /// number, string, boolean or null.
+    pub max_depth: u32,
+    /// Numbers of JSON values of each type.
+    pub node_counts: NodeCounts,
+    /// Total length in bytes of the decoded strings, including the keys of objects.
+    pub string_bytes: usize,
+    /// The keys which appear more than once in an object, in the order they are parsed.
+    /// They are only recorded if `ParseConfig::record_duplicate_keys` is set.
+    pub duplicate_keys: Vec<DuplicateKey>,
+    /// Time taken by parsing.
+    #[cfg(feature = "std")]
+    pub duration: Duration,
+}
+
+impl ParseStats {
+    pub(crate) fn record_value(&mut self, value: &JsonValue) {
+        self.node_counts.count(value);
+        if let JsonValue::String(s) = value {
+            self.string_bytes += s.len();
+        }
+    }
+
+    pub(crate) fn record_key(&mut self, key: &str) {
+        self.string_bytes += key.len();
+    }
+
+    pub(crate) fn record_depth(&mut self, depth: u32) {
+        self.max_depth = self.max_depth.max(depth);
+    }
+
+    pub(crate) fn record_duplicate_key(&mut self, key: &str, position: Position) {
+        self.duplicate_keys.push(DuplicateKey {
+            key: String::from(key),
+            position,
+        });
+    }
+}
+
+/// A key which appears again in an object after its first member, recorded while
+/// parsing with `ParseConfig::record_duplicate_keys`.
+///
+/// Whether the earlier members are kept depends on the Object type, for example
+/// `btree_object` only keeps the last value, so the records tell which data has been
+/// dropped.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{JsonValue, ParseConfig};
+///
+/// let config = ParseConfig::default().record_duplicate_keys(true);
+/// let text = r#"{"a": 1, "a": 2}"#;
+/// let (_, stats) = JsonValue::from_text_with_config_and_stats(text, config).unwrap();
+/// assert_eq!(stats.duplicate_keys[0].key, "a");
+/// assert_eq!(stats.duplicate_keys[0].position.column(), 13);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateKey {
+    /// The duplicate key.
+    pub key: String,
+    /// The position just after the duplicate key in the text.
+    pub position: Position,
+}
+
+/// Statistics of a JSON value, such as its size and shape, see `JsonValue::stats`.
+///
+/// # Examples
+/// ```
+/// use ylong_json::JsonValue;
+///
+/// let value = JsonValue::from_text(r#"{"key": [1, "value"]}"#).unwrap();
+/// let stats = value.stats();
+/// assert_eq!(stats.max_depth, 2);
+/// assert_eq!(stats.max_array_len, 2);
+/// assert_eq!(stats.string_bytes, 8);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ValueStats {
+    /// The maximum nesting depth of arrays and objects. It is 0 if the value is a single
+    /// number, string, boolean or null.
+    pub max_depth: u32,
+    /// Numbers of JSON values of each type.
+    pub node_counts: NodeCounts,
+    /// Total length in bytes of the strings, including the keys of objects.
+    pub string_bytes: usize,
+    /// The maximum number of elements in an array.
+    pub max_array_len: usize,
+    /// The maximum number of members in an object.
+    pub max_object_members: usize,
+}
+
+impl ValueStats {
+    pub(crate) fn record(&mut self, value: &JsonValue, depth: u32) {
+        self.node_counts.count(value);
+        match value {
+            JsonValue::String(s) => self.string_bytes += s.len(),
+            JsonValue::Array(array) => {
+                self.max_depth = self.max_depth.max(depth + 1);
+                self.max_array_len = self.max_array_len.max(array.len());
+                for item in array.iter() {
+                    self.record(item, depth + 1);
+                }
+            }
+            JsonValue::Object(object) => {
+                self.max_depth = self.max_depth.max(depth + 1);
+                self.max_object_members = self.max_object_members.max(object.len());
+                for (key, item) in object.iter() {
+                    self.string_bytes += key.len();
+                    self.record(item, depth + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod ut_stats {
+    use crate::{Error, JsonValue, NodeCounts, ParseConfig};
+
+    /// UT test for `JsonValue::from_text_with_stats`.
+    ///
+    /// # Title
+    /// ut_from_text_with_stats
+    ///
+    /// # Brief
+    /// 1. Creates some JSON texts.
+    /// 2. Calls `JsonValue::from_text_with_stats`.
+    /// 3. Checks if the statistics are correct.
+    #[test]
+    fn ut_from_text_with_stats() {
+        let (_, stats) = JsonValue::from_text_with_stats("1").unwrap();
+        assert_eq!(stats.max_depth, 0);
+        assert_eq!(stats.node_counts.number, 1);
+
+        let text = r#"{"a\u4e2d": [true, false, null, [{}], "x\ty"], "b": [1, -2.5]}"#;
+        let (_, stats) = JsonValue::from_text_with_stats(text).unwrap();
+        assert_eq!(stats.max_depth, 4);
+        assert_eq!(
+            stats.node_counts,
+            NodeCounts {
+                null: 1,
+                boolean: 2,
+                number: 2,
+                string: 1,
+                array: 3,
+                object: 2,
+            }
+        );
+        // "a中", "x\ty" and "b".
+        assert_eq!(stats.string_bytes, 4 + 3 + 1);
+
+        assert!(JsonValue::from_text_with_stats("[1,").is_err());
+    }
+
+    /// UT test for recording duplicate keys into `ParseStats`.
+    ///
+    /// # Title
+    /// ut_record_duplicate_keys
+    ///
+    /// # Brief
+    /// 1. Parses a text with duplicate keys in nested objects, with and without
+    ///    `ParseConfig::record_duplicate_keys`.
+    /// 2. Checks if the duplicate keys and their positions are recorded only with it.
+    /// 3. Checks if `ParseConfig::reject_duplicate_keys` still rejects the text.
+    #[test]
+    fn ut_record_duplicate_keys() {
+        let text =
+            "{\"a\": 1, \"b\": {\"c\": 1, \"c\": 2,\n \"c\": 3}, \"a\": 2, \"d\": [{\"a\": 1}]}";
+        let (_, stats) = JsonValue::from_text_with_stats(text).unwrap();
+        assert!(stats.duplicate_keys.is_empty());
+
+        let config = ParseConfig {
+            record_duplicate_keys: true,
+            ..ParseConfig::default()
+        };
+        let (_, stats) = JsonValue::from_text_with_config_and_stats(text, config).unwrap();
+        let found: Vec<(&str, usize, usize)> = stats
+            .duplicate_keys
+            .iter()
+            .map(|d| (d.key.as_str(), d.position.line(), d.position.column()))
+            .collect();
+        assert_eq!(found, [("c", 1, 27), ("c", 2, 5), ("a", 2, 14)]);
+
+        let config = ParseConfig {
+            reject_duplicate_keys: true,
+            ..config
+        };
+        let result = JsonValue::from_text_with_config_and_stats(text, config);
+        assert!(matches!(result, Err(Error::Parsing(_))));
+    }
+
+    /// UT test for `JsonValue::stats`.
+    ///
+    /// # Title
+    /// ut_json_value_stats
+    ///
+    /// # Brief
+    /// 1. Creates some JSON values.
+    /// 2. Calls `JsonValue::stats`.
+    /// 3. Checks if the statistics are correct and the same as the ones collected while parsing.
+    #[test]
+    fn ut_json_value_stats() {
+        let stats = JsonValue::new_null().stats();
+        assert_eq!(stats.max_depth, 0);
+        assert_eq!(stats.node_counts.total(), 1);
+
+        let text = r#"{"a\u4e2d": [true, false, null, [{}], "x\ty"], "b": [1, -2.5], "c": {}}"#;
+        let (value, parse_stats) = JsonValue::from_text_with_stats(text).unwrap();
+        let stats = value.stats();
+        assert_eq!(stats.max_depth, parse_stats.max_depth);
+        assert_eq!(stats.node_counts, parse_stats.node_counts);
+        assert_eq!(stats.string_bytes, parse_stats.string_bytes);
+        assert_eq!(stats.max_array_len, 5);
+        assert_eq!(stats.max_object_members, 3);
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{JsonPointer, JsonValue};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The maximum length of strings and the maximum number of elements of arrays and
+/// objects in a difference, which keeps the differences of large values readable.
+const SUMMARY_LEN: usize = 32;
+const SUMMARY_ITEMS: usize = 4;
+
+/// Asserts that two JsonValues are equal, like `assert_eq!`.
+///
+/// On failure, it panics with the differences listed by `json_diff`, one per line,
+/// instead of the two whole values. A message can be added after the values.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{assert_json_eq, JsonValue};
+///
+/// let a = JsonValue::from_text(r#"{"x": [1, 2], "y": null}"#).unwrap();
+/// let b = JsonValue::from_text(r#"{"y": null, "x": [1, 2]}"#).unwrap();
+/// assert_json_eq!(a, b);
+/// assert_json_eq!(a, b, "comparing {} values", 2);
+/// ```
+///
+/// ```should_panic
+/// use ylong_json::{assert_json_eq, JsonValue};
+///
+/// let a = JsonValue::from_text(r#"{"x": [1, 2]}"#).unwrap();
+/// let b = JsonValue::from_text(r#"{"x": [1, 3]}"#).unwrap();
+/// // Panics with "  /x/1: 2 != 3".
+/// assert_json_eq!(a, b);
+/// ```
+#[macro_export]
+macro_rules! assert_json_eq {
+    (@compare $left: expr, $right: expr, $($header: tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let diff = $crate::json_diff(left, right);
+                if !diff.is_empty() {
+                    panic!(
+                        "{}\n{} difference(s), left != right:\n  {}",
+                        format_args!($($header)+),
+                        diff.len(),
+                        diff.join("\n  "),
+                    );
+                }
+            }
+        }
+    };
+    ($left: expr, $right: expr $(,)?) => {
+        $crate::assert_json_eq!(@compare $left, $right, "assertion `left == right` failed")
+    };
+    ($left: expr, $right: expr, $($arg: tt)+) => {
+        $crate::assert_json_eq!(
+            @compare $left, $right, "assertion `left == right` failed: {}", format_args!($($arg)+)
+        )
+    };
+}
+
+/// Lists the differences between two JsonValues. Each difference is a line of the JSON
+/// Pointer and the two different values, such as `/a/0: 1 != "1"`, where the root is
+/// written as `(root)` and a missing member or element is written as `(missing)`.
+///
+/// The differences are found by the same rules as `PartialEq`, so the order of the
+/// members of objects is ignored. Large values are written as summaries.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{json_diff, JsonValue};
+///
+/// let a = JsonValue::from_text(r#"{"a": [1, 2], "b": true}"#).unwrap();
+/// let b = JsonValue::from_text(r#"{"a": [1, "2", 3], "c": true}"#).unwrap();
+/// assert_eq!(
+///     json_diff(&a, &b),
+///     [
+///         r#"/a/1: 2 != "2""#,
+///         "/a/2: (missing) != 3",
+///         "/b: true != (missing)",
+///         "/c: (missing) != true",
+///     ]
+/// );
+/// assert!(json_diff(&a, &a).is_empty());
+/// ```
+pub fn json_diff(left: &JsonValue, right: &JsonValue) -> Vec<String> {
+    let mut diff = Vec::new();
+    diff_values(Some(left), Some(right), &mut JsonPointer::new(), &mut diff);
+    diff
+}
+
+fn diff_values(
+    left: Option<&JsonValue>,
+    right: Option<&JsonValue>,
+    pointer: &mut JsonPointer,
+    diff: &mut Vec<String>,
+) {
+    match (left, right) {
+        (Some(JsonValue::Array(a)), Some(JsonValue::Array(b))) => {
+            for i in 0..a.len().max(b.len()) {
+                pointer.push_index(i);
+                diff_values(a.get(i), b.get(i), pointer, diff);
+                pointer.pop();
+            }
+        }
+        (Some(JsonValue::Object(a)), Some(JsonValue::Object(b))) => {
+            for (key, value) in a.iter() {
+                pointer.push(key.as_str());
+                diff_values(Some(value), b.get(key), pointer, diff);
+                pointer.pop();
+            }
+            for (key, value) in b.iter().filter(|(key, _)| !a.contains_key(key)) {
+                pointer.push(key.as_str());
+                diff_values(None, Some(value), pointer, diff);
+                pointer.pop();
+            }
+        }
+        (left, right) if left != right => {
+            let path = match pointer.is_root() {
+                true => String::from("(root)"),
+                false => pointer.to_string(),
+            };
+            diff.push(format!("{}: {} != {}", path, summary(left), summary(right)));
+        }
+        _ => {}
+    }
+}
+
+fn summary(value: Option<&JsonValue>) -> String {
+    match value {
+        Some(value) => value.to_summary_string(SUMMARY_LEN, SUMMARY_ITEMS),
+        None => String::from("(missing)"),
+    }
+}
+
+#[cfg(test)]
+mod ut_test_util {
+    use crate::{json_diff, JsonValue};
+
+    /// UT test for `json_diff`.
+    ///
+    /// # Title
+    /// ut_json_diff
+    ///
+    /// # Brief
+    /// 1. Creates some different `JsonValue`s.
+    /// 2. Calls `json_diff`.
+    /// 3. Checks if the differences are correct.
+    #[test]
+    fn ut_json_diff() {
+        let a = JsonValue::from_text(r#"{"a/b": {"c": [1, {"d": null}]}, "e": 1.0}"#).unwrap();
+        let b = JsonValue::from_text(r#"{"e": 1, "a/b": {"c": [1, {"d": false}]}}"#).unwrap();
+        assert_eq!(json_diff(&a, &b), ["/a~1b/c/1/d: null != false"]);
+        assert_eq!(json_diff(&a, &JsonValue::Null).len(), 1);
+        assert!(json_diff(&a, &a.clone()).is_empty());
+
+        let long = JsonValue::from_text(r#"[1, 2, 3, 4, 5, 6]"#).unwrap();
+        assert_eq!(
+            json_diff(&JsonValue::Null, &long),
+            ["(root): null != [1, 2, 3, 4, … 2 more]"]
+        );
+    }
+
+    /// UT test for `assert_json_eq!`.
+    ///
+    /// # Title
+    /// ut_assert_json_eq
+    ///
+    /// # Brief
+    /// 1. Calls `assert_json_eq!` with equal and different `JsonValue`s.
+    /// 2. Checks if it panics with the differences only for the different ones.
+    #[test]
+    fn ut_assert_json_eq() {
+        let a = JsonValue::from_text(r#"{"x": [1, 2]}"#).unwrap();
+        let b = JsonValue::from_text(r#"{"x": [1, 3]}"#).unwrap();
+        assert_json_eq!(a, a.clone());
+        assert_json_eq!(&a, &a, "message {}", 1);
+
+        let result = std::panic::catch_unwind(|| assert_json_eq!(a, b, "context"));
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert_eq!(
+            message,
+            "assertion `left == right` failed: context\n\
+             1 difference(s), left != right:\n  /x/1: 2 != 3"
+        );
+    }
+}
@@ -0,0 +1,362 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::consts::*;
+use crate::deserializer::Deserializer;
+use crate::reader::{BytesReader, IoReader};
+use crate::states::{
+    check_document, check_recursion, parse_string, parse_value, read_error_char, skip_value,
+};
+use crate::{CompactEncoder, Error, JsonPointer, JsonValue, ParseError};
+use std::io::Read;
+
+/// The value which the filter of `transcode` is called for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TranscodeEvent<'a> {
+    /// The start of an array. If it is kept, its elements are filtered one by one.
+    StartArray,
+    /// The start of an object. If it is kept, its members are filtered one by one.
+    StartObject,
+    /// A null, boolean, number or string, which has been read.
+    Value(&'a JsonValue),
+}
+
+/// What `transcode` does with a value, returned by the filter.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TranscodeAction {
+    /// Copies the value to the output.
+    Keep,
+    /// Removes the value, and its key if it is a member of an object.
+    Drop,
+    /// Writes the given value instead, and skips the original one.
+    Rewrite(JsonValue),
+}
+
+/// Copies the JSON text from `reader` to `writer` in the compact format, where the
+/// filter decides whether each member and element is kept, dropped or rewritten.
+///
+/// The filter is called with the JSON Pointer of every value except the root, in the
+/// order of the text. Only the scalars and the keys being copied are held in memory,
+/// and the dropped or rewritten arrays and objects are skipped without being parsed,
+/// so the memory used does not grow with the size of the text.
+///
+/// The text is checked with `ParseConfig::default`. If an error occurs, the bytes
+/// before it have already been written to `writer`.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{transcode, JsonValue, TranscodeAction, TranscodeEvent};
+///
+/// let text = r#"{"user": {"name": "Tom", "password": "123"}, "ids": [1, 2, 3]}"#;
+/// let mut output = Vec::new();
+/// transcode(text.as_bytes(), &mut output, |pointer, event| {
+///     match (pointer.segments().last(), event) {
+///         (Some("password"), _) => TranscodeAction::Drop,
+///         (Some("name"), TranscodeEvent::Value(_)) => TranscodeAction::Rewrite("***".into()),
+///         (_, TranscodeEvent::Value(v)) if *v == JsonValue::from(2) => TranscodeAction::Drop,
+///         _ => TranscodeAction::Keep,
+///     }
+/// })
+/// .unwrap();
+/// assert_eq!(output, br#"{"user":{"name":"***"},"ids":[1,3]}"#);
+/// ```
+pub fn transcode<R, W, F>(reader: R, writer: W, filter: F) -> Result<(), Error>
+where
+    R: Read,
+    W: std::io::Write,
+    F: FnMut(&JsonPointer, TranscodeEvent<'_>) -> TranscodeAction,
+{
+    let mut transcoder = Transcoder {
+        deserializer: Deserializer::new_from_io(reader),
+        output: writer,
+        filter,
+        pointer: JsonPointer::new(),
+    };
+    transcoder.copy_value()?;
+
+    // If the text is not finished, return TrailingBytes Error.
+    if eat_whitespace_until_not!(transcoder.deserializer).is_some() {
+        let position = transcoder.deserializer.reader.position();
+        return Err(ParseError::TrailingBytes(position).into());
+    }
+    Ok(())
+}
+
+struct Transcoder<R: Read, W, F> {
+    deserializer: Deserializer<IoReader<R>>,
+    output: W,
+    filter: F,
+    // The pointer to the value being read.
+    pointer: JsonPointer,
+}
+
+impl<R, W, F> Transcoder<R, W, F>
+where
+    R: Read,
+    W: std::io::Write,
+    F: FnMut(&JsonPointer, TranscodeEvent<'_>) -> TranscodeAction,
+{
+    /// Copies the next value, whose members or elements are filtered.
+    fn copy_value(&mut self) -> Result<(), Error> {
+        match eat_whitespace_until_not!(self.deserializer) {
+            Some(LEFT_CURLY_BRACKET) => {
+                self.deserializer.reader.discard();
+                self.copy_object()
+            }
+            Some(LEFT_SQUARE_BRACKET) => {
+                self.deserializer.reader.discard();
+                self.copy_array()
+            }
+            _ => {
+                let value = parse_value(&mut self.deserializer)?;
+                self.encode(&value)
+            }
+        }
+    }
+
+    /// Reads the start of the next value and calls the filter. A scalar is read as a
+    /// whole and returned, while an array or object is left unread.
+    fn filter_next(&mut self) -> Result<(Option<JsonValue>, TranscodeAction), Error> {
+        let event = match eat_whitespace_until_not!(self.deserializer) {
+            Some(LEFT_CURLY_BRACKET) => TranscodeEvent::StartObject,
+            Some(LEFT_SQUARE_BRACKET) => TranscodeEvent::StartArray,
+            _ => {
+                let value = parse_value(&mut self.deserializer)?;
+                let action = (self.filter)(&self.pointer, TranscodeEvent::Value(&value));
+                return Ok((Some(value), action));
+            }
+        };
+        Ok((None, (self.filter)(&self.pointer, event)))
+    }
+
+    /// Writes the value returned by `filter_next` as the action says.
+    fn apply(&mut self, scalar: Option<JsonValue>, action: TranscodeAction) -> Result<(), Error> {
+        match (scalar, action) {
+            (Some(value), TranscodeAction::Keep) => self.encode(&value),
+            (None, TranscodeAction::Keep) => self.copy_value(),
+            (scalar, TranscodeAction::Rewrite(value)) => {
+                if scalar.is_none() {
+                    skip_value(&mut self.deserializer)?;
+                }
+                self.encode(&value)
+            }
+            (Some(_), TranscodeAction::Drop) => Ok(()),
+            (None, TranscodeAction::Drop) => skip_value(&mut self.deserializer),
+        }
+    }
+
+    fn encode(&mut self, value: &JsonValue) -> Result<(), Error> {
+        CompactEncoder::new(&mut self.output).encode(value)
+    }
+
+    fn unexpected_character<T>(&mut self) -> Result<T, Error> {
+        let deserializer = &mut self.deserializer;
+        unexpected_character!(deserializer)
+    }
+
+    /// Copies an object whose '{' has been discarded.
+    fn copy_object(&mut self) -> Result<(), Error> {
+        // Uses an internal state machine to determine the flow, the same as `parse_object`.
+        enum InnerState {
+            Start,      // State at the start of the match.
+            AfterComma, // Comma already exists.
+            NoComma,    // Comma didn't exist before
+        }
+
+        self.deserializer.recursion_depth += 1;
+        check_recursion(&mut self.deserializer)?;
+        self.output.write_all(LEFT_CURLY_BRACKET_STR)?;
+
+        let mut written = false;
+        let mut state = InnerState::Start;
+        loop {
+            match (state, eat_whitespace_until_not!(self.deserializer)) {
+                (InnerState::Start | InnerState::NoComma, Some(RIGHT_CURLY_BRACKET)) => {
+                    self.deserializer.reader.discard();
+                    self.deserializer.recursion_depth -= 1;
+                    break;
+                }
+                (InnerState::Start | InnerState::AfterComma, Some(QUOTATION_MARK)) => {
+                    self.deserializer.reader.discard();
+                    let key = parse_string(&mut self.deserializer)?;
+                    match eat_whitespace_until_not!(self.deserializer) {
+                        Some(COLON) => self.deserializer.reader.discard(),
+                        Some(_) => return self.unexpected_character(),
+                        None => return unexpected_eoj!(self.deserializer),
+                    };
+
+                    self.pointer.push(key.as_str());
+                    let (scalar, action) = self.filter_next()?;
+                    if action != TranscodeAction::Drop {
+                        if written {
+                            self.output.write_all(COMMA_STR)?;
+                        }
+                        self.encode(&JsonValue::from(key))?;
+                        self.output.write_all(COLON_STR)?;
+                        written = true;
+                    }
+                    self.apply(scalar, action)?;
+                    self.pointer.pop();
+                    state = InnerState::NoComma;
+                }
+                (InnerState::NoComma, Some(COMMA)) => {
+                    self.deserializer.reader.discard();
+                    state = InnerState::AfterComma;
+                }
+                (_, Some(_)) => return self.unexpected_character(),
+                (_, None) => return unexpected_eoj!(self.deserializer),
+            }
+        }
+        self.output.write_all(RIGHT_CURLY_BRACKET_STR)?;
+        Ok(())
+    }
+
+    /// Copies an array whose '[' has been discarded.
+    fn copy_array(&mut self) -> Result<(), Error> {
+        self.deserializer.recursion_depth += 1;
+        check_recursion(&mut self.deserializer)?;
+        self.output.write_all(LEFT_SQUARE_BRACKET_STR)?;
+
+        let mut written = false;
+        let mut index = 0;
+        if eat_whitespace_until_not!(self.deserializer) == Some(RIGHT_SQUARE_BRACKET) {
+            self.deserializer.reader.discard();
+        } else {
+            loop {
+                self.pointer.push_index(index);
+                let (scalar, action) = self.filter_next()?;
+                if action != TranscodeAction::Drop {
+                    if written {
+                        self.output.write_all(COMMA_STR)?;
+                    }
+                    written = true;
+                }
+                self.apply(scalar, action)?;
+                self.pointer.pop();
+                index += 1;
+
+                match eat_whitespace_until_not!(self.deserializer) {
+                    Some(COMMA) => self.deserializer.reader.discard(),
+                    Some(RIGHT_SQUARE_BRACKET) => {
+                        self.deserializer.reader.discard();
+                        break;
+                    }
+                    Some(_) => return self.unexpected_character(),
+                    None => return unexpected_eoj!(self.deserializer),
+                }
+            }
+        }
+        self.deserializer.recursion_depth -= 1;
+        self.output.write_all(RIGHT_SQUARE_BRACKET_STR)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod ut_transcode {
+    use crate::{transcode, Error, JsonValue, ParseError, TranscodeAction, TranscodeEvent};
+
+    /// UT test for `transcode`.
+    ///
+    /// # Title
+    /// ut_transcode
+    ///
+    /// # Brief
+    /// 1. Transcodes some texts with filters which keep, drop and rewrite values.
+    /// 2. Checks if the outputs and the pointers passed to the filters are correct.
+    #[test]
+    fn ut_transcode() {
+        let text = r#" { "a" : [ 1 , { "pw" : "x" , "b" : [ ] } , [ 2 ] ] , "pw" : { "c" : 3 } ,
+            "d" : "é\n" , "e" : { } } "#;
+        let mut pointers = Vec::new();
+        let mut output = Vec::new();
+        transcode(text.as_bytes(), &mut output, |pointer, event| {
+            pointers.push(pointer.to_string());
+            match (pointer.segments().last(), event) {
+                (Some("pw"), _) => TranscodeAction::Drop,
+                (_, TranscodeEvent::StartArray) if pointer.to_string() == "/a/2" => {
+                    TranscodeAction::Rewrite(JsonValue::Null)
+                }
+                _ => TranscodeAction::Keep,
+            }
+        })
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            r#"{"a":[1,{"b":[]},null],"d":"\u00e9\n","e":{}}"#
+        );
+        assert_eq!(
+            pointers,
+            ["/a", "/a/0", "/a/1", "/a/1/pw", "/a/1/b", "/a/2", "/pw", "/d", "/e"]
+        );
+
+        let text = r#"[{"a": 1}, 2, [3], "4"]"#;
+        let mut output = Vec::new();
+        transcode(text.as_bytes(), &mut output, |_, _| TranscodeAction::Drop).unwrap();
+        assert_eq!(output, b"[]");
+
+        let mut output = Vec::new();
+        transcode(&b" 1.5 "[..], &mut output, |_, _| TranscodeAction::Drop).unwrap();
+        assert_eq!(output, b"1.5");
+    }
+
+    /// UT test for `transcode` with malformed texts.
+    ///
+    /// # Title
+    /// ut_transcode_error
+    ///
+    /// # Brief
+    /// 1. Transcodes some malformed texts.
+    /// 2. Checks if the errors are correct.
+    #[test]
+    fn ut_transcode_error() {
+        let keep = |_: &_, _: TranscodeEvent<'_>| TranscodeAction::Keep;
+        let drop = |_: &_, _: TranscodeEvent<'_>| TranscodeAction::Drop;
+        for text in [
+            r#"{"a" 1}"#,
+            r#"{"a": 1,}"#,
+            "[1 2]",
+            "[1,]",
+            "[}",
+            r#"{"a": [}]}"#,
+        ] {
+            let result = transcode(text.as_bytes(), Vec::new(), keep);
+            assert!(
+                matches!(
+                    result,
+                    Err(Error::Parsing(ParseError::UnexpectedCharacter(..)))
+                ),
+                "{text}"
+            );
+        }
+        for text in [r#"{"a": [1"#, "[", r#"{"a""#] {
+            let result = transcode(text.as_bytes(), Vec::new(), drop);
+            assert!(
+                matches!(
+                    result,
+                    Err(Error::Parsing(ParseError::UnexpectedEndOfJson(..)))
+                ),
+                "{text}"
+            );
+        }
+        assert!(matches!(
+            transcode(&b"[] []"[..], Vec::new(), keep),
+            Err(Error::Parsing(ParseError::TrailingBytes(..)))
+        ));
+        let deep = "[".repeat(200) + &"]".repeat(200);
+        assert!(matches!(
+            transcode(deep.as_bytes(), Vec::new(), keep),
+            Err(Error::ExceedRecursionLimit)
+        ));
+    }
+}
@@ -12,31 +12,62 @@
 // limitations under the License.
 
 mod array;
+mod builder;
+mod coerce;
 mod index;
+mod key;
 mod number;
 mod object;
+mod rename;
+mod shared;
+#[cfg(feature = "c_adapter")]
+mod string;
 
 pub use array::Array;
+pub use builder::{ArrayBuilder, ObjectBuilder};
 pub use index::Index;
+pub use key::Key;
 pub use number::Number;
 pub use object::Object;
-
-use crate::{start_parsing, CompactEncoder, Error, FormattedEncoder};
-use core::fmt::{Debug, Display, Formatter};
-use core::str::FromStr;
+#[cfg(any(
+    feature = "list_object",
+    feature = "vec_object",
+    feature = "index_object"
+))]
+pub use object::OrderedObject;
+pub use shared::SharedValue;
 #[cfg(feature = "c_adapter")]
-use std::ffi::CString;
-use std::io::{Read, Write};
+pub use string::JsonString;
 
-#[cfg(feature = "c_adapter")]
-pub type JsonString = CString;
+/// String of a `JsonValue`. It is a `String` without the `c_adapter` feature, and a
+/// string which keeps a NUL after its bytes with the feature.
 #[cfg(not(feature = "c_adapter"))]
 pub type JsonString = String;
 
+use crate::pointer::{resolve_relative, select_pointers, PathIter};
+use crate::writer::ChunkWriter;
+use crate::{
+    start_parsing, start_partial_parsing, CompactEncoder, EncodeConfig, Error, FormattedEncoder,
+    ParseConfig, ParseStats, RelativeTarget, SummaryEncoder, ValueStats,
+};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display, Formatter};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::io::{Read, Write};
+
 use crate::deserializer::Deserializer;
-#[cfg(not(feature = "c_adapter"))]
+#[cfg(all(
+    feature = "std",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
 use std::fs::File;
-#[cfg(not(feature = "c_adapter"))]
+#[cfg(all(
+    feature = "std",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
 use std::path::Path;
 
 /// There are 6 types of values that appear in Json text:
@@ -55,11 +86,6 @@ use std::path::Path;
 ///
 /// RFC 7159 3. Values say
 /// “A Json value must be an object, an array, a number, a string, or a text string of false, null, or true.”
-///
-/// # features
-/// Uses"c_adatper" feature:
-/// In order to adapt the C encapsulation layer interface, the structure changes the
-/// underlying implementation of String, and uses CString to get the char* pointer easily.
 // TODO: Enhance the encapsulation of JsonValue, makes users can't use enum directly.
 #[derive(Clone)]
 pub enum JsonValue {
@@ -98,7 +124,6 @@ impl Display for JsonValue {
             Self::Null => write!(f, "null"),
             Self::Boolean(b) => write!(f, "{b}"),
             Self::Number(n) => Display::fmt(n, f),
-            // The Debug output is the same for CString and String.
             Self::String(s) => write!(f, "{s:?}"),
             Self::Array(a) => Display::fmt(a, f),
             Self::Object(o) => Display::fmt(o, f),
@@ -159,21 +184,10 @@ impl JsonValue {
     /// use ylong_json::JsonValue;
     ///
     /// let value = JsonValue::new_string("Hello World");
-    /// // When open "c_adapter" feature, the internal value is String.
     /// assert_eq!(value, JsonValue::String(String::from("Hello World")));
-    ///
-    /// // When opening "c_adapter" feature, the internal value is CString.
-    /// // assert_eq!(value, JsonValue::String(CString::new("Hello World")));
     /// ```
     pub fn new_string(str: &str) -> Self {
-        // The underlying implementation of String here is CString.
-        #[cfg(feature = "c_adapter")]
-        let result = Self::String(JsonString::new(str).unwrap());
-
-        #[cfg(not(feature = "c_adapter"))]
-        let result = Self::String(JsonString::from(str));
-
-        result
+        Self::String(str.into())
     }
 
     /// Creates an instance of JsonValue for Array type.
@@ -378,17 +392,10 @@ impl JsonValue {
     ///
     /// # Examples
     /// ```no_run
-    /// #[cfg(feature = "c_adapter")]
-    /// use std::ffi::CString;
     /// use ylong_json::{JsonValue, Error};
     ///
     /// let string_value = JsonValue::new_string("Hello World");
-    /// #[cfg(feature = "c_adapter")]
-    /// assert_eq!(string_value.try_as_string().unwrap(), &CString::new("Hello World").unwrap());
-    /// #[cfg(not(feature = "c_adapter"))]
     /// assert_eq!(string_value.try_as_string().unwrap(), &String::from("Hello World"));
-    /// // When opening "c_adapter" feature, the underlying implementation is CString.
-    /// //assert_eq!(string_value.try_as_string().unwrap(), &CString::new("Hello World").unwrap());
     ///
     /// let other_value = JsonValue::new_null();
     /// assert!(other_value.try_as_string().is_err());
@@ -480,17 +487,10 @@ impl JsonValue {
     ///
     /// # Examples
     /// ```no_run
-    /// #[cfg(feature = "c_adapter")]
-    /// use std::ffi::CString;
     /// use ylong_json::{JsonValue, Error};
     ///
     /// let mut string_value = JsonValue::new_string("Hello World");
-    /// #[cfg(feature = "c_adapter")]
-    /// assert_eq!(string_value.try_as_mut_string().unwrap(), &mut CString::new("Hello World").unwrap());
-    /// #[cfg(not(feature = "c_adapter"))]
     /// assert_eq!(string_value.try_as_mut_string().unwrap(), &mut String::from("Hello World"));
-    /// // When opening "c_adapter" feature, the underlying implementation is CString.
-    /// //assert_eq!(string_value.try_as_mut_string().unwrap(), &mut CString::new("Hello World").unwrap());
     ///
     /// let mut other_value = JsonValue::new_null();
     /// assert!(other_value.try_as_mut_string().is_err());
@@ -587,24 +587,19 @@ impl JsonValue {
     ///
     /// # Examples
     /// ```no_run
-    /// #[cfg(feature = "c_adapter")]
-    /// use std::ffi::CString;
     /// use ylong_json::{JsonValue, Error};
     ///
     /// let string_value = JsonValue::new_string("Hello World");
-    /// #[cfg(feature = "c_adapter")]
-    /// assert_eq!(string_value.try_into_string().unwrap(), CString::new("Hello World").unwrap());
-    /// #[cfg(not(feature = "c_adapter"))]
     /// assert_eq!(string_value.try_into_string().unwrap(), String::from("Hello World"));
-    /// // When opening "c_adapter" feature, the underlying implementation is CString.
-    /// //assert_eq!(string_value.try_into_string().unwrap(), CString::new("Hello World").unwrap());
     ///
     /// let other_value = JsonValue::new_null();
     /// assert!(other_value.try_into_string().is_err());
     /// ```
-    pub fn try_into_string(self) -> Result<JsonString, Error> {
+    // The conversion is only needed with the `c_adapter` feature.
+    #[allow(clippy::useless_conversion)]
+    pub fn try_into_string(self) -> Result<String, Error> {
         match self {
-            Self::String(string) => Ok(string),
+            Self::String(string) => Ok(string.into()),
             _ => Err(Error::TypeTransform),
         }
     }
@@ -669,6 +664,373 @@ impl JsonValue {
         index.index_remove(self)
     }
 
+    /// Gets a member of an Object or Array by Index. Unlike `value[index]`, which reads Null
+    /// in these cases, returns an error with the index if the member does not exist or this
+    /// JsonValue is not an Object or Array as required.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Error, JsonValue};
+    ///
+    /// let value = JsonValue::from_text(r#"{"list": [1, 2]}"#).unwrap();
+    /// assert_eq!(value.try_index("list")?.try_index(1)?, &JsonValue::from(2));
+    ///
+    /// let error = value.try_index("list")?.try_index(2).unwrap_err();
+    /// assert_eq!(error.to_string(), "Index Error: [2] does not exist");
+    /// let error = value.try_index("list")?.try_index("key").unwrap_err();
+    /// assert_eq!(error.to_string(), r#"Index Error: cannot index array by ["key"]"#);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn try_index<I: index::Index>(&self, index: I) -> Result<&JsonValue, Error> {
+        index.index_into_checked(self)
+    }
+
+    /// Applies a JSON Merge Patch (RFC 7396) to this JsonValue.
+    ///
+    /// If the patch is an Object, its members are merged into this JsonValue one by one,
+    /// and members whose value is null are removed. Otherwise, this JsonValue is replaced
+    /// by the patch.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let mut value = JsonValue::from_text(r#"{"a": "b", "c": {"d": "e", "f": "g"}}"#).unwrap();
+    /// let patch = JsonValue::from_text(r#"{"a": "z", "c": {"f": null}}"#).unwrap();
+    /// value.merge_patch(&patch);
+    /// assert_eq!(value, JsonValue::from_text(r#"{"a": "z", "c": {"d": "e"}}"#).unwrap());
+    /// ```
+    pub fn merge_patch(&mut self, patch: &JsonValue) {
+        let patch = match patch {
+            JsonValue::Object(patch) => patch,
+            _ => {
+                *self = patch.clone();
+                return;
+            }
+        };
+
+        if !self.is_object() {
+            *self = JsonValue::Object(Object::new());
+        }
+        if let JsonValue::Object(target) = self {
+            for (key, value) in patch.iter() {
+                if value.is_null() {
+                    target.remove(key);
+                } else if let Some(member) = target.get_mut(key) {
+                    member.merge_patch(value);
+                } else {
+                    let mut member = JsonValue::Null;
+                    member.merge_patch(value);
+                    target.insert(key.clone(), member);
+                }
+            }
+        }
+    }
+
+    /// Merges another JsonValue into this JsonValue recursively.
+    ///
+    /// If both are Objects, the members of `other` are merged into this Object one by one.
+    /// Otherwise, this JsonValue is replaced by `other`. Unlike `JsonValue::merge_patch`,
+    /// null values are copied rather than removing members.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let mut value = JsonValue::from_text(r#"{"a": {"b": 1, "c": 2}, "d": [1]}"#).unwrap();
+    /// let other = JsonValue::from_text(r#"{"a": {"c": null}, "d": [2]}"#).unwrap();
+    /// value.merge(&other);
+    /// assert_eq!(value, JsonValue::from_text(r#"{"a": {"b": 1, "c": null}, "d": [2]}"#).unwrap());
+    /// ```
+    pub fn merge(&mut self, other: &JsonValue) {
+        match (self, other) {
+            (JsonValue::Object(target), JsonValue::Object(other)) => {
+                for (key, value) in other.iter() {
+                    match target.get_mut(key) {
+                        Some(member) => member.merge(value),
+                        None => target.insert(key.clone(), value.clone()),
+                    }
+                }
+            }
+            (target, other) => *target = other.clone(),
+        }
+    }
+
+    /// Gets a reference to the value at the JSON Pointer (RFC 6901), such as "/a/b/0".
+    ///
+    /// Returns None if the pointer is malformed, or the value does not exist.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let value = JsonValue::from_text(r#"{"a": {"b": [1, 2]}, "c/d": 3}"#).unwrap();
+    /// assert_eq!(*value.pointer("/a/b/1").unwrap(), 2.into());
+    /// assert_eq!(*value.pointer("/c~1d").unwrap(), 3.into());
+    /// assert_eq!(value.pointer("").unwrap(), &value);
+    ///
+    /// assert!(value.pointer("/a/b/2").is_none());
+    /// assert!(value.pointer("/a/b/01").is_none());
+    /// assert!(value.pointer("a").is_none());
+    /// ```
+    pub fn pointer(&self, pointer: &str) -> Option<&JsonValue> {
+        if pointer.is_empty() {
+            return Some(self);
+        }
+        let mut value = self;
+        for token in pointer.strip_prefix('/')?.split('/') {
+            value = pointer_step(value, &unescape_pointer_token(token)?)?;
+        }
+        Some(value)
+    }
+
+    /// Gets references to the values at the JSON Pointers, in the same order as the
+    /// pointers. `None` is returned for a malformed pointer, or a value which does not
+    /// exist.
+    ///
+    /// The pointers are resolved in one walk of the tree, where the values along their
+    /// common prefixes are looked up only once, so it is faster than calling
+    /// `JsonValue::pointer` for each of many pointers.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let value = JsonValue::from_text(r#"{"a": 1, "b": [2], "c": {"d": 3}}"#).unwrap();
+    /// let values = value.select_pointers(&["/a", "/b/0", "/c/d", "/c/e"]);
+    /// assert_eq!(values, [Some(&1.into()), Some(&2.into()), Some(&3.into()), None]);
+    /// ```
+    pub fn select_pointers(&self, pointers: &[&str]) -> Vec<Option<&JsonValue>> {
+        select_pointers(self, pointers)
+    }
+
+    /// Gets an iterator over every value in this JsonValue, including itself, with its
+    /// JSON Pointer. The values are yielded in depth-first order, where an array or
+    /// object comes before its elements or members.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let value = JsonValue::from_text(r#"{"a": [1, {"password": 2}]}"#).unwrap();
+    /// let paths: Vec<String> = value.iter_paths().map(|(p, _)| p.to_string()).collect();
+    /// assert_eq!(paths, ["", "/a", "/a/0", "/a/1", "/a/1/password"]);
+    ///
+    /// let found: Vec<String> = value
+    ///     .iter_paths()
+    ///     .filter(|(p, _)| p.segments().last() == Some("password"))
+    ///     .map(|(p, _)| p.to_string())
+    ///     .collect();
+    /// assert_eq!(found, ["/a/1/password"]);
+    /// ```
+    pub fn iter_paths(&self) -> PathIter<'_> {
+        PathIter::new(self)
+    }
+
+    /// Evaluates the Relative JSON Pointer, such as "1/a" or "0#", at the location
+    /// `current`, which is a JSON Pointer (RFC 6901) from this JsonValue.
+    ///
+    /// A relative pointer starts with the number of levels to go up from `current`,
+    /// optionally followed by `+n` or `-n` which moves among the elements of an Array.
+    /// Then it ends with either a JSON Pointer from there, or "#" which gets the key
+    /// or the index of the location.
+    ///
+    /// Returns None if either pointer is malformed, or the location does not exist.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, RelativeTarget};
+    ///
+    /// let value = JsonValue::from_text(r#"{"foo": ["bar", "baz"], "n": {"m": true}}"#).unwrap();
+    /// let target = value.relative_pointer("/foo/1", "0-1").unwrap();
+    /// assert_eq!(target, RelativeTarget::Value(&"bar".into()));
+    /// let target = value.relative_pointer("/foo/1", "2/n/m").unwrap();
+    /// assert_eq!(target, RelativeTarget::Value(&true.into()));
+    /// let target = value.relative_pointer("/foo/1", "1#").unwrap();
+    /// assert_eq!(target, RelativeTarget::Key(String::from("foo")));
+    /// let target = value.relative_pointer("/foo/1", "0#").unwrap();
+    /// assert_eq!(target, RelativeTarget::Index(1));
+    ///
+    /// assert!(value.relative_pointer("/foo/1", "3").is_none());
+    /// ```
+    pub fn relative_pointer(&self, current: &str, relative: &str) -> Option<RelativeTarget<'_>> {
+        resolve_relative(self, current, relative)
+    }
+
+    /// Gets a mutable reference to the value at the JSON Pointer (RFC 6901), such as
+    /// "/a/b/0", creating the missing values on the way.
+    ///
+    /// A null value on the way is replaced by an Array if the next reference token is an
+    /// array index or "-", otherwise by an Object. A missing member of an Object is inserted
+    /// as null, and the index equal to the length of an Array, or "-", appends a null.
+    ///
+    /// Returns None if the pointer is malformed, or it runs into a boolean, a number, a
+    /// string, or an Array with a token which is not an index or larger than the length.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let mut value = JsonValue::new_null();
+    /// *value.pointer_or_insert("/a/b/0").unwrap() = JsonValue::from(1);
+    /// *value.pointer_or_insert("/a/b/-").unwrap() = JsonValue::from(2);
+    /// *value.pointer_or_insert("/a/c~1d").unwrap() = JsonValue::from(3);
+    /// assert_eq!(value.to_compact_string().unwrap(), r#"{"a":{"b":[1,2],"c/d":3}}"#);
+    ///
+    /// assert!(value.pointer_or_insert("/a/b/5").is_none());
+    /// assert!(value.pointer_or_insert("a").is_none());
+    /// ```
+    pub fn pointer_or_insert(&mut self, pointer: &str) -> Option<&mut JsonValue> {
+        if pointer.is_empty() {
+            return Some(self);
+        }
+        let mut value = self;
+        for token in pointer.strip_prefix('/')?.split('/') {
+            value = pointer_step_or_insert(value, &unescape_pointer_token(token)?)?;
+        }
+        Some(value)
+    }
+
+    /// Determines whether two JsonValues are equal, including the order of the members of
+    /// objects, which is ignored by `PartialEq`. It is useful for order-significant protocols.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let a = JsonValue::from_text(r#"[{"x": 1, "y": 2}]"#).unwrap();
+    /// let b = JsonValue::from_text(r#"[{"x": 1, "y": 2}]"#).unwrap();
+    /// assert!(a.eq_ordered(&b));
+    /// assert!(!a.eq_ordered(&JsonValue::new_null()));
+    /// ```
+    pub fn eq_ordered(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Array(a), Self::Array(b)) => {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| a.eq_ordered(b))
+            }
+            (Self::Object(a), Self::Object(b)) => a.eq_ordered(b),
+            (a, b) => a == b,
+        }
+    }
+
+    /// Determines whether two JsonValues are equal, where numbers only need to differ by
+    /// at most `epsilon` if either is a floating point number. It is useful for checking
+    /// computed results, where exact equality of floating point numbers is too strict.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let a = JsonValue::from_text(r#"{"x": 0.3, "y": [1, 2]}"#).unwrap();
+    /// let b = JsonValue::from_text(r#"{"y": [1, 2.0000001], "x": 0.30000001}"#).unwrap();
+    /// assert_ne!(a, b);
+    /// assert!(a.approx_eq(&b, 1e-6));
+    /// assert!(!a.approx_eq(&b, 1e-9));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.approx_eq_with(other, epsilon, false)
+    }
+
+    /// Determines whether two JsonValues are equal like `JsonValue::approx_eq`, but the
+    /// elements of arrays may be in any order.
+    ///
+    /// Each element is matched with the first approximately equal element which is not
+    /// matched yet, so comparing arrays takes quadratic time.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let a = JsonValue::from_text(r#"[1, {"x": 0.5}, 1]"#).unwrap();
+    /// let b = JsonValue::from_text(r#"[{"x": 0.5000001}, 1, 1]"#).unwrap();
+    /// assert!(!a.approx_eq(&b, 1e-6));
+    /// assert!(a.approx_eq_unordered(&b, 1e-6));
+    ///
+    /// let c = JsonValue::from_text(r#"[1, {"x": 0.5}, {"x": 0.5}]"#).unwrap();
+    /// assert!(!a.approx_eq_unordered(&c, 1e-6));
+    /// ```
+    pub fn approx_eq_unordered(&self, other: &Self, epsilon: f64) -> bool {
+        self.approx_eq_with(other, epsilon, true)
+    }
+
+    fn approx_eq_with(&self, other: &Self, epsilon: f64, unordered: bool) -> bool {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.approx_eq(b, epsilon),
+            (Self::Array(a), Self::Array(b)) if unordered => {
+                if a.len() != b.len() {
+                    return false;
+                }
+                let mut matched = alloc::vec![false; b.len()];
+                a.iter().all(|a| {
+                    let found = b
+                        .iter()
+                        .zip(matched.iter_mut())
+                        .find(|(b, matched)| !**matched && a.approx_eq_with(b, epsilon, unordered));
+                    match found {
+                        Some((_, matched)) => {
+                            *matched = true;
+                            true
+                        }
+                        None => false,
+                    }
+                })
+            }
+            (Self::Array(a), Self::Array(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .zip(b.iter())
+                        .all(|(a, b)| a.approx_eq_with(b, epsilon, unordered))
+            }
+            (Self::Object(a), Self::Object(b)) => {
+                a.len() == b.len()
+                    && a.iter().all(|(key, a)| {
+                        b.get(key)
+                            .is_some_and(|b| a.approx_eq_with(b, epsilon, unordered))
+                    })
+            }
+            (a, b) => a == b,
+        }
+    }
+
+    /// Clones the JsonValue, keeping at most `max_depth` levels of arrays and objects.
+    /// The arrays and objects below them are replaced by the placeholder strings "[…]"
+    /// and "{…}".
+    ///
+    /// Only the kept levels are visited, so the cost is bounded by their size instead of
+    /// the size of the whole value. It is useful for taking snapshots of huge values,
+    /// such as for telemetry.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let value = JsonValue::from_text(r#"{"a": [1, {"b": 2}], "c": {}}"#).unwrap();
+    /// let expected = JsonValue::from_text(r#"{"a": [1, "{…}"], "c": {}}"#).unwrap();
+    /// assert_eq!(value.clone_truncated(2), expected);
+    /// assert_eq!(value.clone_truncated(0), JsonValue::from("{…}"));
+    /// ```
+    pub fn clone_truncated(&self, max_depth: usize) -> JsonValue {
+        match self {
+            Self::Array(_) if max_depth == 0 => Self::String("[…]".into()),
+            Self::Object(_) if max_depth == 0 => Self::String("{…}".into()),
+            Self::Array(array) => {
+                let mut clone = Array::new();
+                for value in array.iter() {
+                    clone.push(value.clone_truncated(max_depth - 1));
+                }
+                Self::Array(clone)
+            }
+            Self::Object(object) => {
+                let mut clone = Object::new();
+                for (key, value) in object.iter() {
+                    clone.insert(key.clone(), value.clone_truncated(max_depth - 1));
+                }
+                Self::Object(clone)
+            }
+            value => value.clone(),
+        }
+    }
+
     /// Reads the contents from the file and Trys to deserialize to a JsonValue instance.
     ///
     /// # Examples
@@ -677,7 +1039,10 @@ impl JsonValue {
     ///
     /// let value = JsonValue::from_file("./json.txt").unwrap();
     /// ```
-    #[cfg(not(feature = "c_adapter"))]
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
         let mut file = File::open(path.as_ref())?;
         Self::from_reader(&mut file)
@@ -694,6 +1059,7 @@ impl JsonValue {
     /// let mut file = File::open("./json.txt").unwrap();
     /// let value = JsonValue::from_reader(&mut file).unwrap();
     /// ```
+    #[cfg(feature = "std")]
     pub fn from_reader<R: Read>(input: R) -> Result<Self, Error> {
         let mut deserializer = Deserializer::new_from_io(input);
         start_parsing(&mut deserializer)
@@ -719,7 +1085,206 @@ impl JsonValue {
         start_parsing(&mut deserializer)
     }
 
+    /// Parses the first JSON value at the beginning of the slice and returns it with
+    /// the number of bytes consumed. Unlike `JsonValue::from_text`, the bytes after the
+    /// value are not checked, so JSON embedded in framed protocols or mixed text can be
+    /// extracted in place.
+    ///
+    /// The consumed bytes include the leading whitespace, but not the trailing one.
+    /// A number at the end of the slice is taken as complete.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let text = br#" {"id": 1} {"id": 2}"#;
+    /// let (first, consumed) = JsonValue::from_slice_partial(text).unwrap();
+    /// assert_eq!(first["id"], 1.into());
+    /// assert_eq!(consumed, 10);
+    ///
+    /// let (second, _) = JsonValue::from_slice_partial(&text[consumed..]).unwrap();
+    /// assert_eq!(second["id"], 2.into());
+    /// ```
+    pub fn from_slice_partial(slice: &[u8]) -> Result<(Self, usize), Error> {
+        let mut deserializer = Deserializer::new_from_slice(slice);
+        start_partial_parsing(&mut deserializer)
+    }
+
+    /// The same as `JsonValue::from_reader`, but the limits in the given `ParseConfig`
+    /// are applied while parsing.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Error, JsonValue, ParseConfig};
+    ///
+    /// let text = r#"["a long string"]"#;
+    /// let config = ParseConfig::default().max_string_bytes(8);
+    /// let result = JsonValue::from_reader_with_config(text.as_bytes(), config);
+    /// assert!(matches!(result, Err(Error::ExceedStringLimit)));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_reader_with_config<R: Read>(input: R, config: ParseConfig) -> Result<Self, Error> {
+        let mut deserializer = Deserializer::new_from_io(input).with_config(config);
+        start_parsing(&mut deserializer)
+    }
+
+    /// The same as `JsonValue::from_text`, but the limits in the given `ParseConfig`
+    /// are applied while parsing.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, ParseConfig};
+    ///
+    /// let text = r#"{"key": [1, 2, 3]}"#;
+    /// let value = JsonValue::from_text_with_config(text, ParseConfig::strict()).unwrap();
+    /// assert_eq!(value["key"][2], 3.into());
+    /// ```
+    pub fn from_text_with_config<T: AsRef<[u8]>>(
+        text: T,
+        config: ParseConfig,
+    ) -> Result<Self, Error> {
+        let mut deserializer = Deserializer::new_from_slice(text.as_ref()).with_config(config);
+        start_parsing(&mut deserializer)
+    }
+
+    /// Reads the text from several slices in order and Trys to deserialize it to a Json instance.
+    /// The slices are read as one text without being joined.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let header = r#"{"key": "va"#;
+    /// let body = r#"lue"}"#;
+    /// let value = JsonValue::from_slices(&[header.as_bytes(), body.as_bytes()]).unwrap();
+    ///
+    /// assert_eq!(value["key"], "value".into());
+    /// ```
+    pub fn from_slices(slices: &[&[u8]]) -> Result<Self, Error> {
+        let mut deserializer = Deserializer::new_from_slices(slices);
+        start_parsing(&mut deserializer)
+    }
+
+    /// Reads the text from a type that can be converted to [u8] and Trys to deserialize it to a Json instance.
+    /// If the text is a top-level array, its elements are parsed on at most `threads` threads.
+    ///
+    /// The result is the same as [`JsonValue::from_text`]. It is only faster for very large
+    /// top-level arrays, because the text needs to be scanned once more to split the elements.
+    /// The threads are scoped threads of `std`, which are created for every call instead of
+    /// being taken from a pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let text = r#"[{"key": 1}, {"key": 2}, {"key": 3}]"#;
+    /// let value = JsonValue::from_text_parallel(text.as_bytes(), 2).unwrap();
+    ///
+    /// assert_eq!(value[2]["key"], 3.into());
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn from_text_parallel<T: AsRef<[u8]>>(text: T, threads: usize) -> Result<Self, Error> {
+        crate::parallel::parse_parallel(text.as_ref(), threads, ParseConfig::default())
+    }
+
+    /// The same as `JsonValue::from_text_parallel`, but the limits in the given `ParseConfig`
+    /// are applied while parsing. The result is the same as [`JsonValue::from_text_with_config`].
+    ///
+    /// Every element is parsed with the config. The number of the top-level elements is
+    /// checked against `max_array_len` before they are parsed, and the allocated bytes of
+    /// all elements are checked against `max_alloc_bytes` in total.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Error, JsonValue, ParseConfig};
+    ///
+    /// let text = r#"[{"key": 1}, {"key": 2}, {"key": 3}]"#;
+    /// let config = ParseConfig::default().max_array_len(2);
+    /// let result = JsonValue::from_text_parallel_with_config(text, 2, config);
+    /// assert!(matches!(result, Err(Error::ExceedArrayLimit)));
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn from_text_parallel_with_config<T: AsRef<[u8]>>(
+        text: T,
+        threads: usize,
+        config: ParseConfig,
+    ) -> Result<Self, Error> {
+        crate::parallel::parse_parallel(text.as_ref(), threads, config)
+    }
+
+    /// The same as `JsonValue::from_text`, but also returns the statistics collected while parsing.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let text = r#"{"key": ["value", null]}"#;
+    /// let (value, stats) = JsonValue::from_text_with_stats(text).unwrap();
+    ///
+    /// assert_eq!(value["key"][0], "value".into());
+    /// assert_eq!(stats.max_depth, 2);
+    /// assert_eq!(stats.node_counts.null, 1);
+    /// ```
+    pub fn from_text_with_stats<T: AsRef<[u8]>>(text: T) -> Result<(Self, ParseStats), Error> {
+        Self::from_text_with_config_and_stats(text, ParseConfig::default())
+    }
+
+    /// The same as `JsonValue::from_text_with_stats`, but the options in the given
+    /// `ParseConfig` are applied while parsing. With `ParseConfig::record_duplicate_keys`,
+    /// the duplicate keys are recorded into the statistics.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, ParseConfig};
+    ///
+    /// let config = ParseConfig::default().record_duplicate_keys(true);
+    /// let text = r#"{"id": 1, "tags": {"a": 1, "a": 2}, "id": 2}"#;
+    /// let (_, stats) = JsonValue::from_text_with_config_and_stats(text, config).unwrap();
+    ///
+    /// let keys: Vec<&str> = stats.duplicate_keys.iter().map(|d| d.key.as_str()).collect();
+    /// assert_eq!(keys, ["a", "id"]);
+    /// ```
+    pub fn from_text_with_config_and_stats<T: AsRef<[u8]>>(
+        text: T,
+        config: ParseConfig,
+    ) -> Result<(Self, ParseStats), Error> {
+        #[cfg(feature = "std")]
+        let start = std::time::Instant::now();
+        let mut deserializer = Deserializer::new_from_slice(text.as_ref()).with_config(config);
+        deserializer.stats = Some(ParseStats::default());
+        let value = start_parsing(&mut deserializer)?;
+        let stats = ParseStats {
+            #[cfg(feature = "std")]
+            duration: start.elapsed(),
+            ..deserializer.stats.unwrap_or_default()
+        };
+        Ok((value, stats))
+    }
+
+    /// Gets the statistics of the JsonValue instance, such as its depth, the number of
+    /// values of each type, the total length of strings and the largest array and object.
+    /// They can be used to check quotas or to choose a format for persistence.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let value = JsonValue::from_text(r#"{"key": ["value", null], "other": {}}"#).unwrap();
+    /// let stats = value.stats();
+    ///
+    /// assert_eq!(stats.max_depth, 2);
+    /// assert_eq!(stats.node_counts.object, 2);
+    /// assert_eq!(stats.string_bytes, 3 + 5 + 5);
+    /// assert_eq!(stats.max_object_members, 2);
+    /// ```
+    pub fn stats(&self) -> ValueStats {
+        let mut stats = ValueStats::default();
+        stats.record(self, 0);
+        stats
+    }
+
     /// Serializes the JsonValue instance to a formatted string with additional whitespace characters.
+    /// NaN and infinite floats fail the encoding, see `EncodeConfig`.
     ///
     /// # Examples
     /// ```
@@ -733,13 +1298,44 @@ impl JsonValue {
     /// let string = value.to_formatted_string().unwrap();
     /// assert_eq!(string, text);
     /// ```
-    pub fn to_formatted_string(&self) -> Result<std::string::String, Error> {
+    pub fn to_formatted_string(&self) -> Result<String, Error> {
+        let mut vec = Vec::new();
+        FormattedEncoder::new(&mut vec).encode(self)?;
+        Ok(unsafe { String::from_utf8_unchecked(vec) })
+    }
+
+    /// The same as `JsonValue::to_formatted_string`, but arrays and objects are kept on one
+    /// line if they fit within `max_width` columns, including the indentation and the key.
+    /// Those which do not fit have one element or member per line. Columns are counted in
+    /// bytes of the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let text = r#"{
+    ///     "matrix": [
+    ///         [1, 0, 0],
+    ///         [0, 1, 0],
+    ///         [0, 0, 1]
+    ///     ],
+    ///     "point": {"x": 1, "y": 2}
+    /// }
+    /// "#;
+    /// let value = JsonValue::from_text(text.as_bytes()).unwrap();
+    /// let string = value.to_formatted_string_with_width(30).unwrap();
+    /// assert_eq!(string, text);
+    /// ```
+    pub fn to_formatted_string_with_width(&self, max_width: usize) -> Result<String, Error> {
         let mut vec = Vec::new();
-        self.formatted_encode(&mut vec)?;
-        Ok(unsafe { std::string::String::from_utf8_unchecked(vec) })
+        FormattedEncoder::new(&mut vec)
+            .with_max_width(max_width)
+            .encode(self)?;
+        Ok(unsafe { String::from_utf8_unchecked(vec) })
     }
 
     /// Serializes the JsonValue instance to a one-line string with no additional whitespace.
+    /// NaN and infinite floats fail the encoding, see `EncodeConfig`.
     ///
     /// # Examples
     /// ```
@@ -750,10 +1346,77 @@ impl JsonValue {
     /// let string = value.to_compact_string().unwrap();
     /// assert_eq!(string, text);
     /// ```
-    pub fn to_compact_string(&self) -> Result<std::string::String, Error> {
+    pub fn to_compact_string(&self) -> Result<String, Error> {
         let mut vec = Vec::new();
-        self.compact_encode(&mut vec)?;
-        Ok(unsafe { std::string::String::from_utf8_unchecked(vec) })
+        CompactEncoder::new(&mut vec).encode(self)?;
+        Ok(unsafe { String::from_utf8_unchecked(vec) })
+    }
+
+    /// The same as `JsonValue::to_formatted_string`, but encodes by the options of `config`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{EncodeConfig, JsonValue, NonFiniteFloat};
+    ///
+    /// let mut value = JsonValue::from_text(r#"{"key": 1.5}"#).unwrap();
+    /// value["key"] = JsonValue::from(f64::INFINITY);
+    /// let config = EncodeConfig::default().non_finite_float(NonFiniteFloat::Literal);
+    /// let string = value.to_formatted_string_with_config(config).unwrap();
+    /// assert_eq!(string, "{\n    \"key\": Infinity\n}\n");
+    /// ```
+    pub fn to_formatted_string_with_config(&self, config: EncodeConfig) -> Result<String, Error> {
+        let mut vec = Vec::new();
+        FormattedEncoder::new(&mut vec)
+            .with_config(config)
+            .encode(self)?;
+        Ok(unsafe { String::from_utf8_unchecked(vec) })
+    }
+
+    /// The same as `JsonValue::to_compact_string`, but encodes by the options of `config`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{EncodeConfig, Error, JsonValue, NonFiniteFloat};
+    ///
+    /// let value = JsonValue::from(f64::NAN);
+    /// let config = EncodeConfig::default();
+    /// assert!(matches!(
+    ///     value.to_compact_string_with_config(config),
+    ///     Err(Error::NonFiniteFloat)
+    /// ));
+    ///
+    /// let config = EncodeConfig::default().non_finite_float(NonFiniteFloat::Null);
+    /// assert_eq!(value.to_compact_string_with_config(config).unwrap(), "null");
+    /// ```
+    pub fn to_compact_string_with_config(&self, config: EncodeConfig) -> Result<String, Error> {
+        let mut vec = Vec::new();
+        CompactEncoder::new(&mut vec)
+            .with_config(config)
+            .encode(self)?;
+        Ok(unsafe { String::from_utf8_unchecked(vec) })
+    }
+
+    /// Serializes the JsonValue instance to a bounded summary for logging. Strings and keys
+    /// keep at most `max_len` characters, and arrays and objects keep at most `max_items`
+    /// elements or members, followed by the number of elided ones.
+    ///
+    /// The summary is not valid JSON if anything is elided.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let value = JsonValue::from_text(r#"{"list": [1, 2, 3, 4], "name": "abcdefgh"}"#).unwrap();
+    /// assert_eq!(
+    ///     value.to_summary_string(4, 2),
+    ///     r#"{"list": [1, 2, … 2 more], "name": "abcd…"}"#
+    /// );
+    /// ```
+    pub fn to_summary_string(&self, max_len: usize, max_items: usize) -> String {
+        let mut vec = Vec::new();
+        // Writing into a `Vec` does not fail.
+        let _ = SummaryEncoder::new(&mut vec, max_len, max_items).encode(self);
+        unsafe { String::from_utf8_unchecked(vec) }
     }
 
     /// Serializes the JsonValue instance to a formatted string with additional whitespace characters.
@@ -772,11 +1435,34 @@ impl JsonValue {
     /// value.formatted_encode(&mut vec).unwrap();
     /// assert_eq!(vec, text.as_bytes());
     /// ```
+    #[cfg(feature = "std")]
     pub fn formatted_encode<W: Write>(&self, output: &mut W) -> Result<(), Error> {
         let mut encoder = FormattedEncoder::new(output);
         encoder.encode(self)
     }
 
+    /// The same as `JsonValue::formatted_encode`, but arrays and objects are kept on one
+    /// line if they fit within `max_width` columns, see `JsonValue::to_formatted_string_with_width`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let value = JsonValue::from_text(r#"{"points": [[1, 2], [3, 4]]}"#).unwrap();
+    /// let mut vec = Vec::new();
+    /// value.formatted_encode_with_width(&mut vec, 80).unwrap();
+    /// assert_eq!(vec, b"{\"points\": [[1, 2], [3, 4]]}\n");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn formatted_encode_with_width<W: Write>(
+        &self,
+        output: &mut W,
+        max_width: usize,
+    ) -> Result<(), Error> {
+        let mut encoder = FormattedEncoder::new(output).with_max_width(max_width);
+        encoder.encode(self)
+    }
+
     /// Serializes the JsonValue instance to a one-line string with no additional whitespace.
     /// And outputs to the specified location as a stream of bytes.
     ///
@@ -790,10 +1476,114 @@ impl JsonValue {
     /// value.compact_encode(&mut vec).unwrap();
     /// assert_eq!(vec, text.as_bytes());
     /// ```
+    #[cfg(feature = "std")]
     pub fn compact_encode<W: Write>(&self, output: &mut W) -> Result<(), Error> {
         let mut encoder = CompactEncoder::new(output);
         encoder.encode(self)
     }
+
+    /// The same as `JsonValue::formatted_encode`, but encodes by the options of `config`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{EncodeConfig, JsonValue, NonFiniteFloat};
+    ///
+    /// let value = JsonValue::from(f64::NAN);
+    /// let config = EncodeConfig::default().non_finite_float(NonFiniteFloat::Null);
+    /// let mut vec = Vec::new();
+    /// value.formatted_encode_with_config(&mut vec, config).unwrap();
+    /// assert_eq!(vec, b"null\n");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn formatted_encode_with_config<W: Write>(
+        &self,
+        output: &mut W,
+        config: EncodeConfig,
+    ) -> Result<(), Error> {
+        let mut encoder = FormattedEncoder::new(output).with_config(config);
+        encoder.encode(self)
+    }
+
+    /// The same as `JsonValue::compact_encode`, but encodes by the options of `config`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{EncodeConfig, JsonValue, NonFiniteFloat};
+    ///
+    /// let value = JsonValue::from(f64::NEG_INFINITY);
+    /// let config = EncodeConfig::default().non_finite_float(NonFiniteFloat::Literal);
+    /// let mut vec = Vec::new();
+    /// value.compact_encode_with_config(&mut vec, config).unwrap();
+    /// assert_eq!(vec, b"-Infinity");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn compact_encode_with_config<W: Write>(
+        &self,
+        output: &mut W,
+        config: EncodeConfig,
+    ) -> Result<(), Error> {
+        let mut encoder = CompactEncoder::new(output).with_config(config);
+        encoder.encode(self)
+    }
+
+    /// The same as `JsonValue::compact_encode`, but passes the output to `callback` as
+    /// chunks of `chunk_size` bytes instead of writing it, so that it can be sent to
+    /// scatter-gather IO or ring buffers without a contiguous buffer of the whole output.
+    /// Only the last chunk can be shorter. If `chunk_size` is 0, every piece written by
+    /// the encoder is passed as a chunk without buffering.
+    ///
+    /// The chunks are only valid during the call. An error returned by `callback` stops
+    /// the encoding and is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let value = JsonValue::from_text(r#"{"key": "value"}"#).unwrap();
+    /// let mut chunks = Vec::new();
+    /// value
+    ///     .compact_encode_chunks(4, |chunk| {
+    ///         chunks.push(chunk.to_vec());
+    ///         Ok(())
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(chunks, [&b"{\"ke"[..], b"y\":\"", b"valu", b"e\"}"]);
+    /// ```
+    pub fn compact_encode_chunks<F>(&self, chunk_size: usize, callback: F) -> Result<(), Error>
+    where
+        F: FnMut(&[u8]) -> Result<(), Error>,
+    {
+        let mut writer = ChunkWriter::new(chunk_size, callback);
+        CompactEncoder::new(&mut writer).encode(self)?;
+        writer.finish()
+    }
+
+    /// The same as `JsonValue::compact_encode_chunks`, but the output is the same as
+    /// `JsonValue::formatted_encode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let value = JsonValue::from_text("[1, 2]").unwrap();
+    /// let mut output = Vec::new();
+    /// value
+    ///     .formatted_encode_chunks(3, |chunk| {
+    ///         assert!(chunk.len() <= 3);
+    ///         output.extend_from_slice(chunk);
+    ///         Ok(())
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(output, value.to_formatted_string().unwrap().as_bytes());
+    /// ```
+    pub fn formatted_encode_chunks<F>(&self, chunk_size: usize, callback: F) -> Result<(), Error>
+    where
+        F: FnMut(&[u8]) -> Result<(), Error>,
+    {
+        let mut writer = ChunkWriter::new(chunk_size, callback);
+        FormattedEncoder::new(&mut writer).encode(self)?;
+        writer.finish()
+    }
 }
 
 impl FromStr for JsonValue {
@@ -895,29 +1685,23 @@ impl From<&str> for JsonValue {
     /// let value: JsonValue = "Hello World".into();
     /// ```
     fn from(t: &str) -> Self {
-        #[cfg(feature = "c_adapter")]
-        let result = Self::String(JsonString::new(t).unwrap());
-
-        #[cfg(not(feature = "c_adapter"))]
-        let result = Self::String(String::from(t));
-        result
+        Self::String(t.into())
     }
 }
 
-impl From<JsonString> for JsonValue {
+impl From<String> for JsonValue {
     /// Converts from String to JsonValue.
     ///
     /// # Examples
     /// ```not run
     /// use ylong_json::JsonValue;
     ///
-    /// // Unused "c_adapter" feature.
     /// let value: JsonValue = String::from("Hello World").into();
-    /// // Uses "c_adapter" feature.
-    /// // let value: JsonValue = CString::new("Hello World").into();
     /// ```
-    fn from(t: JsonString) -> Self {
-        Self::String(t)
+    // The conversion is only needed with the `c_adapter` feature.
+    #[allow(clippy::useless_conversion)]
+    fn from(t: String) -> Self {
+        Self::String(t.into())
     }
 }
 
@@ -994,9 +1778,73 @@ json_value_from_type!(Object, JsonValue::new_object);
 
 number_value_from_type!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
 
+/// Unescapes "~1" to '/' and "~0" to '~' in a reference token of a JSON Pointer.
+pub(crate) fn unescape_pointer_token(token: &str) -> Option<Cow<'_, str>> {
+    if !token.contains('~') {
+        return Some(Cow::Borrowed(token));
+    }
+    let mut unescaped = String::with_capacity(token.len());
+    let mut chars = token.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '~' => match chars.next()? {
+                '0' => unescaped.push('~'),
+                '1' => unescaped.push('/'),
+                _ => return None,
+            },
+            ch => unescaped.push(ch),
+        }
+    }
+    Some(Cow::Owned(unescaped))
+}
+
+/// Parses a reference token of a JSON Pointer as an array index, where "-" is `Some(None)`.
+pub(crate) fn pointer_index(token: &str) -> Option<Option<usize>> {
+    // Leading zeros are not allowed in array indexes.
+    match token {
+        "-" => Some(None),
+        "0" => Some(Some(0)),
+        _ if token.starts_with('0') => None,
+        _ => token.parse::<usize>().ok().map(Some),
+    }
+}
+
+/// Gets the member referenced by the token of a JSON Pointer.
+pub(crate) fn pointer_step<'a>(value: &'a JsonValue, token: &str) -> Option<&'a JsonValue> {
+    match value {
+        JsonValue::Object(object) => object.get(token),
+        JsonValue::Array(array) => array.get(pointer_index(token)??),
+        _ => None,
+    }
+}
+
+/// Gets the member referenced by the token of a JSON Pointer, inserting it if it is missing.
+fn pointer_step_or_insert<'a>(value: &'a mut JsonValue, token: &str) -> Option<&'a mut JsonValue> {
+    let index = pointer_index(token);
+    if value.is_null() {
+        *value = match index {
+            Some(_) => JsonValue::Array(Array::new()),
+            None => JsonValue::Object(Object::new()),
+        };
+    }
+    match value {
+        JsonValue::Object(object) => Some(object.get_or_insert_with(token, JsonValue::new_null)),
+        JsonValue::Array(array) => {
+            let index = index?.unwrap_or(array.len());
+            if index == array.len() {
+                array.push(JsonValue::Null);
+            }
+            array.get_mut(index)
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod ut_json_value {
     use super::{array::Array, object::Object, JsonValue};
+    use crate::Error;
+    #[cfg(feature = "std")]
     use std::io::{ErrorKind, Read, Result};
     use std::str::FromStr;
 
@@ -1697,17 +2545,7 @@ mod ut_json_value {
             JsonValue::new_object(Object::new())
         );
 
-        #[cfg(not(feature = "c_adapter"))]
         assert_eq!(JsonValue::from(String::new()), JsonValue::new_string(""));
-
-        #[cfg(feature = "c_adapter")]
-        {
-            use std::ffi::CString;
-            assert_eq!(
-                JsonValue::from(CString::new("").unwrap()),
-                JsonValue::new_string("")
-            );
-        }
     }
 
     /// UT test for `JsonValue::remove`.
@@ -1744,6 +2582,78 @@ mod ut_json_value {
         assert_eq!(array[2], JsonValue::new_null());
     }
 
+    /// UT test for `JsonValue::merge_patch`.
+    ///
+    /// # Title
+    /// ut_json_value_merge_patch
+    ///
+    /// # Brief
+    /// 1. Creates the targets and patches in the examples of RFC 7396.
+    /// 2. Calls `JsonValue::merge_patch`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_json_value_merge_patch() {
+        let cases = [
+            (r#"{"a":"b"}"#, r#"{"a":"c"}"#, r#"{"a":"c"}"#),
+            (r#"{"a":"b"}"#, r#"{"b":"c"}"#, r#"{"a":"b","b":"c"}"#),
+            (r#"{"a":"b"}"#, r#"{"a":null}"#, r#"{}"#),
+            (r#"{"a":"b","b":"c"}"#, r#"{"a":null}"#, r#"{"b":"c"}"#),
+            (r#"{"a":["b"]}"#, r#"{"a":"c"}"#, r#"{"a":"c"}"#),
+            (r#"{"a":"c"}"#, r#"{"a":["b"]}"#, r#"{"a":["b"]}"#),
+            (
+                r#"{"a":{"b":"c"}}"#,
+                r#"{"a":{"b":"d","c":null}}"#,
+                r#"{"a":{"b":"d"}}"#,
+            ),
+            (r#"{"a":[{"b":"c"}]}"#, r#"{"a":[1]}"#, r#"{"a":[1]}"#),
+            (r#"["a","b"]"#, r#"["c","d"]"#, r#"["c","d"]"#),
+            (r#"{"a":"b"}"#, r#"["c"]"#, r#"["c"]"#),
+            (r#"{"a":"foo"}"#, r#"null"#, r#"null"#),
+            (r#"{"a":"foo"}"#, r#""bar""#, r#""bar""#),
+            (r#"{"e":null}"#, r#"{"a":1}"#, r#"{"e":null,"a":1}"#),
+            (r#"[1,2]"#, r#"{"a":"b","c":null}"#, r#"{"a":"b"}"#),
+            (
+                r#"{}"#,
+                r#"{"a":{"bb":{"ccc":null}}}"#,
+                r#"{"a":{"bb":{}}}"#,
+            ),
+        ];
+        for (target, patch, result) in cases {
+            let mut target = JsonValue::from_str(target).unwrap();
+            target.merge_patch(&JsonValue::from_str(patch).unwrap());
+            assert_eq!(target, JsonValue::from_str(result).unwrap());
+        }
+    }
+
+    /// UT test for `JsonValue::merge`.
+    ///
+    /// # Title
+    /// ut_json_value_merge
+    ///
+    /// # Brief
+    /// 1. Creates some `JsonValue`s.
+    /// 2. Calls `JsonValue::merge`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_json_value_merge() {
+        let cases = [
+            (
+                r#"{"a":{"b":1,"c":[1]},"d":true}"#,
+                r#"{"a":{"c":[2],"e":null},"f":"g"}"#,
+                r#"{"a":{"b":1,"c":[2],"e":null},"d":true,"f":"g"}"#,
+            ),
+            (r#"{"a":1}"#, r#"{"a":{"b":2}}"#, r#"{"a":{"b":2}}"#),
+            (r#"{"a":1}"#, r#"[1]"#, r#"[1]"#),
+            (r#"[1]"#, r#"{"a":1}"#, r#"{"a":1}"#),
+            (r#"{"a":1}"#, r#"{}"#, r#"{"a":1}"#),
+        ];
+        for (target, other, result) in cases {
+            let mut target = JsonValue::from_str(target).unwrap();
+            target.merge(&JsonValue::from_str(other).unwrap());
+            assert_eq!(target, JsonValue::from_str(result).unwrap());
+        }
+    }
+
     /// UT test for `JsonValue::from_reader`.
     ///
     /// # Title
@@ -1753,6 +2663,7 @@ mod ut_json_value {
     /// 1. Calls `JsonValue::from_reader` to create some `JsonValue`.
     /// 2. Checks if the test results are correct.
     #[test]
+    #[cfg(feature = "std")]
     fn ut_json_value_from_reader() {
         struct TestErrorIo;
 
@@ -1764,4 +2675,210 @@ mod ut_json_value {
 
         assert!(JsonValue::from_reader(TestErrorIo).is_err());
     }
+
+    /// UT test for `JsonValue::from_slice_partial`.
+    ///
+    /// # Title
+    /// ut_json_value_from_slice_partial
+    ///
+    /// # Brief
+    /// 1. Calls `JsonValue::from_slice_partial` on texts followed by other bytes.
+    /// 2. Checks if the values and the consumed lengths are correct.
+    #[test]
+    fn ut_json_value_from_slice_partial() {
+        let (value, len) = JsonValue::from_slice_partial(b"  [1, 2]\r\nnext").unwrap();
+        assert_eq!(value, JsonValue::from_str("[1, 2]").unwrap());
+        assert_eq!(len, 8);
+
+        let (value, len) = JsonValue::from_slice_partial(b"\"a\"\"b\"").unwrap();
+        assert_eq!(value, JsonValue::new_string("a"));
+        assert_eq!(len, 3);
+
+        let (value, len) = JsonValue::from_slice_partial(b"12,34").unwrap();
+        assert_eq!(value, JsonValue::new_number(12.into()));
+        assert_eq!(len, 2);
+
+        assert!(JsonValue::from_slice_partial(b"  ").is_err());
+        assert!(JsonValue::from_slice_partial(b"[1, 2").is_err());
+        assert!(JsonValue::from_slice_partial(b"}").is_err());
+    }
+
+    /// UT test for `JsonValue::pointer_or_insert`.
+    ///
+    /// # Title
+    /// ut_json_value_pointer_or_insert
+    ///
+    /// # Brief
+    /// 1. Creates some `JsonValue`s.
+    /// 2. Calls `JsonValue::pointer_or_insert` with valid and invalid pointers.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_json_value_pointer_or_insert() {
+        let mut value = JsonValue::from_str(r#"{"a": [0, {"b": null}], "s": "x"}"#).unwrap();
+        assert_eq!(
+            value.pointer_or_insert("/a/0"),
+            Some(&mut JsonValue::new_number(0.into()))
+        );
+        *value.pointer_or_insert("/a/1/b/c").unwrap() = JsonValue::from(1);
+        *value.pointer_or_insert("/a/2/~0").unwrap() = JsonValue::from(2);
+        *value.pointer_or_insert("/a/-/-").unwrap() = JsonValue::from(3);
+        *value.pointer_or_insert("/n/01").unwrap() = JsonValue::from(4);
+        assert_eq!(
+            value,
+            JsonValue::from_str(
+                r#"{"a": [0, {"b": {"c": 1}}, {"~": 2}, [3]], "s": "x", "n": {"01": 4}}"#
+            )
+            .unwrap()
+        );
+
+        let expected = value.clone();
+        for pointer in ["a", "/a/5", "/a/01", "/a/x", "/s/0", "/a/~2", "/a/~"] {
+            assert!(value.pointer_or_insert(pointer).is_none());
+        }
+        assert_eq!(value, expected);
+        assert_eq!(value.pointer_or_insert(""), Some(&mut expected.clone()));
+    }
+
+    /// UT test for `JsonValue::try_index`.
+    ///
+    /// # Title
+    /// ut_json_value_try_index
+    ///
+    /// # Brief
+    /// 1. Creates a `JsonValue`.
+    /// 2. Calls `JsonValue::try_index` with existing and missing indexes.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_json_value_try_index() {
+        let value = JsonValue::from_str(r#"{"a": [null, {"b": 1}]}"#).unwrap();
+        let b = value
+            .try_index("a")
+            .and_then(|a| a.try_index(1)?.try_index("b"));
+        assert_eq!(b.unwrap(), &JsonValue::new_number(1.into()));
+        let key = String::from("a");
+        assert!(value.try_index(&key).unwrap().is_array());
+        assert!(value["a"].try_index(0).unwrap().is_null());
+
+        assert!(matches!(
+            value.try_index("c"),
+            Err(Error::IndexNotFound(index)) if index == r#"["c"]"#
+        ));
+        assert!(matches!(
+            value["a"].try_index(2),
+            Err(Error::IndexNotFound(index)) if index == "[2]"
+        ));
+        assert!(matches!(
+            value.try_index(0),
+            Err(Error::IndexTypeMismatch(index, "object")) if index == "[0]"
+        ));
+        assert!(matches!(
+            value["a"][0].try_index("b"),
+            Err(Error::IndexTypeMismatch(_, "null"))
+        ));
+    }
+
+    /// UT test for `JsonValue::clone_truncated`.
+    ///
+    /// # Title
+    /// ut_json_value_clone_truncated
+    ///
+    /// # Brief
+    /// 1. Creates a nested `JsonValue`.
+    /// 2. Calls `JsonValue::clone_truncated` with different depths.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_json_value_clone_truncated() {
+        let text = r#"{"a": [1, [2, {"b": "c"}]], "d": null, "e": {}}"#;
+        let value = JsonValue::from_str(text).unwrap();
+        assert_eq!(value.clone_truncated(0), JsonValue::from("{…}"));
+        assert_eq!(
+            value.clone_truncated(1),
+            JsonValue::from_str(r#"{"a": "[…]", "d": null, "e": "{…}"}"#).unwrap()
+        );
+        assert_eq!(
+            value.clone_truncated(2),
+            JsonValue::from_str(r#"{"a": [1, "[…]"], "d": null, "e": {}}"#).unwrap()
+        );
+        assert_eq!(
+            value.clone_truncated(3),
+            JsonValue::from_str(r#"{"a": [1, [2, "{…}"]], "d": null, "e": {}}"#).unwrap()
+        );
+        assert_eq!(value.clone_truncated(4), value);
+        assert_eq!(value.clone_truncated(usize::MAX), value);
+        assert_eq!(JsonValue::from(1).clone_truncated(0), JsonValue::from(1));
+    }
+
+    /// UT test for `JsonValue::approx_eq` and `JsonValue::approx_eq_unordered`.
+    ///
+    /// # Title
+    /// ut_json_value_approx_eq
+    ///
+    /// # Brief
+    /// 1. Creates some `JsonValue`s with close numbers and reordered arrays.
+    /// 2. Calls `JsonValue::approx_eq` and `JsonValue::approx_eq_unordered`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_json_value_approx_eq() {
+        let a = JsonValue::from_str(r#"{"a": [1, 2.5, "x"], "b": {"c": -1e10}}"#).unwrap();
+        let b = JsonValue::from_str(r#"{"b": {"c": -1.00001e10}, "a": [1, 2.51, "x"]}"#).unwrap();
+        assert!(a.approx_eq(&b, 1e5));
+        assert!(!a.approx_eq(&b, 1e-3));
+        assert!(a.approx_eq(&a, 0.0));
+        assert!(!a.approx_eq(&JsonValue::Null, 1.0));
+
+        let c = JsonValue::from_str(r#"{"a": ["x", 1, 2.5], "b": {"c": -1e10}}"#).unwrap();
+        assert!(!a.approx_eq(&c, 0.0));
+        assert!(a.approx_eq_unordered(&c, 0.0));
+        let d = JsonValue::from_str(r#"{"a": ["x", 1, 1], "b": {"c": -1e10}}"#).unwrap();
+        assert!(!a.approx_eq_unordered(&d, 0.0));
+        let e = JsonValue::from_str(r#"{"a": [1, 2.5], "b": {"c": -1e10}}"#).unwrap();
+        assert!(!a.approx_eq_unordered(&e, 0.0));
+        let f = JsonValue::from_str(r#"{"a": [1, 2.5, "x"], "d": {"c": -1e10}}"#).unwrap();
+        assert!(!a.approx_eq_unordered(&f, 0.0));
+
+        let inf = JsonValue::from(f64::INFINITY);
+        assert!(inf.approx_eq(&inf, 0.0));
+        assert!(!JsonValue::from(u64::MAX).approx_eq(&JsonValue::from(u64::MAX - 1), 2.0));
+    }
+
+    /// UT test for `Send` and `Sync` of `JsonValue`.
+    ///
+    /// # Title
+    /// ut_json_value_send_sync
+    ///
+    /// # Brief
+    /// 1. Checks if the value types are `Send` and `Sync` at compile time.
+    /// 2. Parses a value and moves it to another thread.
+    /// 3. Shares the value between threads and reads it.
+    #[test]
+    fn ut_json_value_send_sync() {
+        use crate::{Document, SharedValue};
+        use std::sync::Arc;
+        use std::thread;
+
+        fn check<T: Send + Sync>() {}
+        check::<JsonValue>();
+        check::<Array>();
+        check::<Object>();
+        check::<SharedValue>();
+        check::<Document>();
+
+        let value = JsonValue::from_text(r#"{"a": [1, 2, {"b": "c"}], "d": {"e": null}}"#).unwrap();
+        let mut value = thread::spawn(move || value).join().unwrap();
+        value["a"]
+            .try_as_mut_array()
+            .unwrap()
+            .push(JsonValue::from(3));
+
+        let value = Arc::new(value);
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let value = value.clone();
+                thread::spawn(move || value["a"].try_as_array().unwrap().len())
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 4);
+        }
+    }
 }
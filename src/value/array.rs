@@ -11,6 +11,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use core::ops::{Bound, Range, RangeBounds};
+
 #[cfg(feature = "list_array")]
 mod linked_list;
 #[cfg(feature = "list_array")]
@@ -20,3 +22,57 @@ pub use linked_list::Array;
 mod vec;
 #[cfg(feature = "vec_array")]
 pub use vec::Array;
+
+use crate::JsonValue;
+use alloc::vec::Vec;
+
+/// Converts `range` to the positions it covers in an Array of length `len`, clamping
+/// the bounds to the length.
+fn clamp_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    let end = end.min(len);
+    start.min(end)..end
+}
+
+impl From<Array> for Vec<JsonValue> {
+    /// Converts an Array into a Vec of its elements, see `Array::into_vec`.
+    fn from(array: Array) -> Self {
+        array.into_vec()
+    }
+}
+
+#[cfg(test)]
+mod ut_array {
+    use crate::{Array, JsonValue};
+
+    /// UT test for the conversions between `Array` and `Vec`.
+    ///
+    /// # Title
+    /// ut_array_convert
+    ///
+    /// # Brief
+    /// 1. Converts an `Array` into a `Vec` and back.
+    /// 2. Checks if the elements are kept in order.
+    #[test]
+    fn ut_array_convert() {
+        let value = JsonValue::from_text(r#"[1, "a", [null], {"b": true}]"#).unwrap();
+        let array = value.try_as_array().unwrap().clone();
+        let vec = array.clone().into_vec();
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec[1], JsonValue::from("a"));
+        assert_eq!(Array::from(vec), array);
+
+        let vec: Vec<JsonValue> = Array::new().into();
+        assert!(vec.is_empty());
+        assert!(Array::from(vec).is_empty());
+    }
+}
@@ -11,8 +11,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Cursor, CursorMut, Iter, IterMut, JsonValue, LinkedList, Node};
+use crate::{Cursor, CursorMut, Drain, ExtractIf, Iter, IterMut, JsonValue, LinkedList, Node};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt::{Debug, Display, Formatter};
+use core::ops::RangeBounds;
 
 /// Array type, implemented using LinkedList.
 ///
@@ -24,6 +27,9 @@ use core::fmt::{Debug, Display, Formatter};
 /// # Attention
 /// * Only open `list_array` feature can be used, and conflicts with other array-related features.
 ///
+/// * The elements are not contiguous, so this Array does not provide `as_slice` and
+///   `as_mut_slice` like the Vec one. Collect `Array::iter` into a Vec to use slice methods.
+///
 /// # Examples
 /// ```
 /// use ylong_json::Array;
@@ -50,6 +56,13 @@ impl Array {
         }
     }
 
+    /// Creates an empty Array whose nodes are allocated in chunks of at most `size` nodes.
+    pub(crate) fn with_node_chunk_size(size: usize) -> Self {
+        Self {
+            inner: LinkedList::with_chunk_size(size),
+        }
+    }
+
     /// Gets length of Array.
     ///
     /// # Examples
@@ -171,6 +184,36 @@ impl Array {
         self.get_node_mut(index).map(|n| n.get_element_mut())
     }
 
+    /// Returns a common reference to the leading ** member ** in Array.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// assert_eq!(array.first(), None);
+    /// array.push(JsonValue::Null);
+    /// assert_eq!(array.first(), Some(&JsonValue::Null));
+    /// ```
+    pub fn first(&self) -> Option<&JsonValue> {
+        self.inner.iter().next()
+    }
+
+    /// Returns a mutable reference to the leading ** member ** in Array.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// assert_eq!(array.first_mut(), None);
+    /// array.push(JsonValue::Null);
+    /// assert_eq!(array.first_mut(), Some(&mut JsonValue::Null));
+    /// ```
+    pub fn first_mut(&mut self) -> Option<&mut JsonValue> {
+        self.inner.iter_mut().next()
+    }
+
     /// Returns a common reference to the trailing ** member ** in Array.
     ///
     /// # Examples
@@ -221,6 +264,229 @@ impl Array {
         self.get_cursor_mut(index)?.remove_current()
     }
 
+    /// Inserts a JsonValue at the specified index of Array, shifting all the
+    /// elements after it to the right. If the index is greater than the length
+    /// of Array, the JsonValue is inserted at the end.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::Null);
+    /// array.push(JsonValue::Null);
+    ///
+    /// array.insert(1, JsonValue::Boolean(true));
+    /// assert_eq!(array.get(1), Some(&JsonValue::Boolean(true)));
+    ///
+    /// array.insert(10, JsonValue::Boolean(false));
+    /// assert_eq!(array.last(), Some(&JsonValue::Boolean(false)));
+    /// ```
+    pub fn insert(&mut self, index: usize, value: JsonValue) {
+        match self.get_cursor_mut(index) {
+            Some(mut cursor) => cursor.insert_before(value),
+            None => self.push(value),
+        }
+    }
+
+    /// Removes the elements in the specified range from Array, and returns an iterator
+    /// over them. The range is clamped to the length of Array, and the elements which are
+    /// not yielded are removed as well when the iterator is dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// for i in 0..5 {
+    ///     array.push(JsonValue::from(i));
+    /// }
+    ///
+    /// let batch: Vec<JsonValue> = array.drain(..2).collect();
+    /// assert_eq!(batch, vec![JsonValue::from(0), JsonValue::from(1)]);
+    /// assert_eq!(array.to_string(), "[2,3,4]");
+    ///
+    /// array.drain(1..10);
+    /// assert_eq!(array.to_string(), "[2]");
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, JsonValue> {
+        let range = super::clamp_range(range, self.inner.len());
+        self.inner.drain(range.start, range.len())
+    }
+
+    /// Shortens Array to `len` elements, dropping the rest. It has no effect if Array
+    /// is not longer than `len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::Null);
+    /// array.push(JsonValue::Boolean(true));
+    ///
+    /// array.truncate(1);
+    /// assert_eq!(array.to_string(), "[null]");
+    /// array.truncate(5);
+    /// assert_eq!(array.len(), 1);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        while self.inner.len() > len {
+            self.inner.pop_back();
+        }
+    }
+
+    /// Resizes Array to `len` elements. If Array is longer, it is truncated, otherwise the
+    /// elements returned by calling `f` are appended.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::Boolean(true));
+    ///
+    /// array.resize_with(3, JsonValue::new_null);
+    /// assert_eq!(array.to_string(), "[true,null,null]");
+    /// array.resize_with(2, JsonValue::new_null);
+    /// assert_eq!(array.to_string(), "[true,null]");
+    /// ```
+    pub fn resize_with<F>(&mut self, len: usize, mut f: F)
+    where
+        F: FnMut() -> JsonValue,
+    {
+        self.truncate(len);
+        while self.inner.len() < len {
+            self.inner.push_back(f());
+        }
+    }
+
+    /// Removes the elements matching `pred` in one pass, and returns an iterator over
+    /// them. The elements which are not yielded, including those left after the iterator
+    /// is dropped early, remain in Array.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::Null);
+    /// array.push(JsonValue::Boolean(true));
+    /// array.push(JsonValue::Null);
+    ///
+    /// assert_eq!(array.extract_if(|value| value.is_null()).count(), 2);
+    /// assert_eq!(array.to_string(), "[true]");
+    /// ```
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, JsonValue, F>
+    where
+        F: FnMut(&mut JsonValue) -> bool,
+    {
+        self.inner.extract_if(pred)
+    }
+
+    /// Sorts Array with a comparator function, and keeps the order of equal elements.
+    ///
+    /// The sort relinks the nodes in place, so the nodes obtained before stay valid and
+    /// hold the same elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::new_string("b"));
+    /// array.push(JsonValue::new_string("a"));
+    ///
+    /// array.sort_by(|a, b| a.try_as_string().unwrap().cmp(b.try_as_string().unwrap()));
+    /// assert_eq!(array.to_string(), r#"["a","b"]"#);
+    /// ```
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&JsonValue, &JsonValue) -> Ordering,
+    {
+        self.inner.sort_by(compare)
+    }
+
+    /// Gets a cursor pointing to the first element of Array, which can move and seek
+    /// without traversing from the head every time.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::Null);
+    /// array.push(JsonValue::Boolean(true));
+    ///
+    /// let mut cursor = array.cursor_front();
+    /// assert_eq!(cursor.current(), Some(&JsonValue::Null));
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&JsonValue::Boolean(true)));
+    /// assert!(!cursor.seek(2));
+    /// assert_eq!(cursor.index(), None);
+    /// ```
+    pub fn cursor_front(&self) -> Cursor<'_, JsonValue> {
+        self.inner.cursor_front()
+    }
+
+    /// Gets a mutable cursor pointing to the first element of Array, which can insert
+    /// and remove elements at its position in O(1) time.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::Null);
+    /// array.push(JsonValue::Null);
+    ///
+    /// let mut cursor = array.cursor_front_mut();
+    /// cursor.insert_after(JsonValue::Boolean(true));
+    /// assert!(cursor.seek(2));
+    /// cursor.insert_before(JsonValue::Boolean(false));
+    /// assert_eq!(cursor.remove_current(), Some(JsonValue::Null));
+    /// assert_eq!(array.to_string(), "[null,true,false]");
+    /// ```
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, JsonValue> {
+        self.inner.cursor_front_mut()
+    }
+
+    /// Gets a cursor pointing to the last element of Array.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::Null);
+    /// array.push(JsonValue::Boolean(true));
+    ///
+    /// let mut cursor = array.cursor_back();
+    /// assert_eq!(cursor.index(), Some(1));
+    /// cursor.move_prev();
+    /// assert_eq!(cursor.current(), Some(&JsonValue::Null));
+    /// ```
+    pub fn cursor_back(&self) -> Cursor<'_, JsonValue> {
+        self.inner.cursor_back()
+    }
+
+    /// Gets a mutable cursor pointing to the last element of Array.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::Null);
+    ///
+    /// let mut cursor = array.cursor_back_mut();
+    /// cursor.insert_after(JsonValue::Boolean(true));
+    /// assert_eq!(array.to_string(), "[null,true]");
+    /// ```
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, JsonValue> {
+        self.inner.cursor_back_mut()
+    }
+
     /// Returns a common reference to the specified index ** node ** in Array.
     ///
     /// After getting a common reference to a node, the corresponding node cannot be released.
@@ -290,60 +556,41 @@ impl Array {
 
     /// Gets the common cursor of the specified index node.
     fn get_cursor(&self, index: usize) -> Option<Cursor<'_, JsonValue>> {
-        let len = self.len();
-        // If index is greater than the array length, returns.
-        // If index is less than half the array length, searches from front to back;
-        // If index is greater than half the array length, searches from the back to the front.
-        return if index >= len {
-            None
-        } else if index >= (len - 1) / 2 {
-            let mut steps = len - 1 - index;
-            let mut cursor = self.inner.cursor_back();
-            while steps != 0 {
-                let _ = cursor.index()?;
-                cursor.move_prev();
-                steps -= 1;
-            }
-            Some(cursor)
-        } else {
-            let mut steps = index;
-            let mut cursor = self.inner.cursor_front();
-            while steps != 0 {
-                let _ = cursor.index()?;
-                cursor.move_next();
-                steps -= 1;
-            }
-            Some(cursor)
-        };
+        let mut cursor = self.inner.cursor_front();
+        cursor.seek(index).then_some(cursor)
     }
 
     /// Gets the mutable cursor of the specified index node.
     fn get_cursor_mut(&mut self, index: usize) -> Option<CursorMut<'_, JsonValue>> {
-        let len = self.len();
-        // If index is greater than the array length, returns.
-        // If index is less than half the array length, searches from front to back;
-        // If index is greater than half the array length, searches from the back to the front.
-        return if index >= len {
-            None
-        } else if index >= (len - 1) / 2 {
-            let mut steps = len - 1 - index;
-            let mut cursor = self.inner.cursor_back_mut();
-            while steps != 0 {
-                let _ = cursor.index()?;
-                cursor.move_prev();
-                steps -= 1;
-            }
-            Some(cursor)
-        } else {
-            let mut steps = index;
-            let mut cursor = self.inner.cursor_front_mut();
-            while steps != 0 {
-                let _ = cursor.index()?;
-                cursor.move_next();
-                steps -= 1;
-            }
-            Some(cursor)
-        };
+        let mut cursor = self.inner.cursor_front_mut();
+        cursor.seek(index).then_some(cursor)
+    }
+
+    /// Converts the Array into a Vec of its elements in order.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::Null);
+    /// array.push(JsonValue::Boolean(true));
+    /// assert_eq!(array.into_vec(), [JsonValue::Null, JsonValue::Boolean(true)]);
+    /// ```
+    pub fn into_vec(mut self) -> Vec<JsonValue> {
+        let len = self.inner.len();
+        self.inner.drain(0, len).collect()
+    }
+}
+
+impl From<Vec<JsonValue>> for Array {
+    /// Converts a Vec of elements into an Array.
+    fn from(vec: Vec<JsonValue>) -> Self {
+        let mut array = Self::new();
+        for value in vec {
+            array.push(value);
+        }
+        array
     }
 }
 
@@ -514,6 +761,31 @@ mod ut_linked_list {
         assert!(array.last_node_mut().is_none());
     }
 
+    /// UT test for `Array::insert`.
+    ///
+    /// # Title
+    /// ut_array_insert
+    ///
+    /// # Brief
+    /// 1. Creates some `Array`s.
+    /// 2. Calls `Array::insert` with different indexes.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_array_insert() {
+        let mut array = array!(1, 2);
+        array.insert(0, JsonValue::from(0));
+        array.insert(2, JsonValue::Null);
+        array.insert(4, JsonValue::from(3));
+        array.insert(100, JsonValue::from(4));
+        assert_eq!(array, array!(0, 1, JsonValue::Null, 2, 3, 4));
+        assert_eq!(array.len(), 6);
+        assert_eq!(array.last(), Some(&JsonValue::from(4)));
+
+        let mut array = Array::new();
+        array.insert(1, JsonValue::Null);
+        assert_eq!(array, array!(JsonValue::Null));
+    }
+
     /// UT test for `Array::fmt`.
     ///
     /// # Title
@@ -529,4 +801,102 @@ mod ut_linked_list {
         assert_eq!(format!("{array}"), "[1,2]");
         assert_eq!(format!("{array:?}"), "[1,2]");
     }
+
+    /// UT test for `Array::extract_if`.
+    ///
+    /// # Title
+    /// ut_array_extract_if
+    ///
+    /// # Brief
+    /// 1. Creates an `Array`.
+    /// 2. Calls `Array::extract_if`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_array_extract_if() {
+        let mut array = array!(1, JsonValue::Null, 2, JsonValue::Null);
+        let removed: Vec<_> = array.extract_if(|v| v.is_null()).collect();
+        assert_eq!(removed, [JsonValue::Null, JsonValue::Null]);
+        assert_eq!(array, array!(1, 2));
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.last(), Some(&JsonValue::from(2)));
+    }
+
+    /// UT test for `Array::sort_by`.
+    ///
+    /// # Title
+    /// ut_array_sort_by
+    ///
+    /// # Brief
+    /// 1. Creates an `Array`.
+    /// 2. Calls `Array::sort_by`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_array_sort_by() {
+        let mut array = array!(3, 1, 2);
+        let node = array.get_node(1).unwrap() as *const _;
+        array.sort_by(|a, b| {
+            let (a, b) = (a.try_as_number().unwrap(), b.try_as_number().unwrap());
+            a.try_as_i64().unwrap().cmp(&b.try_as_i64().unwrap())
+        });
+        assert_eq!(array, array!(1, 2, 3));
+        assert_eq!(array.get_node(0).unwrap() as *const _, node);
+        assert_eq!(array.last(), Some(&JsonValue::from(3)));
+    }
+
+    /// UT test for `Array::drain`.
+    ///
+    /// # Title
+    /// ut_array_drain
+    ///
+    /// # Brief
+    /// 1. Creates an `Array`.
+    /// 2. Calls `Array::drain` with some ranges, and drops some iterators early.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_array_drain() {
+        let mut array = array!(0, 1, 2, 3, 4, 5, 6, 7);
+        let mut drain = array.drain(1..=3);
+        assert_eq!(drain.len(), 3);
+        assert_eq!(drain.next(), Some(JsonValue::from(1)));
+        drop(drain);
+        assert_eq!(array.to_string(), "[0,4,5,6,7]");
+
+        assert_eq!(array.drain(3..).count(), 2);
+        let (start, end) = (2, 1);
+        assert_eq!(array.drain(start..end).count(), 0);
+        assert_eq!(array.drain(5..9).count(), 0);
+        assert_eq!(array.to_string(), "[0,4,5]");
+
+        assert_eq!(array.drain(..).count(), 3);
+        assert!(array.is_empty());
+    }
+
+    /// UT test for `Array::truncate` and `Array::resize_with`.
+    ///
+    /// # Title
+    /// ut_array_truncate_resize_with
+    ///
+    /// # Brief
+    /// 1. Creates an `Array`.
+    /// 2. Calls `Array::truncate` and `Array::resize_with` with some lengths.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_array_truncate_resize_with() {
+        let mut array = array!(0, 1, 2, 3);
+        array.truncate(2);
+        assert_eq!(array.to_string(), "[0,1]");
+        array.truncate(3);
+        assert_eq!(array.to_string(), "[0,1]");
+
+        let mut next = 1;
+        array.resize_with(4, || {
+            next *= 10;
+            JsonValue::from(next)
+        });
+        assert_eq!(array.to_string(), "[0,1,10,100]");
+        array.resize_with(1, JsonValue::new_null);
+        assert_eq!(array.to_string(), "[0]");
+        array.resize_with(0, JsonValue::new_null);
+        assert!(array.is_empty());
+    }
 }
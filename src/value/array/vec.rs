@@ -12,7 +12,9 @@
 // limitations under the License.
 
 use crate::JsonValue;
+use alloc::vec::{Drain, Vec};
 use core::fmt::{Debug, Display, Formatter};
+use core::ops::RangeBounds;
 use core::slice::{Iter, IterMut};
 
 /// Array type, implemented using Vec.
@@ -22,7 +24,9 @@ use core::slice::{Iter, IterMut};
 ///
 /// # Attention
 /// * 只有开启 `vec_array` feature 时才可以使用，且与其他的 array 相关 feature 冲突。（默认开启）
-/// Only open `vec_array` feature can be used, and conflicts with other array-related features. (Enabled by default)
+///   Only open `vec_array` feature can be used, and conflicts with other array-related features. (Enabled by default)
+///
+/// * The elements can be accessed as a slice by `Array::as_slice` and `Array::as_mut_slice`.
 ///
 /// # Examples
 /// ```
@@ -139,6 +143,39 @@ impl Array {
         self.inner.iter_mut()
     }
 
+    /// Gets a slice of all the elements in Array, on which the slice methods such as
+    /// `chunks` and `windows` can be used directly.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let value = JsonValue::from_text("[[0, 0], [1, 2], [3, 4]]").unwrap();
+    /// let array = value.try_as_array().unwrap();
+    /// assert_eq!(array.as_slice().windows(2).count(), 2);
+    /// ```
+    pub fn as_slice(&self) -> &[JsonValue] {
+        self.inner.as_slice()
+    }
+
+    /// Gets a mutable slice of all the elements in Array, on which the slice methods such
+    /// as `sort_by` and `swap` can be used directly.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::new_string("b"));
+    /// array.push(JsonValue::new_string("a"));
+    ///
+    /// array.as_mut_slice().sort_by(|a, b| a.try_as_string().unwrap().cmp(b.try_as_string().unwrap()));
+    /// assert_eq!(array.to_string(), r#"["a","b"]"#);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [JsonValue] {
+        self.inner.as_mut_slice()
+    }
+
     /// Returns a common reference to the specified index ** member ** in Array.
     ///
     /// # Examples
@@ -169,6 +206,36 @@ impl Array {
         self.inner.get_mut(index)
     }
 
+    /// Returns a common reference to the leading ** member ** in Array.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// assert_eq!(array.first(), None);
+    /// array.push(JsonValue::Null);
+    /// assert_eq!(array.first(), Some(&JsonValue::Null));
+    /// ```
+    pub fn first(&self) -> Option<&JsonValue> {
+        self.inner.first()
+    }
+
+    /// Returns a mutable reference to the leading ** member ** in Array.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// assert_eq!(array.first_mut(), None);
+    /// array.push(JsonValue::Null);
+    /// assert_eq!(array.first_mut(), Some(&mut JsonValue::Null));
+    /// ```
+    pub fn first_mut(&mut self) -> Option<&mut JsonValue> {
+        self.inner.first_mut()
+    }
+
     /// Returns a common reference to the trailing ** member ** in Array.。
     ///
     /// # Examples
@@ -221,6 +288,118 @@ impl Array {
         }
         Some(self.inner.remove(index))
     }
+
+    /// Inserts a JsonValue at the specified index of Array, shifting all the
+    /// elements after it to the right. If the index is greater than the length
+    /// of Array, the JsonValue is inserted at the end.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::Null);
+    /// array.push(JsonValue::Null);
+    ///
+    /// array.insert(1, JsonValue::Boolean(true));
+    /// assert_eq!(array.get(1), Some(&JsonValue::Boolean(true)));
+    ///
+    /// array.insert(10, JsonValue::Boolean(false));
+    /// assert_eq!(array.last(), Some(&JsonValue::Boolean(false)));
+    /// ```
+    pub fn insert(&mut self, index: usize, value: JsonValue) {
+        let index = index.min(self.inner.len());
+        self.inner.insert(index, value)
+    }
+
+    /// Removes the elements in the specified range from Array, and returns an iterator
+    /// over them. The range is clamped to the length of Array, and the elements which are
+    /// not yielded are removed as well when the iterator is dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// for i in 0..5 {
+    ///     array.push(JsonValue::from(i));
+    /// }
+    ///
+    /// let batch: Vec<JsonValue> = array.drain(..2).collect();
+    /// assert_eq!(batch, vec![JsonValue::from(0), JsonValue::from(1)]);
+    /// assert_eq!(array.to_string(), "[2,3,4]");
+    ///
+    /// array.drain(1..10);
+    /// assert_eq!(array.to_string(), "[2]");
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, JsonValue> {
+        let range = super::clamp_range(range, self.inner.len());
+        self.inner.drain(range)
+    }
+
+    /// Shortens Array to `len` elements, dropping the rest. It has no effect if Array
+    /// is not longer than `len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::Null);
+    /// array.push(JsonValue::Boolean(true));
+    ///
+    /// array.truncate(1);
+    /// assert_eq!(array.to_string(), "[null]");
+    /// array.truncate(5);
+    /// assert_eq!(array.len(), 1);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len)
+    }
+
+    /// Resizes Array to `len` elements. If Array is longer, it is truncated, otherwise the
+    /// elements returned by calling `f` are appended.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::Boolean(true));
+    ///
+    /// array.resize_with(3, JsonValue::new_null);
+    /// assert_eq!(array.to_string(), "[true,null,null]");
+    /// array.resize_with(2, JsonValue::new_null);
+    /// assert_eq!(array.to_string(), "[true,null]");
+    /// ```
+    pub fn resize_with<F>(&mut self, len: usize, f: F)
+    where
+        F: FnMut() -> JsonValue,
+    {
+        self.inner.resize_with(len, f)
+    }
+
+    /// Converts the Array into a Vec of its elements in order.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let mut array = Array::new();
+    /// array.push(JsonValue::Null);
+    /// array.push(JsonValue::Boolean(true));
+    /// assert_eq!(array.into_vec(), [JsonValue::Null, JsonValue::Boolean(true)]);
+    /// ```
+    pub fn into_vec(self) -> Vec<JsonValue> {
+        self.inner
+    }
+}
+
+impl From<Vec<JsonValue>> for Array {
+    /// Converts a Vec of elements into an Array without copying them.
+    fn from(vec: Vec<JsonValue>) -> Self {
+        Self { inner: vec }
+    }
 }
 
 impl PartialEq for Array {
@@ -378,6 +557,31 @@ mod ut_vec {
         assert_ne!(array2, array3);
     }
 
+    /// UT test for `Array::insert`.
+    ///
+    /// # Title
+    /// ut_array_insert
+    ///
+    /// # Brief
+    /// 1. Creates some `Array`s.
+    /// 2. Calls `Array::insert` with different indexes.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_array_insert() {
+        let mut array = array!(1, 2);
+        array.insert(0, JsonValue::from(0));
+        array.insert(2, JsonValue::Null);
+        array.insert(4, JsonValue::from(3));
+        array.insert(100, JsonValue::from(4));
+        assert_eq!(array, array!(0, 1, JsonValue::Null, 2, 3, 4));
+        assert_eq!(array.len(), 6);
+        assert_eq!(array.last(), Some(&JsonValue::from(4)));
+
+        let mut array = Array::new();
+        array.insert(1, JsonValue::Null);
+        assert_eq!(array, array!(JsonValue::Null));
+    }
+
     /// UT test for `Array::fmt`.
     ///
     /// # Title
@@ -393,4 +597,81 @@ mod ut_vec {
         assert_eq!(format!("{array}"), "[1,2]");
         assert_eq!(format!("{array:?}"), "[1,2]");
     }
+
+    /// UT test for `Array::drain`.
+    ///
+    /// # Title
+    /// ut_array_drain
+    ///
+    /// # Brief
+    /// 1. Creates an `Array`.
+    /// 2. Calls `Array::drain` with some ranges, and drops some iterators early.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_array_drain() {
+        let mut array = array!(0, 1, 2, 3, 4, 5, 6, 7);
+        let mut drain = array.drain(1..=3);
+        assert_eq!(drain.len(), 3);
+        assert_eq!(drain.next(), Some(JsonValue::from(1)));
+        drop(drain);
+        assert_eq!(array.to_string(), "[0,4,5,6,7]");
+
+        assert_eq!(array.drain(3..).count(), 2);
+        let (start, end) = (2, 1);
+        assert_eq!(array.drain(start..end).count(), 0);
+        assert_eq!(array.drain(5..9).count(), 0);
+        assert_eq!(array.to_string(), "[0,4,5]");
+
+        assert_eq!(array.drain(..).count(), 3);
+        assert!(array.is_empty());
+    }
+
+    /// UT test for `Array::truncate` and `Array::resize_with`.
+    ///
+    /// # Title
+    /// ut_array_truncate_resize_with
+    ///
+    /// # Brief
+    /// 1. Creates an `Array`.
+    /// 2. Calls `Array::truncate` and `Array::resize_with` with some lengths.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_array_truncate_resize_with() {
+        let mut array = array!(0, 1, 2, 3);
+        array.truncate(2);
+        assert_eq!(array.to_string(), "[0,1]");
+        array.truncate(3);
+        assert_eq!(array.to_string(), "[0,1]");
+
+        let mut next = 1;
+        array.resize_with(4, || {
+            next *= 10;
+            JsonValue::from(next)
+        });
+        assert_eq!(array.to_string(), "[0,1,10,100]");
+        array.resize_with(1, JsonValue::new_null);
+        assert_eq!(array.to_string(), "[0]");
+        array.resize_with(0, JsonValue::new_null);
+        assert!(array.is_empty());
+    }
+
+    /// UT test for `Array::as_slice` and `Array::as_mut_slice`.
+    ///
+    /// # Title
+    /// ut_array_as_slice
+    ///
+    /// # Brief
+    /// 1. Creates an `Array`.
+    /// 2. Calls `Array::as_slice` and `Array::as_mut_slice`, and changes the slice.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_array_as_slice() {
+        let mut array = array!(3, 1, 2);
+        assert_eq!(array.as_slice().len(), 3);
+        assert_eq!(array.as_slice()[1], JsonValue::from(1));
+
+        array.as_mut_slice().swap(0, 2);
+        assert_eq!(array.to_string(), "[2,1,3]");
+        assert!(Array::new().as_slice().is_empty());
+    }
 }
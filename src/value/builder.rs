@@ -0,0 +1,255 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Array, JsonValue, Object};
+use alloc::string::String;
+
+/// Builder of an Object, created by `Object::builder`.
+///
+/// The methods take and return the builder, so the members can be added in a chain.
+/// A builder can be used as the value of another builder, which builds nested values.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{Array, JsonValue, Object};
+///
+/// let nickname: Option<&str> = None;
+/// let value: JsonValue = Object::builder()
+///     .field("name", "Tom")
+///     .field_opt("nickname", nickname)
+///     .field("tags", Array::builder().push("a").push("b"))
+///     .into();
+/// assert_eq!(value.to_compact_string().unwrap(), r#"{"name":"Tom","tags":["a","b"]}"#);
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct ObjectBuilder {
+    object: Object,
+}
+
+impl ObjectBuilder {
+    /// Creates an ObjectBuilder without members.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::ObjectBuilder;
+    ///
+    /// let object = ObjectBuilder::new().build();
+    /// assert!(object.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a member, the same as `Object::insert`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let object = Object::builder().field("a", 1).field("b", true).build();
+    /// assert_eq!(object.get("a"), Some(&JsonValue::from(1)));
+    /// assert_eq!(object.get("b"), Some(&JsonValue::from(true)));
+    /// ```
+    pub fn field<K: Into<String>, V: Into<JsonValue>>(mut self, key: K, value: V) -> Self {
+        self.object.insert(key.into(), value.into());
+        self
+    }
+
+    /// Adds a member if `value` is `Some`, or does nothing if it is `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Object;
+    ///
+    /// let object = Object::builder()
+    ///     .field_opt("a", Some(1))
+    ///     .field_opt("b", None::<i32>)
+    ///     .build();
+    /// assert!(object.contains_key("a"));
+    /// assert!(!object.contains_key("b"));
+    /// ```
+    pub fn field_opt<K: Into<String>, V: Into<JsonValue>>(self, key: K, value: Option<V>) -> Self {
+        match value {
+            Some(value) => self.field(key, value),
+            None => self,
+        }
+    }
+
+    /// Finishes the builder and returns the Object.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Object;
+    ///
+    /// let object = Object::builder().field("a", 1).build();
+    /// assert_eq!(object.len(), 1);
+    /// ```
+    pub fn build(self) -> Object {
+        self.object
+    }
+}
+
+impl From<ObjectBuilder> for JsonValue {
+    fn from(builder: ObjectBuilder) -> Self {
+        JsonValue::Object(builder.object)
+    }
+}
+
+/// Builder of an Array, created by `Array::builder`.
+///
+/// The methods take and return the builder, so the elements can be added in a chain.
+/// A builder can be used as the value of another builder, which builds nested values.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{Array, JsonValue, Object};
+///
+/// let value: JsonValue = Array::builder()
+///     .push(1)
+///     .push_opt(None::<i32>)
+///     .push(Object::builder().field("a", "b"))
+///     .into();
+/// assert_eq!(value.to_compact_string().unwrap(), r#"[1,{"a":"b"}]"#);
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct ArrayBuilder {
+    array: Array,
+}
+
+impl ArrayBuilder {
+    /// Creates an ArrayBuilder without elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::ArrayBuilder;
+    ///
+    /// let array = ArrayBuilder::new().build();
+    /// assert!(array.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an element at the end.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Array, JsonValue};
+    ///
+    /// let array = Array::builder().push(1).push("a").build();
+    /// assert_eq!(array.get(1), Some(&JsonValue::from("a")));
+    /// ```
+    pub fn push<V: Into<JsonValue>>(mut self, value: V) -> Self {
+        self.array.push(value.into());
+        self
+    }
+
+    /// Adds an element at the end if `value` is `Some`, or does nothing if it is `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Array;
+    ///
+    /// let array = Array::builder().push_opt(Some(1)).push_opt(None::<i32>).build();
+    /// assert_eq!(array.len(), 1);
+    /// ```
+    pub fn push_opt<V: Into<JsonValue>>(self, value: Option<V>) -> Self {
+        match value {
+            Some(value) => self.push(value),
+            None => self,
+        }
+    }
+
+    /// Finishes the builder and returns the Array.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Array;
+    ///
+    /// let array = Array::builder().push(1).build();
+    /// assert_eq!(array.len(), 1);
+    /// ```
+    pub fn build(self) -> Array {
+        self.array
+    }
+}
+
+impl From<ArrayBuilder> for JsonValue {
+    fn from(builder: ArrayBuilder) -> Self {
+        JsonValue::Array(builder.array)
+    }
+}
+
+impl Object {
+    /// Creates an ObjectBuilder, which adds the members in a chain.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Object;
+    ///
+    /// let object = Object::builder().field("a", 1).field_opt("b", None::<i32>).build();
+    /// assert_eq!(object.len(), 1);
+    /// ```
+    pub fn builder() -> ObjectBuilder {
+        ObjectBuilder::new()
+    }
+}
+
+impl Array {
+    /// Creates an ArrayBuilder, which adds the elements in a chain.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Array;
+    ///
+    /// let array = Array::builder().push(1).push(2).build();
+    /// assert_eq!(array.len(), 2);
+    /// ```
+    pub fn builder() -> ArrayBuilder {
+        ArrayBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod ut_builder {
+    use crate::{Array, JsonValue, Object};
+
+    /// UT test for `ObjectBuilder` and `ArrayBuilder`.
+    ///
+    /// # Title
+    /// ut_builder
+    ///
+    /// # Brief
+    /// 1. Builds nested values with the builders, skipping some `None` values.
+    /// 2. Checks if the result is the same as the parsed text.
+    #[test]
+    fn ut_builder() {
+        let missing: Option<String> = None;
+        let value: JsonValue = Object::builder()
+            .field("a", 1)
+            .field_opt("b", missing.clone())
+            .field_opt("c", Some(String::from("x")))
+            .field(
+                "d",
+                Array::builder()
+                    .push(true)
+                    .push_opt(missing)
+                    .push(JsonValue::Null),
+            )
+            .field("e", Object::builder())
+            .into();
+        let expected = JsonValue::from_text(r#"{"a": 1, "c": "x", "d": [true, null], "e": {}}"#);
+        assert_eq!(value, expected.unwrap());
+        assert!(Array::builder().build().is_empty());
+    }
+}
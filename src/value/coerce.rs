@@ -0,0 +1,258 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Error, JsonValue, Number};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+impl JsonValue {
+    /// Converts the JsonValue to a Number leniently, like `Number(value)` of JavaScript.
+    /// Unlike `JsonValue::try_as_number`, other types are converted as well:
+    ///
+    /// - `true` and `false` are converted to 1 and 0, and `null` to 0.
+    /// - A string is trimmed and parsed as a decimal number, such as `" 42 "` to 42 and
+    ///   `"1.5e3"` to 1500.0. An empty string is converted to 0.
+    ///
+    /// Arrays, objects and the strings which are not finite decimal numbers, such as
+    /// `"0x10"` or `"NaN"`, fail with `Error::TypeTransform`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Number};
+    ///
+    /// assert_eq!(JsonValue::from("42").coerce_to_number().unwrap(), Number::from(42));
+    /// assert_eq!(JsonValue::from("-2.5").coerce_to_number().unwrap(), Number::from(-2.5));
+    /// assert_eq!(JsonValue::from(true).coerce_to_number().unwrap(), Number::from(1));
+    /// assert!(JsonValue::from("abc").coerce_to_number().is_err());
+    /// ```
+    pub fn coerce_to_number(&self) -> Result<Number, Error> {
+        match self {
+            JsonValue::Number(number) => Ok(number.clone()),
+            JsonValue::Boolean(boolean) => Ok(Number::Unsigned(*boolean as u64)),
+            JsonValue::Null => Ok(Number::Unsigned(0)),
+            JsonValue::String(string) => parse_number(string.trim()),
+            _ => Err(Error::TypeTransform),
+        }
+    }
+
+    /// Converts the JsonValue to a String leniently, like `String(value)` of JavaScript.
+    /// Unlike `JsonValue::try_as_string`, other types are converted as well:
+    ///
+    /// - Numbers are formatted in decimal, and floats which are integers have no fraction,
+    ///   such as 1.0 to `"1"`.
+    /// - `true`, `false` and `null` are converted to `"true"`, `"false"` and `"null"`.
+    ///
+    /// Arrays and objects fail with `Error::TypeTransform`, encode them by
+    /// `JsonValue::to_compact_string` instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// assert_eq!(JsonValue::from(42).coerce_to_string().unwrap(), "42");
+    /// assert_eq!(JsonValue::from(2.0).coerce_to_string().unwrap(), "2");
+    /// assert_eq!(JsonValue::Null.coerce_to_string().unwrap(), "null");
+    /// assert!(JsonValue::from_text("[]").unwrap().coerce_to_string().is_err());
+    /// ```
+    pub fn coerce_to_string(&self) -> Result<String, Error> {
+        match self {
+            JsonValue::String(string) => Ok(String::from(string.as_str())),
+            JsonValue::Number(Number::Float(float)) => Ok(format!("{float}")),
+            JsonValue::Number(number) => Ok(number.to_string()),
+            JsonValue::Boolean(boolean) => Ok(boolean.to_string()),
+            JsonValue::Null => Ok(String::from("null")),
+            _ => Err(Error::TypeTransform),
+        }
+    }
+
+    /// Converts the JsonValue to a bool leniently, which is mostly like `Boolean(value)`
+    /// of JavaScript. Unlike `JsonValue::try_as_boolean`, other types are converted as well:
+    ///
+    /// - Numbers are `true` unless they are 0 or NaN, and `null` is `false`.
+    /// - A string is trimmed first. `"true"` and `"false"` are converted to their values
+    ///   ignoring the ASCII case, decimal numbers are converted the same as numbers, and
+    ///   an empty string is `false`.
+    ///
+    /// Arrays, objects and other strings fail with `Error::TypeTransform`, since the
+    /// truthiness of them in JavaScript rarely means what the producer intended.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// assert!(JsonValue::from(1).coerce_to_bool().unwrap());
+    /// assert!(!JsonValue::from("0").coerce_to_bool().unwrap());
+    /// assert!(JsonValue::from("TRUE").coerce_to_bool().unwrap());
+    /// assert!(!JsonValue::Null.coerce_to_bool().unwrap());
+    /// assert!(JsonValue::from("yes").coerce_to_bool().is_err());
+    /// ```
+    pub fn coerce_to_bool(&self) -> Result<bool, Error> {
+        match self {
+            JsonValue::Boolean(boolean) => Ok(*boolean),
+            JsonValue::Number(number) => Ok(is_truthy(number)),
+            JsonValue::Null => Ok(false),
+            JsonValue::String(string) => {
+                let string = string.trim();
+                if string.eq_ignore_ascii_case("true") {
+                    Ok(true)
+                } else if string.eq_ignore_ascii_case("false") {
+                    Ok(false)
+                } else {
+                    parse_number(string).map(|number| is_truthy(&number))
+                }
+            }
+            _ => Err(Error::TypeTransform),
+        }
+    }
+}
+
+/// Parses a trimmed string as a decimal number, keeping integers as integers.
+fn parse_number(string: &str) -> Result<Number, Error> {
+    if string.is_empty() {
+        return Ok(Number::Unsigned(0));
+    }
+    if let Ok(unsigned) = string.parse::<u64>() {
+        return Ok(Number::Unsigned(unsigned));
+    }
+    if let Ok(signed) = string.parse::<i64>() {
+        return Ok(Number::Signed(signed));
+    }
+    // `f64::from_str` also accepts "inf" and "NaN", which are not decimal numbers.
+    match string.parse::<f64>() {
+        Ok(float) if float.is_finite() => Ok(Number::Float(float)),
+        _ => Err(Error::TypeTransform),
+    }
+}
+
+fn is_truthy(number: &Number) -> bool {
+    match number {
+        Number::Unsigned(unsigned) => *unsigned != 0,
+        Number::Signed(signed) => *signed != 0,
+        Number::Float(float) => *float != 0.0 && !float.is_nan(),
+    }
+}
+
+#[cfg(test)]
+mod ut_coerce {
+    use crate::{JsonValue, Number};
+
+    /// UT test for `JsonValue::coerce_to_number`.
+    ///
+    /// # Title
+    /// ut_coerce_to_number
+    ///
+    /// # Brief
+    /// 1. Coerces values of every type to numbers.
+    /// 2. Checks if the results are correct.
+    #[test]
+    fn ut_coerce_to_number() {
+        let cases = [
+            (JsonValue::from(7), Number::Unsigned(7)),
+            (JsonValue::from(-1.5), Number::Float(-1.5)),
+            (JsonValue::from(true), Number::Unsigned(1)),
+            (JsonValue::from(false), Number::Unsigned(0)),
+            (JsonValue::Null, Number::Unsigned(0)),
+            (JsonValue::from(" 42\n"), Number::Unsigned(42)),
+            (JsonValue::from("+42"), Number::Unsigned(42)),
+            (JsonValue::from("-42"), Number::Signed(-42)),
+            (
+                JsonValue::from("18446744073709551615"),
+                Number::Unsigned(u64::MAX),
+            ),
+            (JsonValue::from("1e3"), Number::Float(1000.0)),
+            (JsonValue::from(".5"), Number::Float(0.5)),
+            (JsonValue::from(""), Number::Unsigned(0)),
+        ];
+        for (value, expected) in cases {
+            let number = value.coerce_to_number().unwrap();
+            assert_eq!(number, expected, "{value}");
+            assert_eq!(number.is_float(), expected.is_float(), "{value}");
+        }
+
+        for text in [
+            r#""abc""#,
+            r#""0x10""#,
+            r#""NaN""#,
+            r#""inf""#,
+            r#""1 2""#,
+            "[1]",
+            "{}",
+        ] {
+            let value = JsonValue::from_text(text).unwrap();
+            assert!(value.coerce_to_number().is_err(), "{text}");
+        }
+    }
+
+    /// UT test for `JsonValue::coerce_to_string`.
+    ///
+    /// # Title
+    /// ut_coerce_to_string
+    ///
+    /// # Brief
+    /// 1. Coerces values of every type to strings.
+    /// 2. Checks if the results are correct.
+    #[test]
+    fn ut_coerce_to_string() {
+        let cases = [
+            (JsonValue::from("a"), "a"),
+            (JsonValue::from(42), "42"),
+            (JsonValue::from(-42), "-42"),
+            (JsonValue::from(1.0), "1"),
+            (JsonValue::from(0.25), "0.25"),
+            (JsonValue::from(true), "true"),
+            (JsonValue::from(false), "false"),
+            (JsonValue::Null, "null"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(value.coerce_to_string().unwrap(), expected);
+        }
+        assert!(JsonValue::from_text("{}")
+            .unwrap()
+            .coerce_to_string()
+            .is_err());
+    }
+
+    /// UT test for `JsonValue::coerce_to_bool`.
+    ///
+    /// # Title
+    /// ut_coerce_to_bool
+    ///
+    /// # Brief
+    /// 1. Coerces values of every type to bools.
+    /// 2. Checks if the results are correct.
+    #[test]
+    fn ut_coerce_to_bool() {
+        let cases = [
+            (JsonValue::from(true), true),
+            (JsonValue::from(1), true),
+            (JsonValue::from(-1), true),
+            (JsonValue::from(0), false),
+            (JsonValue::from(0.0), false),
+            (JsonValue::from(f64::NAN), false),
+            (JsonValue::Null, false),
+            (JsonValue::from(" True "), true),
+            (JsonValue::from("false"), false),
+            (JsonValue::from("1"), true),
+            (JsonValue::from("0.0"), false),
+            (JsonValue::from(""), false),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(value.coerce_to_bool().unwrap(), expected, "{value:?}");
+        }
+
+        for text in [r#""yes""#, r#""off""#, "[]", "{}"] {
+            let value = JsonValue::from_text(text).unwrap();
+            assert!(value.coerce_to_bool().is_err(), "{text}");
+        }
+    }
+}
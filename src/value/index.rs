@@ -11,12 +11,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Array, JsonValue, Object};
+use crate::{Array, Error, JsonValue, Object};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
 
 /// Static NULL, which is returned if the searched key-value pair does not exist.
 static NULL: JsonValue = JsonValue::Null;
 
 /// This trait can be used to get an index based on the subscript of an internal member of JsonValue.
+///
+/// It is implemented for `usize` subscripts of Arrays, and `str`, `String` and `Cow<str>` keys
+/// of Objects, as well as references to them. Other integer types are not supported, since
+/// integer literals such as `value[0]` could not be inferred as `usize` any more.
 pub trait Index: private::IndexSealed {
     /// Gets a common reference to the value with the specified subscript (or key) from a JsonValue.
     fn index_into<'a>(&self, value: &'a JsonValue) -> &'a JsonValue;
@@ -26,6 +33,10 @@ pub trait Index: private::IndexSealed {
 
     /// Removes the member with the specified subscript (or key) from a JsonValue.
     fn index_remove(&self, value: &mut JsonValue) -> Option<JsonValue>;
+
+    /// Gets a common reference to the value with the specified subscript (or key) from a
+    /// JsonValue, or an error if it does not exist or the JsonValue has a wrong type.
+    fn index_into_checked<'a>(&self, value: &'a JsonValue) -> Result<&'a JsonValue, Error>;
 }
 
 impl Index for usize {
@@ -141,6 +152,30 @@ impl Index for usize {
         }
         None
     }
+
+    /// Uses the array subscript to visit the Array type of JsonValue, and returns an error
+    /// if the JsonValue is not Array type or the subscript exceeds the length of the array.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Index, JsonValue};
+    ///
+    /// let value = JsonValue::from_text("[true]").unwrap();
+    /// assert_eq!(0.index_into_checked(&value).unwrap(), &JsonValue::Boolean(true));
+    /// assert!(1.index_into_checked(&value).is_err());
+    /// assert!(0.index_into_checked(&JsonValue::Null).is_err());
+    /// ```
+    fn index_into_checked<'a>(&self, value: &'a JsonValue) -> Result<&'a JsonValue, Error> {
+        match value {
+            JsonValue::Array(array) => array
+                .get(*self)
+                .ok_or_else(|| Error::IndexNotFound(format!("[{self}]"))),
+            _ => Err(Error::IndexTypeMismatch(
+                format!("[{self}]"),
+                type_name(value),
+            )),
+        }
+    }
 }
 
 impl Index for str {
@@ -215,25 +250,7 @@ impl Index for str {
     /// ```
     fn index_into_mut<'a>(&self, value: &'a mut JsonValue) -> &'a mut JsonValue {
         if let JsonValue::Object(ref mut object) = value {
-            #[cfg(feature = "list_object")]
-            {
-                return object.get_key_mut_maybe_insert(self);
-            }
-            #[cfg(feature = "vec_object")]
-            {
-                if let Some(pos) = object.iter().position(|(k, _)| k == self) {
-                    return object.get_mut_by_position(pos).unwrap();
-                }
-                object.insert(String::from(self), JsonValue::Null);
-                return object.last_mut().unwrap();
-            }
-            #[cfg(feature = "btree_object")]
-            {
-                if !object.contains_key(self) {
-                    object.insert(String::from(self), JsonValue::Null);
-                }
-                return object.get_mut(self).unwrap();
-            }
+            return object.get_or_insert_with(self, JsonValue::new_null);
         }
         *value = JsonValue::Object(Object::new());
         self.index_into_mut(value)
@@ -261,6 +278,30 @@ impl Index for str {
         }
         None
     }
+
+    /// Uses key to visit Object type JsonValue, and returns an error if the JsonValue is not
+    /// Object type or does not contain the key.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Index, JsonValue};
+    ///
+    /// let value = JsonValue::from_text(r#"{"key": true}"#).unwrap();
+    /// assert_eq!("key".index_into_checked(&value).unwrap(), &JsonValue::Boolean(true));
+    /// assert!("not exist".index_into_checked(&value).is_err());
+    /// assert!("key".index_into_checked(&JsonValue::Null).is_err());
+    /// ```
+    fn index_into_checked<'a>(&self, value: &'a JsonValue) -> Result<&'a JsonValue, Error> {
+        match value {
+            JsonValue::Object(object) => object
+                .get(self)
+                .ok_or_else(|| Error::IndexNotFound(format!("[{self:?}]"))),
+            _ => Err(Error::IndexTypeMismatch(
+                format!("[{self:?}]"),
+                type_name(value),
+            )),
+        }
+    }
 }
 
 impl Index for String {
@@ -278,9 +319,34 @@ impl Index for String {
     fn index_remove(&self, value: &mut JsonValue) -> Option<JsonValue> {
         self.as_str().index_remove(value)
     }
+    /// Same as 'Index for str'.
+    fn index_into_checked<'a>(&self, value: &'a JsonValue) -> Result<&'a JsonValue, Error> {
+        self.as_str().index_into_checked(value)
+    }
 }
 
-impl<'a, T> Index for &'a T
+impl Index for Cow<'_, str> {
+    /// Same as 'Index for str'.
+    fn index_into<'a>(&self, value: &'a JsonValue) -> &'a JsonValue {
+        self.as_ref().index_into(value)
+    }
+
+    /// Same as 'Index for str'.
+    fn index_into_mut<'a>(&self, value: &'a mut JsonValue) -> &'a mut JsonValue {
+        self.as_ref().index_into_mut(value)
+    }
+
+    /// Same as 'Index for str'.
+    fn index_remove(&self, value: &mut JsonValue) -> Option<JsonValue> {
+        self.as_ref().index_remove(value)
+    }
+    /// Same as 'Index for str'.
+    fn index_into_checked<'a>(&self, value: &'a JsonValue) -> Result<&'a JsonValue, Error> {
+        self.as_ref().index_into_checked(value)
+    }
+}
+
+impl<T> Index for &T
 where
     T: ?Sized + Index,
 {
@@ -298,10 +364,29 @@ where
     fn index_remove(&self, value: &mut JsonValue) -> Option<JsonValue> {
         (**self).index_remove(value)
     }
+    /// Implements Index for the relevant reference type.
+    fn index_into_checked<'v>(&self, value: &'v JsonValue) -> Result<&'v JsonValue, Error> {
+        (**self).index_into_checked(value)
+    }
+}
+
+/// Gets the type name of a JsonValue for index errors.
+fn type_name(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Null => "null",
+        JsonValue::Boolean(_) => "boolean",
+        JsonValue::Number(_) => "number",
+        JsonValue::String(_) => "string",
+        JsonValue::Array(_) => "array",
+        JsonValue::Object(_) => "object",
+    }
 }
 
 // To prevent the Index by external implementation.
 mod private {
+    use alloc::borrow::Cow;
+    use alloc::string::String;
+
     pub trait IndexSealed {}
 
     impl IndexSealed for usize {}
@@ -310,12 +395,15 @@ mod private {
 
     impl IndexSealed for String {}
 
-    impl<'a, T> IndexSealed for &'a T where T: ?Sized + IndexSealed {}
+    impl IndexSealed for Cow<'_, str> {}
+
+    impl<T> IndexSealed for &T where T: ?Sized + IndexSealed {}
 }
 
 #[cfg(test)]
 mod ut_index {
     use crate::{Array, Index, JsonValue, Object};
+    use std::borrow::Cow;
 
     /// UT test for `usize::index_into`.
     ///
@@ -480,4 +568,26 @@ mod ut_index {
         );
         assert!(String::from("key1").index_remove(&mut value).is_none());
     }
+
+    /// UT test for `Index` of `Cow<str>`, `String` and `&String`.
+    ///
+    /// # Title
+    /// ut_key_types_index
+    ///
+    /// # Brief
+    /// 1. Creates an Object `JsonValue`.
+    /// 2. Indexes it by `Cow<str>`, `String` and `&String`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_key_types_index() {
+        let key = String::from("key1");
+        let mut value = JsonValue::new_object(object!("key1" => "value1"));
+        assert!(value[&key].is_string());
+        assert!(value[key.clone()].is_string());
+        assert!(value[Cow::Borrowed("key1")].is_string());
+        value[Cow::Owned(String::from("key2"))] = JsonValue::Null;
+        assert_eq!(value.try_as_object().unwrap().len(), 2);
+        assert!(value.remove(Cow::Borrowed("key2")).is_some());
+        assert!(value[Cow::Borrowed("key2")].is_null());
+    }
 }
@@ -0,0 +1,254 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::borrow::{Cow, ToOwned};
+use alloc::string::String;
+use core::borrow::Borrow;
+use core::fmt::{Debug, Display, Formatter};
+use core::ops::Deref;
+
+/// Key of the members of an Object.
+///
+/// A key created from a `&'static str` borrows it, so building objects from string
+/// literals, such as `object!("name" => "Tom")`, allocates nothing for the keys. Keys
+/// created from a `String`, such as the keys of parsed text, own it.
+///
+/// A Key can be used as a `&str` and compared with strings.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{JsonValue, Key, Object};
+///
+/// let mut object = Object::new();
+/// object.insert("a", JsonValue::Null);
+/// object.insert(String::from("b"), JsonValue::Null);
+///
+/// let keys: Vec<&Key> = object.iter().map(|(k, _)| k).collect();
+/// assert_eq!(keys, ["a", "b"]);
+/// assert!(keys[0].is_borrowed());
+/// assert!(!keys[1].is_borrowed());
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key(Cow<'static, str>);
+
+impl Key {
+    /// Creates a Key which borrows a `&'static str`, which can be used in constants.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Key;
+    ///
+    /// const NAME: Key = Key::from_static("name");
+    /// assert_eq!(NAME, "name");
+    /// ```
+    pub const fn from_static(key: &'static str) -> Self {
+        Self(Cow::Borrowed(key))
+    }
+
+    /// Gets the Key as a `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Key;
+    ///
+    /// assert_eq!(Key::from("a").as_str(), "a");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Determines whether the Key borrows a `&'static str` instead of owning a `String`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Key;
+    ///
+    /// assert!(Key::from("a").is_borrowed());
+    /// assert!(!Key::from(String::from("a")).is_borrowed());
+    /// ```
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.0, Cow::Borrowed(_))
+    }
+
+    /// Gets a mutable reference to the owned `String` of the Key, which copies the key
+    /// first if it is borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Key;
+    ///
+    /// let mut key = Key::from("a");
+    /// key.to_mut().push('b');
+    /// assert_eq!(key, "ab");
+    /// assert!(!key.is_borrowed());
+    /// ```
+    pub fn to_mut(&mut self) -> &mut String {
+        self.0.to_mut()
+    }
+
+    /// Converts the Key into a `String`, which copies the key only if it is borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Key;
+    ///
+    /// assert_eq!(Key::from("a").into_string(), "a");
+    /// ```
+    pub fn into_string(self) -> String {
+        self.0.into_owned()
+    }
+}
+
+impl Deref for Key {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Key {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Key {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&'static str> for Key {
+    fn from(key: &'static str) -> Self {
+        Self::from_static(key)
+    }
+}
+
+impl From<String> for Key {
+    fn from(key: String) -> Self {
+        Self(Cow::Owned(key))
+    }
+}
+
+impl From<&String> for Key {
+    fn from(key: &String) -> Self {
+        Self(Cow::Owned(key.to_owned()))
+    }
+}
+
+impl From<Cow<'static, str>> for Key {
+    fn from(key: Cow<'static, str>) -> Self {
+        Self(key)
+    }
+}
+
+impl From<Key> for String {
+    fn from(key: Key) -> Self {
+        key.into_string()
+    }
+}
+
+impl PartialEq<str> for Key {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Key {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for Key {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<Key> for str {
+    fn eq(&self, other: &Key) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Key> for &str {
+    fn eq(&self, other: &Key) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialEq<Key> for String {
+    fn eq(&self, other: &Key) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl Debug for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod ut_key {
+    use crate::{JsonValue, Key, Object};
+    use std::collections::{BTreeMap, HashMap};
+
+    /// UT test for `Key`.
+    ///
+    /// # Title
+    /// ut_key
+    ///
+    /// # Brief
+    /// 1. Creates `Key`s from static and owned strings.
+    /// 2. Checks if they are equal, ordered and hashed the same as the strings.
+    /// 3. Checks if they can be looked up by `&str` in maps.
+    /// 4. Checks if the keys of `object!` are borrowed and the parsed keys are owned.
+    #[test]
+    fn ut_key() {
+        let borrowed = Key::from("key");
+        let owned = Key::from(String::from("key"));
+        assert!(borrowed.is_borrowed());
+        assert!(!owned.is_borrowed());
+        assert_eq!(borrowed, owned);
+        assert_eq!(owned, "key");
+        assert_eq!("key", owned);
+        assert_eq!(owned, String::from("key"));
+        let (a, b) = (Key::from("a"), Key::from(String::from("b")));
+        assert!(a < b);
+        assert_eq!(format!("{owned} {owned:?}"), r#"key "key""#);
+        assert_eq!(owned.len(), 3);
+        assert_eq!(String::from(borrowed.clone()), "key");
+
+        let mut map = HashMap::new();
+        map.insert(owned.clone(), 1);
+        assert_eq!(map.get("key"), Some(&1));
+        let mut map = BTreeMap::new();
+        map.insert(borrowed, 1);
+        assert_eq!(map.get("key"), Some(&1));
+
+        let object = object!("a" => 1; "b" => 2);
+        assert!(object.iter().all(|(k, _)| k.is_borrowed()));
+        let value = JsonValue::from_text(r#"{"a": 1, "b": 2}"#).unwrap();
+        let object = value.try_as_object().unwrap();
+        assert!(object.iter().all(|(k, _)| !k.is_borrowed()));
+    }
+}
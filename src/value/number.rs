@@ -12,11 +12,16 @@
 // limitations under the License.
 
 use crate::Error;
-use core::fmt::{Display, Formatter};
-use std::fmt::Debug;
+use core::fmt::{Debug, Display, Formatter};
+use serde::de::{Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
 
 /// Numerical type
 ///
+/// Integers are kept as `u64` or `i64` and never transit through `f64`,
+/// so `Number` can be embedded in a struct to carry exact integers through
+/// the serde layer.
+///
 /// # Examples
 /// ```
 /// use ylong_json::Number;
@@ -24,6 +29,23 @@ use std::fmt::Debug;
 /// let number: Number = 0.0.into();
 /// assert_eq!(number.is_float(), true);
 /// ```
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use ylong_json::{from_str, to_string, Number};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Counter {
+///     value: Number,
+/// }
+///
+/// let counter: Counter = from_str(r#"{"value":18446744073709551615}"#).unwrap();
+/// assert_eq!(counter.value.try_as_u64().unwrap(), u64::MAX);
+/// assert_eq!(
+///     to_string(&counter).unwrap(),
+///     r#"{"value":18446744073709551615}"#
+/// );
+/// ```
 #[derive(Clone)]
 pub enum Number {
     /// Unsigned integer
@@ -151,13 +173,92 @@ impl Number {
             Self::Float(f) => Ok(*f),
         }
     }
+
+    /// Determines whether two numbers differ by at most `epsilon`. Integers are compared
+    /// exactly, only comparisons involving a floating point number use `epsilon`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Number;
+    ///
+    /// let number: Number = 0.3.into();
+    /// assert!(number.approx_eq(&(0.1 + 0.2).into(), 1e-9));
+    /// assert!(!number.approx_eq(&0.4.into(), 1e-9));
+    /// assert!(!Number::from(1).approx_eq(&2.into(), 1.0));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        match (self, other) {
+            (Self::Float(_), _) | (_, Self::Float(_)) => {
+                let (a, b) = (self.try_as_f64().unwrap(), other.try_as_f64().unwrap());
+                a == b || (if a > b { a - b } else { b - a }) <= epsilon
+            }
+            _ => self == other,
+        }
+    }
 }
 
 impl PartialEq for Number {
     fn eq(&self, other: &Self) -> bool {
-        let a = self.try_as_f64().unwrap();
-        let b = other.try_as_f64().unwrap();
-        a == b
+        // Integers are compared exactly, only comparisons involving a
+        // floating point number fall back to f64.
+        match (self, other) {
+            (Self::Unsigned(a), Self::Unsigned(b)) => a == b,
+            (Self::Signed(a), Self::Signed(b)) => a == b,
+            (Self::Unsigned(u), Self::Signed(i)) | (Self::Signed(i), Self::Unsigned(u)) => {
+                *i >= 0 && *i as u64 == *u
+            }
+            _ => self.try_as_f64().unwrap() == other.try_as_f64().unwrap(),
+        }
+    }
+}
+
+impl Serialize for Number {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Unsigned(u) => serializer.serialize_u64(*u),
+            Self::Signed(i) => serializer.serialize_i64(*i),
+            Self::Float(f) => serializer.serialize_f64(*f),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Number {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct NumberVisitor;
+
+        impl<'de> Visitor<'de> for NumberVisitor {
+            type Value = Number;
+
+            fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+                formatter.write_str("a JSON number")
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Number, E> {
+                // Non-negative integers are always kept as `Unsigned`, which
+                // is the same as what the parser does.
+                if v >= 0 {
+                    Ok(Number::Unsigned(v as u64))
+                } else {
+                    Ok(Number::Signed(v))
+                }
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Number, E> {
+                Ok(Number::Unsigned(v))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<Number, E> {
+                Ok(Number::Float(v))
+            }
+        }
+
+        deserializer.deserialize_any(NumberVisitor)
     }
 }
 
@@ -172,7 +273,7 @@ impl Display for Number {
 }
 
 impl Debug for Number {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
@@ -343,4 +444,54 @@ mod ut_number {
         assert!(Number::Signed(1).try_as_i64().is_ok());
         assert!(Number::Float(1.0).try_as_i64().is_err());
     }
+
+    /// UT test for `Number::eq`.
+    ///
+    /// # Title
+    /// ut_number_eq
+    ///
+    /// # Brief
+    /// 1. Creates some `Number`s.
+    /// 2. Calls `Number::eq`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_number_eq() {
+        assert_eq!(Number::Unsigned(1), Number::Signed(1));
+        assert_eq!(Number::Signed(1), Number::Float(1.0));
+        assert_ne!(Number::Unsigned(u64::MAX), Number::Unsigned(u64::MAX - 1));
+        assert_ne!(Number::Signed(i64::MIN), Number::Signed(i64::MIN + 1));
+        assert_ne!(Number::Unsigned(u64::MAX), Number::Signed(-1));
+    }
+
+    /// UT test for `Number::serialize` and `Number::deserialize`.
+    ///
+    /// # Title
+    /// ut_number_serde
+    ///
+    /// # Brief
+    /// 1. Creates some `Number`s.
+    /// 2. Serializes them and deserializes them back.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_number_serde() {
+        let text = crate::to_string(&Number::Unsigned(u64::MAX)).unwrap();
+        assert_eq!(text, "18446744073709551615");
+        let number: Number = crate::from_str(&text).unwrap();
+        assert_eq!(number.try_as_u64().unwrap(), u64::MAX);
+
+        let text = crate::to_string(&Number::Signed(i64::MIN)).unwrap();
+        assert_eq!(text, "-9223372036854775808");
+        let number: Number = crate::from_str(&text).unwrap();
+        assert!(number.is_signed());
+        assert_eq!(number.try_as_i64().unwrap(), i64::MIN);
+
+        let number: Number = crate::from_str("9007199254740993").unwrap();
+        assert_eq!(number.try_as_u64().unwrap(), 9007199254740993);
+
+        let number: Number = crate::from_str("1.5").unwrap();
+        assert!(number.is_float());
+        assert_eq!(crate::to_string(&number).unwrap(), "1.5");
+
+        assert!(crate::from_str::<Number>("\"1\"").is_err());
+    }
 }
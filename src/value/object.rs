@@ -25,3 +25,197 @@ pub use linked_list::Object;
 mod vec;
 #[cfg(feature = "vec_object")]
 pub use vec::Object;
+
+#[cfg(feature = "index_object")]
+mod index;
+#[cfg(feature = "index_object")]
+pub use index::Object;
+
+use crate::JsonValue;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Object type whose iteration and output order is the insertion order.
+///
+/// It is the same type as `Object`, but only available with the `list_object`,
+/// `vec_object` or `index_object` feature. The `btree_object` feature sorts keys,
+/// so code which names `OrderedObject` for an order-significant protocol does not
+/// compile with it instead of silently reordering members.
+///
+/// `PartialEq` of objects ignores the order, use `Object::eq_ordered` to compare
+/// the order as well.
+///
+/// # Examples
+/// ```
+/// # #[cfg(any(feature = "list_object", feature = "vec_object", feature = "index_object"))]
+/// # {
+/// use ylong_json::{JsonValue, OrderedObject};
+///
+/// let mut object = OrderedObject::new();
+/// object.insert(String::from("b"), JsonValue::Null);
+/// object.insert(String::from("a"), JsonValue::Null);
+/// assert_eq!(JsonValue::Object(object).to_compact_string().unwrap(), r#"{"b":null,"a":null}"#);
+/// # }
+/// ```
+#[cfg(any(
+    feature = "list_object",
+    feature = "vec_object",
+    feature = "index_object"
+))]
+pub type OrderedObject = Object;
+
+impl Object {
+    /// Determines whether two objects have the same key-value pairs in the same
+    /// order. Nested values are compared by `JsonValue::eq_ordered`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let a = JsonValue::from_text(r#"{"x": 1, "y": 2}"#).unwrap();
+    /// let b = JsonValue::from_text(r#"{"y": 2, "x": 1}"#).unwrap();
+    /// let (a, b) = (a.try_as_object().unwrap(), b.try_as_object().unwrap());
+    ///
+    /// assert_eq!(a, b);
+    /// // Only `btree_object` sorts the keys, so they are in the same order.
+    /// assert_eq!(a.eq_ordered(b), cfg!(feature = "btree_object"));
+    /// ```
+    pub fn eq_ordered(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|((k1, v1), (k2, v2))| k1 == k2 && v1.eq_ordered(v2))
+    }
+}
+
+/// Converts a BTreeMap into an Object, in which the members are inserted in key order.
+#[cfg(not(feature = "btree_object"))]
+impl From<BTreeMap<String, JsonValue>> for Object {
+    fn from(map: BTreeMap<String, JsonValue>) -> Self {
+        let mut object = Object::new();
+        for (key, value) in map {
+            object.insert(key, value);
+        }
+        object
+    }
+}
+
+#[cfg(not(feature = "btree_object"))]
+impl Object {
+    /// Converts the Object into a BTreeMap. If a key appears more than once, the first
+    /// value is kept, which is the one `Object::get` returns.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("b"), JsonValue::Null);
+    /// object.insert(String::from("a"), JsonValue::Boolean(true));
+    /// let map = object.into_btree_map();
+    /// assert_eq!(map.keys().collect::<Vec<_>>(), ["a", "b"]);
+    /// ```
+    pub fn into_btree_map(self) -> BTreeMap<String, JsonValue> {
+        let mut map = BTreeMap::new();
+        for (key, value) in self.into_vec_pairs() {
+            map.entry(key).or_insert(value);
+        }
+        map
+    }
+}
+
+impl From<Vec<(String, JsonValue)>> for Object {
+    /// Converts a Vec of key-value pairs into an Object, which is the same as inserting
+    /// the pairs in order.
+    fn from(pairs: Vec<(String, JsonValue)>) -> Self {
+        let mut object = Object::new();
+        for (key, value) in pairs {
+            object.insert(key, value);
+        }
+        object
+    }
+}
+
+impl From<Object> for BTreeMap<String, JsonValue> {
+    /// Converts an Object into a BTreeMap, see `Object::into_btree_map`.
+    fn from(object: Object) -> Self {
+        object.into_btree_map()
+    }
+}
+
+impl From<Object> for Vec<(String, JsonValue)> {
+    /// Converts an Object into a Vec of key-value pairs, see `Object::into_vec_pairs`.
+    fn from(object: Object) -> Self {
+        object.into_vec_pairs()
+    }
+}
+
+#[cfg(test)]
+mod ut_object {
+    use crate::{JsonValue, Object};
+    use std::collections::BTreeMap;
+
+    /// UT test for the order of `Object`.
+    ///
+    /// # Title
+    /// ut_object_order
+    ///
+    /// # Brief
+    /// 1. Creates an `Object` and inserts and removes some keys.
+    /// 2. Checks if the keys are in insertion order, or in key order with `btree_object`.
+    /// 3. Checks if `Object::eq_ordered` compares the order.
+    #[test]
+    fn ut_object_order() {
+        let mut object = Object::new();
+        for key in ["c", "a", "d", "b"] {
+            object.insert(String::from(key), JsonValue::Null);
+        }
+        object.remove("d");
+        let keys: Vec<&str> = object.iter().map(|(k, _)| k.as_str()).collect();
+        if cfg!(feature = "btree_object") {
+            assert_eq!(keys, ["a", "b", "c"]);
+        } else {
+            assert_eq!(keys, ["c", "a", "b"]);
+        }
+
+        let a = JsonValue::from_text(r#"{"k": [{"x": 1, "y": 2}]}"#).unwrap();
+        let b = JsonValue::from_text(r#"{"k": [{"y": 2, "x": 1}]}"#).unwrap();
+        let c = JsonValue::from_text(r#"{"k": [{"x": 1, "y": 3}]}"#).unwrap();
+        assert_eq!(a, b);
+        assert!(a.eq_ordered(&a));
+        assert_eq!(a.eq_ordered(&b), cfg!(feature = "btree_object"));
+        assert!(!a.eq_ordered(&c));
+        assert!(!a.try_as_object().unwrap().eq_ordered(&Object::new()));
+    }
+
+    /// UT test for the conversions between `Object` and std collections.
+    ///
+    /// # Title
+    /// ut_object_convert
+    ///
+    /// # Brief
+    /// 1. Converts an `Object` into a `BTreeMap` and a `Vec` of pairs.
+    /// 2. Converts them back into `Object`s.
+    /// 3. Checks if the members are kept.
+    #[test]
+    fn ut_object_convert() {
+        let value = JsonValue::from_text(r#"{"b": [1], "a": null, "c": {"d": true}}"#).unwrap();
+        let object = value.try_as_object().unwrap().clone();
+
+        let pairs = object.clone().into_vec_pairs();
+        let keys: Vec<&str> = pairs.iter().map(|(k, _)| k.as_str()).collect();
+        let expected: Vec<&str> = object.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, expected);
+        assert!(Object::from(pairs).eq_ordered(&object));
+
+        let map = BTreeMap::from(object.clone());
+        assert_eq!(map.keys().collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(map["c"], JsonValue::from_text(r#"{"d": true}"#).unwrap());
+        assert_eq!(Object::from(map), object);
+
+        assert!(Vec::from(Object::new()).is_empty());
+        assert!(Object::new().into_btree_map().is_empty());
+    }
+}
@@ -11,31 +11,44 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::JsonValue;
+use crate::{JsonValue, Key};
+use alloc::collections::btree_map::{BTreeMap, Iter, IterMut};
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt::{Debug, Display, Formatter};
-use std::collections::btree_map::{BTreeMap, Iter, IterMut};
 
 /// Object type, implemented using the standard library Btree.
 ///
 /// # Situation
 /// * When the average number of objects exceeds 1024 (estimated value) but does not exceed 5000 (estimated value),
-/// and the creation and query ratio is greater than 600.(Number of queries for 1 Object creation).
+///   and the creation and query ratio is greater than 600.(Number of queries for 1 Object creation).
 ///
 /// * When the average number of objects exceeds 5000 (estimated value).
 ///
 /// # Attention
-/// Only opening ` btree_object ` feature can be used, and associated with the object of other feature conflict. (Enabled by default)
+/// * Only opening ` btree_object ` feature can be used, and associated with the object of other feature conflict. (Enabled by default)
+///
+/// * The keys of this Object are always kept in ascending order (compared byte by byte),
+///   regardless of the insertion order. So `iter`, `iter_mut` and all the output of this
+///   Object, such as `to_compact_string` and `to_formatted_string`, are sorted by key.
+///   Two Objects with the same entries always produce the same output.
 ///
 /// # Examples
 /// ```
-/// use ylong_json::Object;
+/// use ylong_json::{JsonValue, Object};
 ///
 /// let object = Object::new();
 /// assert_eq!(object.is_empty(), true);
+///
+/// let mut object = Object::new();
+/// object.insert(String::from("b"), JsonValue::Null);
+/// object.insert(String::from("a"), JsonValue::Null);
+/// let value = JsonValue::Object(object);
+/// assert_eq!(value.to_compact_string().unwrap(), r#"{"a":null,"b":null}"#);
 /// ```
 #[derive(Default, Clone, PartialEq)]
 pub struct Object {
-    inner: BTreeMap<String, JsonValue>,
+    inner: BTreeMap<Key, JsonValue>,
 }
 
 impl Object {
@@ -111,8 +124,8 @@ impl Object {
     /// object.insert(String::from("null"), JsonValue::Null);
     /// assert_eq!(object.len(), 1);
     /// ```
-    pub fn insert(&mut self, key: String, value: JsonValue) {
-        self.inner.insert(key, value);
+    pub fn insert<K: Into<Key>>(&mut self, key: K, value: JsonValue) {
+        self.inner.insert(key.into(), value);
     }
 
     /// Removes the element under the specified Key from Object.
@@ -131,7 +144,7 @@ impl Object {
         self.inner.remove(key)
     }
 
-    /// Gets a common iterator of Object.
+    /// Gets a common iterator of Object. The entries are yielded in ascending order of keys.
     ///
     /// # Examples
     /// ```
@@ -140,11 +153,11 @@ impl Object {
     /// let object = Object::new();
     /// let iter = object.iter();
     /// ```
-    pub fn iter(&self) -> Iter<'_, String, JsonValue> {
+    pub fn iter(&self) -> Iter<'_, Key, JsonValue> {
         self.inner.iter()
     }
 
-    /// Gets a mutable iterator of Object.
+    /// Gets a mutable iterator of Object. The entries are yielded in ascending order of keys.
     ///
     /// # Examples
     /// ```
@@ -153,7 +166,7 @@ impl Object {
     /// let mut object = Object::new();
     /// let iter_mut = object.iter_mut();
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<'_, String, JsonValue> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, Key, JsonValue> {
         self.inner.iter_mut()
     }
 
@@ -188,6 +201,72 @@ impl Object {
     pub fn get_mut(&mut self, key: &str) -> Option<&mut JsonValue> {
         self.inner.get_mut(key)
     }
+
+    /// Gets a mutable reference to the element in Object with the specified key. If there
+    /// is no such element, inserts the key with the value returned by `f` first.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// *object.get_or_insert_with("count", || JsonValue::from(0)) = JsonValue::from(1);
+    /// object.get_or_insert_with("count", || JsonValue::from(0));
+    /// assert_eq!(object.get("count"), Some(&JsonValue::from(1)));
+    /// ```
+    pub fn get_or_insert_with<F>(&mut self, key: &str, f: F) -> &mut JsonValue
+    where
+        F: FnOnce() -> JsonValue,
+    {
+        if !self.inner.contains_key(key) {
+            self.inner.insert(Key::from(String::from(key)), f());
+        }
+        self.inner.get_mut(key).unwrap()
+    }
+
+    /// Converts the Object into a Vec of its key-value pairs in the iteration order.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    /// assert_eq!(object.into_vec_pairs(), [(String::from("a"), JsonValue::Null)]);
+    /// ```
+    pub fn into_vec_pairs(self) -> Vec<(String, JsonValue)> {
+        self.inner
+            .into_iter()
+            .map(|(k, v)| (k.into_string(), v))
+            .collect()
+    }
+
+    /// Converts the Object into a BTreeMap, which keeps the order of the members.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    /// let map = object.into_btree_map();
+    /// assert_eq!(map.get("a"), Some(&JsonValue::Null));
+    /// ```
+    pub fn into_btree_map(self) -> BTreeMap<String, JsonValue> {
+        self.inner
+            .into_iter()
+            .map(|(k, v)| (k.into_string(), v))
+            .collect()
+    }
+}
+
+impl From<BTreeMap<String, JsonValue>> for Object {
+    /// Converts a BTreeMap into an Object, which keeps the order of the members.
+    fn from(map: BTreeMap<String, JsonValue>) -> Self {
+        Self {
+            inner: map.into_iter().map(|(k, v)| (Key::from(k), v)).collect(),
+        }
+    }
 }
 
 impl Display for Object {
@@ -204,14 +283,14 @@ impl Display for Object {
 }
 
 impl Debug for Object {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
 #[cfg(test)]
 mod ut_btree {
-    use crate::{JsonValue, Object};
+    use crate::{JsonValue, Key, Object};
 
     /// UT test for `Object::iter_mut`.
     ///
@@ -228,11 +307,39 @@ mod ut_btree {
         let mut iter = object.iter_mut();
         assert_eq!(
             iter.next(),
-            Some((&String::from("key1"), &mut JsonValue::new_string("value1")))
+            Some((&Key::from("key1"), &mut JsonValue::new_string("value1")))
         );
         assert_eq!(iter.next(), None);
     }
 
+    /// UT test for `Object::iter`.
+    ///
+    /// # Title
+    /// ut_object_iter_sorted
+    ///
+    /// # Brief
+    /// 1. Creates some `Object`s with the same entries in different insertion orders.
+    /// 2. Calls `Object::iter` and encodes them.
+    /// 3. Checks if the test results are sorted by key.
+    #[test]
+    fn ut_object_iter_sorted() {
+        let object1 = object!("b" => 2; "a" => 1; "c" => 3);
+        let object2 = object!("c" => 3; "b" => 2; "a" => 1);
+        let keys: Vec<&str> = object1.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, ["a", "b", "c"]);
+
+        let text1 = JsonValue::Object(object1).to_compact_string().unwrap();
+        let text2 = JsonValue::Object(object2).to_compact_string().unwrap();
+        assert_eq!(text1, r#"{"a":1,"b":2,"c":3}"#);
+        assert_eq!(text1, text2);
+
+        let value = JsonValue::from_text(r#"{"z":{"y":1,"x":2},"a":[]}"#).unwrap();
+        assert_eq!(
+            value.to_compact_string().unwrap(),
+            r#"{"a":[],"z":{"x":2,"y":1}}"#
+        );
+    }
+
     /// UT test for `Object::fmt`.
     ///
     /// # Title
@@ -0,0 +1,411 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{JsonValue, Key};
+use core::fmt::{Debug, Display, Formatter};
+use core::slice::Iter;
+use std::collections::HashMap;
+
+/// Object type, implemented using Vec with a HashMap index.
+///
+/// # Situation
+/// 1. When the average number of entries under Object is large, and the
+///    output order needs to be the same as the insertion order.
+///
+/// 2. When `get` and `contains_key` are called frequently on the same Object.
+///
+/// # Attention
+/// * Only opening the 'index_object' feature, this Object type can be used , and it conflicts with other Objects.
+///
+/// * This Object **provides** the de-duplicate function. Inserting an existing key
+///   replaces the old value and keeps its original position.
+///
+/// * The output order of this Object is the same as the insertion order.
+///
+/// * `get`, `get_mut` and `contains_key` take O(1) time, while `remove` takes O(n) time,
+///   because the entries behind the removed one need to be moved forward.
+/// # Examples
+/// ```
+/// use ylong_json::Object;
+///
+/// let object = Object::new();
+/// assert_eq!(object.is_empty(), true);
+/// ```
+#[derive(Default, Clone)]
+pub struct Object {
+    inner: Vec<(Key, JsonValue)>,
+    index: HashMap<Key, usize>,
+}
+
+impl Object {
+    /// Creates an empty Object.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Object;
+    ///
+    /// let object = Object::new();
+    /// assert_eq!(object.is_empty(), true);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            inner: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Gets the length of Object.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// assert_eq!(object.len(), 0);
+    /// object.insert(String::from("null"), JsonValue::Null);
+    /// assert_eq!(object.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Determines whether the Object is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// assert_eq!(object.is_empty(), true);
+    /// object.insert(String::from("null"), JsonValue::Null);
+    /// assert_eq!(object.is_empty(), false);
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Checks whether the specified key exists in the Object.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object, Number};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("null"), JsonValue::Null);
+    ///
+    /// assert_eq!(object.contains_key("null"), true);
+    /// assert_eq!(object.contains_key("no_such_key"), false);
+    /// ```
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.index.contains_key(key)
+    }
+
+    /// Inserts the specified key and value into the Object. If the key already exists,
+    /// the old value is replaced and the entry keeps its original position. Otherwise,
+    /// the new entry is appended to the end.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// assert_eq!(object.len(), 0);
+    /// object.insert(String::from("null"), JsonValue::Null);
+    /// assert_eq!(object.len(), 1);
+    /// object.insert(String::from("null"), JsonValue::Boolean(true));
+    /// assert_eq!(object.len(), 1);
+    /// assert_eq!(object.get("null"), Some(&JsonValue::Boolean(true)));
+    /// ```
+    pub fn insert<K: Into<Key>>(&mut self, key: K, value: JsonValue) {
+        let key = key.into();
+        match self.index.get(key.as_str()) {
+            Some(pos) => self.inner[*pos].1 = value,
+            None => {
+                self.index.insert(key.clone(), self.inner.len());
+                self.inner.push((key, value));
+            }
+        }
+    }
+
+    /// Removes the element under the specified key from the Object.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object, Number};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("null"), JsonValue::Null);
+    /// assert_eq!(object.len(), 1);
+    /// assert_eq!(object.remove("null"), Some(JsonValue::Null));
+    /// assert_eq!(object.len(), 0);
+    /// ```
+    pub fn remove(&mut self, key: &str) -> Option<JsonValue> {
+        let pos = self.index.remove(key)?;
+        // Entries behind the removed one are moved forward, so their indexes need to be updated.
+        self.index
+            .values_mut()
+            .filter(|i| **i > pos)
+            .for_each(|i| *i -= 1);
+        Some(self.inner.remove(pos).1)
+    }
+
+    /// Gets a common iterator of Object. The entries are yielded in insertion order.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Object;
+    ///
+    /// let object = Object::new();
+    /// let iter = object.iter();
+    /// ```
+    pub fn iter(&self) -> Iter<'_, (Key, JsonValue)> {
+        self.inner.iter()
+    }
+
+    /// Gets a mutable iterator of Object. The entries are yielded in insertion order.
+    /// Keys can not be modified through this iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::Object;
+    ///
+    /// let mut object = Object::new();
+    /// let iter_mut = object.iter_mut();
+    /// ```
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = (&Key, &mut JsonValue)> + ExactSizeIterator {
+        self.inner.iter_mut().map(|(k, v)| (&*k, v))
+    }
+
+    /// Gets a common reference to the element in Object with the specified key.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object, Number};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("test"), JsonValue::Number(Number::from(123)));
+    ///
+    /// assert_eq!(object.get("test"), Some(&JsonValue::Number(Number::from(123))));
+    /// assert_eq!(object.get("no_such_key"), None);
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        self.index.get(key).map(|pos| &self.inner[*pos].1)
+    }
+
+    /// Gets a mutable reference to the element in Object with the specified key.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("null"), JsonValue::Null);
+    ///
+    /// assert_eq!(object.get_mut("null"), Some(&mut JsonValue::Null));
+    /// assert_eq!(object.get_mut("no_such_key"), None);
+    /// ```
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut JsonValue> {
+        let pos = *self.index.get(key)?;
+        Some(&mut self.inner[pos].1)
+    }
+
+    /// Gets a mutable reference to the element in Object with the specified key. If there
+    /// is no such element, inserts the key with the value returned by `f` first.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// *object.get_or_insert_with("count", || JsonValue::from(0)) = JsonValue::from(1);
+    /// object.get_or_insert_with("count", || JsonValue::from(0));
+    /// assert_eq!(object.get("count"), Some(&JsonValue::from(1)));
+    /// ```
+    pub fn get_or_insert_with<F>(&mut self, key: &str, f: F) -> &mut JsonValue
+    where
+        F: FnOnce() -> JsonValue,
+    {
+        let pos = match self.index.get(key) {
+            Some(pos) => *pos,
+            None => {
+                self.insert(String::from(key), f());
+                self.inner.len() - 1
+            }
+        };
+        &mut self.inner[pos].1
+    }
+
+    /// Converts the Object into a Vec of its key-value pairs in the iteration order.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    /// assert_eq!(object.into_vec_pairs(), [(String::from("a"), JsonValue::Null)]);
+    /// ```
+    pub fn into_vec_pairs(self) -> Vec<(String, JsonValue)> {
+        self.inner
+            .into_iter()
+            .map(|(k, v)| (k.into_string(), v))
+            .collect()
+    }
+}
+
+impl PartialEq for Object {
+    /// Determines whether two objects are equal.
+    ///
+    /// The condition for two objects to be equal is that the two objects are of equal length
+    /// and the key-value pair can be one-to-one and exactly equal.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{Object, JsonValue};
+    ///
+    /// let object1 = Object::new();
+    /// let object2 = Object::new();
+    /// let mut object3 = Object::new();
+    /// object3.insert("test".to_string(), JsonValue::Null);
+    ///
+    /// assert_eq!(object1, object2);
+    /// assert_ne!(object1, object3);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        for (k, v) in self.iter() {
+            if other.get(k) != Some(v) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl Display for Object {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{{")?;
+        for (n, (key, value)) in self.inner.iter().enumerate() {
+            if n != 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "\"{key}\":{value}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl Debug for Object {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod ut_index {
+    use crate::{JsonValue, Key, Object};
+
+    /// UT test for `Object::insert`.
+    ///
+    /// # Title
+    /// ut_object_insert
+    ///
+    /// # Brief
+    /// 1. Creates a `Object`.
+    /// 2. Calls `Object::insert` on it with new and existing keys.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_object_insert() {
+        let mut object = object!("key1" => "value1"; "key2" => "value2");
+        object.insert(String::from("key1"), JsonValue::Null);
+        object.insert(String::from("key3"), JsonValue::Boolean(true));
+        assert_eq!(object.len(), 3);
+        assert_eq!(object.get("key1"), Some(&JsonValue::Null));
+        assert_eq!(
+            format!("{object}"),
+            "{\"key1\":null,\"key2\":\"value2\",\"key3\":true}"
+        );
+    }
+
+    /// UT test for `Object::remove`.
+    ///
+    /// # Title
+    /// ut_object_remove
+    ///
+    /// # Brief
+    /// 1. Creates a `Object`.
+    /// 2. Calls `Object::remove` on it.
+    /// 3. Checks if the indexes of the remaining entries are correct.
+    #[test]
+    fn ut_object_remove() {
+        let mut object = object!("key1" => "value1"; "key2" => "value2"; "key3" => "value3");
+        assert_eq!(object.remove("key1"), Some(JsonValue::new_string("value1")));
+        assert_eq!(object.remove("key1"), None);
+        assert_eq!(object.get("key2"), Some(&JsonValue::new_string("value2")));
+        assert_eq!(object.get("key3"), Some(&JsonValue::new_string("value3")));
+        object.insert(String::from("key1"), JsonValue::Null);
+        assert_eq!(
+            format!("{object}"),
+            "{\"key2\":\"value2\",\"key3\":\"value3\",\"key1\":null}"
+        );
+    }
+
+    /// UT test for `Object::iter_mut`.
+    ///
+    /// # Title
+    /// ut_object_iter_mut
+    ///
+    /// # Brief
+    /// 1. Creates a `Object`.
+    /// 2. Calls `Object::iter_mut` on it.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_object_iter_mut() {
+        let mut object = object!("key1" => "value1");
+        let mut iter_mut = object.iter_mut();
+        assert_eq!(
+            iter_mut.next(),
+            Some((&Key::from("key1"), &mut JsonValue::new_string("value1")))
+        );
+        assert_eq!(iter_mut.next(), None);
+
+        let mut object = object!("key1" => 1; "key2" => 2);
+        let mut iter_mut = object.iter_mut();
+        assert_eq!(iter_mut.len(), 2);
+        assert_eq!(iter_mut.next_back().map(|(k, _)| k.as_str()), Some("key2"));
+        assert_eq!(iter_mut.len(), 1);
+    }
+
+    /// UT test for `Object::eq`.
+    ///
+    /// # Title
+    /// ut_object_eq
+    ///
+    /// # Brief
+    /// 1. Creates some `Object`s.
+    /// 2. Calls `Object::eq` on them.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_object_eq() {
+        let object1 = object!("key1" => "value1"; "key2" => "value2");
+        let object2 = object!("key2" => "value2"; "key1" => "value1");
+        let object3 = object!("key1" => "value1"; "key3" => "value3");
+
+        assert_eq!(object1, object2);
+        assert_ne!(object1, object3);
+    }
+}
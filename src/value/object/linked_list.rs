@@ -11,9 +11,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Cursor, CursorMut, Iter, IterMut, JsonValue, LinkedList, Node};
+use crate::{Cursor, CursorMut, ExtractIf, Iter, IterMut, JsonValue, Key, LinkedList, Node};
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt::{Debug, Display, Formatter};
 use core::ptr::null;
+#[cfg(feature = "std")]
+use std::{boxed::Box, collections::HashMap};
+
+/// The number of pairs from which an Object builds its key index.
+#[cfg(feature = "std")]
+const INDEX_THRESHOLD: usize = 16;
 
 /// Object type, implemented using LinkedList.
 ///
@@ -30,15 +38,24 @@ use core::ptr::null;
 ///
 /// * The output order of this Object is the same as the insertion order.
 ///
+/// * With the `std` feature, an Object with at least 16 pairs keeps a hash index from
+///   keys to nodes, so `get`, `get_mut` and `contains_key` take O(1) time, at the cost
+///   of a copy of every key. Smaller objects are scanned. Methods which may change keys
+///   or remove pairs behind the index, such as `iter_mut`, `extract_if` and the mutable
+///   cursors, make the index stale. Lookups then scan the list until the next `insert`,
+///   `remove`, `get_mut` or `get_node_mut` rebuilds the index.
+///
 /// # Examples
 /// ```
 /// use ylong_json::Object;
 ///
 /// let object = Object::new();
 /// ```
-#[derive(Default, Clone, PartialEq)]
 pub struct Object {
-    inner: LinkedList<(String, JsonValue)>,
+    inner: LinkedList<(Key, JsonValue)>,
+    // Only built for large objects.
+    #[cfg(feature = "std")]
+    index: Option<Box<KeyIndex>>,
 }
 
 impl Object {
@@ -54,6 +71,17 @@ impl Object {
     pub fn new() -> Self {
         Self {
             inner: LinkedList::new(),
+            #[cfg(feature = "std")]
+            index: None,
+        }
+    }
+
+    /// Creates an empty Object whose nodes are allocated in chunks of at most `size` nodes.
+    pub(crate) fn with_node_chunk_size(size: usize) -> Self {
+        Self {
+            inner: LinkedList::with_chunk_size(size),
+            #[cfg(feature = "std")]
+            index: None,
         }
     }
 
@@ -100,7 +128,7 @@ impl Object {
     /// assert_eq!(object.contains_key("no_such_key"), false);
     /// ```
     pub fn contains_key(&self, key: &str) -> bool {
-        self.get_cursor(key).is_some()
+        !self.find(key).is_null()
     }
 
     /// Inserts the specified key and value into an Object, appending them to the end without deduplication.
@@ -114,7 +142,26 @@ impl Object {
     /// object.insert(String::from("null"), JsonValue::Null);
     /// assert_eq!(object.len(), 1);
     /// ```
-    pub fn insert(&mut self, key: String, value: JsonValue) {
+    pub fn insert<K: Into<Key>>(&mut self, key: K, value: JsonValue) {
+        let key = key.into();
+        #[cfg(feature = "std")]
+        {
+            self.sync_index();
+            match self.index.as_deref_mut() {
+                Some(index) if index.is_fresh(&self.inner) => {
+                    if index.map.contains_key(&key) {
+                        index.duplicated = true;
+                    } else {
+                        let index_key = key.clone();
+                        self.inner.push_back((key, value));
+                        let node = self.inner.cursor_back().current_node_ptr();
+                        index.map.insert(index_key, node);
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
         self.inner.push_back((key, value))
     }
 
@@ -132,7 +179,52 @@ impl Object {
     /// assert_eq!(object.len(), 0);
     /// ```
     pub fn remove(&mut self, key: &str) -> Option<JsonValue> {
-        self.get_cursor_mut(key)?.remove_current().map(|(_, v)| v)
+        let node = self.find_mut(key);
+        if node.is_null() {
+            return None;
+        }
+        // The node is found in this list.
+        Some(unsafe { self.remove_pair(node) }.1)
+    }
+
+    /// Removes the first key-value pair of Object and returns it, in O(1) time.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Key, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    /// object.insert(String::from("b"), JsonValue::Boolean(true));
+    ///
+    /// assert_eq!(object.pop_first(), Some((Key::from("a"), JsonValue::Null)));
+    /// assert_eq!(object.pop_first(), Some((Key::from("b"), JsonValue::Boolean(true))));
+    /// assert_eq!(object.pop_first(), None);
+    /// ```
+    pub fn pop_first(&mut self) -> Option<(Key, JsonValue)> {
+        let node = self.inner.cursor_front().current_node_ptr();
+        // The node is the head of this list.
+        (!node.is_null()).then(|| unsafe { self.remove_pair(node) })
+    }
+
+    /// Removes the last key-value pair of Object and returns it, in O(1) time.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Key, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    /// object.insert(String::from("b"), JsonValue::Boolean(true));
+    ///
+    /// assert_eq!(object.pop_last(), Some((Key::from("b"), JsonValue::Boolean(true))));
+    /// assert_eq!(object.pop_last(), Some((Key::from("a"), JsonValue::Null)));
+    /// assert_eq!(object.pop_last(), None);
+    /// ```
+    pub fn pop_last(&mut self) -> Option<(Key, JsonValue)> {
+        let node = self.inner.cursor_back().current_node_ptr();
+        // The node is the tail of this list.
+        (!node.is_null()).then(|| unsafe { self.remove_pair(node) })
     }
 
     /// Gets a common iterator of Object.
@@ -144,7 +236,7 @@ impl Object {
     /// let object = Object::new();
     /// let iter = object.iter();
     /// ```
-    pub fn iter(&self) -> Iter<'_, (String, JsonValue)> {
+    pub fn iter(&self) -> Iter<'_, (Key, JsonValue)> {
         self.inner.iter()
     }
 
@@ -157,7 +249,8 @@ impl Object {
     /// let mut object = Object::new();
     /// let iter_mut = object.iter_mut();
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<'_, (String, JsonValue)> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, (Key, JsonValue)> {
+        self.invalidate_index();
         self.inner.iter_mut()
     }
 
@@ -175,7 +268,7 @@ impl Object {
     /// assert_eq!(object.get("no_such_key"), None);
     /// ```
     pub fn get(&self, key: &str) -> Option<&JsonValue> {
-        self.get_cursor(key)?.current().map(|(_, v)| v)
+        self.get_node(key).map(|n| &n.get_element().1)
     }
 
     /// Gets a mutable reference to the element in Object with the specified key.
@@ -192,10 +285,156 @@ impl Object {
     /// assert_eq!(object.get_mut("no_such_key"), None);
     /// ```
     pub fn get_mut(&mut self, key: &str) -> Option<&mut JsonValue> {
-        // Using get_cursor_mut causes a problem referencing temporary variables.
         self.get_node_mut(key).map(|n| &mut n.get_element_mut().1)
     }
 
+    /// Gets a mutable reference to the element in Object with the specified key. If there
+    /// is no such element, inserts the key with the value returned by `f` first.
+    /// If there is an element with the same name, returns the one with the smallest subscript.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// *object.get_or_insert_with("count", || JsonValue::from(0)) = JsonValue::from(1);
+    /// object.get_or_insert_with("count", || JsonValue::from(0));
+    /// assert_eq!(object.get("count"), Some(&JsonValue::from(1)));
+    /// ```
+    pub fn get_or_insert_with<F>(&mut self, key: &str, f: F) -> &mut JsonValue
+    where
+        F: FnOnce() -> JsonValue,
+    {
+        let mut node = self.find_mut(key);
+        if node.is_null() {
+            self.insert(String::from(key), f());
+            node = self.inner.cursor_back().current_node_ptr();
+        }
+        // The node is found in or just inserted into this list.
+        unsafe { &mut self.inner.node_mut(node).get_element_mut().1 }
+    }
+
+    /// Removes the key-value pairs matching `pred` in one pass, and returns an iterator
+    /// over them. The pairs which are not yielded, including those left after the iterator
+    /// is dropped early, remain in Object.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Key, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    /// object.insert(String::from("b"), JsonValue::Boolean(true));
+    ///
+    /// let removed: Vec<_> = object.extract_if(|(_, value)| value.is_null()).collect();
+    /// assert_eq!(removed, vec![(Key::from("a"), JsonValue::Null)]);
+    /// assert_eq!(object.to_string(), r#"{"b":true}"#);
+    /// ```
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, (Key, JsonValue), F>
+    where
+        F: FnMut(&mut (Key, JsonValue)) -> bool,
+    {
+        self.invalidate_index();
+        self.inner.extract_if(pred)
+    }
+
+    /// Sorts the key-value pairs of Object by keys, and keeps the order of pairs with
+    /// equal keys.
+    ///
+    /// The sort relinks the nodes in place, so the nodes obtained before stay valid and
+    /// hold the same pairs.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("b"), JsonValue::Null);
+    /// object.insert(String::from("a"), JsonValue::Boolean(true));
+    ///
+    /// object.sort_keys();
+    /// assert_eq!(object.to_string(), r#"{"a":true,"b":null}"#);
+    /// ```
+    pub fn sort_keys(&mut self) {
+        // The sort is stable, so the index of the first nodes of keys is still valid.
+        self.inner.sort_by(|(a, _), (b, _)| a.cmp(b))
+    }
+
+    /// Gets a cursor pointing to the first key-value pair of Object, which can move
+    /// and seek by position.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Key, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    /// object.insert(String::from("b"), JsonValue::Boolean(true));
+    ///
+    /// let mut cursor = object.cursor_front();
+    /// assert!(cursor.seek(1));
+    /// assert_eq!(cursor.current(), Some(&(Key::from("b"), JsonValue::Boolean(true))));
+    /// ```
+    pub fn cursor_front(&self) -> Cursor<'_, (Key, JsonValue)> {
+        self.inner.cursor_front()
+    }
+
+    /// Gets a mutable cursor pointing to the first key-value pair of Object, which can
+    /// insert and remove pairs at its position in O(1) time.
+    ///
+    /// The keys inserted by the cursor are not de-duplicated, the same as `Object::insert`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Key, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    ///
+    /// let mut cursor = object.cursor_front_mut();
+    /// cursor.insert_before((Key::from("first"), JsonValue::Boolean(true)));
+    /// assert_eq!(object.to_string(), r#"{"first":true,"a":null}"#);
+    /// ```
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, (Key, JsonValue)> {
+        self.invalidate_index();
+        self.inner.cursor_front_mut()
+    }
+
+    /// Gets a cursor pointing to the last key-value pair of Object.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    ///
+    /// let cursor = object.cursor_back();
+    /// assert_eq!(cursor.index(), Some(0));
+    /// ```
+    pub fn cursor_back(&self) -> Cursor<'_, (Key, JsonValue)> {
+        self.inner.cursor_back()
+    }
+
+    /// Gets a mutable cursor pointing to the last key-value pair of Object.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Key, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    /// object.insert(String::from("b"), JsonValue::Null);
+    ///
+    /// let mut cursor = object.cursor_back_mut();
+    /// assert_eq!(cursor.remove_current().map(|(k, _)| k), Some(Key::from("b")));
+    /// assert_eq!(object.len(), 1);
+    /// ```
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, (Key, JsonValue)> {
+        self.invalidate_index();
+        self.inner.cursor_back_mut()
+    }
+
     /// Gets a common reference to the node in Object with the specified key.
     /// If there is an element with the same name, returns the one with the smallest subscript.
     ///
@@ -211,8 +450,10 @@ impl Object {
     /// object.insert(String::from("null"), JsonValue::Null);
     /// assert_eq!(object.get_node("null").is_some(), true);
     /// ```
-    pub fn get_node(&self, key: &str) -> Option<&Node<(String, JsonValue)>> {
-        self.get_cursor(key)?.current_node()
+    pub fn get_node(&self, key: &str) -> Option<&Node<(Key, JsonValue)>> {
+        let node = self.find(key);
+        // The node is found in this list.
+        (!node.is_null()).then(|| unsafe { &*node })
     }
 
     /// Gets a mutable reference to the node in Object with the specified key.
@@ -230,67 +471,219 @@ impl Object {
     /// object.insert(String::from("null"), JsonValue::Null);
     /// assert_eq!(object.get_node_mut("null").is_some(), true);
     /// ```
-    pub fn get_node_mut(&mut self, key: &str) -> Option<&mut Node<(String, JsonValue)>> {
-        self.get_cursor_mut(key)?.current_node()
+    pub fn get_node_mut(&mut self, key: &str) -> Option<&mut Node<(Key, JsonValue)>> {
+        let node = self.find_mut(key);
+        // The node is found in this list.
+        (!node.is_null()).then(|| unsafe { self.inner.node_mut(node) })
     }
 
     /// Gets the last node.
     #[cfg(feature = "c_adapter")]
-    pub(crate) fn last_node_mut(&mut self) -> Option<&mut Node<(String, JsonValue)>> {
+    pub(crate) fn last_node_mut(&mut self) -> Option<&mut Node<(Key, JsonValue)>> {
         let mut cursor = self.inner.cursor_back_mut();
         let _ = cursor.index()?;
         cursor.current_node()
     }
 
-    /// Needs using this method to avoid the life cycle check, which involves unsafe operations.
-    pub(crate) fn get_key_mut_maybe_insert(&mut self, key: &str) -> &mut JsonValue {
-        let mut cursor = self.inner.cursor_front();
-        let mut ptr = null();
-        while cursor.index().is_some() {
-            let current = cursor.current().unwrap();
-            if current.0 == key {
-                ptr = cursor.current_node_ptr();
-                break;
+    /// Removes the node from the list and returns its pair. Keeps the index fresh if it is,
+    /// which only needs a scan if the list has duplicate keys.
+    ///
+    /// # Safety
+    /// The node must belong to this list.
+    unsafe fn remove_pair(&mut self, node: *const Node<(Key, JsonValue)>) -> (Key, JsonValue) {
+        #[cfg(feature = "std")]
+        let fresh = self.fresh_index().is_some();
+        let pair = self.inner.remove_node(node);
+
+        #[cfg(feature = "std")]
+        if fresh {
+            let key = pair.0.as_str();
+            let releases = self.inner.releases();
+            // Indexes the next pair with the same key, if there is one.
+            let next = match self.index.as_deref() {
+                Some(index) if index.duplicated && index.map.get(key) == Some(&node) => {
+                    self.scan(key)
+                }
+                _ => null(),
+            };
+            if let Some(index) = self.index.as_deref_mut() {
+                if index.map.get(key) == Some(&node) {
+                    if next.is_null() {
+                        index.map.remove(key);
+                    } else if let Some(node) = index.map.get_mut(key) {
+                        *node = next;
+                    }
+                }
+                index.releases = releases;
             }
-            cursor.move_next();
         }
+        pair
+    }
 
-        if ptr.is_null() {
-            self.insert(String::from(key), JsonValue::Null);
-            &mut self.inner.back_mut().unwrap().1
-        } else {
-            unsafe {
-                &mut (*(ptr as *mut Node<(String, JsonValue)>))
-                    .get_element_mut()
-                    .1
-            }
+    /// Gets the pointer to the first node with the specified key, or null if there is none.
+    /// Uses the index if it is fresh.
+    fn find(&self, key: &str) -> *const Node<(Key, JsonValue)> {
+        #[cfg(feature = "std")]
+        if let Some(index) = self.fresh_index() {
+            return index.map.get(key).copied().unwrap_or(null());
         }
+        self.scan(key)
+    }
+
+    /// Gets the pointer to the first node with the specified key, or null if there is none.
+    /// Rebuilds the index first if it is stale.
+    fn find_mut(&mut self, key: &str) -> *const Node<(Key, JsonValue)> {
+        #[cfg(feature = "std")]
+        self.sync_index();
+        self.find(key)
     }
 
-    /// Gets the common cursor of the node corresponding to the specified key.
-    fn get_cursor(&self, key: &str) -> Option<Cursor<'_, (String, JsonValue)>> {
+    /// Gets the pointer to the first node with the specified key by traversing the list.
+    fn scan(&self, key: &str) -> *const Node<(Key, JsonValue)> {
         let mut cursor = self.inner.cursor_front();
-        while cursor.index().is_some() {
-            let (k, _) = cursor.current().unwrap();
+        while let Some((k, _)) = cursor.current() {
             if key == k {
-                return Some(cursor);
+                return cursor.current_node_ptr();
             }
             cursor.move_next();
         }
-        None
+        null()
     }
 
-    /// Gets the mutable cursor of the node corresponding to the specified key.
-    fn get_cursor_mut(&mut self, key: &str) -> Option<CursorMut<'_, (String, JsonValue)>> {
-        let mut cursor = self.inner.cursor_front_mut();
-        while cursor.index().is_some() {
-            let (k, _) = cursor.current().unwrap();
-            if key == k {
-                return Some(cursor);
+    /// Gets the index if it has been built and is fresh.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn fresh_index(&self) -> Option<&KeyIndex> {
+        self.index
+            .as_deref()
+            .filter(|index| index.is_fresh(&self.inner))
+    }
+
+    /// Rebuilds the index if it is stale, or builds it if the Object has reached
+    /// `INDEX_THRESHOLD` pairs.
+    #[cfg(feature = "std")]
+    fn sync_index(&mut self) {
+        if self.fresh_index().is_some() || self.inner.len() < INDEX_THRESHOLD {
+            return;
+        }
+        let index = self.index.get_or_insert_with(|| Box::new(KeyIndex::new()));
+        index.map.clear();
+        index.duplicated = false;
+
+        let mut cursor = self.inner.cursor_front();
+        while let Some((key, _)) = cursor.current() {
+            if index.map.contains_key(key) {
+                index.duplicated = true;
+            } else {
+                index.map.insert(key.clone(), cursor.current_node_ptr());
             }
             cursor.move_next();
         }
-        None
+        index.valid = true;
+        index.releases = self.inner.releases();
+    }
+
+    /// Marks the index stale, before the keys may be changed.
+    #[inline]
+    fn invalidate_index(&mut self) {
+        #[cfg(feature = "std")]
+        if let Some(index) = self.index.as_deref_mut() {
+            index.valid = false;
+        }
+    }
+
+    /// Converts the Object into a Vec of its key-value pairs in the iteration order.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    /// assert_eq!(object.into_vec_pairs(), [(String::from("a"), JsonValue::Null)]);
+    /// ```
+    pub fn into_vec_pairs(mut self) -> Vec<(String, JsonValue)> {
+        let len = self.inner.len();
+        self.inner
+            .drain(0, len)
+            .map(|(k, v)| (k.into_string(), v))
+            .collect()
+    }
+}
+
+/// Hash index from keys to the first nodes with them.
+#[cfg(feature = "std")]
+struct KeyIndex {
+    map: HashMap<Key, *const Node<(Key, JsonValue)>>,
+    // Whether the keys may have been changed after the index was built.
+    valid: bool,
+    // The `LinkedList::releases` of the list when the index was synchronized.
+    releases: usize,
+    // Whether the list has duplicate keys, which need a scan after removing.
+    duplicated: bool,
+}
+
+#[cfg(feature = "std")]
+impl KeyIndex {
+    /// Creates an empty KeyIndex.
+    fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+            valid: true,
+            releases: 0,
+            duplicated: false,
+        }
+    }
+
+    /// Checks whether the index matches the list, in which no node has been released or
+    /// changed behind the index.
+    #[inline]
+    fn is_fresh(&self, list: &LinkedList<(Key, JsonValue)>) -> bool {
+        self.valid && self.releases == list.releases()
+    }
+}
+
+// SAFETY: The index only holds pointers to the nodes of the list owned by the same
+// Object, and is only read through `&Object` and changed through `&mut Object`, so it is
+// sent and shared together with the list, which is Send and Sync.
+#[cfg(feature = "std")]
+unsafe impl Send for KeyIndex {}
+#[cfg(feature = "std")]
+unsafe impl Sync for KeyIndex {}
+
+impl Default for Object {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Object {
+    fn clone(&self) -> Self {
+        // Inserts the pairs one by one to build the index along the way.
+        let mut object = Self::new();
+        for (key, value) in self.iter() {
+            object.insert(key.clone(), value.clone());
+        }
+        object
+    }
+}
+
+impl PartialEq for Object {
+    /// Determines whether two objects are equal.
+    ///
+    /// The condition for two objects to be equal is that the two objects are of equal length
+    /// and the key-value pair can be one-to-one and exactly equal. The order of the members is
+    /// ignored, use `Object::eq_ordered` to compare it as well.
+    fn eq(&self, other: &Self) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        for (k, v) in self.iter() {
+            if other.get(k) != Some(v) {
+                return false;
+            }
+        }
+        true
     }
 }
 
@@ -308,14 +701,14 @@ impl Display for Object {
 }
 
 impl Debug for Object {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
 #[cfg(test)]
 mod ut_linked_list {
-    use crate::{JsonValue, Object};
+    use crate::{JsonValue, Key, Object};
 
     /// UT test for `Object::contains_key`.
     ///
@@ -348,7 +741,7 @@ mod ut_linked_list {
         let mut iter_mut = object.iter_mut();
         assert_eq!(
             iter_mut.next(),
-            Some(&mut (String::from("key1"), JsonValue::new_string("value1")))
+            Some(&mut (Key::from("key1"), JsonValue::new_string("value1")))
         );
         assert_eq!(iter_mut.next(), None);
     }
@@ -425,4 +818,204 @@ mod ut_linked_list {
             "{\"key1\":\"value1\",\"key2\":\"value2\"}"
         );
     }
+
+    /// UT test for `Object::extract_if`.
+    ///
+    /// # Title
+    /// ut_object_extract_if
+    ///
+    /// # Brief
+    /// 1. Creates an `Object`.
+    /// 2. Calls `Object::extract_if`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_object_extract_if() {
+        let mut object = object!("a" => 1; "b" => JsonValue::Null; "c" => 2);
+        let removed: Vec<_> = object.extract_if(|(k, _)| k != "b").collect();
+        assert_eq!(removed.len(), 2);
+        assert_eq!(removed[0].0, "a");
+        assert_eq!(removed[1].0, "c");
+        assert_eq!(object, object!("b" => JsonValue::Null));
+        assert!(object.contains_key("b"));
+        assert!(!object.contains_key("a"));
+    }
+
+    /// UT test for the key index of `Object`.
+    ///
+    /// # Title
+    /// ut_object_key_index
+    ///
+    /// # Brief
+    /// 1. Creates an `Object` with duplicate keys.
+    /// 2. Looks up, removes and changes keys through various methods.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_object_key_index() {
+        let mut object = Object::new();
+        object.insert(String::from("a"), JsonValue::from(1));
+        object.insert(String::from("b"), JsonValue::from(2));
+        object.insert(String::from("a"), JsonValue::from(3));
+        assert_eq!(object.get("a"), Some(&JsonValue::from(1)));
+
+        // Removing the first "a" exposes the second one.
+        assert_eq!(object.remove("a"), Some(JsonValue::from(1)));
+        assert_eq!(object.get("a"), Some(&JsonValue::from(3)));
+        assert_eq!(object.remove("a"), Some(JsonValue::from(3)));
+        assert!(!object.contains_key("a"));
+        assert_eq!(object.remove("a"), None);
+
+        // Keys changed by `iter_mut` are found after the change.
+        for (key, _) in object.iter_mut() {
+            key.to_mut().push('!');
+        }
+        assert!(!object.contains_key("b"));
+        assert_eq!(object.get("b!"), Some(&JsonValue::from(2)));
+        *object.get_mut("b!").unwrap() = JsonValue::Null;
+        assert_eq!(object.get("b!"), Some(&JsonValue::Null));
+
+        // Pairs removed by a cursor are not found any more.
+        object.insert(String::from("c"), JsonValue::from(4));
+        assert!(object.cursor_front_mut().remove_current().is_some());
+        assert!(!object.contains_key("b!"));
+        assert_eq!(object.get("c"), Some(&JsonValue::from(4)));
+
+        let mut value = JsonValue::Object(object);
+        value["d"] = JsonValue::from(5);
+        let object = value.try_as_object().unwrap();
+        let cloned = object.clone();
+        assert_eq!(&cloned, object);
+        assert_eq!(cloned.get("d"), Some(&JsonValue::from(5)));
+        assert_eq!(
+            cloned.get_node("c").map(|n| n as *const _),
+            cloned.cursor_front().current_node().map(|n| n as *const _)
+        );
+    }
+
+    /// UT test for the lazy building and invalidation of the key index of `Object`.
+    ///
+    /// # Title
+    /// ut_object_key_index_invalidation
+    ///
+    /// # Brief
+    /// 1. Creates an `Object` below and above the index threshold.
+    /// 2. Changes it through `iter_mut`, the mutable cursors and `extract_if`.
+    /// 3. Checks if lookups scan the list while the index is stale and find the right pairs.
+    #[cfg(feature = "std")]
+    #[test]
+    fn ut_object_key_index_invalidation() {
+        use super::INDEX_THRESHOLD;
+
+        let mut object = Object::new();
+        for i in 0..INDEX_THRESHOLD {
+            object.insert(format!("k{i}"), JsonValue::from(i as u64));
+        }
+        assert!(object.index.is_none());
+        assert_eq!(object.get("k3"), Some(&JsonValue::from(3u64)));
+
+        for i in INDEX_THRESHOLD..INDEX_THRESHOLD + 4 {
+            object.insert(format!("k{i}"), JsonValue::from(i as u64));
+        }
+        assert_eq!(object.fresh_index().unwrap().map.len(), INDEX_THRESHOLD + 4);
+
+        // A duplicate key is indexed by its first pair until that is removed.
+        object.insert(String::from("k2"), JsonValue::Null);
+        assert_eq!(object.get("k2"), Some(&JsonValue::from(2u64)));
+        assert_eq!(object.remove("k2"), Some(JsonValue::from(2u64)));
+        assert_eq!(object.get("k2"), Some(&JsonValue::Null));
+        assert!(object.fresh_index().is_some());
+
+        // Keys changed by `iter_mut` are found by scans.
+        for (key, _) in object.iter_mut().take(1) {
+            *key = Key::from("x");
+        }
+        assert!(object.fresh_index().is_none());
+        assert_eq!(object.get("x"), Some(&JsonValue::from(0u64)));
+        assert_eq!(object.get("k0"), None);
+        assert!(object.get_mut("x").is_some());
+        assert!(object.fresh_index().is_some());
+
+        // Pairs removed by the cursors are not found any more.
+        assert!(object.cursor_front_mut().remove_current().is_some());
+        assert!(object.fresh_index().is_none());
+        assert_eq!(object.get("x"), None);
+        assert!(object.cursor_back_mut().remove_current().is_some());
+        assert!(object.fresh_index().is_none());
+        assert_eq!(object.get("k2"), None);
+        assert_eq!(object.get("k19"), Some(&JsonValue::from(19u64)));
+
+        // Pairs removed by `extract_if` are not found any more.
+        assert!(object.remove("k1").is_some());
+        assert!(object.fresh_index().is_some());
+        assert_eq!(object.extract_if(|(k, _)| k == "k5").count(), 1);
+        assert!(object.fresh_index().is_none());
+        assert_eq!(object.get("k5"), None);
+        assert_eq!(object.get("k6"), Some(&JsonValue::from(6u64)));
+
+        // The index is rebuilt without the removed pairs.
+        assert!(object.get_mut("k6").is_some());
+        assert_eq!(object.fresh_index().unwrap().map.len(), object.len());
+        assert!(!object.contains_key("k5"));
+    }
+
+    /// UT test for `Object::sort_keys`.
+    ///
+    /// # Title
+    /// ut_object_sort_keys
+    ///
+    /// # Brief
+    /// 1. Creates an `Object` with duplicate keys.
+    /// 2. Calls `Object::sort_keys`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_object_sort_keys() {
+        let mut object = object!("c" => 1; "a" => 2; "b" => 3);
+        object.insert(String::from("a"), JsonValue::from(4));
+        let node = object.get_node("c").unwrap() as *const _;
+        object.sort_keys();
+        assert_eq!(object.to_string(), r#"{"a":2,"a":4,"b":3,"c":1}"#);
+        assert_eq!(object.get_node("c").unwrap() as *const _, node);
+        assert_eq!(object.get("a"), Some(&JsonValue::from(2)));
+        assert_eq!(object.remove("a"), Some(JsonValue::from(2)));
+        assert_eq!(object.get("a"), Some(&JsonValue::from(4)));
+    }
+
+    /// UT test for `Object::pop_first` and `Object::pop_last`.
+    ///
+    /// # Title
+    /// ut_object_pop_first_last
+    ///
+    /// # Brief
+    /// 1. Creates an `Object` with duplicate keys.
+    /// 2. Calls `Object::pop_first` and `Object::pop_last`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_object_pop_first_last() {
+        let mut object = object!("a" => 1; "b" => 2; "c" => 3);
+        object.insert(String::from("a"), JsonValue::from(4));
+        object.insert(String::from("b"), JsonValue::from(5));
+
+        assert_eq!(
+            object.pop_first(),
+            Some((Key::from("a"), JsonValue::from(1)))
+        );
+        assert_eq!(object.get("a"), Some(&JsonValue::from(4)));
+        assert_eq!(
+            object.pop_last(),
+            Some((Key::from("b"), JsonValue::from(5)))
+        );
+        assert_eq!(object.get("b"), Some(&JsonValue::from(2)));
+        assert_eq!(
+            object.pop_last(),
+            Some((Key::from("a"), JsonValue::from(4)))
+        );
+        assert!(!object.contains_key("a"));
+        assert_eq!(object.to_string(), r#"{"b":2,"c":3}"#);
+
+        assert!(object.pop_first().is_some());
+        assert!(object.pop_last().is_some());
+        assert_eq!(object.pop_first(), None);
+        assert_eq!(object.pop_last(), None);
+        object.insert(String::from("d"), JsonValue::Null);
+        assert_eq!(object.get("d"), Some(&JsonValue::Null));
+    }
 }
@@ -11,9 +11,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::JsonValue;
+use crate::{JsonValue, Key};
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt::{Debug, Display, Formatter};
 use core::slice::{Iter, IterMut};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+// Objects with no more entries than this are searched linearly by `get` even after
+// `Object::build_index` is called, since hashing the key costs more than comparing.
+#[cfg(feature = "std")]
+const INDEX_THRESHOLD: usize = 32;
 
 /// Object type, implemented using Vec.
 ///
@@ -21,10 +30,10 @@ use core::slice::{Iter, IterMut};
 /// 1. When the average number of entries x under Object is about 15 <= x <= 100.
 ///
 /// 2. When the average number of Object entries x is about 101 <= x <= 1024, and the creation to
-/// query ratio (the average number of queries created once) < 600.
+///    query ratio (the average number of queries created once) < 600.
 ///
 /// 3. When the average number of objects x is about 1025 <= x <= 10000, and the creation to
-/// query ratio (the average number of queries created once) < 500.
+///    query ratio (the average number of queries created once) < 500.
 ///
 /// # Attention
 /// * Only opening the 'vec_object' feature, this Object type can be used , and it conflicts with other Objects.
@@ -33,6 +42,9 @@ use core::slice::{Iter, IterMut};
 /// * Users are required to ensure that there are no duplicate entries.
 ///
 /// * The output order of this Object is the same as the insertion order.
+///
+/// * `get`, `get_mut` and `contains_key` take O(n) time by default. For large Objects which are
+///   queried frequently, `Object::build_index` builds a hash index to make them O(1).
 /// # Examples
 /// ```
 /// use ylong_json::Object;
@@ -42,7 +54,10 @@ use core::slice::{Iter, IterMut};
 /// ```
 #[derive(Default, Clone)]
 pub struct Object {
-    inner: Vec<(String, JsonValue)>,
+    inner: Vec<(Key, JsonValue)>,
+    // Maps every key to the position of its first entry.
+    #[cfg(feature = "std")]
+    index: Option<HashMap<Key, usize>>,
 }
 
 impl Object {
@@ -56,7 +71,11 @@ impl Object {
     /// assert_eq!(object.is_empty(), true);
     /// ```
     pub fn new() -> Self {
-        Self { inner: Vec::new() }
+        Self {
+            inner: Vec::new(),
+            #[cfg(feature = "std")]
+            index: None,
+        }
     }
 
     /// Gets the length of Object.
@@ -102,7 +121,7 @@ impl Object {
     /// assert_eq!(object.contains_key("no_such_key"), false);
     /// ```
     pub fn contains_key(&self, key: &str) -> bool {
-        self.inner.iter().any(|(k, _)| k == key)
+        self.position(key).is_some()
     }
 
     /// Inserts the specified key and value into an Object, appending them to the end without deduplication.
@@ -116,7 +135,14 @@ impl Object {
     /// object.insert(String::from("null"), JsonValue::Null);
     /// assert_eq!(object.len(), 1);
     /// ```
-    pub fn insert(&mut self, key: String, value: JsonValue) {
+    pub fn insert<K: Into<Key>>(&mut self, key: K, value: JsonValue) {
+        let key = key.into();
+        #[cfg(feature = "std")]
+        if let Some(index) = self.index.as_mut() {
+            if !index.contains_key(key.as_str()) {
+                index.insert(key.clone(), self.inner.len());
+            }
+        }
         self.inner.push((key, value))
     }
 
@@ -134,8 +160,46 @@ impl Object {
     /// assert_eq!(object.len(), 0);
     /// ```
     pub fn remove(&mut self, key: &str) -> Option<JsonValue> {
-        let pos = self.inner.iter().position(|(k, _)| k == key)?;
-        Some(self.inner.remove(pos).1)
+        let pos = self.position(key)?;
+        Some(self.remove_at(pos).1)
+    }
+
+    /// Removes the first key-value pair of Object and returns it. It takes O(n) time,
+    /// since the remaining entries are moved forward.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Key, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    /// object.insert(String::from("b"), JsonValue::Boolean(true));
+    ///
+    /// assert_eq!(object.pop_first(), Some((Key::from("a"), JsonValue::Null)));
+    /// assert_eq!(object.pop_first(), Some((Key::from("b"), JsonValue::Boolean(true))));
+    /// assert_eq!(object.pop_first(), None);
+    /// ```
+    pub fn pop_first(&mut self) -> Option<(Key, JsonValue)> {
+        (!self.inner.is_empty()).then(|| self.remove_at(0))
+    }
+
+    /// Removes the last key-value pair of Object and returns it, in O(1) time.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Key, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    /// object.insert(String::from("b"), JsonValue::Boolean(true));
+    ///
+    /// assert_eq!(object.pop_last(), Some((Key::from("b"), JsonValue::Boolean(true))));
+    /// assert_eq!(object.pop_last(), Some((Key::from("a"), JsonValue::Null)));
+    /// assert_eq!(object.pop_last(), None);
+    /// ```
+    pub fn pop_last(&mut self) -> Option<(Key, JsonValue)> {
+        let pos = self.inner.len().checked_sub(1)?;
+        Some(self.remove_at(pos))
     }
 
     /// Gets a common iterator of Object.
@@ -147,11 +211,12 @@ impl Object {
     /// let object = Object::new();
     /// let iter = object.iter();
     /// ```
-    pub fn iter(&self) -> Iter<'_, (String, JsonValue)> {
+    pub fn iter(&self) -> Iter<'_, (Key, JsonValue)> {
         self.inner.iter()
     }
 
-    /// Gets a mutable iterator of Object.
+    /// Gets a mutable iterator of Object. Since keys can be modified through this
+    /// iterator, the index built by `Object::build_index` is dropped.
     ///
     /// # Examples
     /// ```
@@ -160,7 +225,11 @@ impl Object {
     /// let mut object = Object::new();
     /// let iter_mut = object.iter_mut();
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<'_, (String, JsonValue)> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, (Key, JsonValue)> {
+        #[cfg(feature = "std")]
+        {
+            self.index = None;
+        }
         self.inner.iter_mut()
     }
 
@@ -178,7 +247,7 @@ impl Object {
     /// assert_eq!(object.get("no_such_key"), None);
     /// ```
     pub fn get(&self, key: &str) -> Option<&JsonValue> {
-        self.inner.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+        self.position(key).map(|pos| &self.inner[pos].1)
     }
 
     /// Gets a mutable reference to the element in Object with the specified key.
@@ -195,19 +264,115 @@ impl Object {
     /// assert_eq!(object.get_mut("no_such_key"), None);
     /// ```
     pub fn get_mut(&mut self, key: &str) -> Option<&mut JsonValue> {
-        self.inner
-            .iter_mut()
-            .find(|(k, _)| k == key)
-            .map(|(_, v)| v)
+        let pos = self.position(key)?;
+        Some(&mut self.inner[pos].1)
+    }
+
+    /// Gets a mutable reference to the element in Object with the specified key. If there
+    /// is no such element, inserts the key with the value returned by `f` first.
+    /// If there is an element with the same name, returns the one with the smallest subscript.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// *object.get_or_insert_with("count", || JsonValue::from(0)) = JsonValue::from(1);
+    /// object.get_or_insert_with("count", || JsonValue::from(0));
+    /// assert_eq!(object.get("count"), Some(&JsonValue::from(1)));
+    /// ```
+    pub fn get_or_insert_with<F>(&mut self, key: &str, f: F) -> &mut JsonValue
+    where
+        F: FnOnce() -> JsonValue,
+    {
+        let pos = match self.position(key) {
+            Some(pos) => pos,
+            None => {
+                self.insert(String::from(key), f());
+                self.inner.len() - 1
+            }
+        };
+        &mut self.inner[pos].1
+    }
+
+    /// Builds a hash index of the keys if the Object has more than 32 entries, so that `get`,
+    /// `get_mut` and `contains_key` take O(1) time afterwards. The index is kept up to date by
+    /// `insert` and `remove`, and is dropped by `iter_mut`. Returns whether the index exists.
+    ///
+    /// This is useful for large Objects which are queried many times after being created,
+    /// such as configurations. The index costs an extra copy of every key which is not
+    /// borrowed from a `&'static str`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// for i in 0..100 {
+    ///     object.insert(format!("key{i}"), JsonValue::Null);
+    /// }
+    /// assert!(object.build_index());
+    /// assert_eq!(object.get("key99"), Some(&JsonValue::Null));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn build_index(&mut self) -> bool {
+        if self.index.is_none() && self.inner.len() > INDEX_THRESHOLD {
+            let mut index = HashMap::with_capacity(self.inner.len());
+            for (pos, (key, _)) in self.inner.iter().enumerate() {
+                index.entry(key.clone()).or_insert(pos);
+            }
+            self.index = Some(index);
+        }
+        self.index.is_some()
     }
 
-    /// Gets a mutable reference to the last element.
-    pub(crate) fn last_mut(&mut self) -> Option<&mut JsonValue> {
-        self.inner.last_mut().map(|(_, v)| v)
+    // Removes the entry at the position, and keeps the index up to date.
+    fn remove_at(&mut self, pos: usize) -> (Key, JsonValue) {
+        #[cfg(feature = "std")]
+        if let Some(index) = self.index.as_mut() {
+            // Entries behind the removed one are moved forward.
+            if pos + 1 < self.inner.len() {
+                index
+                    .values_mut()
+                    .filter(|i| **i > pos)
+                    .for_each(|i| *i -= 1);
+            }
+            let key = self.inner[pos].0.as_str();
+            if index.get(key) == Some(&pos) {
+                // The next entry with the same key becomes the first one.
+                index.remove(key);
+                if let Some(next) = self.inner[pos + 1..].iter().position(|(k, _)| k == key) {
+                    index.insert(self.inner[pos].0.clone(), pos + next);
+                }
+            }
+        }
+        self.inner.remove(pos)
     }
 
-    pub(crate) fn get_mut_by_position(&mut self, index: usize) -> Option<&mut JsonValue> {
-        self.inner.get_mut(index).map(|(_, v)| v)
+    // Finds the position of the first entry with the specified key.
+    fn position(&self, key: &str) -> Option<usize> {
+        #[cfg(feature = "std")]
+        if let Some(index) = self.index.as_ref() {
+            return index.get(key).copied();
+        }
+        self.inner.iter().position(|(k, _)| k == key)
+    }
+
+    /// Converts the Object into a Vec of its key-value pairs in the iteration order.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert(String::from("a"), JsonValue::Null);
+    /// assert_eq!(object.into_vec_pairs(), [(String::from("a"), JsonValue::Null)]);
+    /// ```
+    pub fn into_vec_pairs(self) -> Vec<(String, JsonValue)> {
+        self.inner
+            .into_iter()
+            .map(|(k, v)| (k.into_string(), v))
+            .collect()
     }
 }
 
@@ -256,14 +421,14 @@ impl Display for Object {
 }
 
 impl Debug for Object {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
 #[cfg(test)]
 mod ut_vec {
-    use crate::{JsonValue, Object};
+    use crate::{JsonValue, Key, Object};
 
     /// UT test for `Object::contains_key`.
     ///
@@ -296,7 +461,7 @@ mod ut_vec {
         let mut iter_mut = object.iter_mut();
         assert_eq!(
             iter_mut.next(),
-            Some(&mut (String::from("key1"), JsonValue::new_string("value1")))
+            Some(&mut (Key::from("key1"), JsonValue::new_string("value1")))
         );
         assert_eq!(iter_mut.next(), None);
     }
@@ -361,4 +526,82 @@ mod ut_vec {
         assert_ne!(object1, object2);
         assert_ne!(object2, object3);
     }
+
+    /// UT test for `Object::build_index`.
+    ///
+    /// # Title
+    /// ut_object_build_index
+    ///
+    /// # Brief
+    /// 1. Creates some `Object`s with duplicate keys.
+    /// 2. Calls `Object::build_index`, `Object::insert` and `Object::remove` on them.
+    /// 3. Checks if the results are the same as the ones without the index.
+    #[test]
+    #[cfg(feature = "std")]
+    fn ut_object_build_index() {
+        let mut small = object!("key1" => 1; "key2" => 2);
+        assert!(!small.build_index());
+
+        let mut object = Object::new();
+        for i in 0..50 {
+            object.insert(format!("key{}", i % 40), JsonValue::new_number(i.into()));
+        }
+        let mut expected = object.clone();
+        assert!(object.build_index());
+        assert_eq!(object.get("key5"), Some(&JsonValue::new_number(5.into())));
+
+        for (key, value) in [("key5", 0), ("key40", 1), ("key41", 2)] {
+            object.insert(String::from(key), JsonValue::new_number(value.into()));
+            expected.insert(String::from(key), JsonValue::new_number(value.into()));
+        }
+        for key in ["key5", "key0", "key40", "key5", "key20", "no_such_key"] {
+            assert_eq!(object.remove(key), expected.remove(key));
+        }
+        for i in 0..42 {
+            let key = format!("key{i}");
+            assert_eq!(object.get(&key), expected.get(&key));
+            assert_eq!(object.contains_key(&key), expected.contains_key(&key));
+        }
+
+        object.iter_mut().for_each(|(k, _)| k.to_mut().push('_'));
+        assert_eq!(object.get("key1_"), expected.get("key1"));
+        assert!(object.build_index());
+        assert_eq!(object.get("key1_"), expected.get("key1"));
+    }
+
+    /// UT test for `Object::pop_first` and `Object::pop_last`.
+    ///
+    /// # Title
+    /// ut_object_pop_first_last
+    ///
+    /// # Brief
+    /// 1. Creates an `Object` with duplicate keys, with and without the index.
+    /// 2. Calls `Object::pop_first` and `Object::pop_last`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_object_pop_first_last() {
+        let mut object = Object::new();
+        for i in 0..40 {
+            object.insert(format!("key{}", i % 35), JsonValue::new_number(i.into()));
+        }
+        let mut expected = object.clone();
+        #[cfg(feature = "std")]
+        assert!(object.build_index());
+
+        for _ in 0..2 {
+            assert_eq!(object.pop_first(), expected.pop_first());
+            assert_eq!(object.pop_last(), expected.pop_last());
+        }
+        for i in 0..36 {
+            let key = format!("key{i}");
+            assert_eq!(object.get(&key), expected.get(&key));
+        }
+        assert_eq!(object.get("key0"), Some(&JsonValue::new_number(35.into())));
+        assert_eq!(object.get("key1"), Some(&JsonValue::new_number(36.into())));
+
+        while object.pop_last().is_some() {}
+        assert!(object.is_empty());
+        assert_eq!(object.pop_first(), None);
+        assert!(!object.contains_key("key0"));
+    }
 }
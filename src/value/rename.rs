@@ -0,0 +1,260 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{JsonValue, Key, Object};
+use alloc::string::String;
+
+impl Object {
+    /// Renames every key of the Object to the result of `f`, and keeps the order of the
+    /// members. Nested objects are not renamed, see `JsonValue::rename_keys_recursive`.
+    ///
+    /// The renamed members are inserted the same as `Object::insert`, so if some keys are
+    /// renamed to the same key, how they are kept depends on the Object type.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, Object};
+    ///
+    /// let mut object = Object::new();
+    /// object.insert("a", JsonValue::Null);
+    /// object.insert("b", JsonValue::Null);
+    /// object.rename_keys(|k| k.to_uppercase());
+    /// assert!(object.contains_key("A"));
+    /// assert!(object.contains_key("B"));
+    /// ```
+    pub fn rename_keys<F, K>(&mut self, mut f: F)
+    where
+        F: FnMut(&str) -> K,
+        K: Into<Key>,
+    {
+        for (key, value) in core::mem::take(self).into_vec_pairs() {
+            self.insert(f(&key), value);
+        }
+    }
+}
+
+impl JsonValue {
+    /// Renames the keys of every object in the JsonValue, including the nested ones, to
+    /// the result of `f`, see `Object::rename_keys`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let mut value = JsonValue::from_text(r#"[{"a": {"b": 1}}]"#).unwrap();
+    /// value.rename_keys_recursive(|k| format!("_{k}"));
+    /// assert_eq!(value, JsonValue::from_text(r#"[{"_a": {"_b": 1}}]"#).unwrap());
+    /// ```
+    pub fn rename_keys_recursive<F, K>(&mut self, mut f: F)
+    where
+        F: FnMut(&str) -> K,
+        K: Into<Key>,
+    {
+        rename_keys_recursive(self, &mut f);
+    }
+
+    /// Renames the keys of every object in the JsonValue from camelCase or PascalCase to
+    /// snake_case, such as `userId` to `user_id`. A run of uppercase letters is taken as
+    /// one word, so `HTTPServer` becomes `http_server`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let mut value = JsonValue::from_text(r#"{"userId": 1, "tags": [{"isHTTPS": true}]}"#).unwrap();
+    /// value.keys_to_snake_case();
+    /// let expected = JsonValue::from_text(r#"{"user_id": 1, "tags": [{"is_https": true}]}"#);
+    /// assert_eq!(value, expected.unwrap());
+    /// ```
+    pub fn keys_to_snake_case(&mut self) {
+        self.rename_keys_recursive(to_snake_case);
+    }
+
+    /// Renames the keys of every object in the JsonValue from snake_case to camelCase,
+    /// such as `user_id` to `userId`. Leading and trailing underscores are kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonValue;
+    ///
+    /// let mut value = JsonValue::from_text(r#"{"user_id": 1, "_links": {"next_page": 2}}"#).unwrap();
+    /// value.keys_to_camel_case();
+    /// let expected = JsonValue::from_text(r#"{"userId": 1, "_links": {"nextPage": 2}}"#);
+    /// assert_eq!(value, expected.unwrap());
+    /// ```
+    pub fn keys_to_camel_case(&mut self) {
+        self.rename_keys_recursive(to_camel_case);
+    }
+}
+
+fn rename_keys_recursive<F, K>(value: &mut JsonValue, f: &mut F)
+where
+    F: FnMut(&str) -> K,
+    K: Into<Key>,
+{
+    match value {
+        JsonValue::Object(object) => {
+            object.rename_keys(&mut *f);
+            for (_, value) in object.iter_mut() {
+                rename_keys_recursive(value, f);
+            }
+        }
+        JsonValue::Array(array) => {
+            for value in array.iter_mut() {
+                rename_keys_recursive(value, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Converts camelCase or PascalCase to snake_case. An underscore is added before an
+/// uppercase letter which follows a lowercase letter or a digit, or which starts a word
+/// after a run of uppercase letters.
+fn to_snake_case(key: &str) -> String {
+    let mut output = String::with_capacity(key.len() + 4);
+    let mut chars = key.chars().peekable();
+    let mut prev: Option<char> = None;
+    while let Some(ch) = chars.next() {
+        if ch.is_uppercase() {
+            let starts_word = match prev {
+                Some(p) if p.is_lowercase() || p.is_ascii_digit() => true,
+                Some(p) if p.is_uppercase() => chars.peek().is_some_and(|n| n.is_lowercase()),
+                _ => false,
+            };
+            if starts_word {
+                output.push('_');
+            }
+            output.extend(ch.to_lowercase());
+        } else {
+            output.push(ch);
+        }
+        prev = Some(ch);
+    }
+    output
+}
+
+/// Converts snake_case to camelCase. The underscores between words are removed and the
+/// next letter is uppercased, but leading and trailing underscores are kept.
+fn to_camel_case(key: &str) -> String {
+    let mut output = String::with_capacity(key.len());
+    let mut underscores = 0;
+    let mut in_word = false;
+    for ch in key.chars() {
+        if ch == '_' {
+            underscores += 1;
+            continue;
+        }
+        if underscores > 0 && in_word {
+            output.extend(ch.to_uppercase());
+        } else {
+            output.extend(core::iter::repeat_n('_', underscores));
+            output.push(ch);
+        }
+        underscores = 0;
+        in_word = true;
+    }
+    output.extend(core::iter::repeat_n('_', underscores));
+    output
+}
+
+#[cfg(test)]
+mod ut_rename {
+    use super::{to_camel_case, to_snake_case};
+    use crate::{JsonValue, Object};
+
+    /// UT test for the case conversions of keys.
+    ///
+    /// # Title
+    /// ut_case_conversion
+    ///
+    /// # Brief
+    /// 1. Converts some keys between camelCase and snake_case.
+    /// 2. Checks if the results are correct.
+    #[test]
+    fn ut_case_conversion() {
+        let cases = [
+            ("userId", "user_id"),
+            ("UserId", "user_id"),
+            ("HTTPServer", "http_server"),
+            ("isHTTPS", "is_https"),
+            ("page2Size", "page2_size"),
+            ("v2X", "v2_x"),
+            ("already_snake", "already_snake"),
+            ("_private", "_private"),
+            ("ÉtatCivil", "état_civil"),
+            ("", ""),
+        ];
+        for (camel, snake) in cases {
+            assert_eq!(to_snake_case(camel), snake, "{camel}");
+        }
+
+        let cases = [
+            ("user_id", "userId"),
+            ("userId", "userId"),
+            ("a__b", "aB"),
+            ("_links", "_links"),
+            ("__meta_data", "__metaData"),
+            ("type_", "type_"),
+            ("page_2_size", "page2Size"),
+            ("état_civil", "étatCivil"),
+            ("_", "_"),
+            ("", ""),
+        ];
+        for (snake, camel) in cases {
+            assert_eq!(to_camel_case(snake), camel, "{snake}");
+        }
+    }
+
+    /// UT test for `Object::rename_keys` and `JsonValue::rename_keys_recursive`.
+    ///
+    /// # Title
+    /// ut_rename_keys
+    ///
+    /// # Brief
+    /// 1. Renames the keys of some values.
+    /// 2. Checks if the keys are renamed and the order and values are kept.
+    /// 3. Checks if the keys are the same after converting back and forth.
+    #[test]
+    fn ut_rename_keys() {
+        let text = r#"{"firstName": "a", "addressList": [{"zipCode": 1}, [{"streetName": null}]]}"#;
+        let mut value = JsonValue::from_text(text).unwrap();
+        let original = value.clone();
+        value.keys_to_snake_case();
+        let expected = JsonValue::from_text(
+            r#"{"first_name": "a", "address_list": [{"zip_code": 1}, [{"street_name": null}]]}"#,
+        )
+        .unwrap();
+        assert!(value.eq_ordered(&expected));
+        value.keys_to_camel_case();
+        assert!(value.eq_ordered(&original));
+
+        let mut object = Object::new();
+        for key in ["c", "a", "b"] {
+            object.insert(key, JsonValue::from(key));
+        }
+        object.rename_keys(|k| if k == "a" { "z" } else { "y" });
+        assert_eq!(object.get("z"), Some(&JsonValue::from("a")));
+        assert!(object.iter().all(|(k, _)| k.is_borrowed()));
+        if cfg!(any(feature = "list_object", feature = "vec_object")) {
+            let keys: Vec<&str> = object.iter().map(|(k, _)| k.as_str()).collect();
+            assert_eq!(keys, ["y", "z", "y"]);
+        } else {
+            assert_eq!(object.len(), 2);
+        }
+
+        let mut value = JsonValue::from(1);
+        value.keys_to_snake_case();
+        assert_eq!(value, JsonValue::from(1));
+    }
+}
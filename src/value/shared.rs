@@ -0,0 +1,170 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::JsonValue;
+use alloc::sync::Arc;
+use core::fmt::{Debug, Display, Formatter};
+use core::ops::Deref;
+
+/// A JsonValue shared by reference counting, which is copied on write.
+///
+/// Cloning a SharedValue takes O(1) time, since only the reference count is increased.
+/// The JsonValue is deep cloned only when `SharedValue::make_mut` is called while other
+/// clones still exist. This is useful when a parsed value, such as a configuration, is
+/// handed to many users which mostly read it.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{JsonValue, SharedValue};
+///
+/// let value = JsonValue::from_text(r#"{"key": "value"}"#).unwrap();
+/// let shared = SharedValue::new(value);
+///
+/// let mut copy = shared.clone();
+/// assert!(SharedValue::ptr_eq(&shared, &copy));
+///
+/// copy.make_mut()["key"] = "other".into();
+/// assert_eq!(shared["key"], "value".into());
+/// assert_eq!(copy["key"], "other".into());
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct SharedValue {
+    inner: Arc<JsonValue>,
+}
+
+impl SharedValue {
+    /// Creates a SharedValue from a JsonValue.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, SharedValue};
+    ///
+    /// let shared = SharedValue::new(JsonValue::Null);
+    /// assert_eq!(*shared, JsonValue::Null);
+    /// ```
+    pub fn new(value: JsonValue) -> Self {
+        Self {
+            inner: Arc::new(value),
+        }
+    }
+
+    /// Gets a mutable reference to the JsonValue. If other clones of this SharedValue
+    /// exist, the JsonValue is deep cloned first, so the other clones are not affected.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, SharedValue};
+    ///
+    /// let mut shared = SharedValue::new(JsonValue::Null);
+    /// let copy = shared.clone();
+    /// *shared.make_mut() = JsonValue::Boolean(true);
+    ///
+    /// assert_eq!(*shared, JsonValue::Boolean(true));
+    /// assert_eq!(*copy, JsonValue::Null);
+    /// ```
+    pub fn make_mut(&mut self) -> &mut JsonValue {
+        Arc::make_mut(&mut self.inner)
+    }
+
+    /// Converts the SharedValue into a JsonValue. The JsonValue is deep cloned only if
+    /// other clones of this SharedValue exist.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, SharedValue};
+    ///
+    /// let shared = SharedValue::new(JsonValue::Null);
+    /// assert_eq!(shared.into_inner(), JsonValue::Null);
+    /// ```
+    pub fn into_inner(self) -> JsonValue {
+        Arc::try_unwrap(self.inner).unwrap_or_else(|inner| (*inner).clone())
+    }
+
+    /// Determines whether two SharedValues point to the same JsonValue.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::{JsonValue, SharedValue};
+    ///
+    /// let shared1 = SharedValue::new(JsonValue::Null);
+    /// let shared2 = SharedValue::new(JsonValue::Null);
+    ///
+    /// assert!(SharedValue::ptr_eq(&shared1, &shared1.clone()));
+    /// assert!(!SharedValue::ptr_eq(&shared1, &shared2));
+    /// ```
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.inner, &other.inner)
+    }
+}
+
+impl Deref for SharedValue {
+    type Target = JsonValue;
+
+    fn deref(&self) -> &JsonValue {
+        &self.inner
+    }
+}
+
+impl From<JsonValue> for SharedValue {
+    fn from(value: JsonValue) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Display for SharedValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&*self.inner, f)
+    }
+}
+
+impl Debug for SharedValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod ut_shared {
+    use crate::{Array, JsonValue, SharedValue};
+
+    /// UT test for `SharedValue::make_mut`.
+    ///
+    /// # Title
+    /// ut_shared_value_make_mut
+    ///
+    /// # Brief
+    /// 1. Creates a `SharedValue` and clones it.
+    /// 2. Calls `SharedValue::make_mut` on the clones.
+    /// 3. Checks if only the JsonValue which is still shared is cloned.
+    #[test]
+    fn ut_shared_value_make_mut() {
+        let mut shared = SharedValue::new(JsonValue::new_array(array!(1, 2)));
+        let copy = shared.clone();
+        assert!(SharedValue::ptr_eq(&shared, &copy));
+
+        shared.make_mut()[0] = JsonValue::Null;
+        assert!(!SharedValue::ptr_eq(&shared, &copy));
+        assert_eq!(format!("{shared}"), "[null,2]");
+        assert_eq!(format!("{copy:?}"), "[1,2]");
+
+        let mut copy2 = copy.clone();
+        drop(copy);
+        let ptr = &*copy2 as *const JsonValue;
+        copy2.make_mut()[1] = JsonValue::Null;
+        assert_eq!(&*copy2 as *const JsonValue, ptr);
+        assert_eq!(
+            copy2.into_inner(),
+            JsonValue::new_array(array!(1, JsonValue::Null))
+        );
+    }
+}
@@ -0,0 +1,418 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// String of a `JsonValue` with the `c_adapter` feature.
+///
+/// The bytes are always followed by a NUL, so C can read the string as a C string
+/// without copying it, and the C getters need not change the value. Unlike `CString`,
+/// the string itself can contain NUL, which is decoded from `"\u0000"`.
+///
+/// A JsonString can also borrow a C string without copying it, which is created by
+/// `ylong_json_create_string_reference`. Such a string is not freed with the value, and
+/// it is copied before it is changed or cloned.
+///
+/// A JsonString can be used as a `&str` and compared with strings.
+///
+/// # Examples
+/// ```
+/// use ylong_json::{JsonString, JsonValue};
+///
+/// let mut value = JsonValue::new_string("Hello");
+/// let string: &mut JsonString = value.try_as_mut_string().unwrap();
+/// string.push_str(" World");
+/// assert_eq!(string, "Hello World");
+/// assert_eq!(string.len(), 11);
+/// ```
+pub struct JsonString {
+    repr: Repr,
+}
+
+enum Repr {
+    // The bytes of the string and a NUL, which are valid UTF-8.
+    Owned(Vec<u8>),
+    // A C string of the caller and its length without the NUL. The bytes are valid
+    // UTF-8, and stay valid and unchanged while the JsonString is alive.
+    Borrowed(*const u8, usize),
+}
+
+// A borrowed C string is only read, like a `&'static str`. The caller of
+// `ylong_json_create_string_reference` keeps it valid and unchanged.
+unsafe impl Send for JsonString {}
+unsafe impl Sync for JsonString {}
+
+impl JsonString {
+    /// Creates an empty JsonString.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonString;
+    ///
+    /// assert!(JsonString::new().is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::from_bytes(vec![0])
+    }
+
+    fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self {
+            repr: Repr::Owned(bytes),
+        }
+    }
+
+    /// Creates a JsonString which borrows `len` bytes at `ptr`, which are followed by a NUL.
+    ///
+    /// # Safety
+    /// The bytes and the NUL must be valid UTF-8, and stay valid and unchanged while the
+    /// JsonString and the values containing it are alive.
+    pub(crate) unsafe fn from_c_str_unchecked(ptr: *const u8, len: usize) -> Self {
+        Self {
+            repr: Repr::Borrowed(ptr, len),
+        }
+    }
+
+    /// Determines whether the JsonString borrows a C string instead of owning its bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonString;
+    ///
+    /// assert!(!JsonString::from("a").is_borrowed());
+    /// ```
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.repr, Repr::Borrowed(..))
+    }
+
+    // Gets the bytes and the NUL, which copies a borrowed C string first.
+    fn to_mut(&mut self) -> &mut Vec<u8> {
+        if let Repr::Borrowed(..) = self.repr {
+            *self = Self::from(self.as_str());
+        }
+        match &mut self.repr {
+            Repr::Owned(bytes) => bytes,
+            Repr::Borrowed(..) => unreachable!(),
+        }
+    }
+
+    /// Gets the JsonString as a `&str`, without the NUL.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonString;
+    ///
+    /// assert_eq!(JsonString::from("a\0b").as_str(), "a\0b");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        // The bytes are valid UTF-8, and the NUL is not a part of any character.
+        unsafe {
+            match self.repr {
+                Repr::Owned(ref bytes) => core::str::from_utf8_unchecked(&bytes[..bytes.len() - 1]),
+                Repr::Borrowed(ptr, len) => {
+                    core::str::from_utf8_unchecked(core::slice::from_raw_parts(ptr, len))
+                }
+            }
+        }
+    }
+
+    /// Appends a string to the end of the JsonString.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonString;
+    ///
+    /// let mut string = JsonString::from("a");
+    /// string.push_str("bc");
+    /// assert_eq!(string, "abc");
+    /// ```
+    pub fn push_str(&mut self, string: &str) {
+        let bytes = self.to_mut();
+        bytes.pop();
+        bytes.extend_from_slice(string.as_bytes());
+        bytes.push(0);
+    }
+
+    /// Appends a char to the end of the JsonString.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonString;
+    ///
+    /// let mut string = JsonString::from("a");
+    /// string.push('b');
+    /// assert_eq!(string, "ab");
+    /// ```
+    pub fn push(&mut self, ch: char) {
+        self.push_str(ch.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Removes the contents of the JsonString.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonString;
+    ///
+    /// let mut string = JsonString::from("a");
+    /// string.clear();
+    /// assert!(string.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Converts the JsonString into a `String`, without the NUL.
+    ///
+    /// # Examples
+    /// ```
+    /// use ylong_json::JsonString;
+    ///
+    /// assert_eq!(JsonString::from("a").into_string(), "a");
+    /// ```
+    pub fn into_string(self) -> String {
+        match self.repr {
+            Repr::Owned(mut bytes) => {
+                bytes.pop();
+                // The bytes are valid UTF-8.
+                unsafe { String::from_utf8_unchecked(bytes) }
+            }
+            Repr::Borrowed(..) => String::from(self.as_str()),
+        }
+    }
+
+    /// Gets a pointer to the bytes, which are followed by a NUL.
+    pub(crate) fn as_c_ptr(&self) -> *const u8 {
+        match self.repr {
+            Repr::Owned(ref bytes) => bytes.as_ptr(),
+            Repr::Borrowed(ptr, _) => ptr,
+        }
+    }
+}
+
+impl Clone for JsonString {
+    // A borrowed C string is copied, so the clone does not depend on the memory of the caller.
+    fn clone(&self) -> Self {
+        match self.repr {
+            Repr::Owned(ref bytes) => Self::from_bytes(bytes.clone()),
+            Repr::Borrowed(..) => Self::from(self.as_str()),
+        }
+    }
+}
+
+impl Default for JsonString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for JsonString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for JsonString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for JsonString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<String> for JsonString {
+    fn from(string: String) -> Self {
+        let mut bytes = string.into_bytes();
+        bytes.push(0);
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<&str> for JsonString {
+    fn from(string: &str) -> Self {
+        let mut bytes = Vec::with_capacity(string.len() + 1);
+        bytes.extend_from_slice(string.as_bytes());
+        bytes.push(0);
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<&String> for JsonString {
+    fn from(string: &String) -> Self {
+        Self::from(string.as_str())
+    }
+}
+
+impl From<Cow<'_, str>> for JsonString {
+    fn from(string: Cow<'_, str>) -> Self {
+        match string {
+            Cow::Borrowed(s) => Self::from(s),
+            Cow::Owned(s) => Self::from(s),
+        }
+    }
+}
+
+impl From<JsonString> for String {
+    fn from(string: JsonString) -> Self {
+        string.into_string()
+    }
+}
+
+impl PartialEq for JsonString {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for JsonString {}
+
+impl PartialOrd for JsonString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonString {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for JsonString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl PartialEq<str> for JsonString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for JsonString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for JsonString {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<JsonString> for str {
+    fn eq(&self, other: &JsonString) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<JsonString> for &str {
+    fn eq(&self, other: &JsonString) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialEq<JsonString> for String {
+    fn eq(&self, other: &JsonString) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl Display for JsonString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl Debug for JsonString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod ut_string {
+    use crate::JsonString;
+
+    /// UT test for the NUL after the bytes of `JsonString`.
+    ///
+    /// # Title
+    /// ut_json_string_nul
+    ///
+    /// # Brief
+    /// 1. Creates and changes some JsonStrings.
+    /// 2. Checks if the bytes are always followed by a NUL.
+    #[test]
+    fn ut_json_string_nul() {
+        fn check(string: &JsonString, expected: &str) {
+            assert_eq!(string, expected);
+            let c_str = unsafe { std::slice::from_raw_parts(string.as_c_ptr(), string.len() + 1) };
+            assert_eq!(&c_str[..string.len()], expected.as_bytes());
+            assert_eq!(c_str[string.len()], 0);
+        }
+
+        let mut string = JsonString::new();
+        check(&string, "");
+        string.push_str("a\0b");
+        check(&string, "a\0b");
+        string.push('中');
+        check(&string, "a\0b中");
+        string.clear();
+        check(&string, "");
+        check(&JsonString::from(String::from("abc")), "abc");
+        check(&JsonString::from("abc").clone(), "abc");
+        assert_eq!(JsonString::from("abc").into_string(), "abc");
+    }
+
+    /// UT test for `JsonString` which borrows a C string.
+    ///
+    /// # Title
+    /// ut_json_string_borrowed
+    ///
+    /// # Brief
+    /// 1. Creates a JsonString which borrows a C string.
+    /// 2. Clones and changes it.
+    /// 3. Checks if the C string is never changed, and the copies own their bytes.
+    #[test]
+    fn ut_json_string_borrowed() {
+        let c_str = b"abc\0";
+        let string = unsafe { JsonString::from_c_str_unchecked(c_str.as_ptr(), 3) };
+        assert!(string.is_borrowed());
+        assert_eq!(string, "abc");
+        assert_eq!(string.as_c_ptr(), c_str.as_ptr());
+
+        let clone = string.clone();
+        assert!(!clone.is_borrowed());
+        assert_eq!(clone, "abc");
+
+        let mut changed = unsafe { JsonString::from_c_str_unchecked(c_str.as_ptr(), 3) };
+        changed.push('d');
+        assert!(!changed.is_borrowed());
+        assert_eq!(changed, "abcd");
+        assert_eq!(string.into_string(), "abc");
+        assert_eq!(c_str, b"abc\0");
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Error;
+use alloc::vec::Vec;
+use core::fmt::Arguments;
+
+/// `Write` provides the byte output interface used by encoders and the serde
+/// serializer, so that they do not depend on `std::io::Write`.
+///
+/// With `std` feature, it is implemented for every type which implements
+/// `std::io::Write`. Otherwise, it is implemented for `Vec<u8>`.
+pub(crate) trait Write {
+    /// Writes all bytes of `buf`.
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Error>;
+
+    /// Writes formatted output. This method is used by `write!` macro.
+    fn write_fmt(&mut self, args: Arguments<'_>) -> Result<(), Error> {
+        // An adapter which forwards `core::fmt::Write` to `Write`,
+        // and records the real error.
+        struct Adapter<'a, W: ?Sized> {
+            inner: &'a mut W,
+            error: Option<Error>,
+        }
+
+        impl<W: Write + ?Sized> core::fmt::Write for Adapter<'_, W> {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                self.inner.write_all(s.as_bytes()).map_err(|e| {
+                    self.error = Some(e);
+                    core::fmt::Error
+                })
+            }
+        }
+
+        let mut adapter = Adapter {
+            inner: self,
+            error: None,
+        };
+        match core::fmt::write(&mut adapter, args) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(adapter
+                .error
+                .unwrap_or_else(|| Error::Custom("Formatter error".into()))),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write + ?Sized> Write for W {
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+        std::io::Write::write_all(self, buf)?;
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Write for Vec<u8> {
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<W: Write + ?Sized> Write for &mut W {
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+        (**self).write_all(buf)
+    }
+}
+
+/// `ChunkWriter` passes the bytes written into it to a callback as chunks of
+/// `chunk_size` bytes, only the last chunk can be shorter. The bytes are collected
+/// in one reused buffer, and full chunks of the written bytes are passed directly.
+///
+/// If `chunk_size` is 0, every non-empty write is passed directly without buffering.
+pub(crate) struct ChunkWriter<F> {
+    buf: Vec<u8>,
+    chunk_size: usize,
+    callback: F,
+}
+
+impl<F: FnMut(&[u8]) -> Result<(), Error>> ChunkWriter<F> {
+    /// Creates a `ChunkWriter`.
+    pub(crate) fn new(chunk_size: usize, callback: F) -> Self {
+        Self {
+            buf: Vec::with_capacity(chunk_size),
+            chunk_size,
+            callback,
+        }
+    }
+
+    /// Passes the remaining bytes to the callback as the last chunk.
+    pub(crate) fn finish(mut self) -> Result<(), Error> {
+        match self.buf.is_empty() {
+            true => Ok(()),
+            false => (self.callback)(&self.buf),
+        }
+    }
+}
+
+impl<F: FnMut(&[u8]) -> Result<(), Error>> Write for ChunkWriter<F> {
+    fn write_all(&mut self, mut buf: &[u8]) -> Result<(), Error> {
+        if self.chunk_size == 0 {
+            return match buf.is_empty() {
+                true => Ok(()),
+                false => (self.callback)(buf),
+            };
+        }
+        while !buf.is_empty() {
+            if self.buf.is_empty() && buf.len() >= self.chunk_size {
+                let (chunk, rest) = buf.split_at(self.chunk_size);
+                (self.callback)(chunk)?;
+                buf = rest;
+                continue;
+            }
+            let len = buf.len().min(self.chunk_size - self.buf.len());
+            self.buf.extend_from_slice(&buf[..len]);
+            buf = &buf[len..];
+            if self.buf.len() == self.chunk_size {
+                (self.callback)(&self.buf)?;
+                self.buf.clear();
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod ut_writer {
+    use crate::writer::{ChunkWriter, Write};
+
+    /// UT test for `Write::write_fmt`.
+    ///
+    /// # Title
+    /// ut_writer_write_fmt
+    ///
+    /// # Brief
+    /// 1. Creates a `Vec<u8>`.
+    /// 2. Calls `Write::write_all` and `Write::write_fmt`.
+    /// 3. Checks if the test results are correct.
+    #[test]
+    fn ut_writer_write_fmt() {
+        let mut vec = Vec::new();
+        Write::write_all(&mut vec, b"[").unwrap();
+        Write::write_fmt(&mut vec, format_args!("{},{:?}", 1, 2.5)).unwrap();
+        Write::write_all(&mut vec, b"]").unwrap();
+        assert_eq!(vec, b"[1,2.5]");
+    }
+
+    /// UT test for `ChunkWriter`.
+    ///
+    /// # Title
+    /// ut_chunk_writer
+    ///
+    /// # Brief
+    /// 1. Writes some bytes into `ChunkWriter`s of different chunk sizes.
+    /// 2. Checks if the chunks have the expected sizes and contents.
+    /// 3. Checks if the error of the callback is returned.
+    #[test]
+    fn ut_chunk_writer() {
+        let mut chunks = Vec::new();
+        let mut writer = ChunkWriter::new(4, |chunk: &[u8]| {
+            chunks.push(chunk.to_vec());
+            Ok(())
+        });
+        Write::write_all(&mut writer, b"ab").unwrap();
+        Write::write_all(&mut writer, b"").unwrap();
+        Write::write_all(&mut writer, b"cdefghijk").unwrap();
+        Write::write_all(&mut writer, b"l").unwrap();
+        writer.finish().unwrap();
+        assert_eq!(chunks, [&b"abcd"[..], b"efgh", b"ijkl"]);
+
+        let mut chunks = Vec::new();
+        let mut writer = ChunkWriter::new(0, |chunk: &[u8]| {
+            chunks.push(chunk.to_vec());
+            Ok(())
+        });
+        Write::write_all(&mut writer, b"ab").unwrap();
+        Write::write_all(&mut writer, b"").unwrap();
+        Write::write_all(&mut writer, b"c").unwrap();
+        writer.finish().unwrap();
+        assert_eq!(chunks, [&b"ab"[..], b"c"]);
+
+        let mut writer = ChunkWriter::new(2, |_: &[u8]| Err(crate::Error::Custom("full".into())));
+        assert!(Write::write_all(&mut writer, b"a").is_ok());
+        assert!(Write::write_all(&mut writer, b"b").is_err());
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A `#![no_std]` library which is built against `ylong_json` by `tests/sdv_no_std_build_test.rs`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use ylong_json::{Error, JsonValue};
+
+/// Parses a JSON text and encodes it again, only with `core` and `alloc`.
+pub fn reencode(text: &str) -> Result<String, Error> {
+    JsonValue::from_text(text)?.to_compact_string()
+}
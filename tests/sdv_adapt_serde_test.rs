@@ -129,6 +129,7 @@ struct ExampleTwo<'a> {
 }
 
 #[test]
+#[allow(clippy::excessive_precision)]
 fn sdv_adapt_serde_example_two() {
     let de_res = from_str::<Vec<ExampleTwo>>(RFC7159_EXAMPLE2).unwrap();
     let expected_0 = ExampleTwo {
@@ -291,7 +292,7 @@ struct ArrayTest {
     array2: Vec<bool>,
     array3: Vec<i64>,
     array4: Vec<f64>,
-    #[warn(clippy::type_complexity)]
+    #[allow(clippy::type_complexity)]
     array5: Vec<Vec<Vec<Vec<Vec<Vec<Vec<String>>>>>>>,
 }
 
@@ -0,0 +1,60 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::panic::catch_unwind;
+use std::path::PathBuf;
+use ylong_json::{from_slice_with_config, JsonValue, ParseConfig};
+
+/// SDV test for the parsing cases of JSONTestSuite in the RFC 8259 mode.
+///
+/// # Title
+/// sdv_json_test_suite
+///
+/// # Brief
+/// 1. Reads every case in `tests/JSONTestSuite/test_parsing`.
+/// 2. Parses it by `JsonValue::from_text_with_config` and `from_slice_with_config`.
+/// 3. Checks if the `y_` cases are accepted, the `n_` cases are rejected, and
+///    no case panics.
+#[test]
+fn sdv_json_test_suite() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/JSONTestSuite/test_parsing");
+    let config = ParseConfig::default().strict_rfc8259(true);
+
+    let mut cases = 0;
+    let mut failures = Vec::new();
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        let text = fs::read(&path).unwrap();
+        cases += 1;
+
+        let results = catch_unwind(|| {
+            (
+                JsonValue::from_text_with_config(&text, config).is_ok(),
+                from_slice_with_config::<serde_json::Value>(&text, config).is_ok(),
+            )
+        });
+        let passed = match results {
+            Ok((tree, serde)) if name.starts_with("y_") => tree && serde,
+            Ok((tree, serde)) if name.starts_with("n_") => !tree && !serde,
+            Ok(_) => true,
+            Err(_) => false,
+        };
+        if !passed {
+            failures.push(name);
+        }
+    }
+    assert!(cases > 0);
+    assert!(failures.is_empty(), "failed cases: {failures:?}");
+}
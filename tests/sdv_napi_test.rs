@@ -0,0 +1,101 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(target_os = "linux")]
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn check(output: Output) {
+    assert!(
+        output.status.success(),
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+// Finds the headers of Node-API, which are installed with Node.js.
+fn node_include_dir() -> Option<String> {
+    if let Ok(dir) = std::env::var("NODE_INCLUDE_DIR") {
+        return Some(dir);
+    }
+    let output = Command::new("node")
+        .args([
+            "-p",
+            "require('path').resolve(process.execPath, '../../include/node')",
+        ])
+        .output()
+        .ok()?;
+    let dir = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    Path::new(&dir).join("node_api.h").exists().then_some(dir)
+}
+
+/// SDV test for the NAPI module in `napi`.
+///
+/// # Brief
+/// 1. Builds `ylong_json` as a staticlib with the `c_adapter` feature.
+/// 2. Builds `napi/ylong_json_napi.cpp` against the Node-API of Node.js, with the headers in
+///    `napi/test/include` in place of `napi:ace_napi`.
+/// 3. Runs `napi/test/ylong_json_napi_test.js`, which checks the values and the lifetimes of
+///    the handles.
+/// 4. Skips the test if Node.js, its headers or a C++ compiler can not be found.
+#[test]
+fn sdv_napi_module() {
+    let cxx = std::env::var("CXX").unwrap_or_else(|_| String::from("c++"));
+    let include = match node_include_dir() {
+        Some(dir) if Command::new(&cxx).arg("--version").output().is_ok() => dir,
+        _ => {
+            println!("Node.js, its headers or a C++ compiler is not found, skip the test.");
+            return;
+        }
+    };
+
+    let root = env!("CARGO_MANIFEST_DIR");
+    let target = format!("{root}/target/napi");
+    check(
+        Command::new(env!("CARGO"))
+            .args(["rustc", "--offline", "--lib", "--manifest-path"])
+            .arg(format!("{root}/Cargo.toml"))
+            .args(["--features", "c_adapter", "--crate-type", "staticlib"])
+            .arg("--target-dir")
+            .arg(&target)
+            .env_remove("RUSTFLAGS")
+            .output()
+            .unwrap(),
+    );
+
+    let module = format!("{target}/ylongJson.node");
+    check(
+        Command::new(&cxx)
+            .args(["-std=c++17", "-shared", "-fPIC", "-Wall", "-Werror"])
+            .arg(format!("-I{root}/napi/test/include"))
+            .arg(format!("-I{include}"))
+            .arg(format!("{root}/napi/ylong_json_napi.cpp"))
+            .arg(format!("{target}/debug/libylong_json.a"))
+            .args(["-lpthread", "-ldl", "-lm"])
+            .arg("-o")
+            .arg(&module)
+            .output()
+            .unwrap(),
+    );
+
+    check(
+        Command::new("node")
+            .arg("--expose-gc")
+            .arg(format!("{root}/napi/test/ylong_json_napi_test.js"))
+            .arg(&module)
+            .output()
+            .unwrap(),
+    );
+}
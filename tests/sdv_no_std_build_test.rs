@@ -0,0 +1,66 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::process::{Command, Output};
+
+fn check(output: Output) {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// SDV test for building `ylong_json` without `std`.
+///
+/// # Brief
+/// 1. Builds `ylong_json` as an rlib with the default features off. The `cdylib` and `staticlib`
+///    crate types need `std` to be linked, so only the Rust library is built.
+/// 2. Builds `tests/no_std`, a `#![no_std]` library which depends on that rlib.
+/// 3. Checks if both of them are built successfully.
+#[test]
+fn sdv_no_std_build() {
+    let root = env!("CARGO_MANIFEST_DIR");
+    let target = format!("{root}/target/no_std");
+    check(
+        Command::new(env!("CARGO"))
+            .args(["rustc", "--offline", "--lib", "--manifest-path"])
+            .arg(format!("{root}/Cargo.toml"))
+            .args([
+                "--no-default-features",
+                "--features",
+                "btree_object,vec_array",
+            ])
+            .args(["--crate-type", "rlib", "--target-dir"])
+            .arg(&target)
+            .env_remove("RUSTFLAGS")
+            .output()
+            .unwrap(),
+    );
+
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    check(
+        Command::new(rustc)
+            .args(["--edition", "2021", "--crate-type", "rlib", "--crate-name"])
+            .arg("ylong_json_no_std_check")
+            .arg("--extern")
+            .arg(format!("ylong_json={target}/debug/libylong_json.rlib"))
+            .arg("-L")
+            .arg(format!("dependency={target}/debug/deps"))
+            .arg("--out-dir")
+            .arg(&target)
+            .arg(format!("{root}/tests/no_std/src/lib.rs"))
+            .output()
+            .unwrap(),
+    );
+}
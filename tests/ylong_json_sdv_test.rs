@@ -11,7 +11,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use ylong_json::{Array, JsonValue, Object};
+use ylong_json::{Array, EncodeConfig, Error, JsonValue, NonFiniteFloat, Object};
 
 const RFC7159_EXAMPLE1: &str = r#"
 {
@@ -377,3 +377,42 @@ fn sdv_json_output() {
         json = temp;
     }
 }
+
+/*
+ * @title  ylong_json 非有限浮点数输出 sdv 测试用例
+ * @design 使用路径覆盖
+ * @precon 无
+ * @brief  1. 准备一个包含 NaN 和无穷大的 Json 实例
+ *         2. 使用默认配置输出
+ *         3. 通过 EncodeConfig 选择其他策略后输出
+ *         4. 校验输出结果
+ * @expect 1. 默认配置输出失败，选择其他策略后得到预期输出的字符串。
+ * @auto   是
+ */
+#[test]
+fn sdv_json_non_finite_float() {
+    let mut json = JsonValue::new_array(Array::new());
+    json[0] = f64::NAN.into();
+    json[1] = f64::INFINITY.into();
+    json[2] = 1.5.into();
+
+    // 默认配置下，NaN 和无穷大无法输出。
+    assert!(matches!(
+        json.to_compact_string(),
+        Err(Error::NonFiniteFloat)
+    ));
+    let mut vec = Vec::new();
+    assert!(json.formatted_encode(&mut vec).is_err());
+
+    let config = EncodeConfig::default().non_finite_float(NonFiniteFloat::Null);
+    assert_eq!(
+        json.to_compact_string_with_config(config).unwrap(),
+        "[null,null,1.5]"
+    );
+
+    let config = EncodeConfig::default().non_finite_float(NonFiniteFloat::Literal);
+    assert_eq!(
+        json.to_compact_string_with_config(config).unwrap(),
+        "[NaN,Infinity,1.5]"
+    );
+}
@@ -0,0 +1,204 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Derive macros of the `ToJson` and `FromJson` traits of ylong_json, which are used
+//! by the `derive` feature of ylong_json instead of depending on this crate directly.
+//!
+//! The types are converted as follows:
+//! * Structs with named fields are converted to Objects with the field names as keys.
+//!   Missing members are read as null, so that `Option` fields may be omitted.
+//! * Tuple structs with one field are converted the same as the field, and those with
+//!   more fields are converted to Arrays. Unit structs are converted to null.
+//! * Enums with only unit variants are converted to the names of the variants.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, Generics, Index, Path,
+};
+
+/// Derives `ylong_json::ToJson` for a struct or a unit-only enum.
+#[proc_macro_derive(ToJson)]
+pub fn derive_to_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_to_json(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `ylong_json::FromJson` for a struct or a unit-only enum.
+#[proc_macro_derive(FromJson)]
+pub fn derive_from_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_json(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_to_json(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let body = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => {
+                let inserts = fields.named.iter().map(|field| {
+                    let ident = field.ident.as_ref().unwrap();
+                    let key = ident.unraw().to_string();
+                    quote! {
+                        object.insert(#key, ::ylong_json::ToJson::to_json(&self.#ident));
+                    }
+                });
+                quote! {
+                    let mut object = ::ylong_json::Object::new();
+                    #(#inserts)*
+                    ::ylong_json::JsonValue::Object(object)
+                }
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                quote!(::ylong_json::ToJson::to_json(&self.0))
+            }
+            Fields::Unnamed(fields) => {
+                let indexes = (0..fields.unnamed.len()).map(Index::from);
+                quote! {
+                    let mut array = ::ylong_json::Array::new();
+                    #(array.push(::ylong_json::ToJson::to_json(&self.#indexes));)*
+                    ::ylong_json::JsonValue::Array(array)
+                }
+            }
+            Fields::Unit => quote!(::ylong_json::JsonValue::Null),
+        },
+        Data::Enum(_) => {
+            let variants = unit_variants(input)?;
+            let names = variants.iter().map(|v| v.unraw().to_string());
+            quote! {
+                let name = match self {
+                    #(Self::#variants => #names,)*
+                };
+                ::ylong_json::JsonValue::new_string(name)
+            }
+        }
+        Data::Union(_) => return Err(unsupported(input)),
+    };
+
+    let ident = &input.ident;
+    let generics = add_bounds(&input.generics, &parse_quote!(::ylong_json::ToJson));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::ylong_json::ToJson for #ident #ty_generics #where_clause {
+            fn to_json(&self) -> ::ylong_json::JsonValue {
+                #body
+            }
+        }
+    })
+}
+
+fn expand_from_json(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let body = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => {
+                let members = fields.named.iter().map(|field| {
+                    let ident = field.ident.as_ref().unwrap();
+                    let key = ident.unraw().to_string();
+                    quote!(#ident: ::ylong_json::from_member(object, #key)?)
+                });
+                quote! {
+                    let object = value.try_as_object()?;
+                    ::core::result::Result::Ok(Self { #(#members,)* })
+                }
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                quote!(::core::result::Result::Ok(Self(
+                    ::ylong_json::FromJson::from_json(value)?
+                )))
+            }
+            Fields::Unnamed(fields) => {
+                let len = fields.unnamed.len();
+                let elements = (0..len)
+                    .map(|i| quote!(::ylong_json::FromJson::from_json(array.get(#i).unwrap())?));
+                quote! {
+                    let array = value.try_as_array()?;
+                    if array.len() != #len {
+                        return ::core::result::Result::Err(::ylong_json::Error::TypeTransform);
+                    }
+                    ::core::result::Result::Ok(Self(#(#elements,)*))
+                }
+            }
+            Fields::Unit => quote! {
+                if value.is_null() {
+                    ::core::result::Result::Ok(Self)
+                } else {
+                    ::core::result::Result::Err(::ylong_json::Error::TypeTransform)
+                }
+            },
+        },
+        Data::Enum(_) => {
+            let variants = unit_variants(input)?;
+            let names = variants.iter().map(|v| v.unraw().to_string());
+            quote! {
+                match value.try_as_string()?.as_str() {
+                    #(#names => ::core::result::Result::Ok(Self::#variants),)*
+                    _ => ::core::result::Result::Err(::ylong_json::Error::TypeTransform),
+                }
+            }
+        }
+        Data::Union(_) => return Err(unsupported(input)),
+    };
+
+    let ident = &input.ident;
+    let generics = add_bounds(&input.generics, &parse_quote!(::ylong_json::FromJson));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::ylong_json::FromJson for #ident #ty_generics #where_clause {
+            fn from_json(
+                value: &::ylong_json::JsonValue,
+            ) -> ::core::result::Result<Self, ::ylong_json::Error> {
+                #body
+            }
+        }
+    })
+}
+
+/// Gets the variants of an enum, which must all be unit variants.
+fn unit_variants(input: &DeriveInput) -> Result<Vec<&syn::Ident>, Error> {
+    let Data::Enum(data) = &input.data else {
+        return Err(unsupported(input));
+    };
+    data.variants
+        .iter()
+        .map(|variant| match variant.fields {
+            Fields::Unit => Ok(&variant.ident),
+            _ => Err(Error::new_spanned(
+                variant,
+                "ToJson and FromJson can only be derived for enums with unit variants",
+            )),
+        })
+        .collect()
+}
+
+/// Adds the trait bound to every type parameter.
+fn add_bounds(generics: &Generics, bound: &Path) -> Generics {
+    let mut generics = generics.clone();
+    let params: Vec<_> = generics.type_params().map(|p| p.ident.clone()).collect();
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause.predicates.push(parse_quote!(#param: #bound));
+    }
+    generics
+}
+
+fn unsupported(input: &DeriveInput) -> Error {
+    Error::new_spanned(
+        &input.ident,
+        "ToJson and FromJson can only be derived for structs and enums",
+    )
+}
@@ -0,0 +1,132 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ylong_json::{FromJson, JsonValue, ToJson};
+
+#[derive(ToJson, FromJson, Debug, PartialEq)]
+struct Device {
+    id: u32,
+    name: String,
+    tags: Vec<String>,
+    mode: Mode,
+    position: Point<f64>,
+    owner: Option<Id>,
+}
+
+#[derive(ToJson, FromJson, Debug, PartialEq)]
+enum Mode {
+    On,
+    Off,
+}
+
+#[derive(ToJson, FromJson, Debug, PartialEq)]
+struct Point<T>(T, T);
+
+#[derive(ToJson, FromJson, Debug, PartialEq)]
+struct Id(u64);
+
+#[derive(ToJson, FromJson, Debug, PartialEq)]
+struct Empty;
+
+#[derive(ToJson, FromJson, Debug, PartialEq)]
+struct Raw {
+    r#type: Kind,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(ToJson, FromJson, Debug, PartialEq)]
+enum Kind {
+    r#struct,
+    r#match,
+}
+
+/// SDV test for `#[derive(ToJson, FromJson)]`.
+///
+/// # Title
+/// sdv_derive_round_trip
+///
+/// # Brief
+/// 1. Converts a struct with derived `ToJson` to `JsonValue`.
+/// 2. Converts the `JsonValue` back by the derived `FromJson`.
+/// 3. Checks if the test results are correct.
+#[test]
+fn sdv_derive_round_trip() {
+    let device = Device {
+        id: 7,
+        name: String::from("lamp"),
+        tags: vec![String::from("home")],
+        mode: Mode::Off,
+        position: Point(1.5, -2.0),
+        owner: Some(Id(42)),
+    };
+    let value = device.to_json();
+    assert_eq!(
+        value,
+        JsonValue::from_text(
+            r#"{"id": 7, "name": "lamp", "tags": ["home"], "mode": "Off",
+                "position": [1.5, -2.0], "owner": 42}"#
+        )
+        .unwrap()
+    );
+    assert_eq!(Device::from_json(&value).unwrap(), device);
+    assert_eq!(Empty.to_json(), JsonValue::Null);
+    assert_eq!(Empty::from_json(&JsonValue::Null).unwrap(), Empty);
+}
+
+/// SDV test for `#[derive(FromJson)]` with missing and invalid members.
+///
+/// # Title
+/// sdv_derive_from_json_errors
+///
+/// # Brief
+/// 1. Converts some `JsonValue`s with missing or invalid members by the derived `FromJson`.
+/// 2. Checks if the test results are correct.
+#[test]
+fn sdv_derive_from_json_errors() {
+    let text = r#"{"id": 7, "name": "lamp", "tags": [], "mode": "On", "position": [0, 0]}"#;
+    let device = Device::from_json(&JsonValue::from_text(text).unwrap()).unwrap();
+    assert_eq!(device.owner, None);
+    assert_eq!(device.mode, Mode::On);
+
+    let text = r#"{"id": 7, "tags": [], "mode": "On", "position": [0, 0]}"#;
+    let error = Device::from_json(&JsonValue::from_text(text).unwrap()).unwrap_err();
+    assert_eq!(error.to_string(), "missing field `name`");
+
+    assert!(Mode::from_json(&JsonValue::new_string("Standby")).is_err());
+    assert!(Point::<u8>::from_json(&JsonValue::from_text("[1, 2, 3]").unwrap()).is_err());
+    assert!(Point::<u8>::from_json(&JsonValue::from_text("[1, 256]").unwrap()).is_err());
+    assert!(Id::from_json(&JsonValue::from_text("-1").unwrap()).is_err());
+    assert!(Empty::from_json(&JsonValue::from(0)).is_err());
+}
+
+/// SDV test for `#[derive(ToJson, FromJson)]` with raw identifiers.
+///
+/// # Title
+/// sdv_derive_raw_identifiers
+///
+/// # Brief
+/// 1. Converts a struct with a raw field name and a raw variant name.
+/// 2. Checks if the keys and names are written without the `r#` prefix.
+#[test]
+fn sdv_derive_raw_identifiers() {
+    let raw = Raw {
+        r#type: Kind::r#match,
+    };
+    let value = raw.to_json();
+    assert_eq!(value, JsonValue::from_text(r#"{"type": "match"}"#).unwrap());
+    assert_eq!(Raw::from_json(&value).unwrap(), raw);
+
+    let value = JsonValue::from_text(r#"{"type": "struct"}"#).unwrap();
+    assert_eq!(Raw::from_json(&value).unwrap().r#type, Kind::r#struct);
+    assert!(Raw::from_json(&JsonValue::from_text(r#"{"r#type": "match"}"#).unwrap()).is_err());
+}